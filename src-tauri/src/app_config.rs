@@ -144,6 +144,9 @@ pub struct InstalledSkill {
     /// 仓库分支
     #[serde(skip_serializing_if = "Option::is_none")]
     pub repo_branch: Option<String>,
+    /// 固定的提交或标签；设置后 `skills update` 不再跟随分支更新
+    #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
+    pub pinned_ref: Option<String>,
     /// README URL
     #[serde(skip_serializing_if = "Option::is_none")]
     pub readme_url: Option<String>,
@@ -672,6 +675,7 @@ impl MultiAppConfig {
             enabled: true, // 自动启用
             created_at: Some(timestamp),
             updated_at: Some(timestamp),
+            sort_index: None,
         };
 
         // 插入到对应的应用配置中
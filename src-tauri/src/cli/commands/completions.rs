@@ -0,0 +1,147 @@
+use clap::{Args, CommandFactory, Subcommand};
+use clap_complete::Shell;
+use std::path::{Path, PathBuf};
+
+use crate::cli::i18n::texts;
+use crate::cli::ui::{info, success};
+use crate::cli::Cli;
+use crate::config::atomic_write;
+use crate::error::AppError;
+
+#[derive(Args, Debug, Clone)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct CompletionsCommand {
+    /// The shell to generate completions for
+    #[arg(value_enum, required = true)]
+    pub shell: Option<Shell>,
+
+    /// Write the script into the shell's user completion directory instead of stdout
+    #[arg(long)]
+    pub install: bool,
+
+    #[command(subcommand)]
+    pub action: Option<CompletionsAction>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum CompletionsAction {
+    /// Rewrite the installed completion script so it covers newly added commands
+    Refresh {
+        /// Shell to refresh (detected from $SHELL when omitted)
+        #[arg(long, value_enum)]
+        shell: Option<Shell>,
+    },
+}
+
+pub fn execute(cmd: CompletionsCommand) -> Result<(), AppError> {
+    if let Some(CompletionsAction::Refresh { shell }) = cmd.action {
+        return refresh(shell);
+    }
+
+    let shell = cmd.shell.ok_or_else(|| {
+        AppError::localized(
+            "completions.shell_required",
+            "请指定 shell（bash、zsh、fish、elvish 或 powershell）",
+            "Specify a shell (bash, zsh, fish, elvish or powershell)",
+        )
+    })?;
+    if cmd.install {
+        return install(shell);
+    }
+    crate::cli::generate_completions(shell);
+    Ok(())
+}
+
+/// 用户级补全脚本的安装位置；Elvish / PowerShell 没有约定目录，返回 None
+pub fn completion_install_path(shell: Shell, home: &Path) -> Option<PathBuf> {
+    let name = Cli::command().get_name().to_string();
+    match shell {
+        Shell::Bash => Some(
+            home.join(".local/share/bash-completion/completions")
+                .join(&name),
+        ),
+        Shell::Zsh => Some(home.join(".zfunc").join(format!("_{name}"))),
+        Shell::Fish => Some(
+            home.join(".config/fish/completions")
+                .join(format!("{name}.fish")),
+        ),
+        _ => None,
+    }
+}
+
+fn completion_script(shell: Shell) -> Vec<u8> {
+    let mut cmd = Cli::command();
+    let name = cmd.get_name().to_string();
+    let mut script = Vec::new();
+    clap_complete::generate(shell, &mut cmd, name, &mut script);
+    script
+}
+
+fn install_path_for(shell: Shell) -> Result<PathBuf, AppError> {
+    let home = dirs::home_dir().ok_or_else(|| {
+        AppError::localized(
+            "completions.home_missing",
+            "无法获取用户主目录",
+            "Cannot determine the home directory",
+        )
+    })?;
+    completion_install_path(shell, &home).ok_or_else(|| {
+        AppError::localized(
+            "completions.unsupported_shell",
+            format!("{shell} 没有约定的补全目录，请重定向 `cc-switch completions {shell}` 的输出"),
+            format!(
+                "{shell} has no conventional completion directory; redirect `cc-switch completions {shell}` instead"
+            ),
+        )
+    })
+}
+
+fn install(shell: Shell) -> Result<(), AppError> {
+    let path = install_path_for(shell)?;
+    atomic_write(&path, &completion_script(shell))?;
+    println!(
+        "{}",
+        success(&texts::completions_installed(
+            &shell.to_string(),
+            &path.display().to_string()
+        ))
+    );
+    if shell == Shell::Zsh {
+        println!("{}", info(texts::completions_zsh_fpath_hint()));
+    }
+    Ok(())
+}
+
+fn refresh(shell: Option<Shell>) -> Result<(), AppError> {
+    let shell = shell.or_else(Shell::from_env).ok_or_else(|| {
+        AppError::localized(
+            "completions.shell_unknown",
+            "无法从 $SHELL 识别当前 shell，请使用 --shell 指定",
+            "Cannot detect the shell from $SHELL; pass --shell",
+        )
+    })?;
+    let path = install_path_for(shell)?;
+    if !path.exists() {
+        return Err(AppError::localized(
+            "completions.not_installed",
+            format!(
+                "未找到已安装的 {shell} 补全脚本: {}，请先运行 `cc-switch completions {shell} --install`",
+                path.display()
+            ),
+            format!(
+                "No installed {shell} completion script at {}; run `cc-switch completions {shell} --install` first",
+                path.display()
+            ),
+        ));
+    }
+
+    atomic_write(&path, &completion_script(shell))?;
+    println!(
+        "{}",
+        success(&texts::completions_refreshed(
+            &shell.to_string(),
+            &path.display().to_string()
+        ))
+    );
+    Ok(())
+}
@@ -2,36 +2,129 @@ use clap::Subcommand;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use crate::app_config::AppType;
+use crate::app_config::{AppType, MultiAppConfig};
+use crate::cli::commands::config_backup;
+use crate::cli::commands::config_bundle;
 use crate::cli::commands::config_common;
+use crate::cli::commands::config_db_check;
+use crate::cli::commands::config_hooks;
+use crate::cli::commands::config_prefs;
+use crate::cli::commands::config_transfer;
+use crate::cli::commands::config_validate;
 use crate::cli::commands::config_webdav;
 use crate::cli::i18n::texts;
-use crate::cli::ui::{error, highlight, info, success, to_json};
+use crate::cli::ui::{emit_output, error, highlight, info, output_is_stdout, success, to_json};
+use crate::cli::{selected_apps, single_app, AppSelection};
 use crate::error::AppError;
-use crate::services::ConfigService;
+use crate::redact::{redact, MaskStyle, RedactLevel};
+use crate::services::{BundleSelection, ConfigService, LegacyImportOutcome, ProviderService};
 use crate::store::AppState;
 
 #[derive(Subcommand)]
 pub enum ConfigCommand {
     /// Show current configuration
-    Show,
+    Show {
+        /// How much to mask: none, secrets (API keys/tokens) or all (also URLs and models)
+        #[arg(long, value_enum, default_value_t = RedactLevel::Secrets)]
+        redact_level: RedactLevel,
+        /// Write the JSON to this file atomically instead of stdout (`-` for stdout)
+        #[arg(long, value_name = "FILE")]
+        output: Option<PathBuf>,
+        /// Only include each app's current provider (other providers are summarised as a count)
+        #[arg(long)]
+        only_current_providers: bool,
+    },
     /// Show configuration file path
     Path,
+    /// Print every app's live config files with section headers, for bug reports
+    /// (use the global --app to limit the apps)
+    Dump {
+        /// How much to mask: none, secrets (API keys/tokens) or all (also URLs and models)
+        #[arg(long, value_enum, default_value_t = RedactLevel::Secrets)]
+        redact_level: RedactLevel,
+        /// Print JSON with each file's parsed content, grouped by app
+        #[arg(long)]
+        json: bool,
+    },
     /// Export configuration to file
     Export {
         /// Output file path
         file: PathBuf,
+        /// Write a redacted JSON snapshot for sharing instead of an importable SQL backup
+        /// (the SQL backup is a full dump that already includes switch history and usage data)
+        #[arg(long, value_enum)]
+        redact_level: Option<RedactLevel>,
+        /// Also export archived providers (left out by default)
+        #[arg(long)]
+        include_archived: bool,
+        /// File format; `toml` writes only Codex providers (requires `--app codex`)
+        #[arg(long, value_enum, default_value_t = ConfigFileFormat::Sql, conflicts_with = "redact_level")]
+        format: ConfigFileFormat,
+        /// Deterministic SQL output for stable diffs: rows sorted, JSON keys sorted, no timestamp
+        #[arg(long, conflicts_with_all = ["redact_level", "format"])]
+        canonical: bool,
     },
     /// Import configuration from file
     Import {
-        /// Input file path
+        /// Input file path, or an https:// URL to download it from
+        #[arg(value_name = "FILE|URL")]
+        file: String,
+        /// Allow downloading from a plain http:// URL
+        #[arg(long)]
+        allow_http: bool,
+        /// File format; `toml` adds or updates Codex providers (requires `--app codex`)
+        #[arg(long, value_enum, default_value_t = ConfigFileFormat::Sql)]
+        format: ConfigFileFormat,
+        /// Print the backup ID and per-app change counts as JSON
+        #[arg(long, conflicts_with = "format")]
+        json: bool,
+    },
+    /// Export a shareable team bundle: selected providers plus MCP servers and prompts
+    ExportBundle {
+        /// Output file path
         file: PathBuf,
+        /// Include providers with this tag (repeatable)
+        #[arg(long = "tag", value_name = "TAG")]
+        tags: Vec<String>,
+        /// Include the provider with this id (repeatable)
+        #[arg(long = "id", value_name = "ID")]
+        ids: Vec<String>,
+        /// Mask API keys, tokens and other secrets in the bundle
+        #[arg(long)]
+        redact: bool,
+    },
+    /// Merge a team bundle; entries whose id already exists are kept as-is
+    ImportBundle {
+        /// Bundle file path, or an https:// URL to download it from
+        #[arg(value_name = "FILE|URL")]
+        file: String,
+        /// Allow downloading from a plain http:// URL
+        #[arg(long)]
+        allow_http: bool,
+        /// Report each provider as new, identical or conflicting with the local one
+        /// (by comparing settings) without importing anything
+        #[arg(long)]
+        preview: bool,
     },
-    /// Create a backup of current configuration
+    /// Create a backup of current configuration (or `backup list` to browse them)
+    #[command(args_conflicts_with_subcommands = true)]
     Backup {
+        #[command(subcommand)]
+        action: Option<BackupCommand>,
         /// Optional custom name for the backup
-        #[arg(long)]
+        #[arg(long, conflicts_with = "auto")]
         name: Option<String>,
+        /// Free-form note explaining why the backup was made (searchable with `backup list`)
+        #[arg(long, conflicts_with = "auto")]
+        note: Option<String>,
+        /// Back up automatically whenever cc-switch runs and the last automatic backup is at
+        /// least this many hours old (0 = off); only updates the setting
+        #[arg(long, value_name = "HOURS")]
+        auto: Option<u64>,
+        /// Cap the total size of the backup directory in MB (0 = off); new backups delete the
+        /// oldest ones to stay under it. Only updates the setting
+        #[arg(long, value_name = "MB", conflicts_with_all = ["name", "note", "auto"])]
+        max_mb: Option<u64>,
     },
     /// Restore from a backup
     Restore {
@@ -42,12 +135,105 @@ pub enum ConfigCommand {
         /// External file path to restore from
         #[arg(long, conflicts_with = "backup")]
         file: Option<PathBuf>,
+
+        /// Print the pre-restore backup ID and per-app change counts as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Validate configuration file (use the global --app to check a single app)
+    Validate {
+        /// Print every check with its `ok` status as JSON (exit code still reflects health)
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check the database for duplicate provider IDs
+    DbCheck {
+        /// Back up, then re-key duplicates with a suffix so every row is kept
+        #[arg(long)]
+        repair: bool,
     },
-    /// Validate configuration file
-    Validate,
     /// Reset to default configuration
     Reset,
 
+    /// Import legacy Claude `settings-*.json` files as providers
+    MigrateLegacy {
+        /// Delete legacy files that were imported or already exist as providers
+        #[arg(long)]
+        cleanup: bool,
+    },
+
+    /// Show or set how secrets are masked on screen
+    MaskStyle {
+        /// New style; omit to show the current one
+        #[arg(value_enum)]
+        style: Option<MaskStyle>,
+    },
+
+    /// Show or replace extra key patterns masked by redaction (glob, or `re:` regex)
+    RedactionPatterns {
+        /// Pattern to treat as secret (repeatable), e.g. `*_ORG_ID` or `re:^X_.*_SID$`
+        #[arg(long = "set", value_name = "PATTERN")]
+        patterns: Vec<String>,
+        /// Remove all custom patterns
+        #[arg(long, conflicts_with = "patterns")]
+        clear: bool,
+    },
+
+    /// Show or set the notes template pre-filled when adding a provider
+    NotesTemplate {
+        /// Template text, e.g. "owner: , tier: , limit: "
+        #[arg(long = "set", value_name = "TEXT")]
+        template: Option<String>,
+        /// Remove the template (notes start empty again)
+        #[arg(long, conflicts_with = "template")]
+        clear: bool,
+    },
+
+    /// Show or set the default timeout for network operations (speedtest, update check, skills,
+    /// usage scripts, WebDAV); the global --network-timeout-ms overrides it for one run
+    NetworkTimeout {
+        /// Timeout in milliseconds
+        #[arg(long = "set", value_name = "MS", value_parser = clap::value_parser!(u64).range(1..))]
+        timeout_ms: Option<u64>,
+        /// Remove the setting (each operation uses its built-in default)
+        #[arg(long, conflicts_with = "timeout_ms")]
+        clear: bool,
+    },
+
+    /// Show or set how long the TUI shows a revealed API key before masking it again
+    SecretRevealTimeout {
+        /// Seconds; 0 keeps the key visible until toggled off
+        #[arg(long = "set", value_name = "SECS")]
+        secs: Option<u64>,
+        /// Remove the setting (use the built-in default)
+        #[arg(long, conflicts_with = "secs")]
+        clear: bool,
+    },
+
+    /// Show or set the shell commands run before/after a provider switch; with the global
+    /// --app the commands override the global ones for that app. An empty value removes a hook
+    SwitchHooks {
+        /// Command run before switching; a non-zero exit aborts the switch
+        #[arg(long = "pre", value_name = "COMMAND")]
+        pre_switch: Option<String>,
+        /// Command run after a successful switch; failures only warn
+        #[arg(long = "post", value_name = "COMMAND")]
+        post_switch: Option<String>,
+        /// Remove both hooks (for the --app override, or the global ones)
+        #[arg(long, conflicts_with_all = ["pre_switch", "post_switch"])]
+        clear: bool,
+    },
+
+    /// Show or set the model pre-filled when adding a provider (per app, use the global --app)
+    DefaultModel {
+        /// Model name, e.g. "claude-sonnet-4-5" or "gpt-5.2-codex"
+        #[arg(long = "set", value_name = "MODEL")]
+        model: Option<String>,
+        /// Remove the default model for this app
+        #[arg(long, conflicts_with = "model")]
+        clear: bool,
+    },
+
     /// Manage common configuration snippet (per app)
     #[command(subcommand)]
     Common(config_common::CommonConfigCommand),
@@ -57,18 +243,122 @@ pub enum ConfigCommand {
     WebDav(config_webdav::WebDavCommand),
 }
 
-pub fn execute(cmd: ConfigCommand, app: Option<AppType>) -> Result<(), AppError> {
+/// `config export/import` 的文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ConfigFileFormat {
+    /// Full SQL dump of the database
+    Sql,
+    /// Codex providers only, one table per provider
+    Toml,
+}
+
+#[derive(Subcommand)]
+pub enum BackupCommand {
+    /// List backups with their notes (newest first)
+    List {
+        /// Only show backups whose ID, name or note contains this text
+        #[arg(long)]
+        search: Option<String>,
+    },
+}
+
+pub fn execute(cmd: ConfigCommand, selection: Option<AppSelection>) -> Result<(), AppError> {
+    // 只读的 validate 支持 `--app all`，其余子命令只接受单个应用
+    let app = match cmd {
+        ConfigCommand::Validate { .. } | ConfigCommand::Dump { .. } => None,
+        _ => single_app(selection.clone())?,
+    };
+
     match cmd {
-        ConfigCommand::Show => show_config(),
+        ConfigCommand::Show {
+            redact_level,
+            output,
+            only_current_providers,
+        } => show_config(redact_level, output.as_deref(), only_current_providers),
         ConfigCommand::Path => show_path(),
-        ConfigCommand::Export { file } => export_config(&file),
-        ConfigCommand::Import { file } => import_config(&file),
-        ConfigCommand::Backup { name } => backup_config(name.as_deref()),
-        ConfigCommand::Restore { backup, file } => {
-            restore_config(backup.as_deref(), file.as_deref())
+        ConfigCommand::Dump { redact_level, json } => {
+            let all: Vec<AppType> = AppType::all().collect();
+            config_validate::dump_live(&selected_apps(selection.as_ref(), &all), redact_level, json)
+        }
+        ConfigCommand::Export {
+            file,
+            include_archived,
+            format: ConfigFileFormat::Toml,
+            ..
+        } => config_transfer::export_codex_toml(&file, app, include_archived),
+        ConfigCommand::Export {
+            file,
+            redact_level,
+            include_archived,
+            format: ConfigFileFormat::Sql,
+            canonical,
+        } => config_transfer::export_config(&file, redact_level, include_archived, canonical),
+        ConfigCommand::Import {
+            file,
+            format: ConfigFileFormat::Toml,
+            ..
+        } => config_transfer::import_codex_toml(Path::new(&file), app),
+        ConfigCommand::Import {
+            file,
+            allow_http,
+            format: ConfigFileFormat::Sql,
+            json,
+        } => config_transfer::import_config(&file, allow_http, json),
+        ConfigCommand::ExportBundle {
+            file,
+            tags,
+            ids,
+            redact,
+        } => config_bundle::export_bundle(&file, BundleSelection { tags, ids }, redact),
+        ConfigCommand::ImportBundle {
+            file,
+            allow_http,
+            preview,
+        } => config_bundle::import_bundle(&file, allow_http, preview),
+        ConfigCommand::Backup {
+            action: Some(BackupCommand::List { search }),
+            ..
+        } => config_backup::list_backups(search.as_deref()),
+        ConfigCommand::Backup {
+            auto: Some(hours), ..
+        } => config_backup::auto_backup(hours),
+        ConfigCommand::Backup {
+            max_mb: Some(mb), ..
+        } => config_backup::backup_max_size(mb),
+        ConfigCommand::Backup { name, note, .. } => {
+            config_backup::backup_config(name.as_deref(), note.as_deref())
         }
-        ConfigCommand::Validate => validate_config(),
+        ConfigCommand::Restore { backup, file, json } => {
+            config_backup::restore_config(backup.as_deref(), file.as_deref(), json)
+        }
+        ConfigCommand::Validate { json } => {
+            let all: Vec<AppType> = AppType::all().collect();
+            config_validate::validate_config(&selected_apps(selection.as_ref(), &all), json)
+        }
+        ConfigCommand::DbCheck { repair } => config_db_check::execute(repair),
         ConfigCommand::Reset => reset_config(),
+        ConfigCommand::MigrateLegacy { cleanup } => migrate_legacy(cleanup),
+        ConfigCommand::MaskStyle { style } => config_prefs::mask_style(style),
+        ConfigCommand::RedactionPatterns { patterns, clear } => {
+            config_prefs::redaction_patterns(patterns, clear)
+        }
+        ConfigCommand::NotesTemplate { template, clear } => {
+            config_prefs::notes_template(template, clear)
+        }
+        ConfigCommand::NetworkTimeout { timeout_ms, clear } => {
+            config_prefs::network_timeout(timeout_ms, clear)
+        }
+        ConfigCommand::SecretRevealTimeout { secs, clear } => {
+            config_prefs::secret_reveal_timeout(secs, clear)
+        }
+        ConfigCommand::SwitchHooks {
+            pre_switch,
+            post_switch,
+            clear,
+        } => config_hooks::execute(app, pre_switch, post_switch, clear),
+        ConfigCommand::DefaultModel { model, clear } => {
+            config_prefs::default_model(app.unwrap_or(AppType::Claude), model, clear)
+        }
         ConfigCommand::Common(cmd) => config_common::execute(cmd, app.unwrap_or(AppType::Claude)),
         ConfigCommand::WebDav(cmd) => config_webdav::execute(cmd),
     }
@@ -78,18 +368,100 @@ fn get_state() -> Result<AppState, AppError> {
     AppState::try_new()
 }
 
-fn show_config() -> Result<(), AppError> {
+/// 当前配置的 JSON 表示，按级别脱敏
+pub(super) fn redacted_config_value(
+    level: RedactLevel,
+    include_archived: bool,
+    only_current: bool,
+) -> Result<serde_json::Value, AppError> {
     let state = get_state()?;
-    let config = state.config.read()?;
+    let config = state.config.read()?.clone();
+    config_snapshot_value(config, level, include_archived, only_current)
+}
 
-    println!("{}", highlight("Current Configuration"));
-    println!("{}", "=".repeat(50));
-    println!();
+/// 将配置裁剪后序列化并脱敏
+///
+/// `only_current` 时，非累加模式的应用只保留当前供应商，其余以 `otherProviders` 计数代替。
+fn config_snapshot_value(
+    mut config: MultiAppConfig,
+    level: RedactLevel,
+    include_archived: bool,
+    only_current: bool,
+) -> Result<serde_json::Value, AppError> {
+    let mut omitted = Vec::new();
+    for app_type in AppType::all() {
+        let Some(manager) = config.get_manager_mut(&app_type) else {
+            continue;
+        };
+        if !include_archived {
+            manager
+                .providers
+                .retain(|_, provider| !provider.is_archived());
+        }
+        if only_current && !app_type.is_additive_mode() {
+            let before = manager.providers.len();
+            let current = manager.current.clone();
+            manager.providers.retain(|id, _| *id == current);
+            omitted.push((app_type, before - manager.providers.len()));
+        }
+    }
+
+    let mut value =
+        serde_json::to_value(&config).map_err(|source| AppError::JsonSerialize { source })?;
+    for (app_type, count) in omitted {
+        if let Some(app) = value
+            .get_mut(app_type.as_str())
+            .and_then(|app| app.as_object_mut())
+        {
+            app.insert("otherProviders".to_string(), serde_json::json!(count));
+        }
+    }
+    redact(&mut value, level);
+    Ok(value)
+}
+
+fn show_config(
+    redact_level: RedactLevel,
+    output: Option<&Path>,
+    only_current: bool,
+) -> Result<(), AppError> {
+    let value = redacted_config_value(redact_level, true, only_current)?;
 
     // Display in pretty JSON format
-    let json = to_json(&*config).map_err(|e| AppError::Message(e.to_string()))?;
-    println!("{}", json);
+    let json = to_json(&value).map_err(|e| AppError::Message(e.to_string()))?;
+    if output_is_stdout(output) {
+        println!("{}", highlight("Current Configuration"));
+        println!("{}", "=".repeat(50));
+        println!();
+    }
+    emit_output(output, &json)
+}
 
+fn migrate_legacy(cleanup: bool) -> Result<(), AppError> {
+    let state = get_state()?;
+    let results = ProviderService::migrate_legacy_claude_files(&state, cleanup)?;
+    if results.is_empty() {
+        println!("{}", info(texts::legacy_settings_none_found()));
+        return Ok(());
+    }
+
+    for item in &results {
+        let file = item
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let removed = if item.removed { " (removed)" } else { "" };
+        let line = match &item.outcome {
+            LegacyImportOutcome::Imported(id) => success(&format!("✓ {file} → {id}{removed}")),
+            LegacyImportOutcome::Duplicate(id) => info(&format!("= {file} = {id}{removed}")),
+            LegacyImportOutcome::Invalid(reason) => error(&format!("✗ {file}: {reason}")),
+        };
+        println!("  {line}");
+    }
+    if !cleanup {
+        println!("{}", info(texts::legacy_settings_cleanup_hint()));
+    }
     Ok(())
 }
 
@@ -130,352 +502,6 @@ fn show_path() -> Result<(), AppError> {
     Ok(())
 }
 
-fn export_config(file: &PathBuf) -> Result<(), AppError> {
-    println!(
-        "{}",
-        info(&format!("Exporting configuration to {}...", file.display()))
-    );
-
-    // Check if target file already exists
-    if file.exists() {
-        let confirm = inquire::Confirm::new(&format!(
-            "File '{}' already exists. Overwrite?",
-            file.display()
-        ))
-        .with_default(false)
-        .prompt()
-        .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
-
-        if !confirm {
-            println!("{}", info("Cancelled."));
-            return Ok(());
-        }
-    }
-
-    // Ensure parent directory exists
-    if let Some(parent) = file.parent() {
-        fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
-    }
-
-    // Export configuration
-    ConfigService::export_config_to_path(file)?;
-
-    println!(
-        "{}",
-        success(&format!("✓ Configuration exported to {}", file.display()))
-    );
-
-    Ok(())
-}
-
-fn import_config(file: &PathBuf) -> Result<(), AppError> {
-    println!(
-        "{}",
-        info(&format!(
-            "Importing configuration from {}...",
-            file.display()
-        ))
-    );
-
-    // Check if source file exists
-    if !file.exists() {
-        return Err(AppError::Message(format!(
-            "File '{}' not found",
-            file.display()
-        )));
-    }
-
-    // Confirm import
-    println!();
-    println!("{}", highlight("Warning:"));
-    println!("This will replace your current database with the imported SQL backup.");
-    println!("A backup will be created automatically.");
-    println!();
-
-    let confirm = inquire::Confirm::new("Continue with import?")
-        .with_default(false)
-        .prompt()
-        .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
-
-    if !confirm {
-        println!("{}", info("Cancelled."));
-        return Ok(());
-    }
-
-    // Perform import
-    let state = get_state()?;
-    let backup_id = ConfigService::import_config_from_path(file, &state)?;
-
-    // 导入后同步 live 配置
-    if let Err(e) = crate::services::provider::ProviderService::sync_current_to_live(&state) {
-        log::warn!("配置导入后同步 live 配置失败: {e}");
-    }
-
-    println!(
-        "{}",
-        success(&format!("✓ Configuration imported from {}", file.display()))
-    );
-    if !backup_id.is_empty() {
-        println!("{}", info(&format!("  Backup created: {}", backup_id)));
-    }
-    println!();
-    println!(
-        "{}",
-        info("Note: Restart your CLI clients to apply the changes.")
-    );
-
-    Ok(())
-}
-
-fn backup_config(custom_name: Option<&str>) -> Result<(), AppError> {
-    let config_path = crate::config::get_app_config_path();
-
-    if let Some(name) = custom_name {
-        println!(
-            "{}",
-            info(&format!("Creating backup with name '{}'...", name))
-        );
-    } else {
-        println!("{}", info("Creating backup of current configuration..."));
-    }
-
-    let backup_id = ConfigService::create_backup(&config_path, custom_name.map(|s| s.to_string()))?;
-
-    if backup_id.is_empty() {
-        println!("{}", error("Failed to create backup."));
-    } else {
-        let backup_dir = config_path.parent().unwrap().join("backups");
-        let backup_file = backup_dir.join(format!("{}.sql", backup_id));
-
-        println!("{}", success(&format!("✓ Backup created: {}", backup_id)));
-        println!("Location: {}", backup_file.display());
-    }
-
-    Ok(())
-}
-
-fn restore_config(backup_id: Option<&str>, file_path: Option<&Path>) -> Result<(), AppError> {
-    let config_path = crate::config::get_app_config_path();
-
-    // 情况1：指定了备份 ID
-    if let Some(id) = backup_id {
-        println!("{}", info(&format!("Restoring from backup '{}'...", id)));
-
-        let confirm =
-            inquire::Confirm::new("This will replace your current configuration. Continue?")
-                .with_default(false)
-                .prompt()
-                .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
-
-        if !confirm {
-            println!("{}", info("Cancelled."));
-            return Ok(());
-        }
-
-        let state = get_state()?;
-        let pre_restore_backup = ConfigService::restore_from_backup_id(id, &state)?;
-
-        // 恢复后同步 live 配置
-        if let Err(e) = crate::services::provider::ProviderService::sync_current_to_live(&state) {
-            log::warn!("备份恢复后同步 live 配置失败: {e}");
-        }
-
-        println!(
-            "{}",
-            success(&format!("✓ Configuration restored from backup '{}'", id))
-        );
-        if !pre_restore_backup.is_empty() {
-            println!(
-                "{}",
-                info(&format!("  Pre-restore backup: {}", pre_restore_backup))
-            );
-        }
-        println!();
-        println!(
-            "{}",
-            info("Note: Restart your CLI clients to apply the changes.")
-        );
-
-        return Ok(());
-    }
-
-    // 情况2：指定了文件路径
-    if let Some(file) = file_path {
-        println!(
-            "{}",
-            info(&format!(
-                "Restoring configuration from {}...",
-                file.display()
-            ))
-        );
-
-        if !file.exists() {
-            return Err(AppError::Message(format!(
-                "File '{}' not found",
-                file.display()
-            )));
-        }
-
-        println!();
-        println!("{}", highlight("Warning:"));
-        println!("This will replace your current database with the SQL backup file.");
-        println!("A backup of the current state will be created first.");
-        println!();
-
-        let confirm = inquire::Confirm::new(texts::config_restore_confirm_prompt())
-            .with_default(false)
-            .prompt()
-            .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
-
-        if !confirm {
-            println!("{}", info("Cancelled."));
-            return Ok(());
-        }
-
-        let state = get_state()?;
-        let pre_restore_backup = ConfigService::import_config_from_path(file, &state)?;
-
-        // 恢复后同步 live 配置
-        if let Err(e) = crate::services::provider::ProviderService::sync_current_to_live(&state) {
-            log::warn!("配置恢复后同步 live 配置失败: {e}");
-        }
-
-        println!(
-            "{}",
-            success(&format!("✓ Configuration restored from {}", file.display()))
-        );
-        if !pre_restore_backup.is_empty() {
-            println!(
-                "{}",
-                info(&format!("  Pre-restore backup: {}", pre_restore_backup))
-            );
-        }
-        println!();
-        println!(
-            "{}",
-            info("Note: Restart your CLI clients to apply the changes.")
-        );
-
-        return Ok(());
-    }
-
-    // 情况3：无参数，显示交互式列表
-    println!("{}", highlight(texts::available_backups()));
-    println!("{}", "=".repeat(50));
-
-    let backups = ConfigService::list_backups(&config_path)?;
-
-    if backups.is_empty() {
-        println!();
-        println!("{}", info(texts::no_backups_found()));
-        println!("{}", info(texts::create_backup_first_hint()));
-        return Ok(());
-    }
-
-    println!();
-    println!("{}", texts::found_backups(backups.len()));
-    println!();
-
-    let choices: Vec<String> = backups
-        .iter()
-        .map(|b| format!("{} - {}", b.display_name, b.id))
-        .collect();
-
-    let selection = inquire::Select::new(texts::select_backup_to_restore(), choices)
-        .prompt()
-        .map_err(|_| AppError::Message(texts::selection_cancelled().to_string()))?;
-
-    let selected_backup = backups
-        .iter()
-        .find(|b| selection.contains(&b.id))
-        .ok_or_else(|| AppError::Message(texts::invalid_selection().to_string()))?;
-
-    println!();
-    println!("{}", highlight(texts::warning_title()));
-    println!("{}", texts::config_restore_warning_replace());
-    println!("{}", texts::config_restore_warning_pre_backup());
-    println!();
-
-    let confirm = inquire::Confirm::new(texts::config_restore_confirm_prompt())
-        .with_default(false)
-        .prompt()
-        .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
-
-    if !confirm {
-        println!("{}", info(texts::cancelled()));
-        return Ok(());
-    }
-
-    let state = get_state()?;
-    let pre_restore_backup = ConfigService::restore_from_backup_id(&selected_backup.id, &state)?;
-
-    // 恢复后同步 live 配置
-    if let Err(e) = crate::services::provider::ProviderService::sync_current_to_live(&state) {
-        log::warn!("备份恢复后同步 live 配置失败: {e}");
-    }
-
-    println!(
-        "{}",
-        success(&format!(
-            "✓ Configuration restored from: {}",
-            selected_backup.display_name
-        ))
-    );
-    if !pre_restore_backup.is_empty() {
-        println!(
-            "{}",
-            info(&format!("  Pre-restore backup: {}", pre_restore_backup))
-        );
-    }
-    println!();
-    println!(
-        "{}",
-        info("Note: Restart your CLI clients to apply the changes.")
-    );
-
-    Ok(())
-}
-
-fn validate_config() -> Result<(), AppError> {
-    let config_dir = crate::config::get_app_config_dir();
-    let db_path = config_dir.join("cc-switch.db");
-
-    println!("{}", info("Validating database..."));
-    println!();
-
-    if !db_path.exists() {
-        println!("{}", error("✗ Database file does not exist"));
-        println!("Path: {}", db_path.display());
-        return Ok(());
-    }
-
-    println!("{} Database file exists", success("✓"));
-    println!("Path: {}", db_path.display());
-
-    let db = crate::Database::init()?;
-    println!("{} Database schema is readable", success("✓"));
-
-    // Show some stats
-    let claude_count = db.get_all_providers("claude")?.len();
-    let codex_count = db.get_all_providers("codex")?.len();
-    let gemini_count = db.get_all_providers("gemini")?.len();
-    let mcp_count = db.get_all_mcp_servers()?.len();
-    let skills_count = db.get_all_installed_skills()?.len();
-
-    println!();
-    println!("{}", highlight("Database Summary:"));
-    println!("Claude providers:  {}", claude_count);
-    println!("Codex providers:   {}", codex_count);
-    println!("Gemini providers:  {}", gemini_count);
-    println!("MCP servers:       {}", mcp_count);
-    println!("Skills installed:  {}", skills_count);
-
-    println!();
-    println!("{}", success("✓ Database validation passed"));
-
-    Ok(())
-}
-
 fn reset_config() -> Result<(), AppError> {
     println!("{}", highlight("Reset Configuration"));
     println!("{}", "=".repeat(50));
@@ -522,3 +548,51 @@ fn reset_config() -> Result<(), AppError> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::Provider;
+    use serde_json::json;
+
+    fn claude_provider(id: &str, token: &str) -> Provider {
+        Provider::with_id(
+            id.to_string(),
+            id.to_string(),
+            json!({ "env": { "ANTHROPIC_AUTH_TOKEN": token } }),
+            None,
+        )
+    }
+
+    #[test]
+    fn only_current_providers_keeps_current_and_counts_others() {
+        let mut config = MultiAppConfig::default();
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        for (id, token) in [
+            ("main", "sk-main-secret-1234"),
+            ("backup", "sk-backup-5678"),
+        ] {
+            manager
+                .providers
+                .insert(id.to_string(), claude_provider(id, token));
+        }
+        manager.current = "main".to_string();
+
+        let value =
+            config_snapshot_value(config.clone(), RedactLevel::Secrets, true, true).expect("value");
+        let claude = &value["claude"];
+        let providers = claude["providers"].as_object().expect("providers");
+        assert_eq!(providers.keys().collect::<Vec<_>>(), vec!["main"]);
+        assert_eq!(claude["otherProviders"], json!(1));
+        let token = claude["providers"]["main"]["settingsConfig"]["env"]["ANTHROPIC_AUTH_TOKEN"]
+            .as_str()
+            .expect("token");
+        assert_ne!(token, "sk-main-secret-1234");
+
+        let full = config_snapshot_value(config, RedactLevel::Secrets, true, false).expect("value");
+        assert_eq!(full["claude"]["providers"].as_object().unwrap().len(), 2);
+        assert!(full["claude"].get("otherProviders").is_none());
+    }
+}
@@ -0,0 +1,261 @@
+use std::path::Path;
+
+use crate::cli::i18n::texts;
+use crate::cli::ui::{create_table, error, highlight, info, success, warning};
+use crate::error::AppError;
+use crate::services::ConfigService;
+use crate::store::AppState;
+
+use super::config_transfer::{print_replace_outcome, reload_after_replace};
+
+fn get_state() -> Result<AppState, AppError> {
+    AppState::try_new()
+}
+
+pub(super) fn auto_backup(hours: u64) -> Result<(), AppError> {
+    crate::settings::set_auto_backup_interval_hours(hours)?;
+    match crate::settings::get_auto_backup_settings() {
+        Some(auto) => println!(
+            "{}",
+            success(&texts::auto_backup_enabled(auto.interval_hours))
+        ),
+        None => println!("{}", info(texts::auto_backup_disabled())),
+    }
+    Ok(())
+}
+
+pub(super) fn backup_max_size(mb: u64) -> Result<(), AppError> {
+    crate::settings::set_backup_max_bytes(mb.saturating_mul(1024 * 1024))?;
+    match crate::settings::get_backup_max_bytes() {
+        Some(bytes) => println!(
+            "{}",
+            success(&texts::backup_max_size_enabled(&format_size(bytes)))
+        ),
+        None => println!("{}", info(texts::backup_max_size_disabled())),
+    }
+    Ok(())
+}
+
+fn format_size(bytes: u64) -> String {
+    const MB: u64 = 1024 * 1024;
+    if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+pub(super) fn list_backups(search: Option<&str>) -> Result<(), AppError> {
+    let backups: Vec<_> = ConfigService::list_backups(&crate::config::get_app_config_path())?
+        .into_iter()
+        .filter(|backup| search.is_none_or(|query| backup.matches(query)))
+        .collect();
+    if backups.is_empty() {
+        println!("{}", info(texts::config_backup_list_empty()));
+        return Ok(());
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["ID", "Created", "Note"]);
+    for backup in backups {
+        table.add_row(vec![
+            backup.id,
+            backup.display_name,
+            backup.note.unwrap_or_default(),
+        ]);
+    }
+    println!("{}", table);
+    Ok(())
+}
+
+pub(super) fn backup_config(custom_name: Option<&str>, note: Option<&str>) -> Result<(), AppError> {
+    let config_path = crate::config::get_app_config_path();
+
+    if let Some(name) = custom_name {
+        println!(
+            "{}",
+            info(&format!("Creating backup with name '{}'...", name))
+        );
+    } else {
+        println!("{}", info("Creating backup of current configuration..."));
+    }
+
+    let (backup_id, prune) = ConfigService::create_backup_with_report(
+        &config_path,
+        custom_name.map(|s| s.to_string()),
+        note,
+    )?;
+
+    if backup_id.is_empty() {
+        println!("{}", error("Failed to create backup."));
+    } else {
+        let backup_dir = config_path.parent().unwrap().join("backups");
+        let backup_file = backup_dir.join(format!("{}.sql", backup_id));
+
+        println!("{}", success(&format!("✓ Backup created: {}", backup_id)));
+        println!("Location: {}", backup_file.display());
+        if prune.pruned_files > 0 {
+            println!(
+                "{}",
+                info(&texts::backup_pruned_by_size(
+                    prune.pruned_files,
+                    &format_size(prune.pruned_bytes)
+                ))
+            );
+        }
+        if prune.oversized {
+            println!("{}", warning(texts::backup_exceeds_max_size()));
+        }
+    }
+
+    Ok(())
+}
+
+pub(super) fn restore_config(
+    backup_id: Option<&str>,
+    file_path: Option<&Path>,
+    json: bool,
+) -> Result<(), AppError> {
+    let config_path = crate::config::get_app_config_path();
+
+    // 情况1：指定了备份 ID
+    if let Some(id) = backup_id {
+        println!("{}", info(&format!("Restoring from backup '{}'...", id)));
+
+        let confirm =
+            inquire::Confirm::new("This will replace your current configuration. Continue?")
+                .with_default(false)
+                .prompt()
+                .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
+
+        if !confirm {
+            println!("{}", info("Cancelled."));
+            return Ok(());
+        }
+
+        let state = get_state()?;
+        let before = state.config.read().map_err(AppError::from)?.clone();
+        let pre_restore_backup = ConfigService::restore_from_backup_id(id, &state)?;
+        let report = reload_after_replace(&before)?;
+
+        return print_replace_outcome(
+            &format!("✓ Configuration restored from backup '{id}'"),
+            &format!("  Pre-restore backup: {pre_restore_backup}"),
+            &pre_restore_backup,
+            &report,
+            json,
+        );
+    }
+
+    // 情况2：指定了文件路径
+    if let Some(file) = file_path {
+        println!(
+            "{}",
+            info(&format!(
+                "Restoring configuration from {}...",
+                file.display()
+            ))
+        );
+
+        if !file.exists() {
+            return Err(AppError::Message(format!(
+                "File '{}' not found",
+                file.display()
+            )));
+        }
+
+        println!();
+        println!("{}", highlight("Warning:"));
+        println!("This will replace your current database with the SQL backup file.");
+        println!("A backup of the current state will be created first.");
+        println!();
+
+        let confirm = inquire::Confirm::new(texts::config_restore_confirm_prompt())
+            .with_default(false)
+            .prompt()
+            .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
+
+        if !confirm {
+            println!("{}", info("Cancelled."));
+            return Ok(());
+        }
+
+        let state = get_state()?;
+        let before = state.config.read().map_err(AppError::from)?.clone();
+        let pre_restore_backup = ConfigService::import_config_from_path(file, &state)?;
+        let report = reload_after_replace(&before)?;
+
+        return print_replace_outcome(
+            &format!("✓ Configuration restored from {}", file.display()),
+            &format!("  Pre-restore backup: {pre_restore_backup}"),
+            &pre_restore_backup,
+            &report,
+            json,
+        );
+    }
+
+    // 情况3：无参数，显示交互式列表
+    println!("{}", highlight(texts::available_backups()));
+    println!("{}", "=".repeat(50));
+
+    let backups = ConfigService::list_backups(&config_path)?;
+
+    if backups.is_empty() {
+        println!();
+        println!("{}", info(texts::no_backups_found()));
+        println!("{}", info(texts::create_backup_first_hint()));
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", texts::found_backups(backups.len()));
+    println!();
+
+    let choices: Vec<String> = backups
+        .iter()
+        .map(|b| format!("{} - {}", b.display_name, b.id))
+        .collect();
+
+    let selection = inquire::Select::new(texts::select_backup_to_restore(), choices)
+        .prompt()
+        .map_err(|_| AppError::Message(texts::selection_cancelled().to_string()))?;
+
+    let selected_backup = backups
+        .iter()
+        .find(|b| selection.contains(&b.id))
+        .ok_or_else(|| AppError::Message(texts::invalid_selection().to_string()))?;
+
+    println!();
+    println!("{}", highlight(texts::warning_title()));
+    println!("{}", texts::config_restore_warning_replace());
+    println!("{}", texts::config_restore_warning_pre_backup());
+    println!();
+
+    let confirm = inquire::Confirm::new(texts::config_restore_confirm_prompt())
+        .with_default(false)
+        .prompt()
+        .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
+
+    if !confirm {
+        println!("{}", info(texts::cancelled()));
+        return Ok(());
+    }
+
+    let state = get_state()?;
+    let before = state.config.read().map_err(AppError::from)?.clone();
+    let pre_restore_backup = ConfigService::restore_from_backup_id(&selected_backup.id, &state)?;
+    let report = reload_after_replace(&before)?;
+
+    print_replace_outcome(
+        &format!(
+            "✓ Configuration restored from: {}",
+            selected_backup.display_name
+        ),
+        &format!("  Pre-restore backup: {pre_restore_backup}"),
+        &pre_restore_backup,
+        &report,
+        json,
+    )
+}
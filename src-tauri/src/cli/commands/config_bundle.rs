@@ -0,0 +1,89 @@
+use std::path::Path;
+
+use crate::cli::i18n::texts;
+use crate::cli::ui::{create_table, highlight, info, success, warning};
+use crate::error::AppError;
+use crate::services::{BundleProviderStatus, BundleSelection, ConfigService};
+use crate::store::AppState;
+
+use super::config_transfer::resolve_import_source;
+
+fn get_state() -> Result<AppState, AppError> {
+    AppState::try_new()
+}
+
+pub(super) fn export_bundle(
+    file: &Path,
+    selection: BundleSelection,
+    redact: bool,
+) -> Result<(), AppError> {
+    let state = get_state()?;
+    let bundle = ConfigService::export_bundle_to_path(&state, &selection, redact, file)?;
+    let provider_count = bundle.providers.values().map(Vec::len).sum::<usize>();
+    let prompt_count = bundle.prompts.values().map(Vec::len).sum::<usize>();
+    println!(
+        "{}",
+        success(&texts::bundle_exported(
+            provider_count,
+            bundle.mcp_servers.len(),
+            prompt_count,
+            &file.display().to_string()
+        ))
+    );
+    if redact {
+        println!("{}", info(texts::bundle_redacted_note()));
+    }
+    Ok(())
+}
+
+pub(super) fn import_bundle(source: &str, allow_http: bool, preview: bool) -> Result<(), AppError> {
+    let state = get_state()?;
+    let (file, _remote) = resolve_import_source(&state, source, allow_http)?;
+    if preview {
+        return preview_bundle(&state, &file);
+    }
+    let summary = ConfigService::import_bundle_from_path(&state, &file)?;
+    println!("{}", success(&texts::bundle_imported(&summary)));
+    Ok(())
+}
+
+fn preview_bundle(state: &AppState, file: &Path) -> Result<(), AppError> {
+    let previews = ConfigService::preview_bundle_from_path(state, file)?;
+    let count = |status: BundleProviderStatus| {
+        previews
+            .iter()
+            .filter(|preview| preview.status == status)
+            .count()
+    };
+
+    if !previews.is_empty() {
+        let mut table = create_table();
+        table.set_header(vec!["Status", "App", "ID", "Name"]);
+        for preview in &previews {
+            let status = match preview.status {
+                BundleProviderStatus::New => success("new"),
+                BundleProviderStatus::Identical => info("identical"),
+                BundleProviderStatus::Conflict => warning("conflict"),
+            };
+            table.add_row(vec![
+                status,
+                preview.app.clone(),
+                preview.id.clone(),
+                preview.name.clone(),
+            ]);
+        }
+        println!("{table}");
+    }
+    println!(
+        "{}",
+        highlight(&texts::bundle_preview_summary(
+            count(BundleProviderStatus::New),
+            count(BundleProviderStatus::Identical),
+            count(BundleProviderStatus::Conflict),
+        ))
+    );
+    if count(BundleProviderStatus::Conflict) > 0 {
+        println!("{}", info(texts::bundle_preview_conflict_hint()));
+    }
+    Ok(())
+}
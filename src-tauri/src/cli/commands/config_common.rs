@@ -2,9 +2,9 @@ use clap::Subcommand;
 use std::fs;
 use std::path::Path;
 
-use crate::app_config::AppType;
+use crate::app_config::{AppType, MultiAppConfig};
 use crate::cli::i18n::texts;
-use crate::cli::ui::{highlight, info, success};
+use crate::cli::ui::{create_table, highlight, info, success};
 use crate::error::AppError;
 use crate::store::AppState;
 
@@ -26,12 +26,42 @@ pub enum CommonConfigCommand {
         #[arg(long)]
         apply: bool,
     },
+    /// Seed the app's default common config snippet if none is set
+    Init {
+        /// Overwrite an existing snippet
+        #[arg(long)]
+        force: bool,
+
+        /// Apply to current provider immediately
+        #[arg(long)]
+        apply: bool,
+    },
     /// Clear common config snippet
     Clear {
         /// Apply to current provider immediately
         #[arg(long)]
         apply: bool,
     },
+    /// List previous versions of the snippet (most recently replaced first)
+    History,
+    /// Restore a previous version listed by `history` (1 = most recent)
+    Revert {
+        /// Version number from `config common history`
+        n: usize,
+
+        /// Apply to current provider immediately
+        #[arg(long)]
+        apply: bool,
+    },
+    /// Show or set whether switching Codex extracts a snippet from the live config.toml
+    AutoExtract {
+        /// Never extract automatically; the snippet is only set explicitly
+        #[arg(long, conflicts_with = "backfill_extract")]
+        no_backfill_extract: bool,
+        /// Restore the default: extract once while the snippet is empty
+        #[arg(long)]
+        backfill_extract: bool,
+    },
 }
 
 pub fn execute(cmd: CommonConfigCommand, app_type: AppType) -> Result<(), AppError> {
@@ -40,7 +70,14 @@ pub fn execute(cmd: CommonConfigCommand, app_type: AppType) -> Result<(), AppErr
         CommonConfigCommand::Set { json, file, apply } => {
             set(app_type, json.as_deref(), file.as_deref(), apply)
         }
+        CommonConfigCommand::Init { force, apply } => init(app_type, force, apply),
         CommonConfigCommand::Clear { apply } => clear(app_type, apply),
+        CommonConfigCommand::History => history(app_type),
+        CommonConfigCommand::Revert { n, apply } => revert(app_type, n, apply),
+        CommonConfigCommand::AutoExtract {
+            no_backfill_extract,
+            backfill_extract,
+        } => auto_extract(no_backfill_extract, backfill_extract),
     }
 }
 
@@ -48,6 +85,18 @@ fn get_state() -> Result<AppState, AppError> {
     AppState::try_new()
 }
 
+fn auto_extract(disable: bool, enable: bool) -> Result<(), AppError> {
+    if disable || enable {
+        crate::settings::set_no_backfill_extract(disable)?;
+    }
+    let enabled = !crate::settings::get_no_backfill_extract();
+    println!(
+        "{}",
+        info(texts::common_snippet_auto_extract_status(enabled))
+    );
+    Ok(())
+}
+
 fn show(app_type: AppType) -> Result<(), AppError> {
     let state = get_state()?;
     let config = state.config.read()?;
@@ -117,6 +166,61 @@ fn set(
     Ok(())
 }
 
+/// 写入应用的默认通用配置片段；已有片段且未指定 `force` 时不做任何修改
+fn seed_default_snippet(
+    config: &mut MultiAppConfig,
+    app_type: &AppType,
+    force: bool,
+) -> Result<bool, AppError> {
+    let default = texts::tui_default_common_snippet_for_app(app_type.as_str());
+    let is_trivial = serde_json::from_str::<serde_json::Value>(default)
+        .ok()
+        .and_then(|value| value.as_object().map(|obj| obj.is_empty()))
+        .unwrap_or(default.trim().is_empty());
+    if is_trivial {
+        return Err(AppError::InvalidInput(
+            texts::config_common_snippet_no_default(app_type.as_str()),
+        ));
+    }
+
+    let existing = config
+        .common_config_snippets
+        .get(app_type)
+        .is_some_and(|snippet| !snippet.trim().is_empty());
+    if existing && !force {
+        return Ok(false);
+    }
+    config
+        .common_config_snippets
+        .set(app_type, Some(default.to_string()));
+    Ok(true)
+}
+
+fn init(app_type: AppType, force: bool, apply: bool) -> Result<(), AppError> {
+    let state = get_state()?;
+    let seeded = {
+        let mut config = state.config.write()?;
+        seed_default_snippet(&mut config, &app_type, force)?
+    };
+    if !seeded {
+        println!(
+            "{}",
+            info(&texts::config_common_snippet_already_set(app_type.as_str()))
+        );
+        return Ok(());
+    }
+    state.save()?;
+
+    println!(
+        "{}",
+        success(&texts::config_common_snippet_initialized(app_type.as_str()))
+    );
+    if apply {
+        apply_to_current(&state, app_type)?;
+    }
+    Ok(())
+}
+
 fn clear(app_type: AppType, apply: bool) -> Result<(), AppError> {
     let state = get_state()?;
     {
@@ -145,6 +249,75 @@ fn clear(app_type: AppType, apply: bool) -> Result<(), AppError> {
     Ok(())
 }
 
+fn history(app_type: AppType) -> Result<(), AppError> {
+    let state = get_state()?;
+    let versions = state.db.get_config_snippet_history(app_type.as_str())?;
+    if versions.is_empty() {
+        println!(
+            "{}",
+            info(&texts::config_common_history_empty(app_type.as_str()))
+        );
+        return Ok(());
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["#", "Replaced", "Lines", "Preview"]);
+    for (index, version) in versions.iter().enumerate() {
+        let replaced = chrono::DateTime::from_timestamp(version.replaced_at, 0)
+            .map(|dt| {
+                dt.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_default();
+        let preview: String = version
+            .snippet
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .chars()
+            .take(60)
+            .collect();
+        table.add_row(vec![
+            (index + 1).to_string(),
+            replaced,
+            version.snippet.lines().count().to_string(),
+            preview,
+        ]);
+    }
+    println!("{}", table);
+    Ok(())
+}
+
+fn revert(app_type: AppType, n: usize, apply: bool) -> Result<(), AppError> {
+    let state = get_state()?;
+    let versions = state.db.get_config_snippet_history(app_type.as_str())?;
+    let version = n
+        .checked_sub(1)
+        .and_then(|index| versions.get(index))
+        .ok_or_else(|| {
+            AppError::InvalidInput(texts::config_common_history_not_found(n, versions.len()))
+        })?;
+
+    {
+        let mut config = state.config.write()?;
+        config
+            .common_config_snippets
+            .set(&app_type, Some(version.snippet.clone()));
+    }
+    // 保存时当前片段会记入历史，因此回退本身也可以再撤销
+    state.save()?;
+
+    println!(
+        "{}",
+        success(&texts::config_common_reverted(n, app_type.as_str()))
+    );
+    if apply {
+        apply_to_current(&state, app_type)?;
+    }
+    Ok(())
+}
+
 fn apply_to_current(state: &AppState, app_type: AppType) -> Result<(), AppError> {
     use crate::services::ProviderService;
 
@@ -158,3 +331,39 @@ fn apply_to_current(state: &AppState, app_type: AppType) -> Result<(), AppError>
     println!("{}", success("✓ Applied to live config."));
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seed_default_snippet_writes_default_and_keeps_existing_unless_forced() {
+        let mut config = MultiAppConfig::default();
+
+        assert!(seed_default_snippet(&mut config, &AppType::Claude, false).expect("seed"));
+        let seeded = config
+            .common_config_snippets
+            .get(&AppType::Claude)
+            .cloned()
+            .expect("snippet set");
+        assert!(seeded.contains("CLAUDE_CODE_DISABLE_NONESSENTIAL_TRAFFIC"));
+
+        let custom = r#"{"env":{"FOO":"1"}}"#.to_string();
+        config
+            .common_config_snippets
+            .set(&AppType::Claude, Some(custom.clone()));
+        assert!(!seed_default_snippet(&mut config, &AppType::Claude, false).expect("no-op"));
+        assert_eq!(
+            config.common_config_snippets.get(&AppType::Claude),
+            Some(&custom)
+        );
+
+        assert!(seed_default_snippet(&mut config, &AppType::Claude, true).expect("force"));
+        assert_eq!(
+            config.common_config_snippets.get(&AppType::Claude),
+            Some(&seeded)
+        );
+
+        assert!(seed_default_snippet(&mut config, &AppType::Codex, false).is_err());
+    }
+}
@@ -0,0 +1,62 @@
+use crate::cli::i18n::texts;
+use crate::cli::ui::{error, highlight, info, success, warning};
+use crate::database::Database;
+use crate::error::AppError;
+use crate::services::ConfigService;
+
+/// `config db-check`：检查数据库中的重复供应商 ID，`--repair` 时先备份再修复
+pub(super) fn execute(repair: bool) -> Result<(), AppError> {
+    let db_path = crate::config::get_app_config_dir().join("cc-switch.db");
+    if !db_path.exists() {
+        println!("{}", error("✗ Database file does not exist"));
+        println!("Path: {}", db_path.display());
+        return Ok(());
+    }
+
+    let db = Database::init()?;
+    let duplicates = db.find_duplicate_provider_ids()?;
+
+    println!("{}", highlight(texts::db_check_duplicate_ids_title()));
+    if duplicates.is_empty() {
+        println!("{}", success(texts::db_check_no_duplicate_ids()));
+        return Ok(());
+    }
+
+    for dup in &duplicates {
+        println!(
+            "{}",
+            warning(&texts::db_check_duplicate_id_line(
+                &dup.app_type,
+                &dup.id,
+                dup.count
+            ))
+        );
+    }
+
+    if !repair {
+        println!("{}", info(texts::db_check_repair_hint()));
+        return Err(AppError::Message(texts::db_check_found_duplicates(
+            duplicates.len(),
+        )));
+    }
+
+    let backup_id = ConfigService::create_backup(&db_path, Some("db-check".to_string()))?;
+    if !backup_id.is_empty() {
+        println!("{}", info(&texts::db_check_backup_created(&backup_id)));
+    }
+
+    let changes = db.rekey_duplicate_provider_ids()?;
+    for change in &changes {
+        println!(
+            "{}",
+            success(&texts::db_check_rekeyed_line(
+                &change.app_type,
+                &change.old_id,
+                &change.new_id
+            ))
+        );
+    }
+    println!("{}", success(&texts::db_check_repaired(changes.len())));
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::app_config::AppType;
+use crate::cli::i18n::texts;
+use crate::cli::ui::{info, success};
+use crate::error::AppError;
+
+/// `config switch-hooks`：设置或清除切换钩子（带 `--app` 时为该应用的覆盖），然后显示生效的钩子
+pub(super) fn execute(
+    app: Option<AppType>,
+    pre_switch: Option<String>,
+    post_switch: Option<String>,
+    clear: bool,
+) -> Result<(), AppError> {
+    if clear {
+        let (pre_switch, post_switch) = (Some(String::new()), Some(String::new()));
+        crate::settings::set_switch_hook(app.as_ref(), pre_switch, post_switch)?;
+        println!("{}", success(texts::switch_hooks_saved()));
+    } else if pre_switch.is_some() || post_switch.is_some() {
+        crate::settings::set_switch_hook(app.as_ref(), pre_switch, post_switch)?;
+        println!("{}", success(texts::switch_hooks_saved()));
+    }
+
+    let apps: Vec<AppType> = match app {
+        Some(app) => vec![app],
+        None => AppType::all().collect(),
+    };
+    for app in apps {
+        let hooks = crate::settings::get_switch_hooks(&app);
+        let none = texts::switch_hooks_none();
+        println!("{}", info(&format!("{}:", app.as_str())));
+        println!(
+            "  pre_switch:  {}",
+            hooks.pre_switch.as_deref().unwrap_or(none)
+        );
+        println!(
+            "  post_switch: {}",
+            hooks.post_switch.as_deref().unwrap_or(none)
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use clap::ValueEnum;
+
+use crate::app_config::AppType;
+use crate::cli::i18n::texts;
+use crate::cli::ui::{info, success};
+use crate::error::AppError;
+use crate::redact::MaskStyle;
+
+pub(super) fn mask_style(style: Option<MaskStyle>) -> Result<(), AppError> {
+    if let Some(style) = style {
+        crate::settings::set_mask_style(style)?;
+    }
+    let current = crate::settings::get_mask_style();
+    let name = current
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default();
+    println!(
+        "{}",
+        info(&texts::mask_style_current(
+            &name,
+            &crate::redact::mask_secret_with("sk-example-key-1234", current)
+        ))
+    );
+    Ok(())
+}
+
+pub(super) fn redaction_patterns(patterns: Vec<String>, clear: bool) -> Result<(), AppError> {
+    if clear || !patterns.is_empty() {
+        crate::settings::set_redaction_patterns(patterns)?;
+        println!("{}", success(texts::redaction_patterns_saved()));
+    }
+
+    let current = crate::settings::get_redaction_patterns();
+    if current.is_empty() {
+        println!("{}", info(texts::redaction_patterns_empty()));
+    } else {
+        for pattern in current {
+            println!("{pattern}");
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn notes_template(template: Option<String>, clear: bool) -> Result<(), AppError> {
+    if clear || template.is_some() {
+        crate::settings::set_provider_notes_template(template)?;
+        println!("{}", success(texts::notes_template_saved()));
+    }
+
+    match crate::settings::get_provider_notes_template() {
+        Some(template) => println!("{template}"),
+        None => println!("{}", info(texts::notes_template_empty())),
+    }
+    Ok(())
+}
+
+pub(super) fn network_timeout(timeout_ms: Option<u64>, clear: bool) -> Result<(), AppError> {
+    if clear || timeout_ms.is_some() {
+        crate::settings::set_network_timeout_ms(timeout_ms)?;
+        println!("{}", success(texts::network_timeout_saved()));
+    }
+
+    match crate::settings::get_network_timeout_ms() {
+        Some(ms) => println!("{ms} ms"),
+        None => println!("{}", info(texts::network_timeout_default())),
+    }
+    Ok(())
+}
+
+pub(super) fn secret_reveal_timeout(secs: Option<u64>, clear: bool) -> Result<(), AppError> {
+    if clear || secs.is_some() {
+        crate::settings::set_secret_reveal_timeout_secs(secs)?;
+        println!("{}", success(texts::secret_reveal_timeout_saved()));
+    }
+
+    println!(
+        "{}",
+        texts::secret_reveal_timeout_value(crate::settings::get_secret_reveal_timeout_secs())
+    );
+    Ok(())
+}
+
+pub(super) fn default_model(
+    app: AppType,
+    model: Option<String>,
+    clear: bool,
+) -> Result<(), AppError> {
+    if clear || model.is_some() {
+        crate::settings::set_default_model(&app, model)?;
+        println!("{}", success(&texts::default_model_saved(app.as_str())));
+    }
+
+    match crate::settings::get_default_model(&app) {
+        Some(model) => println!("{model}"),
+        None => println!("{}", info(&texts::default_model_empty(app.as_str()))),
+    }
+    Ok(())
+}
@@ -0,0 +1,323 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use indicatif::{ProgressBar, ProgressStyle};
+
+use crate::app_config::{AppType, MultiAppConfig};
+use crate::cli::i18n::texts;
+use crate::cli::ui::{highlight, info, success, to_json};
+use crate::error::AppError;
+use crate::redact::RedactLevel;
+use crate::services::remote_import::RemoteImportFile;
+use crate::services::{ConfigService, ImportChangeReport, ProviderService};
+use crate::store::AppState;
+
+use super::config::redacted_config_value;
+
+fn get_state() -> Result<AppState, AppError> {
+    AppState::try_new()
+}
+
+pub(super) fn export_config(
+    file: &PathBuf,
+    redact_level: Option<RedactLevel>,
+    include_archived: bool,
+    canonical: bool,
+) -> Result<(), AppError> {
+    println!(
+        "{}",
+        info(&format!("Exporting configuration to {}...", file.display()))
+    );
+
+    // Check if target file already exists
+    if file.exists() {
+        let confirm = inquire::Confirm::new(&format!(
+            "File '{}' already exists. Overwrite?",
+            file.display()
+        ))
+        .with_default(false)
+        .prompt()
+        .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
+
+        if !confirm {
+            println!("{}", info("Cancelled."));
+            return Ok(());
+        }
+    }
+
+    // Ensure parent directory exists
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
+    }
+
+    // Export configuration：指定脱敏级别时导出 JSON 快照（仅供分享，不可导入）
+    match redact_level {
+        Some(level) => {
+            crate::config::write_json_file(
+                file,
+                &redacted_config_value(level, include_archived, false)?,
+            )?;
+            println!("{}", info(texts::config_export_redacted_note()));
+        }
+        None => ConfigService::export_config_to_path(file, include_archived, canonical)?,
+    }
+
+    println!(
+        "{}",
+        success(&format!("✓ Configuration exported to {}", file.display()))
+    );
+
+    Ok(())
+}
+
+/// `--format toml` 只支持 Codex 供应商
+fn require_codex_for_toml(app: Option<AppType>) -> Result<(), AppError> {
+    if app == Some(AppType::Codex) {
+        return Ok(());
+    }
+    Err(AppError::InvalidInput(
+        texts::config_toml_requires_codex().to_string(),
+    ))
+}
+
+pub(super) fn export_codex_toml(
+    file: &Path,
+    app: Option<AppType>,
+    include_archived: bool,
+) -> Result<(), AppError> {
+    require_codex_for_toml(app)?;
+    let state = get_state()?;
+    ConfigService::export_codex_toml_to_path(&state, file, include_archived)?;
+    println!(
+        "{}",
+        success(&texts::config_codex_toml_exported(
+            &file.display().to_string()
+        ))
+    );
+    Ok(())
+}
+
+pub(super) fn import_codex_toml(file: &Path, app: Option<AppType>) -> Result<(), AppError> {
+    require_codex_for_toml(app)?;
+    let state = get_state()?;
+    let summary = ConfigService::import_codex_toml_from_path(&state, file)?;
+    println!(
+        "{}",
+        success(&texts::config_codex_toml_imported(
+            summary.added,
+            summary.updated
+        ))
+    );
+    Ok(())
+}
+
+/// 解析导入源：URL 先下载到临时文件（显示进度），否则按本地路径处理
+pub(super) fn resolve_import_source(
+    state: &AppState,
+    source: &str,
+    allow_http: bool,
+) -> Result<(PathBuf, Option<RemoteImportFile>), AppError> {
+    if !ConfigService::is_remote_source(source) {
+        return Ok((PathBuf::from(source), None));
+    }
+
+    println!("{}", info(&format!("Downloading {source}...")));
+    let bar = ProgressBar::new(0);
+    if let Ok(style) = ProgressStyle::with_template("{bar:30} {bytes}/{total_bytes}") {
+        bar.set_style(style);
+    }
+    let result =
+        ConfigService::download_remote_source(state, source, allow_http, &|done, total| {
+            if let Some(total) = total {
+                bar.set_length(total);
+            }
+            bar.set_position(done);
+        });
+    bar.finish_and_clear();
+    let remote = result?;
+    println!("{}", info(&format!("  Downloaded {} bytes", remote.bytes)));
+    Ok((remote.path.clone(), Some(remote)))
+}
+
+pub(super) fn import_config(source: &str, allow_http: bool, json: bool) -> Result<(), AppError> {
+    println!(
+        "{}",
+        info(&format!("Importing configuration from {source}..."))
+    );
+
+    let state = get_state()?;
+    let (file, _remote) = resolve_import_source(&state, source, allow_http)?;
+
+    // Check if source file exists
+    if !file.exists() {
+        return Err(AppError::Message(format!(
+            "File '{}' not found",
+            file.display()
+        )));
+    }
+    ConfigService::check_import_file(&file)?;
+
+    // Confirm import
+    println!();
+    println!("{}", highlight("Warning:"));
+    println!("This will replace your current database with the imported SQL backup.");
+    println!("A backup will be created automatically.");
+    println!();
+
+    let confirm = inquire::Confirm::new("Continue with import?")
+        .with_default(false)
+        .prompt()
+        .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
+
+    if !confirm {
+        println!("{}", info("Cancelled."));
+        return Ok(());
+    }
+
+    // Perform import
+    let before = state.config.read().map_err(AppError::from)?.clone();
+    let backup_id = ConfigService::import_config_from_path(&file, &state)?;
+    let report = reload_after_replace(&before)?;
+
+    print_replace_outcome(
+        &format!("✓ Configuration imported from {source}"),
+        &format!("  Backup created: {backup_id}"),
+        &backup_id,
+        &report,
+        json,
+    )
+}
+
+/// 导入 / 恢复替换了数据库：重新加载后同步 live 配置，并与替换前的配置对比统计变更
+pub(super) fn reload_after_replace(
+    before: &MultiAppConfig,
+) -> Result<ImportChangeReport, AppError> {
+    let replaced = get_state()?;
+    if let Err(e) = ProviderService::sync_current_to_live(&replaced) {
+        log::warn!("替换数据库后同步 live 配置失败: {e}");
+    }
+    let after = replaced.config.read().map_err(AppError::from)?;
+    Ok(ConfigService::import_change_report(before, &after))
+}
+
+/// `--json` 输出的变更报告：每个应用都列出，便于脚本直接按键读取
+fn import_change_report_value(report: &ImportChangeReport) -> serde_json::Value {
+    let by_app = |entries: &[(AppType, crate::services::ChangeCounts)]| {
+        entries
+            .iter()
+            .map(|(app, counts)| (app.as_str().to_string(), serde_json::json!(counts)))
+            .collect::<serde_json::Map<_, _>>()
+    };
+    serde_json::json!({
+        "providers": by_app(&report.providers),
+        "mcpServers": report.mcp_servers,
+        "prompts": by_app(&report.prompts),
+    })
+}
+
+/// 打印导入 / 恢复的结果：成功信息、自动备份 ID 与变更统计
+pub(super) fn print_replace_outcome(
+    message: &str,
+    backup_line: &str,
+    backup_id: &str,
+    report: &ImportChangeReport,
+    json: bool,
+) -> Result<(), AppError> {
+    if json {
+        let value = serde_json::json!({
+            "backupId": (!backup_id.is_empty()).then_some(backup_id),
+            "changes": import_change_report_value(report),
+        });
+        let text = to_json(&value)
+            .map_err(|e| AppError::Message(texts::failed_to_serialize_json(&e.to_string())))?;
+        println!("{text}");
+        return Ok(());
+    }
+
+    println!("{}", success(message));
+    if !backup_id.is_empty() {
+        println!("{}", info(backup_line));
+    }
+    println!();
+    print_import_change_report(report);
+    println!();
+    println!(
+        "{}",
+        info("Note: Restart your CLI clients to apply the changes.")
+    );
+    Ok(())
+}
+
+/// 按类型分组打印导入变更；导入前后都为空的分组不显示
+fn print_import_change_report(report: &ImportChangeReport) {
+    println!("{}", highlight(texts::config_import_changes_title()));
+    let mut lines = Vec::new();
+    for (app, counts) in &report.providers {
+        if !counts.is_empty() {
+            let label = texts::config_import_providers_label(app.as_str());
+            lines.push(texts::config_import_change_line(&label, counts));
+        }
+    }
+    if !report.mcp_servers.is_empty() {
+        lines.push(texts::config_import_change_line(
+            texts::config_import_mcp_label(),
+            &report.mcp_servers,
+        ));
+    }
+    for (app, counts) in &report.prompts {
+        if !counts.is_empty() {
+            let label = texts::config_import_prompts_label(app.as_str());
+            lines.push(texts::config_import_change_line(&label, counts));
+        }
+    }
+    if lines.is_empty() {
+        println!("{}", texts::config_import_changes_none());
+    }
+    for line in lines {
+        println!("{line}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::Provider;
+    use serde_json::json;
+
+    fn claude_provider(id: &str, token: &str) -> Provider {
+        Provider::with_id(
+            id.to_string(),
+            id.to_string(),
+            json!({ "env": { "ANTHROPIC_AUTH_TOKEN": token } }),
+            None,
+        )
+    }
+
+    #[test]
+    fn import_change_report_json_lists_counts_per_app() {
+        let mut before = MultiAppConfig::default();
+        before
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager")
+            .providers
+            .insert("gone".to_string(), claude_provider("gone", "sk-gone"));
+        let mut after = MultiAppConfig::default();
+        after
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager")
+            .providers
+            .insert("new".to_string(), claude_provider("new", "sk-new"));
+
+        let report = ConfigService::import_change_report(&before, &after);
+        let value = import_change_report_value(&report);
+        assert_eq!(
+            value["providers"]["claude"],
+            json!({ "added": 1, "updated": 0, "unchanged": 0, "removed": 1 })
+        );
+        for app in AppType::all() {
+            assert!(value["providers"][app.as_str()].is_object());
+            assert!(value["prompts"][app.as_str()].is_object());
+        }
+        assert_eq!(value["mcpServers"]["added"], json!(0));
+    }
+}
@@ -0,0 +1,264 @@
+use std::path::Path;
+
+use crate::app_config::AppType;
+use crate::cli::i18n::texts;
+use crate::cli::ui::{error, highlight, info, success, to_json, warning};
+use crate::database::SCHEMA_VERSION;
+use crate::error::AppError;
+use crate::redact::RedactLevel;
+use crate::services::{AppValidationReport, ConfigService};
+use crate::store::AppState;
+
+fn get_state() -> Result<AppState, AppError> {
+    AppState::try_new()
+}
+
+/// `config validate --json` 的输出：每项检查带 `ok` 状态与详情，顶层 `ok` 为总体结果
+///
+/// `schema` 为 None 表示数据库文件不存在；否则是打开（并迁移）后读到的 schema 版本
+fn validation_report_value(
+    db_path: &Path,
+    schema: Option<&Result<i32, AppError>>,
+    reports: &[AppValidationReport],
+) -> serde_json::Value {
+    let mut checks = vec![serde_json::json!({
+        "check": "databaseFile",
+        "ok": schema.is_some(),
+        "path": db_path.display().to_string(),
+    })];
+    match schema {
+        Some(Ok(version)) => checks.push(serde_json::json!({
+            "check": "databaseSchema",
+            "ok": *version == SCHEMA_VERSION,
+            "version": version,
+            "expected": SCHEMA_VERSION,
+        })),
+        Some(Err(err)) => checks.push(serde_json::json!({
+            "check": "databaseSchema",
+            "ok": false,
+            "expected": SCHEMA_VERSION,
+            "error": err.to_string(),
+        })),
+        None => {}
+    }
+    checks.extend(reports.iter().map(|report| {
+        serde_json::json!({
+            "check": "providers",
+            "app": report.app_type.as_str(),
+            "ok": report.issues.is_empty(),
+            "providerCount": report.provider_count,
+            "current": report.current,
+            "issues": report.issues,
+        })
+    }));
+    let ok = checks.iter().all(|check| check["ok"] == true);
+    serde_json::json!({ "ok": ok, "checks": checks })
+}
+
+fn validation_failed(issue_count: usize) -> AppError {
+    AppError::localized(
+        "config.validate.failed",
+        format!("配置校验发现 {issue_count} 个问题"),
+        format!("Configuration validation found {issue_count} issue(s)"),
+    )
+}
+
+pub(super) fn validate_config(apps: &[AppType], json: bool) -> Result<(), AppError> {
+    let config_dir = crate::config::get_app_config_dir();
+    let db_path = config_dir.join("cc-switch.db");
+
+    if json {
+        let schema = db_path
+            .exists()
+            .then(|| crate::Database::init().and_then(|db| db.schema_version()));
+        let schema_ok = matches!(schema, Some(Ok(SCHEMA_VERSION)));
+        let reports: Vec<_> = if schema_ok {
+            ConfigService::validate(&get_state()?, None)?
+                .into_iter()
+                .filter(|report| apps.contains(&report.app_type))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let value = validation_report_value(&db_path, schema.as_ref(), &reports);
+        let text = to_json(&value)
+            .map_err(|e| AppError::Message(texts::failed_to_serialize_json(&e.to_string())))?;
+        println!("{text}");
+
+        let issue_count = usize::from(!schema_ok)
+            + reports
+                .iter()
+                .map(|report| report.issues.len())
+                .sum::<usize>();
+        return match issue_count {
+            0 => Ok(()),
+            count => Err(validation_failed(count)),
+        };
+    }
+
+    println!("{}", info("Validating database..."));
+    println!();
+
+    if !db_path.exists() {
+        println!("{}", error("✗ Database file does not exist"));
+        println!("Path: {}", db_path.display());
+        return Ok(());
+    }
+
+    println!("{} Database file exists", success("✓"));
+    println!("Path: {}", db_path.display());
+
+    let db = crate::Database::init()?;
+    let version = db.schema_version()?;
+    if version != SCHEMA_VERSION {
+        println!(
+            "{} Database schema version {version} (expected {SCHEMA_VERSION})",
+            error("✗")
+        );
+        return Err(validation_failed(1));
+    }
+    println!("{} Database schema version {version}", success("✓"));
+
+    let state = get_state()?;
+    let reports: Vec<_> = ConfigService::validate(&state, None)?
+        .into_iter()
+        .filter(|report| apps.contains(&report.app_type))
+        .collect();
+    for report in &reports {
+        println!();
+        println!(
+            "{}",
+            highlight(&texts::config_validate_app_header(
+                report.app_type.as_str(),
+                report.provider_count,
+                &report.current,
+            ))
+        );
+        if report.issues.is_empty() {
+            println!("{} {}", success("✓"), texts::config_validate_no_issues());
+        }
+        for issue in &report.issues {
+            println!("{} {}", error("✗"), issue);
+        }
+    }
+
+    if apps.len() > 1 {
+        println!();
+        println!("{}", highlight("Database Summary:"));
+        println!("MCP servers:       {}", db.get_all_mcp_servers()?.len());
+        println!(
+            "Skills installed:  {}",
+            db.get_all_installed_skills()?.len()
+        );
+    }
+
+    let issue_count: usize = reports.iter().map(|report| report.issues.len()).sum();
+    println!();
+    if issue_count > 0 {
+        return Err(validation_failed(issue_count));
+    }
+    println!("{}", success("✓ Database validation passed"));
+
+    Ok(())
+}
+
+pub(super) fn dump_live(
+    apps: &[AppType],
+    redact_level: RedactLevel,
+    json: bool,
+) -> Result<(), AppError> {
+    let files = ConfigService::dump_live_configs(apps, redact_level)?;
+
+    if json {
+        let mut grouped: indexmap::IndexMap<&str, Vec<serde_json::Value>> =
+            indexmap::IndexMap::new();
+        for file in &files {
+            grouped
+                .entry(file.app.as_str())
+                .or_default()
+                .push(serde_json::json!({
+                    "path": file.path.display().to_string(),
+                    "exists": file.text.is_some(),
+                    "content": file.parsed,
+                }));
+        }
+        let text = to_json(&grouped)
+            .map_err(|e| AppError::Message(texts::failed_to_serialize_json(&e.to_string())))?;
+        println!("{text}");
+        return Ok(());
+    }
+
+    let mut current_app = None;
+    for file in files {
+        if current_app.as_ref() != Some(&file.app) {
+            println!(
+                "{}",
+                highlight(&format!("===== {} =====", file.app.as_str()))
+            );
+            current_app = Some(file.app.clone());
+        }
+        println!("{}", highlight(&format!("# {}", file.path.display())));
+        match file.text {
+            Some(text) => println!("{}\n", text.trim_end()),
+            None => println!("{}\n", info(texts::config_dump_file_missing())),
+        }
+    }
+    if redact_level == RedactLevel::None {
+        println!("{}", warning(texts::config_dump_unredacted_warning()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn validation_report_json_lists_each_check_with_ok_status() {
+        let reports = vec![
+            AppValidationReport {
+                app_type: AppType::Claude,
+                provider_count: 2,
+                current: "main".to_string(),
+                issues: Vec::new(),
+            },
+            AppValidationReport {
+                app_type: AppType::Codex,
+                provider_count: 1,
+                current: String::new(),
+                issues: vec!["no current provider".to_string()],
+            },
+        ];
+
+        let value = validation_report_value(
+            Path::new("/tmp/cc-switch.db"),
+            Some(&Ok(SCHEMA_VERSION)),
+            &reports,
+        );
+        let checks = value["checks"].as_array().expect("checks");
+        assert_eq!(checks.len(), 4);
+        assert_eq!(checks[1]["check"], json!("databaseSchema"));
+        assert_eq!(checks[1]["ok"], json!(true));
+        assert!(checks.iter().all(|check| check["ok"].is_boolean()));
+        assert_eq!(checks[0]["check"], json!("databaseFile"));
+        assert_eq!(checks[2]["app"], json!("claude"));
+        assert_eq!(checks[2]["ok"], json!(true));
+        assert_eq!(checks[3]["ok"], json!(false));
+        assert_eq!(checks[3]["issues"], json!(["no current provider"]));
+        assert_eq!(value["ok"], json!(false));
+
+        let missing = validation_report_value(Path::new("/tmp/cc-switch.db"), None, &[]);
+        assert_eq!(missing["checks"].as_array().map(Vec::len), Some(1));
+        assert_eq!(missing["ok"], json!(false));
+
+        let outdated = validation_report_value(
+            Path::new("/tmp/cc-switch.db"),
+            Some(&Ok(SCHEMA_VERSION - 1)),
+            &[],
+        );
+        assert_eq!(outdated["checks"][1]["ok"], json!(false));
+        assert_eq!(outdated["checks"][1]["expected"], json!(SCHEMA_VERSION));
+        assert_eq!(outdated["ok"], json!(false));
+    }
+}
@@ -40,6 +40,13 @@ pub enum WebDavCommand {
 
         #[arg(long, conflicts_with = "auto_sync")]
         no_auto_sync: bool,
+
+        /// Stop syncing per-machine switch history (provider last-used times)
+        #[arg(long, conflicts_with = "include_history")]
+        exclude_history: bool,
+
+        #[arg(long, conflicts_with = "exclude_history")]
+        include_history: bool,
     },
 
     /// Clear stored WebDAV sync settings
@@ -70,10 +77,18 @@ pub enum WebDavCommand {
     CheckConnection,
 
     /// Upload the current local snapshot to WebDAV
-    Upload,
+    Upload {
+        /// Upload into this remote profile instead of the configured one (this run only)
+        #[arg(long)]
+        profile: Option<String>,
+    },
 
     /// Download the current remote snapshot from WebDAV
-    Download,
+    Download {
+        /// Download from this remote profile instead of the configured one (this run only)
+        #[arg(long)]
+        profile: Option<String>,
+    },
 
     /// Migrate legacy V1 remote data to V2 protocol
     MigrateV1ToV2,
@@ -92,6 +107,8 @@ pub fn execute(cmd: WebDavCommand) -> Result<(), AppError> {
             disable,
             auto_sync,
             no_auto_sync,
+            exclude_history,
+            include_history,
         } => set(
             base_url,
             remote_root,
@@ -102,6 +119,8 @@ pub fn execute(cmd: WebDavCommand) -> Result<(), AppError> {
             disable,
             auto_sync,
             no_auto_sync,
+            exclude_history,
+            include_history,
         ),
         WebDavCommand::Clear => clear(),
         WebDavCommand::Jianguoyun {
@@ -120,8 +139,8 @@ pub fn execute(cmd: WebDavCommand) -> Result<(), AppError> {
             no_auto_sync,
         ),
         WebDavCommand::CheckConnection => check_connection(),
-        WebDavCommand::Upload => upload(),
-        WebDavCommand::Download => download(),
+        WebDavCommand::Upload { profile } => upload(profile.as_deref()),
+        WebDavCommand::Download { profile } => download(profile.as_deref()),
         WebDavCommand::MigrateV1ToV2 => migrate_v1_to_v2(),
     }
 }
@@ -147,6 +166,7 @@ fn show() -> Result<(), AppError> {
     println!("Username:     {}", blank_as_na(&settings.username));
     println!("Password:     {}", masked_secret(&settings.password));
     println!("Auto Sync:    {}", yes_no(settings.auto_sync));
+    println!("Sync History: {}", yes_no(!settings.exclude_history));
     println!(
         "Last Sync:    {}",
         settings
@@ -179,6 +199,8 @@ fn set(
     disable: bool,
     auto_sync: bool,
     no_auto_sync: bool,
+    exclude_history: bool,
+    include_history: bool,
 ) -> Result<(), AppError> {
     let mut settings = merged_settings(
         get_webdav_sync_settings(),
@@ -191,6 +213,8 @@ fn set(
         disable,
         auto_sync,
         no_auto_sync,
+        exclude_history,
+        include_history,
     );
     settings.normalize();
     set_webdav_sync_settings(Some(settings))?;
@@ -262,14 +286,20 @@ fn check_connection() -> Result<(), AppError> {
     Ok(())
 }
 
-fn upload() -> Result<(), AppError> {
-    let summary = WebDavSyncService::upload()?;
+fn upload(profile: Option<&str>) -> Result<(), AppError> {
+    let summary = match profile {
+        Some(profile) => WebDavSyncService::upload_to_profile(profile)?,
+        None => WebDavSyncService::upload()?,
+    };
     println!("{}", success(&summary.message));
     Ok(())
 }
 
-fn download() -> Result<(), AppError> {
-    let summary = WebDavSyncService::download()?;
+fn download(profile: Option<&str>) -> Result<(), AppError> {
+    let summary = match profile {
+        Some(profile) => WebDavSyncService::download_from_profile(profile)?,
+        None => WebDavSyncService::download()?,
+    };
     sync_live_config_after_webdav();
     println!("{}", success(&summary.message));
     Ok(())
@@ -306,6 +336,8 @@ fn merged_settings(
     disable: bool,
     auto_sync: bool,
     no_auto_sync: bool,
+    exclude_history: bool,
+    include_history: bool,
 ) -> WebDavSyncSettings {
     let mut settings = current.unwrap_or_default();
 
@@ -336,6 +368,12 @@ fn merged_settings(
     if no_auto_sync {
         settings.auto_sync = false;
     }
+    if exclude_history {
+        settings.exclude_history = true;
+    }
+    if include_history {
+        settings.exclude_history = false;
+    }
 
     settings
 }
@@ -361,7 +399,7 @@ fn masked_secret(value: &str) -> String {
     if value.trim().is_empty() {
         return "N/A".to_string();
     }
-    "********".to_string()
+    crate::redact::mask_secret(value)
 }
 
 #[cfg(test)]
@@ -379,6 +417,7 @@ mod tests {
             username: "demo".to_string(),
             password: "secret".to_string(),
             auto_sync: false,
+            exclude_history: false,
             status: WebDavSyncStatus {
                 last_error: Some("boom".to_string()),
                 ..WebDavSyncStatus::default()
@@ -396,6 +435,8 @@ mod tests {
             false,
             true,
             false,
+            true,
+            false,
         );
 
         assert!(merged.enabled);
@@ -405,6 +446,7 @@ mod tests {
         assert_eq!(merged.username, "demo");
         assert_eq!(merged.password, "secret");
         assert!(merged.auto_sync);
+        assert!(merged.exclude_history);
         assert_eq!(merged.status.last_error.as_deref(), Some("boom"));
     }
 }
@@ -1,5 +1,6 @@
 use crate::app_config::AppType;
 use crate::cli::ui::{create_table, error, highlight, info, success};
+use crate::cli::{selected_apps, AppSelection};
 use crate::error::AppError;
 use crate::services::env_checker;
 use crate::services::local_env_check::{check_local_environment, ToolCheckStatus};
@@ -15,12 +16,12 @@ pub enum EnvCommand {
     Tools,
 }
 
-pub fn execute(cmd: EnvCommand, app: Option<AppType>) -> Result<(), AppError> {
-    let app_type = app.unwrap_or(AppType::Claude);
+pub fn execute(cmd: EnvCommand, app: Option<AppSelection>) -> Result<(), AppError> {
+    let apps = selected_apps(app.as_ref(), &[AppType::Claude]);
 
     match cmd {
-        EnvCommand::Check => check_conflicts(app_type),
-        EnvCommand::List => list_env_vars(app_type),
+        EnvCommand::Check => apps.into_iter().try_for_each(check_conflicts),
+        EnvCommand::List => apps.into_iter().try_for_each(list_env_vars),
         EnvCommand::Tools => check_local_tools(),
     }
 }
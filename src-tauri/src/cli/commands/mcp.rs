@@ -1,15 +1,27 @@
 use clap::Subcommand;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use crate::app_config::{AppType, McpApps, McpServer};
-use crate::cli::ui::{create_table, error, highlight, info, success};
+use crate::cli::ui::{create_table, emit_output, error, highlight, info, success, warning};
 use crate::error::AppError;
-use crate::services::McpService;
+use crate::services::{
+    McpCheckLevel, McpServerCheck, McpServerCheckResult, McpService, McpSortBy, McpSyncOutcome,
+};
+use crate::settings::McpSyncSettings;
 use crate::store::AppState;
 
 #[derive(Subcommand)]
 pub enum McpCommand {
     /// List all MCP servers
-    List,
+    List {
+        /// Only show servers enabled for --app but missing from its live config (needs `mcp sync`)
+        #[arg(long)]
+        unsynced: bool,
+        /// Sort order: id (default), name, or enabled (servers enabled for --app first)
+        #[arg(long, value_enum, default_value_t = McpSortBy::Id, conflicts_with = "unsynced")]
+        sort_by: McpSortBy,
+    },
     /// Add a new MCP server (interactive)
     Add,
     /// Edit an MCP server
@@ -37,25 +49,85 @@ pub enum McpCommand {
         /// Command to validate
         command: String,
     },
+    /// Check every server enabled for --app: stdio command on PATH, or http/sse URL reachable
+    #[command(alias = "doctor")]
+    ValidateAll {
+        /// URL probe timeout in milliseconds (defaults to the global network timeout, else 5000)
+        #[arg(long, value_name = "N")]
+        timeout_ms: Option<u64>,
+        /// Disable servers whose command is not on PATH for --app (previews unless --yes)
+        #[arg(long)]
+        fix: bool,
+        /// Apply the --fix changes
+        #[arg(long, requires = "fix")]
+        yes: bool,
+    },
     /// Sync MCP configuration to live files
-    Sync,
-    /// Import MCP servers from live configuration
-    Import,
+    Sync {
+        /// App order to sync in, e.g. codex,claude (unlisted apps follow in default order)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        order: Vec<AppType>,
+        /// Keep syncing the remaining apps when one app fails
+        #[arg(long)]
+        continue_on_error: bool,
+        /// Save --order/--continue-on-error as the default sync policy
+        #[arg(long)]
+        save: bool,
+    },
+    /// Import MCP servers from live configuration, or from an `mcp export` file
+    Import {
+        /// List the servers that would be imported without changing anything
+        #[arg(long, conflicts_with = "file")]
+        dry_run: bool,
+        /// Import from this export file; servers are enabled for --app (or the app it was exported for)
+        #[arg(long, value_name = "FILE")]
+        file: Option<PathBuf>,
+    },
+    /// Export MCP servers as JSON; with --app only servers enabled for that app
+    Export {
+        /// Write to this file atomically instead of stdout (`-` for stdout)
+        #[arg(long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
 }
 
 pub fn execute(cmd: McpCommand, app: Option<AppType>) -> Result<(), AppError> {
-    let app_type = app.unwrap_or(AppType::Claude);
+    let app_type = app.clone().unwrap_or(AppType::Claude);
 
     match cmd {
-        McpCommand::List => list_servers(app_type),
+        McpCommand::List {
+            unsynced: false,
+            sort_by,
+        } => list_servers(app_type, sort_by),
+        McpCommand::List { unsynced: true, .. } => list_unsynced_servers(app_type),
         McpCommand::Add => add_server(app_type),
         McpCommand::Edit { id } => edit_server(app_type, &id),
         McpCommand::Delete { id } => delete_server(&id),
         McpCommand::Enable { id } => enable_server(app_type, &id),
         McpCommand::Disable { id } => disable_server(app_type, &id),
         McpCommand::Validate { command } => validate_command(&command),
-        McpCommand::Sync => sync_servers(),
-        McpCommand::Import => import_servers(app_type),
+        McpCommand::ValidateAll {
+            timeout_ms,
+            fix,
+            yes,
+        } => {
+            let timeout = crate::proxy::resolve_timeout(
+                timeout_ms.map(Duration::from_millis),
+                Duration::from_millis(5000),
+            );
+            validate_all_servers(&app_type, timeout, fix, yes)
+        }
+        McpCommand::Sync {
+            order,
+            continue_on_error,
+            save,
+        } => sync_servers(order, continue_on_error, save),
+        McpCommand::Import {
+            file: Some(file), ..
+        } => import_servers_from_file(&file, app),
+        McpCommand::Import { dry_run: false, .. } => import_servers(app_type),
+        McpCommand::Import { dry_run: true, .. } => preview_import_servers(app_type),
+        McpCommand::Export { output } => export_servers(output.as_deref(), app.as_ref()),
     }
 }
 
@@ -63,7 +135,7 @@ fn get_state() -> Result<AppState, AppError> {
     AppState::try_new()
 }
 
-fn list_servers(app_type: AppType) -> Result<(), AppError> {
+fn list_servers(app_type: AppType, sort_by: McpSortBy) -> Result<(), AppError> {
     let state = get_state()?;
     let servers = McpService::get_all_servers(&state)?;
 
@@ -77,9 +149,8 @@ fn list_servers(app_type: AppType) -> Result<(), AppError> {
     let mut table = create_table();
     table.set_header(vec!["ID", "Name", "Claude", "Codex", "Gemini", "Tags"]);
 
-    // 按 ID 排序
     let mut server_list: Vec<_> = servers.into_iter().collect();
-    server_list.sort_by(|(a, _), (b, _)| a.cmp(b));
+    server_list.sort_by(|(id_a, a), (id_b, b)| sort_by.compare(&app_type, (id_a, a), (id_b, b)));
 
     for (id, server) in server_list {
         let claude_marker = if server.apps.claude { "✓" } else { " " };
@@ -110,6 +181,39 @@ fn list_servers(app_type: AppType) -> Result<(), AppError> {
     Ok(())
 }
 
+fn list_unsynced_servers(app_type: AppType) -> Result<(), AppError> {
+    let state = get_state()?;
+    let missing = McpService::list_unsynced(&state, &app_type)?;
+
+    if missing.is_empty() {
+        println!(
+            "{}",
+            success(&format!(
+                "✓ All MCP servers enabled for {} are present in its live config",
+                app_type.as_str()
+            ))
+        );
+        return Ok(());
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["ID", "Name"]);
+    for server in &missing {
+        table.add_row(vec![server.id.clone(), server.name.clone()]);
+    }
+
+    println!("{}", table);
+    println!(
+        "\n{} {} server(s) enabled for {} but missing from its live config",
+        info("ℹ"),
+        missing.len(),
+        app_type.as_str()
+    );
+    println!("{} Run 'cc-switch mcp sync' to fix the drift", info("→"));
+
+    Ok(())
+}
+
 fn delete_server(id: &str) -> Result<(), AppError> {
     let state = get_state()?;
 
@@ -232,12 +336,47 @@ fn disable_server(app_type: AppType, id: &str) -> Result<(), AppError> {
     Ok(())
 }
 
-fn sync_servers() -> Result<(), AppError> {
+fn sync_servers(order: Vec<AppType>, continue_on_error: bool, save: bool) -> Result<(), AppError> {
     let state = get_state()?;
 
+    let settings = if order.is_empty() && !continue_on_error && !save {
+        crate::settings::get_mcp_sync_settings()
+    } else {
+        McpSyncSettings {
+            order,
+            continue_on_error,
+        }
+    };
+    if save {
+        crate::settings::set_mcp_sync_settings(settings.clone())?;
+        println!("{}", success("✓ Saved MCP sync policy"));
+    }
+
     println!("{}", info("Syncing all enabled MCP servers..."));
 
-    McpService::sync_all_enabled(&state)?;
+    let results = McpService::sync_all_enabled_with(&state, &settings)?;
+    let mut failed = Vec::new();
+    for result in &results {
+        let app = result.app.as_str();
+        match &result.outcome {
+            McpSyncOutcome::Synced(count) => {
+                println!("  {}", success(&format!("✓ {app}: {count} server(s)")))
+            }
+            McpSyncOutcome::Failed(err) => {
+                println!("  {}", error(&format!("✗ {app}: {err}")));
+                failed.push(app);
+            }
+            McpSyncOutcome::Skipped => {
+                println!("  {}", info(&format!("- {app}: skipped")))
+            }
+        }
+    }
+    if !failed.is_empty() {
+        return Err(AppError::Message(format!(
+            "MCP sync failed for: {}",
+            failed.join(", ")
+        )));
+    }
 
     println!("{}", success("✓ All MCP servers synced successfully"));
     println!(
@@ -248,6 +387,37 @@ fn sync_servers() -> Result<(), AppError> {
     Ok(())
 }
 
+fn export_servers(output: Option<&Path>, app: Option<&AppType>) -> Result<(), AppError> {
+    let state = get_state()?;
+    let (json, count) = McpService::export_json(&state, app)?;
+    emit_output(output, &json)?;
+    let scope = app
+        .map(|app| format!(" enabled for {}", app.as_str()))
+        .unwrap_or_default();
+    // 写到 stderr，导出到 stdout 时不破坏 JSON
+    eprintln!(
+        "{}",
+        info(&format!("Exported {count} MCP server(s){scope}"))
+    );
+    Ok(())
+}
+
+fn import_servers_from_file(file: &Path, app: Option<AppType>) -> Result<(), AppError> {
+    let state = get_state()?;
+    let summary = McpService::import_from_path(&state, file, app)?;
+    println!(
+        "{}",
+        success(&format!(
+            "✓ Imported MCP servers from {}: {} added, {} enabled, {} unchanged",
+            file.display(),
+            summary.added,
+            summary.enabled,
+            summary.unchanged
+        ))
+    );
+    Ok(())
+}
+
 fn import_servers(app_type: AppType) -> Result<(), AppError> {
     let state = get_state()?;
     let app_str = app_type.as_str().to_string();
@@ -289,6 +459,53 @@ fn import_servers(app_type: AppType) -> Result<(), AppError> {
     Ok(())
 }
 
+fn preview_import_servers(app_type: AppType) -> Result<(), AppError> {
+    let state = get_state()?;
+    let app_str = app_type.as_str().to_string();
+
+    let previews = match app_type {
+        AppType::OpenCode => Vec::new(),
+        _ => McpService::preview_import(&state, &app_type)?,
+    };
+
+    if previews.is_empty() {
+        println!(
+            "{}",
+            info(&format!("No new MCP servers found in {} config.", app_str))
+        );
+        return Ok(());
+    }
+
+    let added = previews.iter().filter(|p| p.is_new).count();
+    println!(
+        "{}",
+        highlight(&format!(
+            "Dry run: would import {} MCP server(s) from {} ({} new, {} existing)",
+            previews.len(),
+            app_str,
+            added,
+            previews.len() - added
+        ))
+    );
+    for preview in &previews {
+        let action = if preview.is_new { "new" } else { "enable" };
+        println!("  {:<7} {} ({})", action, preview.id, preview.transport);
+    }
+    if added < previews.len() {
+        // 导入只为已存在的服务器启用该应用，不会覆盖其已保存的定义
+        println!(
+            "{}",
+            info(&format!(
+                "Existing servers keep their stored definition; import only enables them for {}.",
+                app_str
+            ))
+        );
+    }
+    println!("{}", info("No changes were made."));
+
+    Ok(())
+}
+
 fn add_server(_app_type: AppType) -> Result<(), AppError> {
     let state = get_state()?;
 
@@ -369,6 +586,125 @@ fn edit_server(_app_type: AppType, id: &str) -> Result<(), AppError> {
     Ok(())
 }
 
+fn validate_all_servers(
+    app_type: &AppType,
+    timeout: Duration,
+    fix: bool,
+    yes: bool,
+) -> Result<(), AppError> {
+    let state = get_state()?;
+    let results = McpService::validate_all(&state, app_type, timeout)?;
+
+    if results.is_empty() {
+        println!(
+            "{}",
+            info(&format!(
+                "No MCP servers enabled for {}.",
+                app_type.as_str()
+            ))
+        );
+        return Ok(());
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["ID", "Target", "Result", "Detail"]);
+    for result in &results {
+        let level = match result.check.level() {
+            McpCheckLevel::Pass => "pass",
+            McpCheckLevel::Warn => "warn",
+            McpCheckLevel::Fail => "fail",
+        };
+        let detail = match &result.check {
+            McpServerCheck::CommandFound(path) => format!("found at {}", path.display()),
+            McpServerCheck::CommandMissing(_) => "not found in PATH".to_string(),
+            McpServerCheck::UrlReachable(status) => format!("HTTP {status}"),
+            McpServerCheck::UrlServerError(status) => format!("HTTP {status} (server error)"),
+            McpServerCheck::UrlUnreachable(reason) => format!("unreachable: {reason}"),
+            McpServerCheck::NoTarget => "no command or url configured".to_string(),
+        };
+        table.add_row(vec![
+            result.id.clone(),
+            result.target.clone(),
+            level.to_string(),
+            detail,
+        ]);
+    }
+    println!("{}", table);
+
+    let count = |level: McpCheckLevel| {
+        results
+            .iter()
+            .filter(|result| result.check.level() == level)
+            .count()
+    };
+    let (passed, warned, mut failed) = (
+        count(McpCheckLevel::Pass),
+        count(McpCheckLevel::Warn),
+        count(McpCheckLevel::Fail),
+    );
+    if fix {
+        failed -= fix_missing_commands(&state, app_type, &results, yes)?;
+    }
+    let summary = format!("{passed} passed, {warned} warning(s), {failed} failed");
+    if failed > 0 {
+        println!("{}", error(&summary));
+        return Err(AppError::localized(
+            "mcp.validate.failed",
+            format!("{failed} 个 MCP 服务器检查未通过"),
+            format!("{failed} MCP server(s) failed validation"),
+        ));
+    }
+    if warned > 0 {
+        println!("{}", warning(&summary));
+    } else {
+        println!("{}", success(&summary));
+    }
+    Ok(())
+}
+
+/// 处理 `--fix`：未加 `--yes` 时只列出将被关闭的服务器；返回实际关闭的数量
+fn fix_missing_commands(
+    state: &AppState,
+    app_type: &AppType,
+    results: &[McpServerCheckResult],
+    yes: bool,
+) -> Result<usize, AppError> {
+    let missing: Vec<&str> = results
+        .iter()
+        .filter(|result| matches!(result.check, McpServerCheck::CommandMissing(_)))
+        .map(|result| result.id.as_str())
+        .collect();
+    if missing.is_empty() {
+        println!(
+            "{}",
+            info("Nothing to fix: every stdio command is on PATH.")
+        );
+        return Ok(0);
+    }
+    if !yes {
+        println!(
+            "{}",
+            warning(&format!(
+                "Would disable for {}: {} (re-run with --fix --yes to apply)",
+                app_type.as_str(),
+                missing.join(", ")
+            ))
+        );
+        return Ok(0);
+    }
+
+    let disabled = McpService::disable_missing_commands(state, app_type, results)?;
+    println!(
+        "{}",
+        success(&format!(
+            "✓ Disabled for {}: {}",
+            app_type.as_str(),
+            disabled.join(", ")
+        ))
+    );
+    Ok(disabled.len())
+}
+
 fn validate_command(command: &str) -> Result<(), AppError> {
     println!("{}", info(&format!("Validating command '{}'...", command)));
 
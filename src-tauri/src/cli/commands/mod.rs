@@ -1,12 +1,25 @@
+pub mod completions;
 pub mod config;
+mod config_backup;
+mod config_bundle;
 mod config_common;
+mod config_db_check;
+mod config_hooks;
+mod config_prefs;
+mod config_transfer;
+mod config_validate;
 pub mod config_webdav;
 pub mod env;
 pub mod mcp;
 pub mod prompts;
 pub mod provider;
+mod provider_current;
+pub mod provider_failover;
 pub mod provider_input;
 mod provider_inspect;
+mod provider_live;
+mod provider_manage;
+mod provider_switch;
 pub mod proxy;
 pub mod skills;
 pub mod update;
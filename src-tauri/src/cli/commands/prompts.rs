@@ -37,6 +37,13 @@ pub enum PromptsCommand {
         /// Prompt preset ID
         id: String,
     },
+    /// Reorder prompt presets: the given IDs move to the top in that order
+    #[command(alias = "move")]
+    Reorder {
+        /// Prompt preset IDs in the desired order (unlisted presets keep their order after them)
+        #[arg(required = true)]
+        ids: Vec<String>,
+    },
 }
 
 pub fn execute(cmd: PromptsCommand, app: Option<AppType>) -> Result<(), AppError> {
@@ -51,6 +58,7 @@ pub fn execute(cmd: PromptsCommand, app: Option<AppType>) -> Result<(), AppError
         PromptsCommand::Edit { id } => edit_prompt(app_type, &id),
         PromptsCommand::Delete { id } => delete_prompt(app_type, &id),
         PromptsCommand::Show { id } => show_prompt(app_type, &id),
+        PromptsCommand::Reorder { ids } => reorder_prompts(app_type, &ids),
     }
 }
 
@@ -72,11 +80,8 @@ fn list_prompts(app_type: AppType) -> Result<(), AppError> {
     let mut table = create_table();
     table.set_header(vec!["", "ID", "Name", "Description", "Updated"]);
 
-    // 按更新时间排序
-    let mut prompt_list: Vec<_> = prompts.into_iter().collect();
-    prompt_list.sort_by(|(_, a), (_, b)| b.updated_at.unwrap_or(0).cmp(&a.updated_at.unwrap_or(0)));
-
-    for (id, prompt) in prompt_list {
+    // 按自定义顺序排序，未排序的按更新时间倒序
+    for (id, prompt) in PromptService::sorted_prompts(prompts) {
         let enabled_marker = if prompt.enabled { "✓" } else { " " };
         let updated = prompt
             .updated_at
@@ -119,6 +124,27 @@ fn list_prompts(app_type: AppType) -> Result<(), AppError> {
     Ok(())
 }
 
+fn reorder_prompts(app_type: AppType, ids: &[String]) -> Result<(), AppError> {
+    let state = get_state()?;
+    let prompts = PromptService::get_prompts(&state, app_type.clone())?;
+    if let Some(missing) = ids.iter().find(|id| !prompts.contains_key(id.as_str())) {
+        return Err(AppError::Message(format!(
+            "Prompt preset '{}' not found",
+            missing
+        )));
+    }
+
+    let order = PromptService::reorder_prompts(&state, app_type.clone(), ids)?;
+
+    println!("{}", success("✓ Reordered prompt presets"));
+    for (index, id) in order.iter().enumerate() {
+        println!("  {}. {}", index + 1, id);
+    }
+    println!("{}", info(&format!("  Application: {}", app_type.as_str())));
+
+    Ok(())
+}
+
 fn show_current(app_type: AppType) -> Result<(), AppError> {
     let state = get_state()?;
     let prompts = PromptService::get_prompts(&state, app_type.clone())?;
@@ -321,6 +347,7 @@ fn create_prompt(_app_type: AppType) -> Result<(), AppError> {
         enabled: false,
         created_at: Some(timestamp),
         updated_at: Some(timestamp),
+        sort_index: None,
     };
 
     PromptService::upsert_prompt(&state, _app_type.clone(), &id, prompt)?;
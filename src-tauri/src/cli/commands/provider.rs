@@ -1,6 +1,12 @@
 use clap::Subcommand;
+use std::path::PathBuf;
+use std::time::Duration;
 
-use super::provider_inspect;
+pub use super::provider_switch::{live_write_lines, resolve_switch_target};
+use super::{
+    provider_current, provider_failover, provider_inspect, provider_live, provider_manage,
+    provider_switch,
+};
 use crate::app_config::AppType;
 use crate::cli::commands::provider_input::{
     current_timestamp, display_provider_summary, generate_provider_id, prompt_basic_fields,
@@ -8,10 +14,12 @@ use crate::cli::commands::provider_input::{
     ProviderAddMode,
 };
 use crate::cli::i18n::texts;
-use crate::cli::ui::{error, highlight, info, success, warning};
+use crate::cli::ui::{highlight, info, success, warning};
+use crate::cli::{selected_apps, single_app, AppSelection};
 use crate::error::AppError;
-use crate::provider::{Provider, ProviderMeta};
-use crate::services::ProviderService;
+use crate::provider::{CodexAuthTarget, Provider, ProviderMeta};
+use crate::services::provider::SettingsPatch;
+use crate::services::{HealthWait, ProviderService, SwitchOptions};
 use crate::store::AppState;
 use inquire::{Confirm, Select, Text};
 
@@ -19,33 +27,215 @@ fn supports_official_provider(app_type: &AppType) -> bool {
     matches!(app_type, AppType::Codex)
 }
 
+/// `provider list --sort-by`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ProviderSortBy {
+    /// Manual sort order, then creation time
+    Order,
+    /// Most recently switched-to first; never-used providers last
+    LastUsed,
+}
+
 #[derive(Subcommand)]
 pub enum ProviderCommand {
     /// List all providers
-    List,
+    List {
+        /// Only show the current provider (use `--app all` for every app)
+        #[arg(long)]
+        current_only: bool,
+        /// Print as JSON (with --current-only)
+        #[arg(long, requires = "current_only")]
+        json: bool,
+        /// Sort order for the list
+        #[arg(long, value_enum, default_value_t = ProviderSortBy::Order)]
+        sort_by: ProviderSortBy,
+        /// Also list archived providers
+        #[arg(long)]
+        archived: bool,
+        /// Only list favorite providers
+        #[arg(long)]
+        favorites: bool,
+        /// Append tags, notes, usage-script status and last-used time columns
+        #[arg(long)]
+        show_meta: bool,
+    },
     /// Show current provider
     Current,
+    /// Show one provider's details (name, url, model, tags, notes, current flag)
+    Show {
+        /// Provider ID
+        id: String,
+        /// Print greppable `key: value` lines instead of a table
+        #[arg(long)]
+        plain: bool,
+        /// Print the API key unmasked
+        #[arg(long)]
+        reveal: bool,
+    },
     /// Switch to a provider
+    ///
+    /// Accepts `switch <ID>` (app from `--app`) or `switch <APP> <ID>`. With two
+    /// positionals the first is always the app; a single positional is always the ID.
     Switch {
-        /// Provider ID to switch to
-        id: String,
+        /// Provider ID to switch to, or the app when followed by an ID
+        #[arg(
+            value_name = "APP|ID",
+            required_unless_present_any = ["next", "prev", "target_id"]
+        )]
+        id: Option<String>,
+        /// Provider ID when the first positional names an app
+        #[arg(value_name = "ID")]
+        provider: Option<String>,
+        /// Create the app's config dir and write live files even if the app was never run
+        #[arg(long)]
+        force_init: bool,
+        /// Poll the new endpoint after switching and roll back if it never becomes healthy
+        #[arg(long)]
+        wait_healthy: bool,
+        /// Total time to wait for a healthy endpoint in milliseconds (with --wait-healthy)
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = 5000,
+            requires = "wait_healthy"
+        )]
+        timeout_ms: u64,
+        /// Delay between health probes in milliseconds (with --wait-healthy)
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = 500,
+            requires = "wait_healthy"
+        )]
+        poll_interval_ms: u64,
+        /// Keep the switch when the endpoint stays unhealthy (still exits non-zero)
+        #[arg(long, requires = "wait_healthy")]
+        no_rollback: bool,
+        /// Switch Codex even if live config.toml has MCP servers not in the MCP store
+        #[arg(long)]
+        confirm: bool,
+        /// Roll back the switch if any MCP server fails to sync (default: warn and continue)
+        #[arg(long)]
+        strict_mcp: bool,
+        /// Keep the stored provider snapshot as authored instead of re-reading the live files
+        /// (faster, works on read-only config dirs, but skips normalization)
+        #[arg(long)]
+        no_refresh_snapshot: bool,
+        /// Temporary override for this switch's live config only, as dotted.path=value or
+        /// /json/pointer=value (repeatable), e.g. env.ANTHROPIC_MODEL=claude-x or
+        /// /env/ANTHROPIC_MODEL=claude-x; the stored provider is unchanged.
+        /// Codex accepts only JSON paths such as auth.OPENAI_API_KEY (config.toml is text)
+        #[arg(long = "set", value_name = "PATH=VALUE")]
+        sets: Vec<String>,
+        /// Don't capture the current live config into the outgoing provider before switching
+        /// (for a broken live config); that provider's stored snapshot is left as it was
+        #[arg(long)]
+        no_backfill: bool,
+        /// Switch to the next provider in sort order (wraps; picks the first when none is current)
+        #[arg(long, conflicts_with_all = ["id", "prev", "target_id"])]
+        next: bool,
+        /// Switch to the previous provider in sort order (wraps)
+        #[arg(long, conflicts_with_all = ["id", "target_id"])]
+        prev: bool,
+        /// Provider ID or name to switch to; with `--app all` every app that has it is switched
+        /// by exact ID or name (apps without it are skipped). Combines with --confirm,
+        /// --force-init and --no-refresh-snapshot; use the positional ID for the other options
+        #[arg(
+            long = "id",
+            value_name = "ID",
+            conflicts_with_all = [
+                "id",
+                "sets",
+                "wait_healthy",
+                "strict_mcp",
+                "no_backfill",
+            ]
+        )]
+        target_id: Option<String>,
     },
     /// Add a new provider (interactive)
-    Add,
+    Add {
+        /// Create the provider by copying the API URL and key of this provider (non-interactive)
+        #[arg(long, value_name = "ID")]
+        from_provider: Option<String>,
+        /// App the --from-provider belongs to (defaults to the target --app)
+        #[arg(long, value_enum, requires = "from_provider")]
+        from_app: Option<AppType>,
+        /// Create the provider from this app's live config (API URL and key are mapped
+        /// when it differs from --app)
+        #[arg(long, value_enum, value_name = "APP", conflicts_with = "from_provider")]
+        from_live_app: Option<AppType>,
+    },
     /// Edit a provider
     Edit {
         /// Provider ID to edit
         id: String,
     },
+    /// Move a provider to another app, converting its API URL and key where possible
+    Move {
+        /// Provider ID to move
+        id: String,
+        /// App to move the provider to
+        #[arg(long, value_enum)]
+        to_app: AppType,
+    },
+    /// Show which stored provider the live config matches (base URL, model, wire_api)
+    Identify,
+    /// Refresh the current provider's stored snapshot from its live config
+    Snapshot {
+        /// Provider ID (defaults to the current provider; must be the current one)
+        id: Option<String>,
+    },
+    /// Seed a provider's stored snapshot from a config file without touching live files
+    /// (for provisioning before the app is installed)
+    SetCurrentFrom {
+        /// Config file: the provider's settings JSON, or a config.toml (Codex) / .env (Gemini)
+        file: PathBuf,
+        /// Provider ID (defaults to the current provider)
+        #[arg(long)]
+        id: Option<String>,
+    },
+    /// Archive a provider: hidden from list and export, cannot be switched to
+    Archive {
+        /// Provider ID to archive
+        id: String,
+    },
+    /// Restore an archived provider
+    Unarchive {
+        /// Provider ID to restore
+        id: String,
+    },
+    /// Mark a provider as a favorite (TUI: `F` shows favorites only)
+    Favorite {
+        /// Provider ID
+        id: String,
+    },
+    /// Remove a provider from favorites
+    Unfavorite {
+        /// Provider ID
+        id: String,
+    },
     /// Delete a provider
     Delete {
         /// Provider ID to delete
         id: String,
     },
-    /// Duplicate a provider
-    Duplicate {
-        /// Provider ID to duplicate
+    /// Import a provider from a `ccswitch://` share link
+    ImportLink {
+        /// The `ccswitch://v1/import?...` URL
+        url: String,
+        /// Print the provider the link would create without saving it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Copy a provider under a new name (never made current)
+    #[command(alias = "duplicate")]
+    Clone {
+        /// Provider ID to copy
         id: String,
+        /// Display name of the copy; its ID is derived from this name
+        #[arg(long)]
+        name: String,
     },
     /// Test provider endpoint speed
     Speedtest {
@@ -57,31 +247,251 @@ pub enum ProviderCommand {
         /// Provider ID to check
         id: String,
     },
+    /// Run the provider's usage script and show the remaining quota
+    Usage {
+        /// Provider ID to query
+        id: String,
+        /// Override a script credential for this run only (repeatable):
+        /// API_KEY, BASE_URL, ACCESS_TOKEN or USER_ID
+        #[arg(long = "env", value_name = "KEY=VALUE")]
+        env: Vec<String>,
+    },
     /// Fetch remote model list for a provider
     FetchModels {
         /// Provider ID to query
         id: String,
     },
+    /// Show or change provider tags
+    Tag {
+        /// Provider ID
+        id: String,
+        /// Tag to add (repeatable)
+        #[arg(long)]
+        add: Vec<String>,
+        /// Tag to remove (repeatable)
+        #[arg(long)]
+        remove: Vec<String>,
+    },
+    /// Apply a settings patch to all providers sharing a tag (all-or-nothing)
+    GroupSet {
+        /// Tag selecting the providers to update
+        #[arg(long)]
+        tag: String,
+        /// Settings patch as dotted.path=value or RFC 6901 /json/pointer=value (repeatable),
+        /// e.g. env.ANTHROPIC_BASE_URL=https://proxy or /env/ANTHROPIC_BASE_URL=https://proxy;
+        /// use the pointer form for keys containing '.' (escape '/' as ~1 and '~' as ~0)
+        #[arg(long = "set", value_name = "PATH=VALUE", required = true)]
+        sets: Vec<String>,
+    },
+    /// Change a provider's ID (the display name is unchanged)
+    RenameId {
+        /// Current provider ID
+        old: String,
+        /// New provider ID
+        new: String,
+    },
+    /// Export every provider of the app as one JSON file each (for version control)
+    ExportAll {
+        /// Target directory; files are named after sanitized provider IDs
+        #[arg(long, value_name = "DIR")]
+        split: PathBuf,
+        /// Replace API keys and tokens with a placeholder
+        #[arg(long)]
+        redact: bool,
+    },
+    /// Print the exact live config files a switch would write, without writing them
+    PreviewLive {
+        /// Provider ID
+        id: String,
+        /// Write to this file atomically instead of stdout (`-` for stdout)
+        #[arg(long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+    /// Manage the failover queue and run a health-gated switch
+    #[command(subcommand)]
+    Failover(provider_failover::FailoverCommand),
+    /// Show or change where a Codex provider's API key is written (auth.json or ~/.codex/.env)
+    CodexAuth {
+        /// Provider ID
+        id: String,
+        /// New target; omit to show the current one
+        #[arg(value_enum)]
+        target: Option<CodexAuthTarget>,
+        /// Print an `export NAME="key"` line instead of relying on a file
+        #[arg(long)]
+        print_export: bool,
+    },
 }
 
-pub fn execute(cmd: ProviderCommand, app: Option<AppType>) -> Result<(), AppError> {
-    let app_type = app.unwrap_or(AppType::Claude);
+pub fn execute(
+    cmd: ProviderCommand,
+    app: Option<AppSelection>,
+    verbose: bool,
+) -> Result<(), AppError> {
+    if let ProviderCommand::List {
+        current_only: true,
+        json,
+        ..
+    } = cmd
+    {
+        let apps = selected_apps(app.as_ref(), &[AppType::Claude]);
+        return provider_current::list_current_providers(&apps, json);
+    }
+    if let (
+        Some(AppSelection::All),
+        ProviderCommand::Switch {
+            target_id: Some(target),
+            confirm,
+            force_init,
+            no_refresh_snapshot,
+            ..
+        },
+    ) = (app.as_ref(), &cmd)
+    {
+        return provider_switch::switch_all_apps(
+            target,
+            *confirm,
+            *force_init,
+            SwitchOptions {
+                refresh_snapshot: !no_refresh_snapshot,
+                ..SwitchOptions::default()
+            },
+        );
+    }
+    let explicit_app = single_app(app)?;
+    let app_type = explicit_app.clone().unwrap_or(AppType::Claude);
 
     match cmd {
-        ProviderCommand::List => provider_inspect::list_providers(app_type),
+        ProviderCommand::List {
+            sort_by,
+            archived,
+            favorites,
+            show_meta,
+            ..
+        } => provider_inspect::list_providers(app_type, sort_by, archived, favorites, show_meta),
         ProviderCommand::Current => provider_inspect::show_current(app_type),
-        ProviderCommand::Switch { id } => switch_provider(app_type, &id),
-        ProviderCommand::Add => add_provider(app_type),
+        ProviderCommand::Show { id, plain, reveal } => {
+            provider_inspect::show_provider(app_type, &id, plain, reveal)
+        }
+        ProviderCommand::Switch {
+            id,
+            provider,
+            force_init,
+            wait_healthy,
+            timeout_ms,
+            poll_interval_ms,
+            no_rollback,
+            confirm,
+            strict_mcp,
+            no_refresh_snapshot,
+            sets,
+            no_backfill,
+            next,
+            prev: _,
+            target_id,
+        } => {
+            let (app_type, id) = match id.or(target_id) {
+                Some(id) => resolve_switch_target(explicit_app, id, provider)?,
+                // 未给出 ID 时 clap 已要求 --next 或 --prev
+                None => {
+                    let delta = if next { 1 } else { -1 };
+                    let id = ProviderService::relative_provider_id(
+                        &get_state()?,
+                        app_type.clone(),
+                        delta,
+                    )?;
+                    (app_type, id)
+                }
+            };
+            let overlay = sets
+                .iter()
+                .map(|raw| SettingsPatch::parse(raw))
+                .collect::<Result<Vec<_>, _>>()?;
+            let health_wait = wait_healthy.then(|| HealthWait {
+                timeout: Duration::from_millis(timeout_ms),
+                interval: Duration::from_millis(poll_interval_ms),
+                rollback: !no_rollback,
+            });
+            provider_switch::switch_provider(
+                app_type,
+                &id,
+                force_init,
+                health_wait,
+                confirm,
+                SwitchOptions {
+                    strict_mcp,
+                    refresh_snapshot: !no_refresh_snapshot,
+                    overlay,
+                    backfill: !no_backfill,
+                },
+                verbose,
+            )
+        }
+        ProviderCommand::Add {
+            from_live_app: Some(from_app),
+            ..
+        } => provider_manage::add_provider_from_live(from_app, app_type),
+        ProviderCommand::Add {
+            from_provider: None,
+            ..
+        } => add_provider(app_type),
+        ProviderCommand::Add {
+            from_provider: Some(source_id),
+            from_app,
+            ..
+        } => {
+            let from_app = from_app.unwrap_or_else(|| app_type.clone());
+            provider_manage::add_provider_from(from_app, &source_id, app_type)
+        }
         ProviderCommand::Edit { id } => edit_provider(app_type, &id),
+        ProviderCommand::Move { id, to_app } => {
+            provider_manage::move_provider(app_type, &id, to_app)
+        }
+        ProviderCommand::Identify => provider_inspect::identify_live(app_type),
+        ProviderCommand::Snapshot { id } => {
+            provider_live::refresh_snapshot(app_type, id.as_deref())
+        }
+        ProviderCommand::SetCurrentFrom { file, id } => {
+            provider_live::seed_snapshot(app_type, &file, id.as_deref())
+        }
+        ProviderCommand::Archive { id } => provider_manage::set_archived(app_type, &id, true),
+        ProviderCommand::Unarchive { id } => provider_manage::set_archived(app_type, &id, false),
+        ProviderCommand::Favorite { id } => provider_manage::set_favorite(app_type, &id, true),
+        ProviderCommand::Unfavorite { id } => provider_manage::set_favorite(app_type, &id, false),
         ProviderCommand::Delete { id } => delete_provider(app_type, &id),
-        ProviderCommand::Duplicate { id } => duplicate_provider(app_type, &id),
+        ProviderCommand::ImportLink { url, dry_run } => provider_manage::import_link(&url, dry_run),
+        ProviderCommand::Clone { id, name } => {
+            provider_manage::clone_provider(app_type, &id, &name)
+        }
         ProviderCommand::Speedtest { id } => provider_inspect::speedtest_provider(app_type, &id),
         ProviderCommand::StreamCheck { id } => {
             provider_inspect::stream_check_provider(app_type, &id)
         }
+        ProviderCommand::Usage { id, env } => provider_inspect::usage_provider(app_type, &id, &env),
         ProviderCommand::FetchModels { id } => {
             provider_inspect::fetch_models_provider(app_type, &id)
         }
+        ProviderCommand::Tag { id, add, remove } => {
+            provider_manage::tag_provider(app_type, &id, &add, &remove)
+        }
+        ProviderCommand::GroupSet { tag, sets } => {
+            provider_manage::group_set_providers(app_type, &tag, &sets)
+        }
+        ProviderCommand::RenameId { old, new } => {
+            provider_manage::rename_provider_id(app_type, &old, &new)
+        }
+        ProviderCommand::ExportAll { split, redact } => {
+            provider_manage::export_all_providers(app_type, &split, redact)
+        }
+        ProviderCommand::CodexAuth {
+            id,
+            target,
+            print_export,
+        } => provider_live::codex_auth_provider(app_type, &id, target, print_export),
+        ProviderCommand::PreviewLive { id, output } => {
+            provider_live::preview_live_provider(app_type, &id, output.as_deref())
+        }
+        ProviderCommand::Failover(cmd) => provider_failover::execute(cmd, app_type),
     }
 }
 
@@ -89,44 +499,6 @@ fn get_state() -> Result<AppState, AppError> {
     AppState::try_new()
 }
 
-fn switch_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
-    let state = get_state()?;
-    let app_str = app_type.as_str().to_string();
-    let skip_live_sync = !crate::sync_policy::should_sync_live(&app_type);
-
-    // 检查 provider 是否存在
-    let providers = ProviderService::list(&state, app_type.clone())?;
-    let Some(provider) = providers.get(id).cloned() else {
-        return Err(AppError::Message(format!("Provider '{}' not found", id)));
-    };
-
-    // 执行切换
-    ProviderService::switch(&state, app_type.clone(), id)?;
-    if let Err(err) =
-        crate::claude_plugin::sync_claude_plugin_on_provider_switch(&app_type, &provider)
-    {
-        println!(
-            "{}",
-            warning(&texts::claude_plugin_sync_failed_warning(&err.to_string()))
-        );
-    }
-
-    println!("{}", success(&format!("✓ Switched to provider '{}'", id)));
-    println!("{}", info(&format!("  Application: {}", app_str)));
-    if skip_live_sync {
-        println!(
-            "{}",
-            warning(&texts::live_sync_skipped_uninitialized_warning(&app_str))
-        );
-    }
-    println!(
-        "\n{}",
-        info("Note: Restart your CLI client to apply the changes.")
-    );
-
-    Ok(())
-}
-
 fn delete_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
     let state = get_state()?;
 
@@ -299,10 +671,7 @@ fn edit_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
     let original = manager
         .providers
         .get(id)
-        .ok_or_else(|| {
-            let msg = texts::entity_not_found(texts::entity_provider(), id);
-            AppError::localized("provider.not_found", msg.clone(), msg)
-        })?
+        .ok_or_else(|| AppError::provider_not_found(id))?
         .clone();
     let is_current = manager.current == id;
     drop(config);
@@ -382,9 +751,3 @@ fn edit_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
 
     Ok(())
 }
-
-fn duplicate_provider(_app_type: AppType, id: &str) -> Result<(), AppError> {
-    println!("{}", info(&format!("Duplicating provider '{}'...", id)));
-    println!("{}", error("Provider duplication is not yet implemented."));
-    Ok(())
-}
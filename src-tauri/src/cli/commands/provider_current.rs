@@ -0,0 +1,90 @@
+use serde::Serialize;
+
+use super::provider_inspect::extract_api_url;
+use crate::app_config::AppType;
+use crate::cli::i18n::texts;
+use crate::cli::ui::{create_table, warning};
+use crate::error::AppError;
+use crate::services::provider::provider_tags;
+use crate::services::ProviderService;
+use crate::store::AppState;
+
+/// `provider list --current-only` 输出的一行
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CurrentProviderRow {
+    app: String,
+    id: String,
+    name: String,
+    api_url: Option<String>,
+    tags: Vec<String>,
+}
+
+fn collect_current_rows(
+    state: &AppState,
+    apps: &[AppType],
+) -> Result<(Vec<CurrentProviderRow>, Vec<String>), AppError> {
+    let mut rows = Vec::new();
+    let mut missing = Vec::new();
+
+    for app_type in apps {
+        let current_id = ProviderService::current(state, app_type.clone())?;
+        let providers = ProviderService::list(state, app_type.clone())?;
+        match providers.get(&current_id) {
+            Some(provider) => rows.push(CurrentProviderRow {
+                app: app_type.as_str().to_string(),
+                id: current_id,
+                name: provider.name.clone(),
+                api_url: extract_api_url(provider, app_type),
+                tags: provider_tags(provider).to_vec(),
+            }),
+            None => missing.push(app_type.as_str().to_string()),
+        }
+    }
+
+    Ok((rows, missing))
+}
+
+/// 仅输出当前供应商；所选应用均无当前供应商时返回错误（非零退出）
+pub(crate) fn list_current_providers(apps: &[AppType], json: bool) -> Result<(), AppError> {
+    let state = AppState::try_new()?;
+    let (rows, missing) = collect_current_rows(&state, apps)?;
+
+    if rows.is_empty() {
+        let missing = missing.join(", ");
+        return Err(AppError::localized(
+            "provider.current.none",
+            format!("没有当前供应商: {missing}"),
+            format!("No current provider: {missing}"),
+        ));
+    }
+
+    if json {
+        let output = serde_json::to_string_pretty(&rows)
+            .map_err(|e| AppError::Message(format!("Failed to serialize JSON: {e}")))?;
+        println!("{output}");
+        return Ok(());
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["App", "ID", "Name", "API URL", "Tags"]);
+    for row in rows {
+        table.add_row(vec![
+            row.app,
+            row.id,
+            row.name,
+            row.api_url.unwrap_or_else(|| "N/A".to_string()),
+            row.tags.join(", "),
+        ]);
+    }
+    println!("{table}");
+
+    if !missing.is_empty() {
+        println!(
+            "{}",
+            warning(&texts::no_current_provider_for_apps(&missing.join(", ")))
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use clap::Subcommand;
+use std::time::Duration;
+
+use crate::app_config::AppType;
+use crate::cli::i18n::texts;
+use crate::cli::ui::{create_table, error, info, success, warning};
+use crate::error::AppError;
+use crate::services::provider::FailoverOutcome;
+use crate::services::ProviderService;
+use crate::store::AppState;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum FailoverCommand {
+    /// Show the failover queue in order
+    List,
+    /// Add a provider to the failover queue
+    Add {
+        /// Provider ID
+        id: String,
+    },
+    /// Remove a provider from the failover queue
+    Remove {
+        /// Provider ID
+        id: String,
+    },
+    /// Probe queued providers in order and switch to the first healthy one
+    Run {
+        /// Per-provider health check timeout in milliseconds
+        /// (defaults to the global network timeout, else 5000)
+        #[arg(long, value_name = "N")]
+        timeout_ms: Option<u64>,
+    },
+}
+
+pub(super) fn execute(cmd: FailoverCommand, app_type: AppType) -> Result<(), AppError> {
+    let state = AppState::try_new()?;
+    match cmd {
+        FailoverCommand::List => list_queue(&state, app_type),
+        FailoverCommand::Add { id } => {
+            ProviderService::set_in_failover_queue(&state, app_type.clone(), &id, true)?;
+            println!(
+                "{}",
+                success(&texts::failover_queue_added(&id, app_type.as_str()))
+            );
+            Ok(())
+        }
+        FailoverCommand::Remove { id } => {
+            ProviderService::set_in_failover_queue(&state, app_type.clone(), &id, false)?;
+            println!(
+                "{}",
+                success(&texts::failover_queue_removed(&id, app_type.as_str()))
+            );
+            Ok(())
+        }
+        FailoverCommand::Run { timeout_ms } => {
+            let timeout = crate::proxy::resolve_timeout(
+                timeout_ms.map(Duration::from_millis),
+                Duration::from_millis(5000),
+            );
+            run_failover(&state, app_type, timeout)
+        }
+    }
+}
+
+fn list_queue(state: &AppState, app_type: AppType) -> Result<(), AppError> {
+    let queue = ProviderService::failover_queue(state, app_type.clone())?;
+    if queue.is_empty() {
+        println!("{}", info(&texts::failover_queue_empty(app_type.as_str())));
+        return Ok(());
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["#", "ID", "Name"]);
+    for (index, item) in queue.iter().enumerate() {
+        table.add_row(vec![
+            (index + 1).to_string(),
+            item.provider_id.clone(),
+            item.provider_name.clone(),
+        ]);
+    }
+    println!("{}", table);
+    Ok(())
+}
+
+fn run_failover(state: &AppState, app_type: AppType, timeout: Duration) -> Result<(), AppError> {
+    let report = ProviderService::failover_run(state, app_type.clone(), timeout)?;
+
+    for attempt in &report.attempts {
+        let line = match &attempt.outcome {
+            FailoverOutcome::Healthy => success(&format!("✓ {}", attempt.provider_id)),
+            FailoverOutcome::Skipped(reason) => {
+                warning(&format!("- {} ({reason})", attempt.provider_id))
+            }
+            FailoverOutcome::Failed(reason) => {
+                error(&format!("✗ {} ({reason})", attempt.provider_id))
+            }
+        };
+        println!("  {line}");
+    }
+
+    if report.switched {
+        println!(
+            "{}",
+            success(&texts::switched_to_provider(&report.selected))
+        );
+        println!("{}", info(texts::restart_note()));
+    } else {
+        println!(
+            "{}",
+            info(&texts::failover_current_is_healthy(&report.selected))
+        );
+    }
+    Ok(())
+}
@@ -5,6 +5,7 @@ use crate::app_config::AppType;
 use crate::cli::i18n::texts;
 use crate::error::AppError;
 use crate::provider::Provider;
+pub use crate::services::provider::generate_provider_id;
 use colored::Colorize;
 use inquire::{Confirm, Select, Text};
 use serde_json::{json, Value};
@@ -43,6 +44,29 @@ mod tests {
             "official Codex provider should use responses wire API"
         );
     }
+
+    #[test]
+    fn generate_provider_id_never_returns_empty_id() {
+        assert_eq!(generate_provider_id("My Relay", &[]), "my-relay");
+        assert_eq!(generate_provider_id("!!!", &[]), "provider");
+        assert_eq!(generate_provider_id("   ", &[]), "provider");
+        assert_eq!(
+            generate_provider_id("???", &["provider".to_string()]),
+            "provider-1"
+        );
+    }
+
+    #[test]
+    fn codex_provider_key_stays_lowercase_alphanumeric() {
+        assert_eq!(clean_codex_provider_key("My Relay/v2"), "my_relay_v2");
+        // 名称全部被过滤掉时回退为 custom，而不是空键
+        assert_eq!(clean_codex_provider_key("智谱"), "custom");
+        assert_eq!(clean_codex_provider_key("!!!"), "custom");
+        let key = clean_codex_provider_key(&generate_provider_id("Ünïcödé Relay", &[]));
+        assert!(key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'));
+    }
 }
 
 pub fn prompt_settings_config_for_add(
@@ -134,42 +158,6 @@ impl OptionalFields {
     }
 }
 
-/// 生成唯一的 Provider ID
-/// 基于名称转换为 kebab-case，如有冲突则追加数字后缀
-pub fn generate_provider_id(name: &str, existing_ids: &[String]) -> String {
-    // 转换为 kebab-case
-    let base_id = name
-        .to_lowercase()
-        .chars()
-        .map(|c| {
-            if c.is_alphanumeric() || c == '-' || c == '_' {
-                c
-            } else if c.is_whitespace() {
-                '-'
-            } else {
-                '-'
-            }
-        })
-        .collect::<String>()
-        .trim_matches('-')
-        .to_string();
-
-    // 检查唯一性
-    if !existing_ids.contains(&base_id) {
-        return base_id;
-    }
-
-    // 追加数字后缀
-    let mut counter = 1;
-    loop {
-        let candidate = format!("{}-{}", base_id, counter);
-        if !existing_ids.contains(&candidate) {
-            return candidate;
-        }
-        counter += 1;
-    }
-}
-
 /// 收集基本字段：name, website_url
 pub fn prompt_basic_fields(
     current: Option<&Provider>,
@@ -285,6 +273,7 @@ pub fn prompt_settings_config(
 /// - `env_key`: 环境变量键名（如 "ANTHROPIC_MODEL"）
 /// - `placeholder`: 占位符示例值
 /// - `current`: 当前配置（编辑模式）
+/// - `default`: 无现有值时预填的默认模型（设置中的 `defaultModels`）
 ///
 /// # 返回
 /// - `Some(value)`: 用户输入了值或需要保留现有值
@@ -294,6 +283,7 @@ fn prompt_model_field(
     env_key: &str,
     placeholder: &str,
     current: Option<&Value>,
+    default: Option<&str>,
 ) -> Result<Option<String>, AppError> {
     // 尝试提取现有值
     let existing_value = current
@@ -301,7 +291,7 @@ fn prompt_model_field(
         .and_then(|e| e.get(env_key))
         .and_then(|m| m.as_str());
 
-    let input = if let Some(existing) = existing_value {
+    let input = if let Some(existing) = existing_value.or(default) {
         // 编辑模式 - 有现有值：预填充
         Text::new(&format!("{}：", field_name))
             .with_initial_value(existing)
@@ -388,13 +378,30 @@ fn prompt_claude_config(current: Option<&Value>) -> Result<Value, AppError> {
     env.insert("ANTHROPIC_AUTH_TOKEN".to_string(), json!(api_key.trim()));
     env.insert("ANTHROPIC_BASE_URL".to_string(), json!(base_url.trim()));
 
-    if config_models {
+    // 新增时的默认模型：用于预填或直接写入 DEFAULT_*_MODEL
+    let default_model = current
+        .is_none()
+        .then(|| crate::settings::get_default_model(&AppType::Claude))
+        .flatten();
+
+    if !config_models {
+        if let Some(model) = default_model.as_deref() {
+            for key in [
+                "ANTHROPIC_DEFAULT_HAIKU_MODEL",
+                "ANTHROPIC_DEFAULT_SONNET_MODEL",
+                "ANTHROPIC_DEFAULT_OPUS_MODEL",
+            ] {
+                env.insert(key.to_string(), json!(model));
+            }
+        }
+    } else {
         // 使用新的辅助函数处理四个模型字段
         let model = prompt_model_field(
             texts::model_default_label(),
             "ANTHROPIC_MODEL",
             texts::model_sonnet_placeholder(),
             current,
+            None,
         )?;
 
         let haiku = prompt_model_field(
@@ -402,6 +409,7 @@ fn prompt_claude_config(current: Option<&Value>) -> Result<Value, AppError> {
             "ANTHROPIC_DEFAULT_HAIKU_MODEL",
             texts::model_haiku_placeholder(),
             current,
+            default_model.as_deref(),
         )?;
 
         let sonnet = prompt_model_field(
@@ -409,6 +417,7 @@ fn prompt_claude_config(current: Option<&Value>) -> Result<Value, AppError> {
             "ANTHROPIC_DEFAULT_SONNET_MODEL",
             texts::model_sonnet_placeholder(),
             current,
+            default_model.as_deref(),
         )?;
 
         let opus = prompt_model_field(
@@ -416,6 +425,7 @@ fn prompt_claude_config(current: Option<&Value>) -> Result<Value, AppError> {
             "ANTHROPIC_DEFAULT_OPUS_MODEL",
             texts::model_opus_placeholder(),
             current,
+            default_model.as_deref(),
         )?;
 
         // 条件写入：只在值存在时写入配置
@@ -517,7 +527,8 @@ fn prompt_codex_config(current: Option<&Value>) -> Result<Value, AppError> {
     }
 
     // 3. Model
-    let model = if let Some(current) = current_model.as_deref() {
+    let default_model = crate::settings::get_default_model(&AppType::Codex);
+    let model = if let Some(current) = current_model.as_deref().or(default_model.as_deref()) {
         Text::new(&format!("{}:", texts::model_label()))
             .with_initial_value(current)
             .with_help_message("Model name (e.g., gpt-5.2-codex, o3)")
@@ -591,7 +602,8 @@ fn prompt_codex_official_config(current: Option<&Value>) -> Result<Value, AppErr
             .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
     };
 
-    let model = if let Some(current) = current_model.as_deref() {
+    let default_model = crate::settings::get_default_model(&AppType::Codex);
+    let model = if let Some(current) = current_model.as_deref().or(default_model.as_deref()) {
         Text::new(&format!("{}:", texts::model_label()))
             .with_initial_value(current)
             .with_help_message("Model name (e.g., gpt-5.2-codex, o3)")
@@ -682,11 +694,18 @@ fn prompt_gemini_config(current: Option<&Value>) -> Result<Value, AppError> {
                 .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
         };
 
+        let mut env = json!({
+            "GEMINI_API_KEY": api_key.trim(),
+            "GOOGLE_GEMINI_BASE_URL": base_url.trim()
+        });
+        if current.is_none() {
+            if let Some(model) = crate::settings::get_default_model(&AppType::Gemini) {
+                env["GEMINI_MODEL"] = json!(model);
+            }
+        }
+
         Ok(json!({
-            "env": {
-                "GEMINI_API_KEY": api_key.trim(),
-                "GOOGLE_GEMINI_BASE_URL": base_url.trim()
-            },
+            "env": env,
             "config": {}
         }))
     }
@@ -704,7 +723,9 @@ pub fn prompt_optional_fields(current: Option<&Provider>) -> Result<OptionalFiel
             .prompt()
             .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
     } else {
+        let template = crate::settings::get_provider_notes_template().unwrap_or_default();
         Text::new(texts::notes_label())
+            .with_initial_value(&template)
             .with_placeholder(texts::notes_example_placeholder())
             .with_help_message(texts::notes_help_new())
             .prompt()
@@ -898,8 +919,5 @@ fn detect_gemini_auth_type(value: Option<&Value>) -> Option<String> {
 
 /// 遮蔽 API Key 显示（用于摘要显示）
 fn mask_api_key(key: &str) -> String {
-    if key.len() <= 8 {
-        return "***".to_string();
-    }
-    format!("{}...{}", &key[..4], &key[key.len() - 4..])
+    crate::redact::mask_secret(key)
 }
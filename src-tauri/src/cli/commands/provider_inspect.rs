@@ -1,11 +1,12 @@
 use serde_json::Value;
 
 use crate::app_config::AppType;
+use crate::cli::commands::provider::ProviderSortBy;
 use crate::cli::i18n::texts;
 use crate::cli::ui::{create_table, error, highlight, info, success, warning};
 use crate::error::AppError;
 use crate::provider::Provider;
-use crate::services::{ProviderService, SpeedtestService, StreamCheckService};
+use crate::services::{ProviderService, SpeedtestService, StreamCheckService, UsageEnvOverride};
 use crate::store::AppState;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -34,10 +35,23 @@ struct ClaudeConfig {
 fn get_state() -> Result<AppState, AppError> {
     AppState::try_new()
 }
-pub(crate) fn list_providers(app_type: AppType) -> Result<(), AppError> {
+pub(crate) fn list_providers(
+    app_type: AppType,
+    sort_by: ProviderSortBy,
+    include_archived: bool,
+    favorites_only: bool,
+    show_meta: bool,
+) -> Result<(), AppError> {
     let state = get_state()?;
     let app_str = app_type.as_str().to_string();
-    let providers = ProviderService::list(&state, app_type.clone())?;
+    let mut providers = ProviderService::list(&state, app_type.clone())?;
+    let archived_count = providers.values().filter(|p| p.is_archived()).count();
+    if !include_archived {
+        providers.retain(|_, provider| !provider.is_archived());
+    }
+    if favorites_only {
+        providers.retain(|_, provider| provider.is_favorite());
+    }
     let current_id = ProviderService::current(&state, app_type.clone())?;
 
     if providers.is_empty() {
@@ -46,31 +60,112 @@ pub(crate) fn list_providers(app_type: AppType) -> Result<(), AppError> {
         return Ok(());
     }
 
+    let show_last_used = sort_by == ProviderSortBy::LastUsed && !show_meta;
     let mut table = create_table();
-    table.set_header(vec!["", "ID", "Name", "API URL"]);
+    let mut header = vec!["", "ID", "Name", "API URL"];
+    if show_last_used {
+        header.push("Last Used");
+    }
+    if show_meta {
+        header.extend(["Tags", "Notes", "Usage Script", "Last Used"]);
+    }
+    table.set_header(header);
 
     let mut provider_list: Vec<_> = providers.into_iter().collect();
-    provider_list.sort_by(|(_, a), (_, b)| match (a.sort_index, b.sort_index) {
-        (Some(idx_a), Some(idx_b)) => idx_a.cmp(&idx_b),
-        (Some(_), None) => std::cmp::Ordering::Less,
-        (None, Some(_)) => std::cmp::Ordering::Greater,
-        (None, None) => a.created_at.cmp(&b.created_at),
-    });
+    sort_provider_list(&mut provider_list, sort_by);
 
     for (id, provider) in provider_list {
-        let current_marker = if id == current_id { "✓" } else { " " };
+        let current_marker = if id == current_id {
+            "✓"
+        } else if provider.is_archived() {
+            "⊘"
+        } else if provider.is_favorite() {
+            "★"
+        } else {
+            " "
+        };
         let api_url = extract_api_url(&provider, &app_type).unwrap_or_else(|| "N/A".to_string());
+        let last_used = format_last_used(provider.last_used_at());
+        let meta_columns = show_meta.then(|| provider_meta_columns(&provider));
 
-        table.add_row(vec![current_marker.to_string(), id, provider.name, api_url]);
+        let mut row = vec![current_marker.to_string(), id, provider.name, api_url];
+        if show_last_used {
+            row.push(last_used);
+        }
+        if let Some(columns) = meta_columns {
+            row.extend(columns);
+        }
+        table.add_row(row);
     }
 
     println!("{}", table);
     println!("\n{} Application: {}", info("ℹ"), app_str);
     println!("{} Current: {}", info("→"), highlight(&current_id));
+    if archived_count > 0 && !include_archived {
+        println!(
+            "{}",
+            info(&texts::provider_archived_hidden_hint(archived_count))
+        );
+    }
 
     Ok(())
 }
 
+fn sort_provider_list(list: &mut [(String, Provider)], sort_by: ProviderSortBy) {
+    list.sort_by(|(_, a), (_, b)| match (a.sort_index, b.sort_index) {
+        (Some(idx_a), Some(idx_b)) => idx_a.cmp(&idx_b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => a.created_at.cmp(&b.created_at),
+    });
+    if sort_by == ProviderSortBy::LastUsed {
+        // 稳定排序：最近使用的在前，未使用过的保持原顺序排在最后
+        list.sort_by_key(|(_, provider)| std::cmp::Reverse(provider.last_used_at()));
+    }
+}
+
+/// `provider list --show-meta` 追加的列：标签、备注（首行截断）、用量脚本状态、最近使用时间
+fn provider_meta_columns(provider: &Provider) -> [String; 4] {
+    const NOTES_MAX_CHARS: usize = 30;
+    let meta = provider.meta.as_ref();
+    let tags = meta.map(|meta| meta.tags.join(",")).unwrap_or_default();
+    let notes = provider
+        .notes
+        .as_deref()
+        .and_then(|notes| notes.lines().next())
+        .map(|line| {
+            if line.chars().count() > NOTES_MAX_CHARS {
+                let head: String = line.chars().take(NOTES_MAX_CHARS - 1).collect();
+                format!("{head}…")
+            } else {
+                line.to_string()
+            }
+        })
+        .unwrap_or_default();
+    let usage_script = match meta.and_then(|meta| meta.usage_script.as_ref()) {
+        Some(script) if script.enabled => "enabled",
+        Some(_) => "disabled",
+        None => "-",
+    };
+    [
+        tags,
+        notes,
+        usage_script.to_string(),
+        format_last_used(provider.last_used_at()),
+    ]
+}
+
+fn format_last_used(timestamp: Option<i64>) -> String {
+    timestamp
+        .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+        .map(|dt| {
+            dt.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_else(|| texts::provider_never_used().to_string())
+}
+
 pub(crate) fn show_current(app_type: AppType) -> Result<(), AppError> {
     let state = get_state()?;
     let current_id = ProviderService::current(&state, app_type.clone())?;
@@ -138,12 +233,135 @@ pub(crate) fn show_current(app_type: AppType) -> Result<(), AppError> {
     Ok(())
 }
 
+pub(crate) fn identify_live(app_type: AppType) -> Result<(), AppError> {
+    let state = get_state()?;
+    let identity = ProviderService::identify_live(&state, app_type.clone())?;
+    let or_na = |value: &Option<String>| value.clone().unwrap_or_else(|| "N/A".to_string());
+
+    println!(
+        "{}",
+        highlight(&texts::identify_live_header(app_type.as_str()))
+    );
+    println!("  Base URL: {}", or_na(&identity.live.base_url));
+    println!("  Model:    {}", or_na(&identity.live.model));
+    if matches!(app_type, AppType::Codex) {
+        println!("  wire_api: {}", or_na(&identity.live.wire_api));
+    }
+    println!();
+
+    if identity.matches.is_empty() {
+        println!("{}", warning(&texts::identify_no_match()));
+        if !identity.same_endpoint.is_empty() {
+            println!(
+                "{}",
+                info(&texts::identify_same_endpoint(
+                    &identity.same_endpoint.join(", ")
+                ))
+            );
+        }
+    } else if identity.matches.contains(&identity.current) {
+        println!(
+            "{}",
+            success(&texts::identify_matches_current(&identity.current))
+        );
+    } else {
+        println!(
+            "{}",
+            warning(&texts::identify_matches_other(
+                &identity.matches.join(", "),
+                &identity.current
+            ))
+        );
+    }
+
+    Ok(())
+}
+
+pub(crate) fn show_provider(
+    app_type: AppType,
+    id: &str,
+    plain: bool,
+    reveal: bool,
+) -> Result<(), AppError> {
+    let state = get_state()?;
+    let providers = ProviderService::list(&state, app_type.clone())?;
+    let provider = providers
+        .get(id)
+        .ok_or_else(|| AppError::provider_not_found(id))?;
+    let is_current = ProviderService::current(&state, app_type.clone())? == id;
+    let fields = provider_detail_fields(provider, &app_type, is_current, reveal);
+
+    if plain {
+        for (key, value) in fields {
+            println!("{key}: {value}");
+        }
+        return Ok(());
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["Field", "Value"]);
+    for (key, value) in fields {
+        table.add_row(vec![key.to_string(), value]);
+    }
+    println!("{}", table);
+    Ok(())
+}
+
+/// `provider show` 的键值列表；密钥默认遮罩
+pub(super) fn provider_detail_fields(
+    provider: &Provider,
+    app_type: &AppType,
+    is_current: bool,
+    reveal: bool,
+) -> Vec<(&'static str, String)> {
+    let model = match app_type {
+        AppType::Claude => StreamCheckService::extract_env_model(provider, "ANTHROPIC_MODEL"),
+        AppType::Codex => StreamCheckService::extract_codex_model(provider),
+        AppType::Gemini => StreamCheckService::extract_env_model(provider, "GEMINI_MODEL"),
+        AppType::OpenCode => provider
+            .settings_config
+            .get("models")
+            .and_then(|value| value.as_object())
+            .map(|models| models.keys().cloned().collect::<Vec<_>>().join(",")),
+    };
+    let api_key =
+        extract_api_key(provider, app_type).map(
+            |key| {
+                if reveal {
+                    key
+                } else {
+                    mask_api_key(&key)
+                }
+            },
+        );
+    let tags = provider
+        .meta
+        .as_ref()
+        .map(|meta| meta.tags.join(","))
+        .unwrap_or_default();
+
+    vec![
+        ("id", provider.id.clone()),
+        ("name", provider.name.clone()),
+        ("app", app_type.as_str().to_string()),
+        ("current", is_current.to_string()),
+        (
+            "url",
+            extract_api_url(provider, app_type).unwrap_or_default(),
+        ),
+        ("model", model.unwrap_or_default()),
+        ("api_key", api_key.unwrap_or_default()),
+        ("tags", tags),
+        ("notes", provider.notes.clone().unwrap_or_default()),
+    ]
+}
+
 pub(crate) fn speedtest_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
     let state = get_state()?;
     let providers = ProviderService::list(&state, app_type.clone())?;
     let provider = providers
         .get(id)
-        .ok_or_else(|| AppError::Message(format!("Provider '{}' not found", id)))?;
+        .ok_or_else(|| AppError::provider_not_found(id))?;
 
     let api_url = extract_api_url(provider, &app_type)
         .ok_or_else(|| AppError::Message(format!("No API URL configured for provider '{}'", id)))?;
@@ -192,12 +410,71 @@ pub(crate) fn speedtest_provider(app_type: AppType, id: &str) -> Result<(), AppE
     Ok(())
 }
 
+pub(crate) fn usage_provider(app_type: AppType, id: &str, env: &[String]) -> Result<(), AppError> {
+    let overrides = env
+        .iter()
+        .map(|raw| UsageEnvOverride::parse(raw))
+        .collect::<Result<Vec<_>, _>>()?;
+    let state = get_state()?;
+    let providers = ProviderService::list(&state, app_type.clone())?;
+    let provider = providers
+        .get(id)
+        .ok_or_else(|| AppError::provider_not_found(id))?;
+
+    println!(
+        "{}",
+        info(&format!("Querying usage for '{}'...", provider.name))
+    );
+    for item in &overrides {
+        println!(
+            "{}",
+            info(&format!(
+                "Override: {}={}",
+                item.key.as_str(),
+                crate::redact::mask_secret(&item.value)
+            ))
+        );
+    }
+    println!();
+
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| AppError::Message(format!("Failed to create async runtime: {}", e)))?;
+    let result = runtime.block_on(ProviderService::query_usage_with_env(
+        &state, app_type, id, &overrides,
+    ))?;
+
+    if !result.success {
+        let message = result.error.unwrap_or_else(|| "Unknown error".to_string());
+        return Err(AppError::Message(format!("Usage query failed: {message}")));
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["Plan", "Used", "Remaining", "Total", "Unit", "Valid"]);
+    let amount = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+    for usage in result.data.unwrap_or_default() {
+        table.add_row(vec![
+            usage.plan_name.unwrap_or_default(),
+            amount(usage.used),
+            amount(usage.remaining),
+            amount(usage.total),
+            usage.unit.unwrap_or_default(),
+            match usage.is_valid {
+                Some(false) => usage.invalid_message.unwrap_or_else(|| "no".to_string()),
+                _ => "yes".to_string(),
+            },
+        ]);
+    }
+    println!("{}", table);
+
+    Ok(())
+}
+
 pub(crate) fn stream_check_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
     let state = get_state()?;
     let providers = ProviderService::list(&state, app_type.clone())?;
     let provider = providers
         .get(id)
-        .ok_or_else(|| AppError::Message(format!("Provider '{}' not found", id)))?
+        .ok_or_else(|| AppError::provider_not_found(id))?
         .clone();
     let config = state.db.get_stream_check_config()?;
 
@@ -237,7 +514,7 @@ pub(crate) fn fetch_models_provider(app_type: AppType, id: &str) -> Result<(), A
     let providers = ProviderService::list(&state, app_type.clone())?;
     let provider = providers
         .get(id)
-        .ok_or_else(|| AppError::Message(format!("Provider '{}' not found", id)))?;
+        .ok_or_else(|| AppError::provider_not_found(id))?;
     let target = model_fetch_target(provider, &app_type)?;
 
     println!(
@@ -328,22 +605,32 @@ fn model_fetch_target(
         }
         AppType::OpenCode => Ok(ModelFetchTarget {
             base_url,
-            auth_value: provider
-                .settings_config
-                .get("options")
-                .and_then(|options| options.get("apiKey"))
-                .and_then(|value| value.as_str())
-                .map(str::trim)
-                .filter(|value| !value.is_empty())
-                .map(str::to_string)
-                .ok_or_else(|| {
-                    AppError::Message(format!("Missing API key for provider '{}'", provider.id))
-                })?,
+            auth_value: extract_api_key(provider, app_type).ok_or_else(|| {
+                AppError::Message(format!("Missing API key for provider '{}'", provider.id))
+            })?,
             strategy: ProviderModelFetchStrategy::Bearer,
         }),
     }
 }
 
+fn extract_api_key(provider: &Provider, app_type: &AppType) -> Option<String> {
+    match app_type {
+        AppType::Claude => StreamCheckService::extract_claude_key(provider),
+        AppType::Codex => StreamCheckService::extract_codex_key(provider),
+        AppType::Gemini => extract_gemini_model_fetch_auth(provider)
+            .ok()
+            .map(|(key, _)| key),
+        AppType::OpenCode => provider
+            .settings_config
+            .get("options")
+            .and_then(|options| options.get("apiKey"))
+            .and_then(|value| value.as_str())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string),
+    }
+}
+
 fn claude_uses_bearer_auth(provider: &Provider, base_url: &str) -> bool {
     if base_url.contains("openrouter.ai") {
         return true;
@@ -420,7 +707,7 @@ fn to_tui_strategy(strategy: ProviderModelFetchStrategy) -> crate::cli::tui::Mod
     }
 }
 
-fn extract_api_url(provider: &Provider, app_type: &AppType) -> Option<String> {
+pub(super) fn extract_api_url(provider: &Provider, app_type: &AppType) -> Option<String> {
     StreamCheckService::extract_base_url(provider, app_type)
         .ok()
         .map(|value| value.trim().trim_end_matches('/').to_string())
@@ -466,11 +753,7 @@ fn extract_claude_config(settings_config: &Value) -> ClaudeConfig {
 }
 
 fn mask_api_key(key: &str) -> String {
-    if key.len() > 8 {
-        format!("{}...", &key[..8])
-    } else {
-        key.to_string()
-    }
+    crate::redact::mask_secret(key)
 }
 
 fn simplify_model_name(name: &str) -> String {
@@ -488,6 +771,107 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    #[test]
+    fn sort_by_last_used_puts_recent_first_and_never_used_last() {
+        let make = |id: &str, sort_index: usize, last_used_at: Option<i64>| {
+            let mut provider = Provider::with_id(id.to_string(), id.to_string(), json!({}), None);
+            provider.sort_index = Some(sort_index);
+            provider.meta = Some(crate::provider::ProviderMeta {
+                last_used_at,
+                ..Default::default()
+            });
+            (id.to_string(), provider)
+        };
+        let mut list = vec![
+            make("old", 0, Some(100)),
+            make("never-b", 1, None),
+            make("recent", 2, Some(200)),
+            make("never-a", 3, None),
+        ];
+
+        sort_provider_list(&mut list, ProviderSortBy::LastUsed);
+        let ids: Vec<&str> = list.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["recent", "old", "never-b", "never-a"]);
+
+        sort_provider_list(&mut list, ProviderSortBy::Order);
+        let ids: Vec<&str> = list.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["old", "never-b", "recent", "never-a"]);
+    }
+
+    #[test]
+    fn meta_columns_show_tags_and_usage_script_status() {
+        let mut provider =
+            Provider::with_id("demo".to_string(), "Demo".to_string(), json!({}), None);
+        provider.notes = Some("shared team key\nsecond line".to_string());
+        provider.meta = Some(crate::provider::ProviderMeta {
+            tags: vec!["work".to_string(), "eu".to_string()],
+            usage_script: Some(crate::provider::UsageScript {
+                enabled: true,
+                language: "javascript".to_string(),
+                code: "return {}".to_string(),
+                timeout: None,
+                api_key: None,
+                base_url: None,
+                access_token: None,
+                user_id: None,
+                template_type: None,
+                auto_query_interval: None,
+            }),
+            ..Default::default()
+        });
+
+        let [tags, notes, usage_script, last_used] = provider_meta_columns(&provider);
+        assert_eq!(tags, "work,eu");
+        assert_eq!(notes, "shared team key");
+        assert_eq!(usage_script, "enabled");
+        assert_eq!(last_used, texts::provider_never_used());
+
+        provider.meta = None;
+        let [tags, _, usage_script, _] = provider_meta_columns(&provider);
+        assert_eq!(tags, "");
+        assert_eq!(usage_script, "-");
+    }
+
+    #[test]
+    fn provider_detail_fields_mask_key_unless_revealed() {
+        let mut provider = Provider::with_id(
+            "demo".to_string(),
+            "Demo".to_string(),
+            json!({
+                "env": {
+                    "ANTHROPIC_BASE_URL": "https://claude.example.com/",
+                    "ANTHROPIC_AUTH_TOKEN": "sk-secret-token",
+                    "ANTHROPIC_MODEL": "claude-x"
+                }
+            }),
+            None,
+        );
+        provider.notes = Some("team key".to_string());
+        provider.meta = Some(crate::provider::ProviderMeta {
+            tags: vec!["work".to_string(), "eu".to_string()],
+            ..Default::default()
+        });
+
+        let fields = provider_detail_fields(&provider, &AppType::Claude, true, false);
+        let get = |fields: &[(&str, String)], key: &str| {
+            fields
+                .iter()
+                .find(|(name, _)| *name == key)
+                .map(|(_, value)| value.clone())
+                .expect("field present")
+        };
+        assert_eq!(get(&fields, "current"), "true");
+        assert_eq!(get(&fields, "url"), "https://claude.example.com");
+        assert_eq!(get(&fields, "model"), "claude-x");
+        assert_eq!(get(&fields, "api_key"), "********");
+        assert_eq!(get(&fields, "tags"), "work,eu");
+        assert_eq!(get(&fields, "notes"), "team key");
+
+        let revealed = provider_detail_fields(&provider, &AppType::Claude, false, true);
+        assert_eq!(get(&revealed, "api_key"), "sk-secret-token");
+        assert_eq!(get(&revealed, "current"), "false");
+    }
+
     #[test]
     fn model_fetch_target_for_claude_uses_base_url_and_api_key() {
         let provider = Provider::with_id(
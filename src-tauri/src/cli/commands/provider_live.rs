@@ -0,0 +1,98 @@
+use std::path::Path;
+
+use crate::app_config::AppType;
+use crate::cli::i18n::texts;
+use crate::cli::ui::{emit_output, highlight, info, output_is_stdout, success, warning};
+use crate::error::AppError;
+use crate::provider::{CodexAuthTarget, Provider};
+use crate::services::ProviderService;
+use crate::store::AppState;
+
+pub(super) fn refresh_snapshot(app_type: AppType, id: Option<&str>) -> Result<(), AppError> {
+    let state = get_state()?;
+    let id = ProviderService::refresh_snapshot(&state, app_type.clone(), id)?;
+    println!(
+        "{}",
+        success(&texts::provider_snapshot_refreshed(&id, app_type.as_str()))
+    );
+    Ok(())
+}
+
+pub(super) fn seed_snapshot(
+    app_type: AppType,
+    file: &Path,
+    id: Option<&str>,
+) -> Result<(), AppError> {
+    let state = get_state()?;
+    let id = ProviderService::seed_snapshot_from_file(&state, app_type.clone(), id, file)?;
+    println!(
+        "{}",
+        success(&texts::provider_snapshot_seeded(&id, app_type.as_str()))
+    );
+    println!("{}", info(&texts::provider_snapshot_seeded_hint(&id)));
+    Ok(())
+}
+
+pub(super) fn preview_live_provider(
+    app_type: AppType,
+    id: &str,
+    output: Option<&Path>,
+) -> Result<(), AppError> {
+    let state = get_state()?;
+    let files = ProviderService::preview_live(&state, app_type, id)?;
+    // 写入文件时不带终端颜色
+    let to_stdout = output_is_stdout(output);
+    let mut sections = Vec::with_capacity(files.len());
+    for file in &files {
+        let header = format!("# {}", file.path.display());
+        let body = match &file.content {
+            Some(content) => content.trim_end().to_string(),
+            None if to_stdout => warning(texts::live_preview_file_removed()),
+            None => texts::live_preview_file_removed().to_string(),
+        };
+        let header = if to_stdout {
+            highlight(&header)
+        } else {
+            header
+        };
+        sections.push(format!("{header}\n{body}"));
+    }
+    emit_output(output, &sections.join("\n\n"))
+}
+
+pub(super) fn codex_auth_provider(
+    app_type: AppType,
+    id: &str,
+    target: Option<CodexAuthTarget>,
+    print_export: bool,
+) -> Result<(), AppError> {
+    if app_type != AppType::Codex {
+        return Err(AppError::Message(
+            texts::codex_auth_requires_codex_app().to_string(),
+        ));
+    }
+    let state = get_state()?;
+
+    if print_export {
+        println!("{}", ProviderService::codex_auth_export_line(&state, id)?);
+        return Ok(());
+    }
+
+    let target = match target {
+        Some(target) => {
+            ProviderService::set_codex_auth_target(&state, id, target)?;
+            println!("{}", success(&texts::codex_auth_target_updated(id, target)));
+            target
+        }
+        None => ProviderService::list(&state, AppType::Codex)?
+            .get(id)
+            .map(Provider::codex_auth_target)
+            .ok_or_else(|| AppError::provider_not_found(id))?,
+    };
+    println!("{}", info(&texts::codex_auth_target_current(target)));
+    Ok(())
+}
+
+fn get_state() -> Result<AppState, AppError> {
+    AppState::try_new()
+}
@@ -0,0 +1,258 @@
+use std::path::Path;
+
+use super::provider_inspect;
+use crate::app_config::AppType;
+use crate::cli::commands::provider_input::generate_provider_id;
+use crate::cli::i18n::texts;
+use crate::cli::ui::{highlight, info, success, warning};
+use crate::error::AppError;
+use crate::services::provider::{provider_tags, SettingsPatch, UNMAPPED_PROVIDER_FIELDS};
+use crate::services::ProviderService;
+use crate::store::AppState;
+
+pub(super) fn import_link(url: &str, dry_run: bool) -> Result<(), AppError> {
+    let prepared = crate::deeplink::parse_deeplink_url(url)
+        .and_then(|request| crate::deeplink::prepare_provider_from_deeplink(&request))
+        .map_err(|err| {
+            AppError::localized(
+                "deeplink.invalid",
+                format!("无效的导入链接：{err}"),
+                format!("Invalid import link: {err}"),
+            )
+        })?;
+
+    if dry_run {
+        println!("{}", highlight(texts::deeplink_dry_run_title()));
+        for (key, value) in provider_inspect::provider_detail_fields(
+            &prepared.provider,
+            &prepared.app_type,
+            false,
+            false,
+        ) {
+            // 尚未导入，"current" 没有意义
+            if key != "current" {
+                println!("{key}: {value}");
+            }
+        }
+        println!("switch_after_import: {}", prepared.switch_after_import);
+        println!("{}", info(texts::deeplink_dry_run_note()));
+        return Ok(());
+    }
+
+    let state = get_state()?;
+    let app_str = prepared.app_type.as_str().to_string();
+    let id = prepared.provider.id.clone();
+    ProviderService::add(&state, prepared.app_type.clone(), prepared.provider)?;
+    if prepared.switch_after_import {
+        ProviderService::switch(&state, prepared.app_type, &id)?;
+    }
+    println!(
+        "{}",
+        success(&texts::deeplink_provider_imported(&id, &app_str))
+    );
+    Ok(())
+}
+
+/// `provider add --from-provider`：复制另一供应商的 API 地址与 Key 到目标应用
+pub(super) fn add_provider_from(
+    from_app: AppType,
+    source_id: &str,
+    app_type: AppType,
+) -> Result<(), AppError> {
+    let state = get_state()?;
+    let source = ProviderService::list(&state, from_app.clone())?
+        .shift_remove(source_id)
+        .ok_or_else(|| AppError::provider_not_found(source_id))?;
+    let existing_ids: Vec<String> = ProviderService::list(&state, app_type.clone())?
+        .into_keys()
+        .collect();
+    let id = generate_provider_id(&source.name, &existing_ids);
+
+    let provider = ProviderService::convert_provider(&source, &from_app, &app_type, id.clone())?;
+    ProviderService::add(&state, app_type.clone(), provider)?;
+
+    println!(
+        "{}",
+        success(&texts::provider_converted(
+            source_id,
+            from_app.as_str(),
+            &id,
+            app_type.as_str()
+        ))
+    );
+    println!(
+        "{}",
+        info(&texts::provider_convert_unmapped_note(
+            &UNMAPPED_PROVIDER_FIELDS.join(", ")
+        ))
+    );
+    Ok(())
+}
+
+/// `provider add --from-live-app`：以某应用当前的 live 配置创建供应商
+pub(super) fn add_provider_from_live(from_app: AppType, app_type: AppType) -> Result<(), AppError> {
+    let state = get_state()?;
+    // 沿用来源应用当前供应商的名称，便于辨认
+    let current = ProviderService::current(&state, from_app.clone())?;
+    let name = ProviderService::list(&state, from_app.clone())?
+        .shift_remove(&current)
+        .map(|provider| provider.name)
+        .unwrap_or_else(|| format!("{} live", from_app.as_str()));
+    let existing_ids: Vec<String> = ProviderService::list(&state, app_type.clone())?
+        .into_keys()
+        .collect();
+    let id = generate_provider_id(&name, &existing_ids);
+
+    let provider = ProviderService::provider_from_live(&from_app, &app_type, id.clone(), name)?;
+    ProviderService::add(&state, app_type.clone(), provider)?;
+
+    println!(
+        "{}",
+        success(&texts::provider_added_from_live(
+            from_app.as_str(),
+            &id,
+            app_type.as_str()
+        ))
+    );
+    if from_app != app_type {
+        println!(
+            "{}",
+            info(&texts::provider_convert_unmapped_note(
+                &UNMAPPED_PROVIDER_FIELDS.join(", ")
+            ))
+        );
+    }
+    Ok(())
+}
+
+/// `provider move`：把供应商移到另一应用，保留名称、备注与标签
+pub(super) fn move_provider(app_type: AppType, id: &str, to_app: AppType) -> Result<(), AppError> {
+    let state = get_state()?;
+    let outcome = ProviderService::move_provider(&state, app_type.clone(), to_app.clone(), id)?;
+
+    println!(
+        "{}",
+        success(&texts::provider_moved(
+            id,
+            app_type.as_str(),
+            to_app.as_str()
+        ))
+    );
+    if outcome.settings_converted {
+        println!(
+            "{}",
+            info(&texts::provider_convert_unmapped_note(
+                &UNMAPPED_PROVIDER_FIELDS.join(", ")
+            ))
+        );
+    } else {
+        println!(
+            "{}",
+            warning(&texts::provider_move_needs_edit(id, to_app.as_str()))
+        );
+    }
+    Ok(())
+}
+
+pub(super) fn set_archived(app_type: AppType, id: &str, archived: bool) -> Result<(), AppError> {
+    let state = get_state()?;
+    ProviderService::set_archived(&state, app_type.clone(), id, archived)?;
+    let message = if archived {
+        texts::provider_archived(id, app_type.as_str())
+    } else {
+        texts::provider_unarchived(id, app_type.as_str())
+    };
+    println!("{}", success(&message));
+    Ok(())
+}
+
+pub(super) fn set_favorite(app_type: AppType, id: &str, favorite: bool) -> Result<(), AppError> {
+    let state = get_state()?;
+    ProviderService::set_favorite(&state, app_type.clone(), id, favorite)?;
+    let message = if favorite {
+        texts::provider_favorited(id, app_type.as_str())
+    } else {
+        texts::provider_unfavorited(id, app_type.as_str())
+    };
+    println!("{}", success(&message));
+    Ok(())
+}
+
+pub(super) fn tag_provider(
+    app_type: AppType,
+    id: &str,
+    add: &[String],
+    remove: &[String],
+) -> Result<(), AppError> {
+    let state = get_state()?;
+    let tags = if add.is_empty() && remove.is_empty() {
+        let providers = ProviderService::list(&state, app_type)?;
+        let provider = providers
+            .get(id)
+            .ok_or_else(|| AppError::provider_not_found(id))?;
+        provider_tags(provider).to_vec()
+    } else {
+        ProviderService::update_tags(&state, app_type, id, add, remove)?
+    };
+
+    println!("{}", info(&texts::provider_tags_line(id, &tags)));
+    Ok(())
+}
+
+pub(super) fn group_set_providers(
+    app_type: AppType,
+    tag: &str,
+    sets: &[String],
+) -> Result<(), AppError> {
+    let patches = sets
+        .iter()
+        .map(|raw| SettingsPatch::parse(raw))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let state = get_state()?;
+    let count = ProviderService::apply_tag_patch(&state, app_type, tag, &patches)?;
+
+    println!("{}", success(&texts::provider_group_updated(count, tag)));
+    Ok(())
+}
+
+pub(super) fn clone_provider(app_type: AppType, id: &str, name: &str) -> Result<(), AppError> {
+    let state = get_state()?;
+    let new_id = ProviderService::clone(&state, app_type, id, name)?;
+
+    println!("{}", success(&texts::provider_cloned(id, &new_id)));
+    Ok(())
+}
+
+pub(super) fn rename_provider_id(app_type: AppType, old: &str, new: &str) -> Result<(), AppError> {
+    let state = get_state()?;
+    ProviderService::rename_id(&state, app_type, old, new)?;
+
+    println!("{}", success(&texts::provider_id_renamed(old, new.trim())));
+    Ok(())
+}
+
+pub(super) fn export_all_providers(
+    app_type: AppType,
+    dir: &Path,
+    redact: bool,
+) -> Result<(), AppError> {
+    let state = get_state()?;
+    let written = ProviderService::export_split(&state, app_type.clone(), dir, redact)?;
+    for path in &written {
+        println!("  {}", path.display());
+    }
+    println!(
+        "{}",
+        success(&texts::providers_exported_split(
+            written.len(),
+            app_type.as_str(),
+            &dir.display().to_string()
+        ))
+    );
+    Ok(())
+}
+
+fn get_state() -> Result<AppState, AppError> {
+    AppState::try_new()
+}
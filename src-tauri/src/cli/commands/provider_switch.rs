@@ -0,0 +1,260 @@
+use crate::app_config::AppType;
+use crate::cli::i18n::texts;
+use crate::cli::ui::{info, success, warning};
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::services::{
+    HealthWait, LiveFileWrite, McpService, ProviderService, SwitchOptions, SwitchOutcome,
+};
+use crate::store::AppState;
+
+/// 解析 `provider switch` 的位置参数：两个位置参数时第一个必须是应用名，
+/// 单个位置参数时始终视为供应商 ID（应用取自 `--app`，默认 Claude）
+pub fn resolve_switch_target(
+    explicit_app: Option<AppType>,
+    first: String,
+    second: Option<String>,
+) -> Result<(AppType, String), AppError> {
+    let Some(id) = second else {
+        return Ok((explicit_app.unwrap_or(AppType::Claude), first));
+    };
+    let positional_app = first.parse::<AppType>()?;
+    match explicit_app {
+        Some(app) if app != positional_app => Err(AppError::localized(
+            "provider.switch.app_conflict",
+            format!(
+                "位置参数指定的应用 '{}' 与 --app '{}' 冲突",
+                positional_app.as_str(),
+                app.as_str()
+            ),
+            format!(
+                "App '{}' given as positional conflicts with --app '{}'",
+                positional_app.as_str(),
+                app.as_str()
+            ),
+        )),
+        _ => Ok((positional_app, id)),
+    }
+}
+
+/// `--app all --id X`：在每个有 X 的应用中切换，其余应用跳过
+pub(super) fn switch_all_apps(
+    target: &str,
+    confirm: bool,
+    force_init: bool,
+    options: SwitchOptions,
+) -> Result<(), AppError> {
+    let state = get_state()?;
+    let apps: Vec<AppType> = AppType::all().collect();
+    // 先解析所有应用并完成切换前检查，歧义或未纳管的 MCP 在切换任何应用之前报告
+    let resolved = ProviderService::resolve_each_app(&state, &apps, target)?;
+    if !confirm {
+        for (app, _) in resolved.iter().filter(|(_, found)| found.is_some()) {
+            ensure_no_unmanaged_mcp(&state, app)?;
+        }
+    }
+    let mut switched: Vec<&str> = Vec::new();
+    for (app, found) in &resolved {
+        let Some(id) = found else {
+            println!(
+                "{}",
+                info(&texts::provider_switch_all_skipped(app.as_str(), target))
+            );
+            continue;
+        };
+        let result = ProviderService::list(&state, app.clone())?
+            .get(id)
+            .cloned()
+            .ok_or_else(|| AppError::provider_not_found(id.as_str()))
+            .and_then(|provider| {
+                let outcome = ProviderService::switch_reporting_hooks_with(
+                    &state,
+                    app.clone(),
+                    id,
+                    options.clone(),
+                )?;
+                Ok((provider, outcome))
+            });
+        match result {
+            Ok((provider, outcome)) => {
+                println!(
+                    "{}",
+                    success(&texts::provider_switch_all_switched(app.as_str(), id))
+                );
+                print_switch_warnings(app, &provider, &outcome);
+                print_live_sync_status(&state, app, force_init)?;
+                switched.push(app.as_str());
+            }
+            Err(err) => {
+                println!(
+                    "{}",
+                    warning(&texts::provider_switch_all_failed(
+                        app.as_str(),
+                        &switched.join(", ")
+                    ))
+                );
+                return Err(err);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Codex 切换会重写 config.toml，未纳入 MCP 存储的 [mcp_servers.*] 可能丢失
+fn ensure_no_unmanaged_mcp(state: &AppState, app_type: &AppType) -> Result<(), AppError> {
+    if *app_type != AppType::Codex || !crate::sync_policy::should_sync_live(app_type) {
+        return Ok(());
+    }
+    let at_risk = McpService::unmanaged_live_servers(state, app_type)?;
+    if at_risk.is_empty() {
+        return Ok(());
+    }
+    println!(
+        "{}",
+        warning(&texts::switch_unmanaged_mcp_warning(&at_risk.join(", ")))
+    );
+    Err(AppError::localized(
+        "provider.switch.unmanaged_mcp",
+        "请先执行 `cc-switch --app codex mcp import`，或添加 --confirm 继续切换",
+        "Run `cc-switch --app codex mcp import` first, or pass --confirm to switch anyway",
+    ))
+}
+
+/// 切换成功后的告警：MCP 同步失败、Codex 空 API Key、post_switch 钩子失败与插件同步失败
+fn print_switch_warnings(app_type: &AppType, provider: &Provider, outcome: &SwitchOutcome) {
+    if !outcome.mcp_failures.is_empty() {
+        let failed = outcome
+            .mcp_failures
+            .iter()
+            .map(|failure| format!("{} ({}): {}", failure.server_id, failure.app, failure.error))
+            .collect::<Vec<_>>();
+        println!(
+            "{}",
+            warning(&texts::switch_mcp_sync_failed_warning(&failed.join("; ")))
+        );
+    }
+    if outcome.empty_api_key {
+        println!(
+            "{}",
+            warning(&texts::codex_empty_api_key_warning(&provider.id))
+        );
+    }
+    if let Some(err) = &outcome.hook_warning {
+        println!(
+            "{}",
+            warning(&texts::post_switch_hook_failed_warning(&err.to_string()))
+        );
+    }
+    if let Err(err) =
+        crate::claude_plugin::sync_claude_plugin_on_provider_switch(app_type, provider)
+    {
+        println!(
+            "{}",
+            warning(&texts::claude_plugin_sync_failed_warning(&err.to_string()))
+        );
+    }
+}
+
+pub(super) fn switch_provider(
+    app_type: AppType,
+    id: &str,
+    force_init: bool,
+    health_wait: Option<HealthWait>,
+    confirm: bool,
+    options: SwitchOptions,
+    verbose: bool,
+) -> Result<(), AppError> {
+    let state = get_state()?;
+    let app_str = app_type.as_str().to_string();
+
+    // 按 ID、名称或唯一前缀解析目标供应商
+    let resolved = ProviderService::resolve_provider_ref(&state, app_type.clone(), id)?;
+    if resolved != id {
+        println!("{}", info(&texts::provider_ref_resolved(id, &resolved)));
+    }
+    let id = resolved.as_str();
+    let providers = ProviderService::list(&state, app_type.clone())?;
+    let Some(provider) = providers.get(id).cloned() else {
+        return Err(AppError::provider_not_found(id));
+    };
+
+    if !confirm {
+        ensure_no_unmanaged_mcp(&state, &app_type)?;
+    }
+
+    let overlay_count = options.overlay.len();
+    // 执行切换；--wait-healthy 时轮询端点，超时仍不健康默认自动回滚
+    let outcome = match health_wait {
+        Some(wait) => {
+            println!("{}", info(&texts::provider_health_check_waiting(id)));
+            ProviderService::switch_wait_healthy(&state, app_type.clone(), id, wait, options)?
+        }
+        None => {
+            ProviderService::switch_reporting_hooks_with(&state, app_type.clone(), id, options)?
+        }
+    };
+    print_switch_warnings(&app_type, &provider, &outcome);
+
+    println!("{}", success(&format!("✓ Switched to provider '{}'", id)));
+    println!("{}", info(&format!("  Application: {}", app_str)));
+    if overlay_count > 0 {
+        println!("{}", info(&texts::switch_overlay_applied(overlay_count)));
+    }
+    if verbose {
+        for line in live_write_lines(&outcome.written) {
+            println!("{}", info(&line));
+        }
+    }
+    print_live_sync_status(&state, &app_type, force_init)?;
+    println!(
+        "\n{}",
+        info("Note: Restart your CLI client to apply the changes.")
+    );
+
+    Ok(())
+}
+
+/// 应用未初始化时切换只更新存储：`--force-init` 创建配置目录并写入 live，否则提示跳过
+fn print_live_sync_status(
+    state: &AppState,
+    app_type: &AppType,
+    force_init: bool,
+) -> Result<(), AppError> {
+    if crate::sync_policy::should_sync_live(app_type) {
+        return Ok(());
+    }
+    let app_str = app_type.as_str();
+    if force_init {
+        let dir = ProviderService::force_init_live(state, app_type.clone())?;
+        println!(
+            "{}",
+            warning(&texts::live_force_initialized_warning(
+                app_str,
+                &dir.display().to_string()
+            ))
+        );
+    } else {
+        println!(
+            "{}",
+            warning(&texts::live_sync_skipped_uninitialized_warning(app_str))
+        );
+        println!("{}", info(&texts::live_sync_force_init_hint(app_str)));
+    }
+    Ok(())
+}
+
+/// `provider switch --verbose` 输出的 live 文件清单
+pub fn live_write_lines(written: &[LiveFileWrite]) -> Vec<String> {
+    if written.is_empty() {
+        return vec![texts::switch_no_live_files_written().to_string()];
+    }
+    std::iter::once(texts::switch_live_files_written_title().to_string())
+        .chain(written.iter().map(|file| {
+            texts::switch_live_file_written(&file.path.display().to_string(), file.bytes)
+        }))
+        .collect()
+}
+
+fn get_state() -> Result<AppState, AppError> {
+    AppState::try_new()
+}
@@ -2,15 +2,21 @@ use clap::Subcommand;
 use std::future::Future;
 
 use crate::app_config::AppType;
-use crate::cli::ui::{create_table, highlight, info, success};
+use crate::cli::ui::{create_table, error, highlight, info, success};
 use crate::error::AppError;
-use crate::services::skill::{SkillRepo, SyncMethod};
-use crate::services::SkillService;
+use crate::services::skill::{RepoReachability, SkillRepo, SyncMethod};
+use crate::services::{SkillService, SkillUpdateAction};
 
 #[derive(Subcommand)]
 pub enum SkillsCommand {
     /// List installed skills (from ~/.cc-switch/skills.json)
-    List,
+    List {
+        /// Optional name filter (matches name/directory)
+        query: Option<String>,
+        /// Only show skills enabled for the selected app (--app), or for any app
+        #[arg(long)]
+        enabled_only: bool,
+    },
     /// Discover available skills (from enabled repos)
     #[command(alias = "search")]
     Discover {
@@ -21,6 +27,17 @@ pub enum SkillsCommand {
     Install {
         /// Skill directory name or full key (owner/name:directory)
         spec: String,
+        /// Pin the skill to a commit SHA or tag instead of following its branch
+        #[arg(long, value_name = "REF")]
+        pin: Option<String>,
+    },
+    /// Re-download installed skills from their repo branch (pinned skills are skipped)
+    Update {
+        /// Skill directory or id (omit to update all)
+        spec: Option<String>,
+        /// Also update pinned skills to their branch head, dropping the pin
+        #[arg(long)]
+        force: bool,
     },
     /// Uninstall a skill (remove from SSOT and app dirs)
     Uninstall {
@@ -31,11 +48,17 @@ pub enum SkillsCommand {
     Enable {
         /// Skill directory or id
         spec: String,
+        /// Apps to enable at once, e.g. claude,gemini (defaults to --app)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        apps: Vec<AppType>,
     },
     /// Disable a skill for the selected app
     Disable {
         /// Skill directory or id
         spec: String,
+        /// Apps to disable at once, e.g. claude,gemini (defaults to --app)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        apps: Vec<AppType>,
     },
     /// Sync enabled skills to app skills dirs
     Sync,
@@ -86,18 +109,30 @@ pub enum SkillReposCommand {
         /// Repository (GitHub URL or owner/name)
         url: String,
     },
+    /// Check that each enabled repository and branch exists on GitHub
+    Verify,
 }
 
 pub fn execute(cmd: SkillsCommand, app: Option<AppType>) -> Result<(), AppError> {
     let app_type = app.clone().unwrap_or(AppType::Claude);
 
     match cmd {
-        SkillsCommand::List => list_installed(),
+        SkillsCommand::List {
+            query,
+            enabled_only,
+        } => list_installed(app.as_ref(), enabled_only, query.as_deref()),
         SkillsCommand::Discover { query } => discover_skills(query.as_deref()),
-        SkillsCommand::Install { spec } => install_skill(&app_type, &spec),
+        SkillsCommand::Install { spec, pin } => install_skill(&app_type, &spec, pin.as_deref()),
+        SkillsCommand::Update { spec, force } => update_skills(spec.as_deref(), force),
         SkillsCommand::Uninstall { spec } => uninstall_skill(&spec),
-        SkillsCommand::Enable { spec } => toggle_skill(&app_type, &spec, true),
-        SkillsCommand::Disable { spec } => toggle_skill(&app_type, &spec, false),
+        SkillsCommand::Enable { spec, apps } if apps.is_empty() => {
+            toggle_skill(&app_type, &spec, true)
+        }
+        SkillsCommand::Enable { spec, apps } => toggle_skill_apps(&spec, &apps, true),
+        SkillsCommand::Disable { spec, apps } if apps.is_empty() => {
+            toggle_skill(&app_type, &spec, false)
+        }
+        SkillsCommand::Disable { spec, apps } => toggle_skill_apps(&spec, &apps, false),
         SkillsCommand::Sync => sync_skills(app.as_ref()),
         SkillsCommand::ScanUnmanaged => scan_unmanaged(),
         SkillsCommand::ImportFromApps { directories } => import_from_apps(directories),
@@ -115,8 +150,12 @@ fn run_async<T>(fut: impl Future<Output = Result<T, AppError>>) -> Result<T, App
         .block_on(fut)
 }
 
-fn list_installed() -> Result<(), AppError> {
-    let skills = SkillService::list_installed()?;
+fn list_installed(
+    app: Option<&AppType>,
+    enabled_only: bool,
+    query: Option<&str>,
+) -> Result<(), AppError> {
+    let skills = SkillService::list_installed_filtered(app, enabled_only, query)?;
 
     if skills.is_empty() {
         println!("{}", info("No installed skills found."));
@@ -124,15 +163,25 @@ fn list_installed() -> Result<(), AppError> {
     }
 
     let mut table = create_table();
-    table.set_header(vec!["Directory", "Name", "Claude", "Codex", "Gemini"]);
+    let mut header = vec!["Directory", "Name", "Claude", "Codex", "Gemini", "OpenCode"];
+    if let Some(app) = app {
+        header.insert(0, app.as_str());
+    }
+    table.set_header(header);
     for skill in skills {
-        table.add_row(vec![
+        let mut row = vec![
             skill.directory,
             skill.name,
             if skill.apps.claude { "✓" } else { " " }.to_string(),
             if skill.apps.codex { "✓" } else { " " }.to_string(),
             if skill.apps.gemini { "✓" } else { " " }.to_string(),
-        ]);
+            if skill.apps.opencode { "✓" } else { " " }.to_string(),
+        ];
+        if let Some(app) = app {
+            let enabled = skill.apps.is_enabled_for(app);
+            row.insert(0, if enabled { "enabled" } else { "disabled" }.to_string());
+        }
+        table.add_row(row);
     }
 
     println!("{}", table);
@@ -168,13 +217,18 @@ fn discover_skills(query: Option<&str>) -> Result<(), AppError> {
     Ok(())
 }
 
-fn install_skill(app_type: &AppType, spec: &str) -> Result<(), AppError> {
+fn install_skill(app_type: &AppType, spec: &str, pin: Option<&str>) -> Result<(), AppError> {
     let service = SkillService::new()?;
-    let installed = run_async(service.install(spec, app_type))?;
+    let installed = run_async(service.install(spec, app_type, pin))?;
+    let pinned = installed
+        .pinned_ref
+        .as_deref()
+        .map(|pin| format!(", pinned to {pin}"))
+        .unwrap_or_default();
     println!(
         "{}",
         success(&format!(
-            "✓ Installed skill '{}' (enabled for {})",
+            "✓ Installed skill '{}' (enabled for {}{pinned})",
             installed.directory,
             app_type.as_str()
         ))
@@ -182,6 +236,35 @@ fn install_skill(app_type: &AppType, spec: &str) -> Result<(), AppError> {
     Ok(())
 }
 
+fn update_skills(spec: Option<&str>, force: bool) -> Result<(), AppError> {
+    let service = SkillService::new()?;
+    let results = run_async(service.update(spec, force))?;
+
+    if results.is_empty() {
+        println!("{}", info("No installed skills found."));
+        return Ok(());
+    }
+
+    for (directory, action) in results {
+        match action {
+            SkillUpdateAction::Update => {
+                println!("{}", success(&format!("✓ Updated skill '{directory}'")))
+            }
+            SkillUpdateAction::Pinned(pin) => println!(
+                "{}",
+                info(&format!(
+                    "- Skipped '{directory}' (pinned to {pin}; use --force to update)"
+                ))
+            ),
+            SkillUpdateAction::NoSource => println!(
+                "{}",
+                info(&format!("- Skipped '{directory}' (no source repo)"))
+            ),
+        }
+    }
+    Ok(())
+}
+
 fn uninstall_skill(spec: &str) -> Result<(), AppError> {
     SkillService::uninstall(spec)?;
     println!("{}", success(&format!("✓ Uninstalled skill '{spec}'")));
@@ -202,6 +285,29 @@ fn toggle_skill(app_type: &AppType, spec: &str, enabled: bool) -> Result<(), App
     Ok(())
 }
 
+fn toggle_skill_apps(spec: &str, apps: &[AppType], enabled: bool) -> Result<(), AppError> {
+    let changed = SkillService::set_apps_enabled(spec, apps, enabled)?;
+    let action = if enabled { "Enabled" } else { "Disabled" };
+    if changed.is_empty() {
+        println!(
+            "{}",
+            info(&format!(
+                "'{}' is already {} for all selected apps",
+                spec,
+                action.to_lowercase()
+            ))
+        );
+        return Ok(());
+    }
+
+    let names: Vec<&str> = changed.iter().map(AppType::as_str).collect();
+    println!(
+        "{}",
+        success(&format!("✓ {} '{}' for {}", action, spec, names.join(", ")))
+    );
+    Ok(())
+}
+
 fn sync_skills(app: Option<&AppType>) -> Result<(), AppError> {
     SkillService::sync_all_enabled(app)?;
     println!("{}", success("✓ Skills synced successfully"));
@@ -262,6 +368,9 @@ fn show_skill_info(spec: &str) -> Result<(), AppError> {
         "Enabled:   claude={} codex={} gemini={}",
         record.apps.claude, record.apps.codex, record.apps.gemini
     );
+    if let Some(pin) = record.pinned_ref.as_deref() {
+        println!("Pinned:    {pin}");
+    }
 
     Ok(())
 }
@@ -273,6 +382,7 @@ fn execute_repos(cmd: SkillReposCommand) -> Result<(), AppError> {
         SkillReposCommand::Remove { url } => remove_repo(&url),
         SkillReposCommand::Enable { url } => set_repo_enabled(&url, true),
         SkillReposCommand::Disable { url } => set_repo_enabled(&url, false),
+        SkillReposCommand::Verify => verify_repos(),
     }
 }
 
@@ -297,6 +407,52 @@ fn list_repos() -> Result<(), AppError> {
     Ok(())
 }
 
+fn verify_repos() -> Result<(), AppError> {
+    let service = SkillService::new()?;
+    let results = run_async(service.verify_enabled_repos())?;
+
+    if results.is_empty() {
+        println!("{}", info("No enabled skill repos to verify."));
+        return Ok(());
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["Repo", "Branch", "Status", "Detail"]);
+    let mut failed = 0;
+    for result in &results {
+        let status = match result.status {
+            RepoReachability::Reachable => success("reachable"),
+            RepoReachability::NotFound => error("not found"),
+            RepoReachability::AuthRequired => error("auth required"),
+            RepoReachability::Unreachable => error("unreachable"),
+        };
+        if result.status != RepoReachability::Reachable {
+            failed += 1;
+        }
+        table.add_row(vec![
+            format!("{}/{}", result.repo.owner, result.repo.name),
+            result.repo.branch.clone(),
+            status,
+            result.detail.clone().unwrap_or_default(),
+        ]);
+    }
+    println!("{}", table);
+
+    if failed > 0 {
+        println!(
+            "{}",
+            info("Not found also covers private repos: GitHub answers 404 without credentials.")
+        );
+        return Err(AppError::localized(
+            "skills.repos.verify_failed",
+            format!("{failed} 个技能仓库不可用"),
+            format!("{failed} skill repo(s) failed verification"),
+        ));
+    }
+    println!("{}", success("✓ All enabled skill repos are reachable."));
+    Ok(())
+}
+
 fn add_repo(_url: &str) -> Result<(), AppError> {
     let repo = parse_repo_spec(_url)?;
     SkillService::upsert_repo(repo)?;
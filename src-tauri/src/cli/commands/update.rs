@@ -1,11 +1,13 @@
-use clap::Args;
+use clap::{Args, Subcommand};
 use flate2::read::GzDecoder;
 use semver::Version;
 use serde::Deserialize;
 use sha2::{Digest, Sha256};
 use std::fs;
+use std::future::Future;
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use tar::Archive;
 use tempfile::TempDir;
 use url::Url;
@@ -17,6 +19,8 @@ const REPO_URL: &str = env!("CARGO_PKG_REPOSITORY");
 const BINARY_NAME: &str = "cc-switch";
 const CHECKSUMS_FILE_NAME: &str = "checksums.txt";
 const HTTP_REQUEST_TIMEOUT_SECS: u64 = 30;
+const DEFAULT_UPDATE_CHECK_TIMEOUT_MS: u64 = 10_000;
+const UPDATE_CHECK_TIMEOUT_ERROR_KEY: &str = "update.check.timeout";
 const MAX_RELEASE_ASSET_SIZE_BYTES: u64 = 100 * 1024 * 1024;
 const USER_AGENT: &str = concat!(
     env!("CARGO_PKG_NAME"),
@@ -29,6 +33,24 @@ pub struct UpdateCommand {
     /// Target version (example: v4.6.2). Defaults to latest release.
     #[arg(long)]
     pub version: Option<String>,
+
+    /// Download and verify the release into a temp directory without replacing the binary
+    #[arg(long)]
+    pub dry_run: bool,
+
+    #[command(subcommand)]
+    pub action: Option<UpdateAction>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum UpdateAction {
+    /// Check for a newer release without installing it
+    Check {
+        /// Give up on the release query after this many milliseconds
+        /// (defaults to the global network timeout, else 5000)
+        #[arg(long)]
+        timeout_ms: Option<u64>,
+    },
 }
 
 #[derive(Debug, Deserialize)]
@@ -53,7 +75,46 @@ struct DownloadedAsset {
 
 pub fn execute(cmd: UpdateCommand) -> Result<(), AppError> {
     let runtime = create_runtime()?;
-    runtime.block_on(execute_async(cmd))
+    match cmd.action {
+        Some(UpdateAction::Check { timeout_ms }) => runtime.block_on(execute_check(timeout_ms)),
+        None => runtime.block_on(execute_async(cmd)),
+    }
+}
+
+async fn execute_check(timeout_ms: Option<u64>) -> Result<(), AppError> {
+    let timeout = crate::proxy::resolve_timeout(
+        timeout_ms.map(Duration::from_millis),
+        Duration::from_millis(DEFAULT_UPDATE_CHECK_TIMEOUT_MS),
+    );
+    let check = check_for_update_with_timeout(timeout).await?;
+
+    if check.is_already_latest {
+        println!(
+            "{}",
+            info(&format!(
+                "Already on latest version: v{}",
+                check.current_version
+            ))
+        );
+    } else if check.is_downgrade {
+        println!(
+            "{}",
+            info(&format!(
+                "Current version v{} is newer than latest release {}.",
+                check.current_version, check.target_tag
+            ))
+        );
+    } else {
+        println!(
+            "{}",
+            highlight(&format!(
+                "Update available: v{} -> {}",
+                check.current_version, check.target_tag
+            ))
+        );
+        println!("{}", info("Run `cc-switch update` to install it."));
+    }
+    Ok(())
 }
 
 async fn execute_async(cmd: UpdateCommand) -> Result<(), AppError> {
@@ -118,7 +179,22 @@ async fn execute_async(cmd: UpdateCommand) -> Result<(), AppError> {
     )
     .await?;
     let extracted_binary = extract_binary(&downloaded_asset.archive_path)?;
-    replace_current_binary(&extracted_binary)?;
+
+    if cmd.dry_run {
+        let archive_size = file_size(&downloaded_asset.archive_path)?;
+        let binary_size = file_size(&extracted_binary)?;
+        println!("{}", info(&format!("Asset: {}", release_asset.name)));
+        println!("{}", info(&format!("Archive size: {archive_size} bytes")));
+        println!("{}", info(&format!("Binary size: {binary_size} bytes")));
+        println!("{}", success("Checksum verified."));
+    }
+    if !install_extracted_binary(&extracted_binary, cmd.dry_run)? {
+        println!(
+            "{}",
+            info("Dry run: the current binary was not replaced; downloaded files are discarded.")
+        );
+        return Ok(());
+    }
 
     println!(
         "{}",
@@ -138,9 +214,9 @@ fn create_runtime() -> Result<tokio::runtime::Runtime, AppError> {
         .map_err(|e| AppError::Message(format!("Failed to create runtime: {e}")))
 }
 
+/// 也用于下载更新包：总时长固定，全局网络超时只约束建立连接
 fn create_http_client() -> Result<reqwest::Client, AppError> {
-    reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(HTTP_REQUEST_TIMEOUT_SECS))
+    crate::proxy::transfer_client_builder(Duration::from_secs(HTTP_REQUEST_TIMEOUT_SECS))
         .build()
         .map_err(|e| AppError::Message(format!("Failed to initialize HTTP client: {e}")))
 }
@@ -151,7 +227,7 @@ async fn resolve_target_tag(
 ) -> Result<String, AppError> {
     let tag = match version.map(str::trim).filter(|v| !v.is_empty()) {
         Some(version) => normalize_tag(version),
-        None => fetch_latest_release_tag(client).await?,
+        None => fetch_latest_release_tag(client, REPO_URL).await?,
     };
     validate_target_tag(&tag)?;
     Ok(tag)
@@ -192,8 +268,11 @@ fn normalize_tag(version: &str) -> String {
     }
 }
 
-async fn fetch_latest_release_tag(client: &reqwest::Client) -> Result<String, AppError> {
-    let api_url = release_api_url(REPO_URL, "latest")?;
+async fn fetch_latest_release_tag(
+    client: &reqwest::Client,
+    repo_url: &str,
+) -> Result<String, AppError> {
+    let api_url = release_api_url(repo_url, "latest")?;
     let release = client
         .get(api_url)
         .header(reqwest::header::USER_AGENT, USER_AGENT)
@@ -603,6 +682,21 @@ fn extract_tar_binary(_archive_path: &Path, _extract_dir: &Path) -> Result<PathB
     ))
 }
 
+fn file_size(path: &Path) -> Result<u64, AppError> {
+    fs::metadata(path)
+        .map(|meta| meta.len())
+        .map_err(|e| AppError::io(path, e))
+}
+
+/// 安装解压出的二进制；dry-run 时不触碰当前可执行文件，返回是否已替换
+fn install_extracted_binary(new_binary_path: &Path, dry_run: bool) -> Result<bool, AppError> {
+    if dry_run {
+        return Ok(false);
+    }
+    replace_current_binary(new_binary_path)?;
+    Ok(true)
+}
+
 fn replace_current_binary(new_binary_path: &Path) -> Result<(), AppError> {
     #[cfg(windows)]
     {
@@ -668,9 +762,16 @@ pub(crate) struct UpdateCheckInfo {
 }
 
 pub(crate) async fn check_for_update() -> Result<UpdateCheckInfo, AppError> {
+    let timeout =
+        crate::proxy::resolve_timeout(None, Duration::from_millis(DEFAULT_UPDATE_CHECK_TIMEOUT_MS));
+    check_for_update_with_timeout(timeout).await
+}
+
+pub(crate) async fn check_for_update_with_timeout(
+    timeout: Duration,
+) -> Result<UpdateCheckInfo, AppError> {
     let current_version = env!("CARGO_PKG_VERSION");
-    let client = create_http_client()?;
-    let target_tag = resolve_target_tag(&client, None).await?;
+    let target_tag = fetch_latest_tag_with_timeout(REPO_URL, timeout).await?;
     let target_version = target_tag.trim_start_matches('v');
 
     let is_already_latest = target_version == current_version;
@@ -684,6 +785,35 @@ pub(crate) async fn check_for_update() -> Result<UpdateCheckInfo, AppError> {
     })
 }
 
+async fn fetch_latest_tag_with_timeout(
+    repo_url: &str,
+    timeout: Duration,
+) -> Result<String, AppError> {
+    let client = create_http_client()?;
+    let tag = with_check_timeout(timeout, fetch_latest_release_tag(&client, repo_url)).await?;
+    validate_target_tag(&tag)?;
+    Ok(tag)
+}
+
+async fn with_check_timeout<T>(
+    timeout: Duration,
+    fut: impl Future<Output = Result<T, AppError>>,
+) -> Result<T, AppError> {
+    tokio::time::timeout(timeout, fut).await.map_err(|_| {
+        let ms = timeout.as_millis();
+        AppError::localized(
+            UPDATE_CHECK_TIMEOUT_ERROR_KEY,
+            format!("检查更新超时（{ms} 毫秒）"),
+            format!("Update check timed out after {ms} ms"),
+        )
+    })?
+}
+
+/// 是否为检查更新超时错误（供 TUI 给出专门提示）
+pub(crate) fn is_update_check_timeout(err: &AppError) -> bool {
+    matches!(err, AppError::Localized { key, .. } if *key == UPDATE_CHECK_TIMEOUT_ERROR_KEY)
+}
+
 pub(crate) async fn download_and_apply(
     target_tag: &str,
     on_progress: impl Fn(u64, Option<u64>),
@@ -723,6 +853,52 @@ pub(crate) async fn download_and_apply(
 mod tests {
     use super::*;
 
+    #[test]
+    fn check_times_out_when_server_never_responds() {
+        // 只监听不响应：连接进入 backlog，请求永远得不到回复
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind listener");
+        let repo_url = format!(
+            "http://{}/farion1231/cc-switch",
+            listener.local_addr().expect("local addr")
+        );
+
+        let runtime = create_runtime().expect("runtime");
+        let err = runtime
+            .block_on(fetch_latest_tag_with_timeout(
+                &repo_url,
+                Duration::from_millis(200),
+            ))
+            .expect_err("never-responding server must time out");
+
+        assert!(is_update_check_timeout(&err), "unexpected error: {err}");
+        drop(listener);
+    }
+
+    #[test]
+    fn dry_run_leaves_current_executable_untouched() {
+        let current_exe = std::env::current_exe().expect("current exe");
+        let before = fs::metadata(&current_exe).expect("exe metadata");
+        let staged = current_exe
+            .parent()
+            .expect("exe parent")
+            .join(format!("{BINARY_NAME}.new"));
+
+        let temp = tempfile::tempdir().expect("tempdir");
+        let new_binary = temp.path().join(BINARY_NAME);
+        fs::write(&new_binary, b"not a real binary").expect("write fake binary");
+
+        let replaced = install_extracted_binary(&new_binary, true).expect("dry run");
+
+        assert!(!replaced);
+        let after = fs::metadata(&current_exe).expect("exe metadata");
+        assert_eq!(before.len(), after.len());
+        assert_eq!(
+            before.modified().expect("mtime"),
+            after.modified().expect("mtime")
+        );
+        assert!(!staged.exists(), "dry run must not stage a replacement");
+    }
+
     #[test]
     fn normalize_tag_adds_prefix_when_missing() {
         assert_eq!(normalize_tag("4.6.2"), "v4.6.2");
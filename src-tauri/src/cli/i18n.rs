@@ -329,6 +329,24 @@ pub mod texts {
         " "
     }
 
+    pub fn codex_empty_api_key_warning(id: &str) -> String {
+        if is_chinese() {
+            format!(
+                "供应商 '{id}' 的 API Key 为空，未写入 auth.json；请用 `provider edit` 设置 Key"
+            )
+        } else {
+            format!("Provider '{id}' has an empty API key, so auth.json was not written; set one with `provider edit`")
+        }
+    }
+
+    pub fn tui_provider_drift_marker() -> &'static str {
+        if is_chinese() {
+            "≠ live 已漂移"
+        } else {
+            "≠ live drift"
+        }
+    }
+
     pub fn tui_highlight_symbol() -> &'static str {
         "➤ "
     }
@@ -365,6 +383,22 @@ pub mod texts {
         }
     }
 
+    pub fn tui_toast_no_last_action() -> &'static str {
+        if is_chinese() {
+            "本次会话还没有可重复的操作"
+        } else {
+            "No action to repeat in this session yet"
+        }
+    }
+
+    pub fn tui_toast_last_action_other_app(app: &str) -> String {
+        if is_chinese() {
+            format!("上次操作属于 {app}，请先切换到该应用")
+        } else {
+            format!("The last action belongs to {app}; switch to that app first")
+        }
+    }
+
     pub fn tui_error_invalid_config_structure(e: &str) -> String {
         if is_chinese() {
             format!("配置结构无效：{e}")
@@ -407,11 +441,38 @@ pub mod texts {
 
     pub fn tui_default_common_snippet_for_app(app: &str) -> &'static str {
         match app {
+            "claude" => {
+                "{\n  \"env\": {\n    \"CLAUDE_CODE_DISABLE_NONESSENTIAL_TRAFFIC\": 1\n  }\n}\n"
+            }
             "codex" => "",
             _ => "{}\n",
         }
     }
 
+    pub fn config_common_snippet_initialized(app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已为应用 '{app}' 写入默认通用配置片段")
+        } else {
+            format!("✓ Seeded the default common config snippet for app '{app}'")
+        }
+    }
+
+    pub fn config_common_snippet_already_set(app: &str) -> String {
+        if is_chinese() {
+            format!("应用 '{app}' 已有通用配置片段，未覆盖（使用 --force 覆盖）")
+        } else {
+            format!("App '{app}' already has a common config snippet; left unchanged (use --force to overwrite)")
+        }
+    }
+
+    pub fn config_common_snippet_no_default(app: &str) -> String {
+        if is_chinese() {
+            format!("应用 '{app}' 没有默认通用配置片段")
+        } else {
+            format!("There is no default common config snippet for app '{app}'")
+        }
+    }
+
     pub fn tui_latency_ms(ms: u128) -> String {
         if is_chinese() {
             format!("{ms} ms")
@@ -509,9 +570,9 @@ pub mod texts {
 
     pub fn tui_footer_action_keys_prompts() -> &'static str {
         if is_chinese() {
-            "[ ] 切换应用  Enter 查看  a 激活  x 取消激活  e 编辑  d 删除  / 过滤  Esc 返回  ? 帮助"
+            "[ ] 切换应用  Enter 查看  a 激活  x 取消激活  e 编辑  d 删除  K/J 上移/下移  / 过滤  Esc 返回  ? 帮助"
         } else {
-            "[ ] switch app  Enter view  a activate  x deactivate  e edit  d delete  / filter  Esc back  ? help"
+            "[ ] switch app  Enter view  a activate  x deactivate  e edit  d delete  K/J move  / filter  Esc back  ? help"
         }
     }
 
@@ -565,9 +626,25 @@ pub mod texts {
 
     pub fn tui_help_text() -> &'static str {
         if is_chinese() {
-            "[ ]  切换应用\n←→  切换菜单/内容焦点\n↑↓  移动\n/   过滤\nEsc  返回\n?   显示/关闭帮助\n\n页面快捷键（在页面内容区顶部显示）：\n- 供应商：Enter 详情，s 切换，a 添加，e 编辑，d 删除，t 测速，c 健康检查\n- 供应商详情：s 切换，e 编辑，t 测速，c 健康检查\n- MCP：x 启用/禁用(当前应用)，m 选择应用，a 添加，e 编辑，i 导入已有，d 删除\n- 提示词：Enter 查看，a 激活，x 取消激活(当前)，e 编辑，d 删除\n- 技能：Enter 详情，x 启用/禁用(当前应用)，m 选择应用，d 卸载，i 导入已有\n- 配置：Enter 打开/执行，e 编辑片段\n- 设置：Enter 应用"
+            "[ ]  切换应用\n←→  切换菜单/内容焦点\n↑↓  移动\nPgUp/PgDn  列表翻页\n/   过滤\nEsc  返回\n?   显示/关闭帮助\nCtrl+L  最近的提示消息\n\n页面快捷键（在页面内容区顶部显示）：\n- 供应商：Enter 详情，s 切换，n/p 切换到下/上一个，a 添加，e 编辑，d 删除，t 测速，c 健康检查\n- 供应商详情：s 切换，e 编辑，t 测速，c 健康检查\n- MCP：x 启用/禁用(当前应用)，m 选择应用，a 添加，e 编辑，i 导入已有，d 删除\n- 提示词：Enter 查看，a 激活，x 取消激活(当前)，e 编辑，d 删除，K/J 上移/下移\n- 技能：Enter 详情，x 启用/禁用(当前应用)，m 选择应用，d 卸载，i 导入已有\n- 配置：Enter 打开/执行，e 编辑片段\n- 设置：Enter 应用"
+        } else {
+            "[ ]  switch app\n←→  focus menu/content\n↑↓  move\nPgUp/PgDn  page through lists\n/   filter\nEsc  back\n?   toggle help\nCtrl+L  recent notifications\n\nPage keys (shown at the top of each page):\n- Providers: Enter details, s switch, n/p switch to next/prev, a add, e edit, d delete, t speedtest, c stream check\n- Provider Detail: s switch, e edit, t speedtest, c stream check\n- MCP: x toggle current, m select apps, a add, e edit, i import existing, d delete\n- Prompts: Enter view, a activate, x deactivate active, e edit, d delete, K/J move up/down\n- Skills: Enter details, x toggle current, m select apps, d uninstall, i import existing\n- Config: Enter open/run, e edit snippet\n- Settings: Enter apply"
+        }
+    }
+
+    pub fn tui_toast_history_title() -> &'static str {
+        if is_chinese() {
+            "最近的提示消息"
+        } else {
+            "Recent Notifications"
+        }
+    }
+
+    pub fn tui_toast_history_empty() -> &'static str {
+        if is_chinese() {
+            "本次会话还没有提示消息"
         } else {
-            "[ ]  switch app\n←→  focus menu/content\n↑↓  move\n/   filter\nEsc  back\n?   toggle help\n\nPage keys (shown at the top of each page):\n- Providers: Enter details, s switch, a add, e edit, d delete, t speedtest, c stream check\n- Provider Detail: s switch, e edit, t speedtest, c stream check\n- MCP: x toggle current, m select apps, a add, e edit, i import existing, d delete\n- Prompts: Enter view, a activate, x deactivate active, e edit, d delete\n- Skills: Enter details, x toggle current, m select apps, d uninstall, i import existing\n- Config: Enter open/run, e edit snippet\n- Settings: Enter apply"
+            "No notifications in this session yet"
         }
     }
 
@@ -1267,6 +1344,14 @@ pub mod texts {
         }
     }
 
+    pub fn tui_label_pinned_ref() -> &'static str {
+        if is_chinese() {
+            "固定版本"
+        } else {
+            "Pinned"
+        }
+    }
+
     pub fn tui_label_readme() -> &'static str {
         if is_chinese() {
             "README"
@@ -1649,9 +1734,9 @@ pub mod texts {
 
     pub fn tui_provider_detail_keys() -> &'static str {
         if is_chinese() {
-            "按键：s=切换  e=编辑  t=测速  c=健康检查"
+            "按键：s=切换  e=编辑  t=测速  c=健康检查  v=显示密钥"
         } else {
-            "Keys: s=switch  e=edit  t=speedtest  c=stream check"
+            "Keys: s=switch  e=edit  t=speedtest  c=stream check  v=reveal key"
         }
     }
 
@@ -1663,6 +1748,14 @@ pub mod texts {
         }
     }
 
+    pub fn tui_key_switch_next_prev() -> &'static str {
+        if is_chinese() {
+            "切换到下/上一个"
+        } else {
+            "next/prev"
+        }
+    }
+
     pub fn tui_key_edit() -> &'static str {
         if is_chinese() {
             "编辑"
@@ -1687,6 +1780,133 @@ pub mod texts {
         }
     }
 
+    pub fn tui_key_group() -> &'static str {
+        if is_chinese() {
+            "分组"
+        } else {
+            "group"
+        }
+    }
+
+    pub fn tui_provider_group_untagged() -> &'static str {
+        if is_chinese() {
+            "无标签"
+        } else {
+            "Untagged"
+        }
+    }
+
+    pub fn tui_provider_group_uncategorized() -> &'static str {
+        if is_chinese() {
+            "未分类"
+        } else {
+            "Uncategorized"
+        }
+    }
+
+    pub fn tui_key_favorite() -> &'static str {
+        if is_chinese() {
+            "收藏"
+        } else {
+            "favorite"
+        }
+    }
+
+    pub fn tui_key_favorites_only() -> &'static str {
+        if is_chinese() {
+            "仅收藏"
+        } else {
+            "favorites"
+        }
+    }
+
+    pub fn tui_key_sort() -> &'static str {
+        if is_chinese() {
+            "排序"
+        } else {
+            "sort"
+        }
+    }
+
+    pub fn tui_toast_mcp_sort_by_id() -> &'static str {
+        if is_chinese() {
+            "MCP 服务器按 ID 排序"
+        } else {
+            "MCP servers sorted by ID"
+        }
+    }
+
+    pub fn tui_toast_mcp_sort_by_name() -> &'static str {
+        if is_chinese() {
+            "MCP 服务器按名称排序"
+        } else {
+            "MCP servers sorted by name"
+        }
+    }
+
+    pub fn tui_toast_mcp_sort_by_enabled() -> &'static str {
+        if is_chinese() {
+            "MCP 服务器按启用状态排序（已启用在前）"
+        } else {
+            "MCP servers sorted by enabled first"
+        }
+    }
+
+    pub fn tui_toast_provider_favorites_only(on: bool) -> &'static str {
+        if is_chinese() {
+            if on {
+                "仅显示收藏的供应商"
+            } else {
+                "显示全部供应商"
+            }
+        } else if on {
+            "Showing favorite providers only"
+        } else {
+            "Showing all providers"
+        }
+    }
+
+    pub fn tui_toast_provider_grouping_off() -> &'static str {
+        if is_chinese() {
+            "已关闭供应商分组"
+        } else {
+            "Provider grouping off"
+        }
+    }
+
+    pub fn tui_toast_provider_grouping_by_tag() -> &'static str {
+        if is_chinese() {
+            "按标签分组"
+        } else {
+            "Grouped by tag"
+        }
+    }
+
+    pub fn tui_toast_provider_grouping_by_category() -> &'static str {
+        if is_chinese() {
+            "按分类分组"
+        } else {
+            "Grouped by category"
+        }
+    }
+
+    pub fn tui_key_reveal_secret() -> &'static str {
+        if is_chinese() {
+            "显示密钥"
+        } else {
+            "reveal key"
+        }
+    }
+
+    pub fn tui_secret_reveal_countdown(remaining_secs: Option<u64>) -> String {
+        match (remaining_secs, is_chinese()) {
+            (Some(secs), true) => format!("（{secs} 秒后隐藏）"),
+            (Some(secs), false) => format!("(hides in {secs}s)"),
+            (None, true) => "（按 v 隐藏）".to_string(),
+            (None, false) => "(press v to hide)".to_string(),
+        }
+    }
+
     pub fn tui_stream_check_status_operational() -> &'static str {
         if is_chinese() {
             "正常"
@@ -2079,6 +2299,14 @@ pub mod texts {
         }
     }
 
+    pub fn tui_key_move_up_down() -> &'static str {
+        if is_chinese() {
+            "上移/下移"
+        } else {
+            "move up/down"
+        }
+    }
+
     pub fn tui_provider_list_keys() -> &'static str {
         if is_chinese() {
             "按键：a=新增  e=编辑  Enter=详情  s=切换  /=搜索"
@@ -2221,11 +2449,15 @@ pub mod texts {
         }
     }
 
-    pub fn tui_confirm_uninstall_skill_message(name: &str, directory: &str) -> String {
+    pub fn tui_confirm_uninstall_skill_message(
+        name: &str,
+        directory: &str,
+        enabled_apps: &str,
+    ) -> String {
         if is_chinese() {
-            format!("确认卸载 '{name}'（{directory}）？")
+            format!("确认卸载 '{name}'（{directory}）？\n已启用于：{enabled_apps}")
         } else {
-            format!("Uninstall '{name}' ({directory})?")
+            format!("Uninstall '{name}' ({directory})?\nEnabled for: {enabled_apps}")
         }
     }
 
@@ -2682,6 +2914,14 @@ pub mod texts {
         }
     }
 
+    pub fn tui_config_item_init_live() -> &'static str {
+        if is_chinese() {
+            "初始化客户端配置"
+        } else {
+            "Force Init Live Config"
+        }
+    }
+
     pub fn tui_config_item_reset() -> &'static str {
         if is_chinese() {
             "重置配置"
@@ -2802,6 +3042,28 @@ pub mod texts {
         }
     }
 
+    pub fn tui_confirm_switch_empty_credentials_title() -> &'static str {
+        if is_chinese() {
+            "API Key 为空"
+        } else {
+            "Empty API Key"
+        }
+    }
+
+    pub fn tui_confirm_switch_empty_credentials_message(name: &str, id: &str) -> String {
+        if is_chinese() {
+            format!(
+                "供应商 '{}' ({}) 未填写 API Key，切换后请求可能返回 401。仍要切换？",
+                name, id
+            )
+        } else {
+            format!(
+                "Provider '{}' ({}) has no API key; requests will likely fail with 401. Switch anyway?",
+                name, id
+            )
+        }
+    }
+
     pub fn tui_mcp_add_title() -> &'static str {
         if is_chinese() {
             "新增 MCP 服务器"
@@ -3026,6 +3288,30 @@ pub mod texts {
         }
     }
 
+    pub fn tui_config_init_live_title() -> &'static str {
+        if is_chinese() {
+            "强制初始化客户端配置"
+        } else {
+            "Force Init Live Config"
+        }
+    }
+
+    pub fn tui_config_init_live_message(app: &str) -> String {
+        if is_chinese() {
+            format!("将创建 {app} 的配置目录，并写入通常由 {app} 首次运行时生成的文件。继续？")
+        } else {
+            format!("This creates the {app} config dir and writes files that {app} normally creates on first run. Continue?")
+        }
+    }
+
+    pub fn tui_toast_live_already_initialized(app: &str) -> String {
+        if is_chinese() {
+            format!("{app} 已初始化，无需强制初始化。")
+        } else {
+            format!("{app} is already initialized; nothing to do.")
+        }
+    }
+
     pub fn tui_config_reset_title() -> &'static str {
         if is_chinese() {
             "重置配置"
@@ -3489,6 +3775,22 @@ pub mod texts {
         }
     }
 
+    pub fn tui_toast_skip_skill_uninstall_confirm_toggled(enabled: bool) -> String {
+        if is_chinese() {
+            if enabled {
+                "卸载 Skill 时将不再确认。".to_string()
+            } else {
+                "已恢复卸载 Skill 前的确认。".to_string()
+            }
+        } else {
+            if enabled {
+                "Skill uninstall will no longer ask for confirmation.".to_string()
+            } else {
+                "Skill uninstall confirmation restored.".to_string()
+            }
+        }
+    }
+
     pub fn tui_toast_claude_plugin_integration_toggled(enabled: bool) -> String {
         if is_chinese() {
             if enabled {
@@ -3537,6 +3839,20 @@ pub mod texts {
         }
     }
 
+    pub fn tui_toast_provider_favorite_set(favorite: bool) -> &'static str {
+        if is_chinese() {
+            if favorite {
+                "已收藏供应商。"
+            } else {
+                "已取消收藏。"
+            }
+        } else if favorite {
+            "Provider added to favorites."
+        } else {
+            "Provider removed from favorites."
+        }
+    }
+
     pub fn tui_toast_provider_deleted() -> &'static str {
         if is_chinese() {
             "供应商已删除。"
@@ -3685,6 +4001,14 @@ pub mod texts {
         }
     }
 
+    pub fn tui_toast_prompt_reordered() -> &'static str {
+        if is_chinese() {
+            "提示词顺序已更新。"
+        } else {
+            "Prompt order updated."
+        }
+    }
+
     pub fn tui_toast_exported_to(path: &str) -> String {
         if is_chinese() {
             format!("已导出到 {}", path)
@@ -4092,6 +4416,19 @@ pub mod texts {
         ("🔧 Settings", "🔧 设置")
     }
 
+    pub fn menu_repeat_last_action() -> &'static str {
+        let (en, zh) = menu_repeat_last_action_variants();
+        if is_chinese() {
+            zh
+        } else {
+            en
+        }
+    }
+
+    pub fn menu_repeat_last_action_variants() -> (&'static str, &'static str) {
+        ("🔁 Repeat last action", "🔁 重复上次操作")
+    }
+
     pub fn menu_exit() -> &'static str {
         let (en, zh) = menu_exit_variants();
         if is_chinese() {
@@ -4487,121 +4824,400 @@ pub mod texts {
         }
     }
 
-    pub fn restart_note() -> &'static str {
+    pub fn post_switch_hook_failed_warning(err: &str) -> String {
         if is_chinese() {
-            "注意：请重启 CLI 客户端以应用更改。"
+            format!("⚠ 切换已完成，但 post_switch 钩子失败：{err}")
         } else {
-            "Note: Restart your CLI client to apply the changes."
+            format!("⚠ Switched, but the post_switch hook failed: {err}")
         }
     }
 
-    pub fn live_sync_skipped_uninitialized_warning(app: &str) -> String {
+    pub fn switch_mcp_sync_failed_warning(failed: &str) -> String {
         if is_chinese() {
-            format!("⚠ 未检测到 {app} 客户端本地配置，已跳过写入 live 文件；先运行一次 {app} 初始化后再试。")
+            format!(
+                "⚠ 切换已完成，但以下 MCP 服务器同步失败（使用 --strict-mcp 可改为回滚）：{failed}"
+            )
         } else {
-            format!("⚠ Live sync skipped: {app} client not initialized; run it once to initialize, then retry.")
+            format!("⚠ Switched, but these MCP servers failed to sync (use --strict-mcp to roll back instead): {failed}")
         }
     }
 
-    pub fn no_deletable_providers() -> &'static str {
+    pub fn switch_unmanaged_mcp_warning(ids: &str) -> String {
         if is_chinese() {
-            "没有可删除的供应商（无法删除当前供应商）。"
+            format!("Codex config.toml 中以下 MCP 服务器尚未纳入 cc-switch 管理，切换可能导致丢失: {ids}")
         } else {
-            "No providers available for deletion (cannot delete current provider)."
+            format!("These MCP servers in Codex config.toml are not managed by cc-switch and may be lost on switch: {ids}")
         }
     }
 
-    pub fn select_provider_to_delete() -> &'static str {
+    pub fn config_validate_app_header(app: &str, count: usize, current: &str) -> String {
+        let current = if current.is_empty() { "-" } else { current };
         if is_chinese() {
-            "选择要删除的供应商："
+            format!("{app}（{count} 个供应商，当前: {current}）")
         } else {
-            "Select provider to delete:"
+            format!("{app} ({count} providers, current: {current})")
         }
     }
 
-    pub fn confirm_delete(id: &str) -> String {
+    pub fn config_validate_no_issues() -> &'static str {
         if is_chinese() {
-            format!("确定要删除供应商 '{}' 吗？", id)
+            "未发现问题"
         } else {
-            format!("Are you sure you want to delete provider '{}'?", id)
+            "No issues found"
         }
     }
 
-    pub fn cancelled() -> &'static str {
+    pub fn identify_live_header(app: &str) -> String {
         if is_chinese() {
-            "已取消。"
+            format!("{app} live 配置识别字段")
         } else {
-            "Cancelled."
+            format!("{app} live config identifying fields")
         }
     }
 
-    pub fn selection_cancelled() -> &'static str {
+    pub fn identify_matches_current(id: &str) -> String {
         if is_chinese() {
-            "已取消选择"
+            format!("✓ live 配置与当前供应商 '{id}' 一致")
         } else {
-            "Selection cancelled"
+            format!("✓ Live config matches the current provider '{id}'")
         }
     }
 
-    pub fn invalid_selection() -> &'static str {
+    pub fn identify_matches_other(ids: &str, current: &str) -> String {
         if is_chinese() {
-            "选择无效"
+            format!("live 配置对应供应商 {ids}，但当前供应商记录为 '{current}'")
         } else {
-            "Invalid selection"
+            format!("Live config matches {ids}, but the recorded current provider is '{current}'")
         }
     }
 
-    pub fn available_backups() -> &'static str {
+    pub fn identify_no_match() -> String {
         if is_chinese() {
-            "可用备份"
+            "没有匹配的供应商：live 配置已与所有存储的供应商发生漂移".to_string()
         } else {
-            "Available Backups"
+            "No match: live config has drifted from every stored provider".to_string()
         }
     }
 
-    pub fn no_backups_found() -> &'static str {
+    pub fn identify_same_endpoint(ids: &str) -> String {
         if is_chinese() {
-            "未找到备份。"
+            format!(
+                "API 地址相同但 model/wire_api 不同: {ids}（可用 `provider snapshot` 保存修改）"
+            )
         } else {
-            "No backups found."
+            format!("Same base URL, different model/wire_api: {ids} (save the edit with `provider snapshot`)")
         }
     }
 
-    pub fn create_backup_first_hint() -> &'static str {
+    pub fn provider_snapshot_refreshed(id: &str, app: &str) -> String {
         if is_chinese() {
-            "请先创建备份：cc-switch config backup"
+            format!("✓ 已按 live 配置刷新 {app} 供应商 '{id}' 的快照")
         } else {
-            "Create a backup first: cc-switch config backup"
+            format!("✓ Refreshed {app} provider '{id}' snapshot from live config")
         }
     }
 
-    pub fn found_backups(count: usize) -> String {
+    pub fn provider_snapshot_seeded(id: &str, app: &str) -> String {
         if is_chinese() {
-            format!("找到 {} 个备份：", count)
+            format!("✓ 已从文件预置 {app} 供应商 '{id}' 的快照（未修改 live 配置）")
         } else {
-            format!("Found {} backup(s):", count)
+            format!("✓ Seeded {app} provider '{id}' snapshot from file (live config untouched)")
         }
     }
 
-    pub fn select_backup_to_restore() -> &'static str {
+    pub fn provider_snapshot_seeded_hint(id: &str) -> String {
         if is_chinese() {
-            "选择要恢复的备份："
+            format!("应用安装后运行 `cc-switch provider switch {id}` 写入 live 配置")
         } else {
-            "Select backup to restore:"
+            format!(
+                "After installing the app, run `cc-switch provider switch {id}` to write it live"
+            )
         }
     }
 
-    pub fn warning_title() -> &'static str {
+    pub fn provider_archived(id: &str, app: &str) -> String {
         if is_chinese() {
-            "警告："
+            format!("✓ 已归档 {app} 供应商 '{id}'（`provider unarchive {id}` 可恢复）")
         } else {
-            "Warning:"
+            format!("✓ Archived {app} provider '{id}' (restore with `provider unarchive {id}`)")
         }
     }
 
-    pub fn config_restore_warning_replace() -> &'static str {
+    pub fn provider_unarchived(id: &str, app: &str) -> String {
         if is_chinese() {
-            "这将用所选备份替换你当前的配置。"
+            format!("✓ 已恢复 {app} 供应商 '{id}'")
+        } else {
+            format!("✓ Restored {app} provider '{id}'")
+        }
+    }
+
+    pub fn provider_favorited(id: &str, app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已收藏 {app} 供应商 '{id}'")
+        } else {
+            format!("✓ Added {app} provider '{id}' to favorites")
+        }
+    }
+
+    pub fn provider_unfavorited(id: &str, app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已取消收藏 {app} 供应商 '{id}'")
+        } else {
+            format!("✓ Removed {app} provider '{id}' from favorites")
+        }
+    }
+
+    pub fn provider_archived_hidden_hint(count: usize) -> String {
+        if is_chinese() {
+            format!("另有 {count} 个已归档供应商未显示（使用 --archived 查看）")
+        } else {
+            format!("{count} archived provider(s) hidden (use --archived to show)")
+        }
+    }
+
+    pub fn provider_converted(source_id: &str, from_app: &str, id: &str, app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已基于 {from_app} 供应商 '{source_id}' 创建 {app} 供应商 '{id}'（已复制 API 地址与 Key）")
+        } else {
+            format!("✓ Created {app} provider '{id}' from {from_app} provider '{source_id}' (API URL and key copied)")
+        }
+    }
+
+    pub fn provider_convert_unmapped_note(fields: &str) -> String {
+        if is_chinese() {
+            format!("以下字段无法跨应用映射，已使用默认值：{fields}")
+        } else {
+            format!("Not mapped across apps (left at defaults): {fields}")
+        }
+    }
+
+    pub fn provider_added_from_live(from_app: &str, id: &str, app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已按 {from_app} 的 live 配置创建 {app} 供应商 '{id}'")
+        } else {
+            format!("✓ Created {app} provider '{id}' from the {from_app} live config")
+        }
+    }
+
+    pub fn provider_moved(id: &str, from_app: &str, to_app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已将供应商 '{id}' 从 {from_app} 移到 {to_app}")
+        } else {
+            format!("✓ Moved provider '{id}' from {from_app} to {to_app}")
+        }
+    }
+
+    pub fn provider_move_needs_edit(id: &str, to_app: &str) -> String {
+        if is_chinese() {
+            format!("无法转换 API 地址与 Key，已写入 {to_app} 的配置模板；请运行 `cc-switch --app {to_app} provider edit {id}` 补全配置")
+        } else {
+            format!("Could not convert the API URL and key; left a {to_app} template with placeholders. Run `cc-switch --app {to_app} provider edit {id}` to fill it in")
+        }
+    }
+
+    pub fn switch_live_files_written_title() -> &'static str {
+        if is_chinese() {
+            "已写入的 live 文件："
+        } else {
+            "Live files written:"
+        }
+    }
+
+    pub fn switch_live_file_written(path: &str, bytes: u64) -> String {
+        if is_chinese() {
+            format!("  {path}（{bytes} 字节）")
+        } else {
+            format!("  {path} ({bytes} bytes)")
+        }
+    }
+
+    pub fn switch_no_live_files_written() -> &'static str {
+        if is_chinese() {
+            "未写入任何 live 文件。"
+        } else {
+            "No live files were written."
+        }
+    }
+
+    pub fn provider_health_check_waiting(id: &str) -> String {
+        if is_chinese() {
+            format!("正在检查供应商 '{id}' 的端点健康状况…")
+        } else {
+            format!("Checking endpoint health for provider '{id}'...")
+        }
+    }
+
+    pub fn live_preview_file_removed() -> &'static str {
+        if is_chinese() {
+            "（切换时该文件会被移除）"
+        } else {
+            "(this file is removed on switch)"
+        }
+    }
+
+    pub fn failover_queue_added(id: &str, app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已将 '{id}' 加入 {app} 故障转移队列")
+        } else {
+            format!("✓ Added '{id}' to the {app} failover queue")
+        }
+    }
+
+    pub fn failover_queue_removed(id: &str, app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已将 '{id}' 移出 {app} 故障转移队列")
+        } else {
+            format!("✓ Removed '{id}' from the {app} failover queue")
+        }
+    }
+
+    pub fn failover_queue_empty(app: &str) -> String {
+        if is_chinese() {
+            format!("{app} 的故障转移队列为空。使用 `provider failover add <id>` 添加。")
+        } else {
+            format!("The {app} failover queue is empty. Add one with `provider failover add <id>`.")
+        }
+    }
+
+    pub fn failover_current_is_healthy(id: &str) -> String {
+        if is_chinese() {
+            format!("当前供应商 '{id}' 健康，无需切换")
+        } else {
+            format!("Current provider '{id}' is healthy; no switch needed")
+        }
+    }
+
+    pub fn restart_note() -> &'static str {
+        if is_chinese() {
+            "注意：请重启 CLI 客户端以应用更改。"
+        } else {
+            "Note: Restart your CLI client to apply the changes."
+        }
+    }
+
+    pub fn live_sync_skipped_uninitialized_warning(app: &str) -> String {
+        if is_chinese() {
+            format!("⚠ 未检测到 {app} 客户端本地配置，已跳过写入 live 文件；先运行一次 {app} 初始化后再试。")
+        } else {
+            format!("⚠ Live sync skipped: {app} client not initialized; run it once to initialize, then retry.")
+        }
+    }
+
+    pub fn live_sync_force_init_hint(app: &str) -> String {
+        if is_chinese() {
+            format!("  也可使用 `--force-init` 直接创建 {app} 的基础配置。")
+        } else {
+            format!("  Or pass `--force-init` to create a baseline {app} config now.")
+        }
+    }
+
+    pub fn live_force_initialized_warning(app: &str, dir: &str) -> String {
+        if is_chinese() {
+            format!("⚠ 已强制初始化 {app}：创建了 {dir} 并写入了通常由 {app} 首次运行时生成的配置文件。")
+        } else {
+            format!("⚠ Force-initialized {app}: created {dir} and wrote config files that {app} normally creates on first run.")
+        }
+    }
+
+    pub fn no_deletable_providers() -> &'static str {
+        if is_chinese() {
+            "没有可删除的供应商（无法删除当前供应商）。"
+        } else {
+            "No providers available for deletion (cannot delete current provider)."
+        }
+    }
+
+    pub fn select_provider_to_delete() -> &'static str {
+        if is_chinese() {
+            "选择要删除的供应商："
+        } else {
+            "Select provider to delete:"
+        }
+    }
+
+    pub fn confirm_delete(id: &str) -> String {
+        if is_chinese() {
+            format!("确定要删除供应商 '{}' 吗？", id)
+        } else {
+            format!("Are you sure you want to delete provider '{}'?", id)
+        }
+    }
+
+    pub fn cancelled() -> &'static str {
+        if is_chinese() {
+            "已取消。"
+        } else {
+            "Cancelled."
+        }
+    }
+
+    pub fn selection_cancelled() -> &'static str {
+        if is_chinese() {
+            "已取消选择"
+        } else {
+            "Selection cancelled"
+        }
+    }
+
+    pub fn invalid_selection() -> &'static str {
+        if is_chinese() {
+            "选择无效"
+        } else {
+            "Invalid selection"
+        }
+    }
+
+    pub fn available_backups() -> &'static str {
+        if is_chinese() {
+            "可用备份"
+        } else {
+            "Available Backups"
+        }
+    }
+
+    pub fn no_backups_found() -> &'static str {
+        if is_chinese() {
+            "未找到备份。"
+        } else {
+            "No backups found."
+        }
+    }
+
+    pub fn create_backup_first_hint() -> &'static str {
+        if is_chinese() {
+            "请先创建备份：cc-switch config backup"
+        } else {
+            "Create a backup first: cc-switch config backup"
+        }
+    }
+
+    pub fn found_backups(count: usize) -> String {
+        if is_chinese() {
+            format!("找到 {} 个备份：", count)
+        } else {
+            format!("Found {} backup(s):", count)
+        }
+    }
+
+    pub fn select_backup_to_restore() -> &'static str {
+        if is_chinese() {
+            "选择要恢复的备份："
+        } else {
+            "Select backup to restore:"
+        }
+    }
+
+    pub fn warning_title() -> &'static str {
+        if is_chinese() {
+            "警告："
+        } else {
+            "Warning:"
+        }
+    }
+
+    pub fn config_restore_warning_replace() -> &'static str {
+        if is_chinese() {
+            "这将用所选备份替换你当前的配置。"
         } else {
             "This will replace your current configuration with the selected backup."
         }
@@ -4709,14 +5325,6 @@ pub mod texts {
         }
     }
 
-    pub fn provider_not_found(id: &str) -> String {
-        if is_chinese() {
-            format!("供应商不存在: {}", id)
-        } else {
-            format!("Provider not found: {}", id)
-        }
-    }
-
     pub fn generated_id(id: &str) -> String {
         if is_chinese() {
             format!("生成的 ID: {}", id)
@@ -5857,83 +6465,279 @@ pub mod texts {
         }
     }
 
-    // ============================================
-    // MCP SERVER MANAGEMENT (MCP 服务器管理)
-    // ============================================
-
-    pub fn mcp_management() -> &'static str {
+    // Provider Tags & Group Edit
+    pub fn provider_tags_line(id: &str, tags: &[String]) -> String {
+        let tags = if tags.is_empty() {
+            "-".to_string()
+        } else {
+            tags.join(", ")
+        };
         if is_chinese() {
-            "🛠️  MCP 服务器管理"
+            format!("供应商 '{id}' 的标签: {tags}")
         } else {
-            "🛠️  MCP Server Management"
+            format!("Tags for provider '{id}': {tags}")
         }
     }
 
-    pub fn no_mcp_servers() -> &'static str {
+    pub fn provider_never_used() -> &'static str {
         if is_chinese() {
-            "未找到 MCP 服务器。"
+            "从未使用"
         } else {
-            "No MCP servers found."
+            "never"
         }
     }
 
-    pub fn sync_all_servers() -> &'static str {
+    pub fn provider_id_renamed(old: &str, new: &str) -> String {
         if is_chinese() {
-            "🔄 同步所有服务器"
+            format!("✓ 已将供应商 ID '{old}' 修改为 '{new}'")
         } else {
-            "🔄 Sync All Servers"
+            format!("✓ Renamed provider ID '{old}' to '{new}'")
         }
     }
 
-    pub fn synced_successfully() -> &'static str {
+    pub fn provider_switch_all_switched(app: &str, id: &str) -> String {
         if is_chinese() {
-            "✓ 所有 MCP 服务器同步成功"
+            format!("✓ {app}: 已切换到 '{id}'")
         } else {
-            "✓ All MCP servers synced successfully"
+            format!("✓ {app}: switched to '{id}'")
         }
     }
 
-    // ============================================
-    // PROMPT MANAGEMENT (提示词管理)
-    // ============================================
-
-    pub fn prompts_management() -> &'static str {
+    pub fn provider_switch_all_skipped(app: &str, target: &str) -> String {
         if is_chinese() {
-            "💬 提示词管理"
+            format!("- {app}: 没有 '{target}' 供应商，已跳过")
         } else {
-            "💬 Prompt Management"
+            format!("- {app}: no '{target}' provider, skipped")
         }
     }
 
-    pub fn no_prompts() -> &'static str {
+    pub fn provider_switch_all_failed(app: &str, switched: &str) -> String {
+        let switched = if switched.is_empty() { "-" } else { switched };
         if is_chinese() {
-            "未找到提示词预设。"
+            format!("✗ {app}: 切换失败；已切换的应用：{switched}")
         } else {
-            "No prompt presets found."
+            format!("✗ {app}: switch failed; already switched: {switched}")
         }
     }
 
-    pub fn switch_active_prompt() -> &'static str {
+    pub fn provider_ref_resolved(query: &str, id: &str) -> String {
         if is_chinese() {
-            "🔄 切换活动提示词"
+            format!("'{query}' 匹配到供应商 '{id}'")
         } else {
-            "🔄 Switch Active Prompt"
+            format!("'{query}' matches provider '{id}'")
         }
     }
 
-    pub fn no_prompts_available() -> &'static str {
+    pub fn provider_cloned(source: &str, new_id: &str) -> String {
         if is_chinese() {
-            "没有可用的提示词。"
+            format!("✓ 已将供应商 '{source}' 复制为 '{new_id}'")
         } else {
-            "No prompts available."
+            format!("✓ Cloned provider '{source}' as '{new_id}'")
         }
     }
 
-    pub fn select_prompt_to_activate() -> &'static str {
+    pub fn providers_exported_split(count: usize, app: &str, dir: &str) -> String {
         if is_chinese() {
-            "选择要激活的提示词："
+            format!("✓ 已将 {count} 个 {app} 供应商导出到 {dir}")
         } else {
-            "Select prompt to activate:"
+            format!("✓ Exported {count} {app} provider(s) to {dir}")
+        }
+    }
+
+    pub fn codex_auth_requires_codex_app() -> &'static str {
+        if is_chinese() {
+            "该命令仅适用于 Codex，请添加 --app codex"
+        } else {
+            "This command only applies to Codex; pass --app codex"
+        }
+    }
+
+    pub fn codex_auth_target_updated(id: &str, target: crate::provider::CodexAuthTarget) -> String {
+        let label = codex_auth_target_label(target);
+        if is_chinese() {
+            format!("✓ 供应商 '{id}' 的 API Key 将写入 {label}")
+        } else {
+            format!("✓ Provider '{id}' will write its API key to {label}")
+        }
+    }
+
+    pub fn codex_auth_target_current(target: crate::provider::CodexAuthTarget) -> String {
+        let label = codex_auth_target_label(target);
+        if is_chinese() {
+            format!("API Key 写入位置: {label}")
+        } else {
+            format!("API key target: {label}")
+        }
+    }
+
+    fn codex_auth_target_label(target: crate::provider::CodexAuthTarget) -> &'static str {
+        match target {
+            crate::provider::CodexAuthTarget::AuthJson => "~/.codex/auth.json",
+            crate::provider::CodexAuthTarget::EnvFile => "~/.codex/.env",
+        }
+    }
+
+    pub fn no_current_provider_for_apps(apps: &str) -> String {
+        if is_chinese() {
+            format!("⚠ 以下应用没有当前供应商: {apps}")
+        } else {
+            format!("⚠ No current provider for: {apps}")
+        }
+    }
+
+    pub fn provider_group_updated(count: usize, tag: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已更新 {count} 个带标签 '{tag}' 的供应商")
+        } else {
+            format!("✓ Updated {count} provider(s) tagged '{tag}'")
+        }
+    }
+
+    // ============================================
+    // CONFIG DB CHECK (数据库检查)
+    // ============================================
+
+    pub fn db_check_duplicate_ids_title() -> &'static str {
+        if is_chinese() {
+            "检查重复的供应商 ID"
+        } else {
+            "Checking for duplicate provider IDs"
+        }
+    }
+
+    pub fn db_check_no_duplicate_ids() -> &'static str {
+        if is_chinese() {
+            "✓ 未发现重复的供应商 ID"
+        } else {
+            "✓ No duplicate provider IDs found"
+        }
+    }
+
+    pub fn db_check_duplicate_id_line(app: &str, id: &str, count: usize) -> String {
+        if is_chinese() {
+            format!("⚠ {app}: ID '{id}' 出现 {count} 次")
+        } else {
+            format!("⚠ {app}: ID '{id}' appears {count} times")
+        }
+    }
+
+    pub fn db_check_repair_hint() -> &'static str {
+        if is_chinese() {
+            "使用 `cc-switch config db-check --repair` 修复（会先自动备份）。"
+        } else {
+            "Run `cc-switch config db-check --repair` to fix (a backup is taken first)."
+        }
+    }
+
+    pub fn db_check_found_duplicates(count: usize) -> String {
+        if is_chinese() {
+            format!("发现 {count} 个重复的供应商 ID")
+        } else {
+            format!("Found {count} duplicate provider ID(s)")
+        }
+    }
+
+    pub fn db_check_backup_created(backup_id: &str) -> String {
+        if is_chinese() {
+            format!("已创建备份: {backup_id}")
+        } else {
+            format!("Backup created: {backup_id}")
+        }
+    }
+
+    pub fn db_check_rekeyed_line(app: &str, old_id: &str, new_id: &str) -> String {
+        if is_chinese() {
+            format!("✓ {app}: '{old_id}' 的重复项已改为 '{new_id}'")
+        } else {
+            format!("✓ {app}: duplicate of '{old_id}' re-keyed to '{new_id}'")
+        }
+    }
+
+    pub fn db_check_repaired(count: usize) -> String {
+        if is_chinese() {
+            format!("✓ 已修复 {count} 行，所有供应商均已保留")
+        } else {
+            format!("✓ Re-keyed {count} row(s); all providers were kept")
+        }
+    }
+
+    // ============================================
+    // MCP SERVER MANAGEMENT (MCP 服务器管理)
+    // ============================================
+
+    pub fn mcp_management() -> &'static str {
+        if is_chinese() {
+            "🛠️  MCP 服务器管理"
+        } else {
+            "🛠️  MCP Server Management"
+        }
+    }
+
+    pub fn no_mcp_servers() -> &'static str {
+        if is_chinese() {
+            "未找到 MCP 服务器。"
+        } else {
+            "No MCP servers found."
+        }
+    }
+
+    pub fn sync_all_servers() -> &'static str {
+        if is_chinese() {
+            "🔄 同步所有服务器"
+        } else {
+            "🔄 Sync All Servers"
+        }
+    }
+
+    pub fn synced_successfully() -> &'static str {
+        if is_chinese() {
+            "✓ 所有 MCP 服务器同步成功"
+        } else {
+            "✓ All MCP servers synced successfully"
+        }
+    }
+
+    // ============================================
+    // PROMPT MANAGEMENT (提示词管理)
+    // ============================================
+
+    pub fn prompts_management() -> &'static str {
+        if is_chinese() {
+            "💬 提示词管理"
+        } else {
+            "💬 Prompt Management"
+        }
+    }
+
+    pub fn no_prompts() -> &'static str {
+        if is_chinese() {
+            "未找到提示词预设。"
+        } else {
+            "No prompt presets found."
+        }
+    }
+
+    pub fn switch_active_prompt() -> &'static str {
+        if is_chinese() {
+            "🔄 切换活动提示词"
+        } else {
+            "🔄 Switch Active Prompt"
+        }
+    }
+
+    pub fn no_prompts_available() -> &'static str {
+        if is_chinese() {
+            "没有可用的提示词。"
+        } else {
+            "No prompts available."
+        }
+    }
+
+    pub fn select_prompt_to_activate() -> &'static str {
+        if is_chinese() {
+            "选择要激活的提示词："
+        } else {
+            "Select prompt to activate:"
         }
     }
 
@@ -6111,6 +6915,14 @@ pub mod texts {
         }
     }
 
+    pub fn skip_skill_uninstall_confirm_label() -> &'static str {
+        if is_chinese() {
+            "卸载 Skill 时跳过确认"
+        } else {
+            "Skip skill uninstall confirmation"
+        }
+    }
+
     pub fn skip_claude_onboarding_confirm(enable: bool, path: &str) -> String {
         if is_chinese() {
             if enable {
@@ -6337,6 +7149,20 @@ pub mod texts {
         }
     }
 
+    pub fn common_snippet_auto_extract_status(enabled: bool) -> &'static str {
+        if is_chinese() {
+            if enabled {
+                "切换 Codex 时，若通用片段为空，会从 live config.toml 自动提取。"
+            } else {
+                "已关闭自动提取：通用片段只能通过 `config common set` 显式设置。"
+            }
+        } else if enabled {
+            "Switching Codex extracts the common snippet from the live config.toml while it is empty."
+        } else {
+            "Auto-extraction is off: the common snippet is only set via `config common set`."
+        }
+    }
+
     pub fn config_common_snippet_none_set() -> &'static str {
         if is_chinese() {
             "未设置通用配置片段。"
@@ -6353,6 +7179,30 @@ pub mod texts {
         }
     }
 
+    pub fn config_common_history_empty(app: &str) -> String {
+        if is_chinese() {
+            format!("应用 '{app}' 暂无通用配置片段历史")
+        } else {
+            format!("No common config snippet history for app '{app}'")
+        }
+    }
+
+    pub fn config_common_history_not_found(n: usize, total: usize) -> String {
+        if is_chinese() {
+            format!("历史版本 {n} 不存在（共 {total} 个，见 `config common history`）")
+        } else {
+            format!("No history version {n} ({total} available; see `config common history`)")
+        }
+    }
+
+    pub fn config_common_reverted(n: usize, app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已将应用 '{app}' 的通用配置片段恢复为历史版本 {n}")
+        } else {
+            format!("✓ Restored history version {n} of the common config snippet for app '{app}'")
+        }
+    }
+
     pub fn config_common_snippet_require_json_or_file() -> &'static str {
         if is_chinese() {
             "请提供 --json 或 --file"
@@ -6546,6 +7396,443 @@ pub mod texts {
         }
     }
 
+    pub fn mask_style_current(style: &str, sample: &str) -> String {
+        if is_chinese() {
+            format!("密钥遮罩样式：{style}（示例：{sample}）")
+        } else {
+            format!("Secret mask style: {style} (e.g. {sample})")
+        }
+    }
+
+    pub fn deeplink_dry_run_title() -> &'static str {
+        if is_chinese() {
+            "导入链接预览（未保存）"
+        } else {
+            "Import link preview (not saved)"
+        }
+    }
+
+    pub fn deeplink_dry_run_note() -> &'static str {
+        if is_chinese() {
+            "这是预览，未添加任何供应商；去掉 --dry-run 即可导入"
+        } else {
+            "Dry run: no provider was added; rerun without --dry-run to import"
+        }
+    }
+
+    pub fn deeplink_provider_imported(id: &str, app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已从链接导入供应商 '{id}'（应用：{app}）")
+        } else {
+            format!("✓ Imported provider '{id}' for app '{app}' from link")
+        }
+    }
+
+    pub fn legacy_settings_none_found() -> &'static str {
+        if is_chinese() {
+            "Claude 配置目录中没有遗留的 settings-*.json 文件"
+        } else {
+            "No legacy settings-*.json files found in the Claude config dir"
+        }
+    }
+
+    pub fn legacy_settings_cleanup_hint() -> &'static str {
+        if is_chinese() {
+            "遗留文件已保留；使用 --cleanup 删除已导入或重复的文件"
+        } else {
+            "Legacy files were kept; rerun with --cleanup to delete imported or duplicate ones"
+        }
+    }
+
+    pub fn output_written_to(path: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已写入 {path}")
+        } else {
+            format!("✓ Written to {path}")
+        }
+    }
+
+    pub fn notes_template_saved() -> &'static str {
+        if is_chinese() {
+            "✓ 备注模板已保存"
+        } else {
+            "✓ Notes template saved"
+        }
+    }
+
+    pub fn notes_template_empty() -> &'static str {
+        if is_chinese() {
+            "未设置备注模板（新增供应商时备注为空）"
+        } else {
+            "No notes template set (notes start empty when adding a provider)"
+        }
+    }
+
+    pub fn switch_hooks_label() -> &'static str {
+        if is_chinese() {
+            "切换钩子"
+        } else {
+            "Switch hooks"
+        }
+    }
+
+    pub fn tui_switch_hooks_configured() -> &'static str {
+        if is_chinese() {
+            "已配置"
+        } else {
+            "configured"
+        }
+    }
+
+    pub fn tui_switch_hooks_editor_title() -> &'static str {
+        if is_chinese() {
+            "编辑切换钩子（JSON，preSwitch/postSwitch，apps 为按应用覆盖；清空即移除）"
+        } else {
+            "Edit Switch Hooks (JSON: preSwitch/postSwitch, per-app under apps; empty clears)"
+        }
+    }
+
+    pub fn tui_toast_switch_hooks_saved() -> &'static str {
+        if is_chinese() {
+            "切换钩子已保存。"
+        } else {
+            "Switch hooks saved."
+        }
+    }
+
+    pub fn switch_hooks_saved() -> &'static str {
+        if is_chinese() {
+            "✓ 切换钩子已保存"
+        } else {
+            "✓ Switch hooks saved"
+        }
+    }
+
+    pub fn switch_hooks_none() -> &'static str {
+        if is_chinese() {
+            "（未设置）"
+        } else {
+            "(none)"
+        }
+    }
+
+    pub fn secret_reveal_timeout_label() -> &'static str {
+        if is_chinese() {
+            "明文密钥显示时长"
+        } else {
+            "Revealed key timeout"
+        }
+    }
+
+    pub fn secret_reveal_timeout_value(secs: u64) -> String {
+        match (secs, is_chinese()) {
+            (0, true) => "直到手动隐藏".to_string(),
+            (0, false) => "until hidden manually".to_string(),
+            (secs, true) => format!("{secs} 秒"),
+            (secs, false) => format!("{secs}s"),
+        }
+    }
+
+    pub fn secret_reveal_timeout_saved() -> &'static str {
+        if is_chinese() {
+            "✓ 明文密钥显示时长已保存"
+        } else {
+            "✓ Revealed key timeout saved"
+        }
+    }
+
+    pub fn tui_secret_reveal_timeout_prompt() -> &'static str {
+        if is_chinese() {
+            "秒数（0 = 直到手动隐藏，留空恢复默认）："
+        } else {
+            "Seconds (0 = until hidden manually, empty = default):"
+        }
+    }
+
+    pub fn tui_toast_secret_reveal_timeout_saved() -> &'static str {
+        if is_chinese() {
+            "明文密钥显示时长已保存。"
+        } else {
+            "Revealed key timeout saved."
+        }
+    }
+
+    pub fn tui_toast_secret_reveal_timeout_invalid() -> &'static str {
+        if is_chinese() {
+            "请输入非负整数秒数。"
+        } else {
+            "Enter a whole number of seconds."
+        }
+    }
+
+    pub fn network_timeout_saved() -> &'static str {
+        if is_chinese() {
+            "✓ 网络超时已保存"
+        } else {
+            "✓ Network timeout saved"
+        }
+    }
+
+    pub fn network_timeout_default() -> &'static str {
+        if is_chinese() {
+            "未设置网络超时（各操作使用内置默认值）"
+        } else {
+            "No network timeout set (each operation uses its built-in default)"
+        }
+    }
+
+    pub fn default_model_saved(app: &str) -> String {
+        if is_chinese() {
+            format!("✓ {app} 的默认模型已保存")
+        } else {
+            format!("✓ Default model for {app} saved")
+        }
+    }
+
+    pub fn default_model_empty(app: &str) -> String {
+        if is_chinese() {
+            format!("未设置 {app} 的默认模型（新增供应商时模型留空）")
+        } else {
+            format!(
+                "No default model set for {app} (the model starts empty when adding a provider)"
+            )
+        }
+    }
+
+    pub fn bundle_exported(providers: usize, mcp: usize, prompts: usize, path: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已导出团队配置包（{providers} 个供应商、{mcp} 个 MCP 服务器、{prompts} 个提示词）到 {path}")
+        } else {
+            format!("✓ Exported team bundle ({providers} providers, {mcp} MCP servers, {prompts} prompts) to {path}")
+        }
+    }
+
+    pub fn bundle_redacted_note() -> &'static str {
+        if is_chinese() {
+            "密钥已替换为 <redacted>，导入后请填写各自的密钥。"
+        } else {
+            "Secrets were replaced with <redacted>; fill in your own keys after importing."
+        }
+    }
+
+    pub fn auto_backup_enabled(hours: u64) -> String {
+        if is_chinese() {
+            format!(
+                "✓ 已开启自动备份：运行 cc-switch 时若距上次自动备份超过 {hours} 小时则自动备份"
+            )
+        } else {
+            format!("✓ Automatic backups on: cc-switch backs up when it runs and the last automatic backup is {hours}h or older")
+        }
+    }
+
+    pub fn switch_overlay_applied(count: usize) -> String {
+        if is_chinese() {
+            format!("  已将 {count} 项临时覆盖写入 live 配置（存储的供应商未修改）")
+        } else {
+            format!("  Applied {count} temporary override(s) to the live config (stored provider unchanged)")
+        }
+    }
+
+    pub fn backup_max_size_enabled(size: &str) -> String {
+        if is_chinese() {
+            format!("✓ 备份目录上限为 {size}：超出时创建备份会删除最旧的备份")
+        } else {
+            format!("✓ Backup directory capped at {size}: new backups delete the oldest ones to stay under it")
+        }
+    }
+
+    pub fn backup_max_size_disabled() -> &'static str {
+        if is_chinese() {
+            "已取消备份目录大小上限"
+        } else {
+            "Backup directory size limit removed"
+        }
+    }
+
+    pub fn backup_pruned_by_size(count: usize, size: &str) -> String {
+        if is_chinese() {
+            format!("为满足备份目录大小上限，已删除 {count} 个旧备份，释放 {size}")
+        } else {
+            format!("Removed {count} old backup(s) to stay under the size limit, freeing {size}")
+        }
+    }
+
+    pub fn backup_exceeds_max_size() -> &'static str {
+        if is_chinese() {
+            "⚠ 该备份本身已超过备份目录大小上限，未删除其他备份；请调大 --max-mb"
+        } else {
+            "⚠ This backup alone exceeds the backup directory size limit; no other backups were removed. Consider raising --max-mb"
+        }
+    }
+
+    pub fn config_dump_file_missing() -> &'static str {
+        if is_chinese() {
+            "（文件不存在）"
+        } else {
+            "(file does not exist)"
+        }
+    }
+
+    pub fn config_dump_unredacted_warning() -> &'static str {
+        if is_chinese() {
+            "注意：以上内容未脱敏，分享前请移除密钥"
+        } else {
+            "Warning: the output above is not redacted; remove secrets before sharing it"
+        }
+    }
+
+    pub fn config_toml_requires_codex() -> &'static str {
+        if is_chinese() {
+            "`--format toml` 仅支持 Codex，请同时指定 `--app codex`"
+        } else {
+            "`--format toml` only supports Codex; pass `--app codex`"
+        }
+    }
+
+    pub fn config_codex_toml_exported(path: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已将 Codex 供应商导出为 TOML：{path}")
+        } else {
+            format!("✓ Codex providers exported as TOML to {path}")
+        }
+    }
+
+    pub fn config_codex_toml_imported(added: usize, updated: usize) -> String {
+        if is_chinese() {
+            format!("✓ 已从 TOML 导入 Codex 供应商：新增 {added} 个，更新 {updated} 个")
+        } else {
+            format!("✓ Imported Codex providers from TOML: {added} added, {updated} updated")
+        }
+    }
+
+    pub fn config_backup_list_empty() -> &'static str {
+        if is_chinese() {
+            "没有匹配的备份"
+        } else {
+            "No matching backups"
+        }
+    }
+
+    pub fn auto_backup_disabled() -> &'static str {
+        if is_chinese() {
+            "自动备份已关闭"
+        } else {
+            "Automatic backups are off"
+        }
+    }
+
+    pub fn config_import_changes_title() -> &'static str {
+        if is_chinese() {
+            "变更："
+        } else {
+            "Changes applied:"
+        }
+    }
+
+    pub fn config_import_changes_none() -> &'static str {
+        if is_chinese() {
+            "  （前后均无供应商、MCP 服务器或提示词）"
+        } else {
+            "  (no providers, MCP servers or prompts before or after)"
+        }
+    }
+
+    pub fn config_import_providers_label(app: &str) -> String {
+        if is_chinese() {
+            format!("供应商（{app}）")
+        } else {
+            format!("Providers ({app})")
+        }
+    }
+
+    pub fn config_import_mcp_label() -> &'static str {
+        if is_chinese() {
+            "MCP 服务器"
+        } else {
+            "MCP servers"
+        }
+    }
+
+    pub fn config_import_prompts_label(app: &str) -> String {
+        if is_chinese() {
+            format!("提示词（{app}）")
+        } else {
+            format!("Prompts ({app})")
+        }
+    }
+
+    pub fn config_import_change_line(
+        label: &str,
+        counts: &crate::services::ChangeCounts,
+    ) -> String {
+        if is_chinese() {
+            format!(
+                "  {label}：新增 {}，更新 {}，未变 {}，移除 {}",
+                counts.added, counts.updated, counts.unchanged, counts.removed
+            )
+        } else {
+            format!(
+                "  {label}: {} added, {} updated, {} unchanged, {} removed",
+                counts.added, counts.updated, counts.unchanged, counts.removed
+            )
+        }
+    }
+
+    pub fn bundle_imported(summary: &crate::services::BundleImportSummary) -> String {
+        let skipped = summary.providers_skipped + summary.mcp_skipped + summary.prompts_skipped;
+        if is_chinese() {
+            format!(
+                "✓ 已合并团队配置包：新增 {} 个供应商、{} 个 MCP 服务器、{} 个提示词（已存在而跳过 {skipped} 项）",
+                summary.providers_added, summary.mcp_added, summary.prompts_added
+            )
+        } else {
+            format!(
+                "✓ Merged team bundle: added {} providers, {} MCP servers, {} prompts ({skipped} already present, skipped)",
+                summary.providers_added, summary.mcp_added, summary.prompts_added
+            )
+        }
+    }
+
+    pub fn bundle_preview_summary(new: usize, identical: usize, conflict: usize) -> String {
+        if is_chinese() {
+            format!("预览（未做任何修改）：{new} 个新供应商，{identical} 个与本地一致，{conflict} 个与本地冲突")
+        } else {
+            format!("Preview (nothing imported): {new} new, {identical} identical, {conflict} conflicting providers")
+        }
+    }
+
+    pub fn bundle_preview_conflict_hint() -> &'static str {
+        if is_chinese() {
+            "导入时冲突的供应商保留本地版本；如需采用配置包中的版本，请先删除或重命名本地供应商"
+        } else {
+            "Import keeps the local version of conflicting providers; delete or rename the local one to take the bundle's version"
+        }
+    }
+
+    pub fn redaction_patterns_saved() -> &'static str {
+        if is_chinese() {
+            "✓ 已保存自定义脱敏模式"
+        } else {
+            "✓ Saved custom redaction patterns"
+        }
+    }
+
+    pub fn redaction_patterns_empty() -> &'static str {
+        if is_chinese() {
+            "未配置自定义脱敏模式（仅使用内置规则）"
+        } else {
+            "No custom redaction patterns (built-in rules only)"
+        }
+    }
+
+    pub fn config_export_redacted_note() -> &'static str {
+        if is_chinese() {
+            "已导出脱敏后的 JSON 快照，仅用于分享，无法通过 config import 导入。"
+        } else {
+            "Wrote a redacted JSON snapshot for sharing; it cannot be restored with config import."
+        }
+    }
+
     pub fn backup_created(id: &str) -> String {
         if is_chinese() {
             format!("✓ 已创建备份，ID: {}", id)
@@ -6953,6 +8240,14 @@ pub mod texts {
         }
     }
 
+    pub fn tui_toast_update_check_timed_out() -> &'static str {
+        if is_chinese() {
+            "检查更新超时：无法连接 GitHub，请检查网络后重试。"
+        } else {
+            "Update check timed out: GitHub did not respond. Check your network and retry."
+        }
+    }
+
     pub fn tui_key_hide() -> &'static str {
         if is_chinese() {
             "隐藏"
@@ -6976,6 +8271,34 @@ pub mod texts {
             format!("Background update failed: {err}")
         }
     }
+
+    // ============================================
+    // COMPLETIONS (Shell 补全)
+    // ============================================
+
+    pub fn completions_installed(shell: &str, path: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已将 {shell} 补全脚本安装到 {path}")
+        } else {
+            format!("✓ Installed {shell} completions to {path}")
+        }
+    }
+
+    pub fn completions_refreshed(shell: &str, path: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已刷新 {shell} 补全脚本: {path}")
+        } else {
+            format!("✓ Refreshed {shell} completions at {path}")
+        }
+    }
+
+    pub fn completions_zsh_fpath_hint() -> &'static str {
+        if is_chinese() {
+            "请确保 compinit 运行前 ~/.zfunc 已加入 $fpath。"
+        } else {
+            "Make sure ~/.zfunc is in $fpath before compinit runs."
+        }
+    }
 }
 
 #[cfg(test)]
@@ -10,6 +10,71 @@ pub mod tui;
 pub mod ui;
 
 use crate::app_config::AppType;
+use crate::error::AppError;
+
+/// `--app` 参数：单个应用，或 `all` 表示全部应用
+#[derive(Debug, Clone, PartialEq)]
+pub enum AppSelection {
+    One(AppType),
+    All,
+}
+
+impl AppSelection {
+    /// 展开为具体的应用列表
+    pub fn apps(&self) -> Vec<AppType> {
+        match self {
+            AppSelection::One(app) => vec![app.clone()],
+            AppSelection::All => AppType::all().collect(),
+        }
+    }
+}
+
+impl clap::ValueEnum for AppSelection {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            AppSelection::One(AppType::Claude),
+            AppSelection::One(AppType::Codex),
+            AppSelection::One(AppType::Gemini),
+            AppSelection::One(AppType::OpenCode),
+            AppSelection::All,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        match self {
+            AppSelection::One(app) => app.to_possible_value(),
+            AppSelection::All => Some(clap::builder::PossibleValue::new("all")),
+        }
+    }
+}
+
+/// `--errors`：失败时错误输出的格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ErrorFormat {
+    /// `Error: ...` 文本
+    #[default]
+    Text,
+    /// 单行 JSON 对象（code、message 及结构化字段）
+    Json,
+}
+
+/// 将 `--app` 展开为要处理的应用列表，供只读的诊断命令共用；未指定时使用 `default`
+pub fn selected_apps(selection: Option<&AppSelection>, default: &[AppType]) -> Vec<AppType> {
+    selection.map_or_else(|| default.to_vec(), AppSelection::apps)
+}
+
+/// 将 `--app` 收窄为单个应用；不支持 `all` 的命令在此报错
+pub fn single_app(selection: Option<AppSelection>) -> Result<Option<AppType>, AppError> {
+    match selection {
+        None => Ok(None),
+        Some(AppSelection::One(app)) => Ok(Some(app)),
+        Some(AppSelection::All) => Err(AppError::localized(
+            "cli.app_all_unsupported",
+            "该命令不支持 `--app all`，请指定单个应用",
+            "`--app all` is not supported by this command; specify a single app",
+        )),
+    }
+}
 
 #[derive(Parser)]
 #[command(
@@ -21,12 +86,22 @@ use crate::app_config::AppType;
 pub struct Cli {
     /// Specify the application type
     #[arg(short, long, global = true, value_enum)]
-    pub app: Option<AppType>,
+    pub app: Option<AppSelection>,
 
     /// Enable verbose output
     #[arg(short, long, global = true)]
     pub verbose: bool,
 
+    /// Error output format on failure (json prints a single-line object to stderr)
+    #[arg(long, global = true, value_enum, default_value_t = ErrorFormat::Text)]
+    pub errors: ErrorFormat,
+
+    /// Default timeout in milliseconds for network operations (speedtest, update check,
+    /// skills, usage scripts, MCP URL checks, WebDAV requests); per-command `--timeout-ms`
+    /// flags still win. Backup uploads/downloads use it only as the connect timeout
+    #[arg(long, global = true, value_name = "MS")]
+    pub network_timeout_ms: Option<u64>,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -68,12 +143,8 @@ pub enum Commands {
     #[command(alias = "ui")]
     Interactive,
 
-    /// Generate shell completions
-    Completions {
-        /// The shell to generate completions for
-        #[arg(value_enum)]
-        shell: Shell,
-    },
+    /// Generate shell completions (`--install` writes them, `refresh` rewrites the installed script)
+    Completions(commands::completions::CompletionsCommand),
 }
 
 /// Generate shell completions
@@ -151,6 +222,418 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_completions_shell_and_refresh_subcommand() {
+        use super::commands::completions::CompletionsAction;
+        use clap_complete::Shell;
+
+        let cli = Cli::parse_from(["cc-switch", "completions", "zsh", "--install"]);
+        match cli.command {
+            Some(Commands::Completions(cmd)) => {
+                assert_eq!(cmd.shell, Some(Shell::Zsh));
+                assert!(cmd.install);
+                assert!(cmd.action.is_none());
+            }
+            _ => panic!("expected completions command"),
+        }
+
+        let cli = Cli::parse_from(["cc-switch", "completions", "refresh", "--shell", "fish"]);
+        match cli.command {
+            Some(Commands::Completions(cmd)) => assert!(matches!(
+                cmd.action,
+                Some(CompletionsAction::Refresh {
+                    shell: Some(Shell::Fish)
+                })
+            )),
+            _ => panic!("expected completions refresh command"),
+        }
+
+        assert!(Cli::try_parse_from(["cc-switch", "completions"]).is_err());
+    }
+
+    #[test]
+    fn completion_install_paths_follow_shell_conventions() {
+        use super::commands::completions::completion_install_path;
+        use clap_complete::Shell;
+        use std::path::Path;
+
+        let home = Path::new("/home/u");
+        assert_eq!(
+            completion_install_path(Shell::Bash, home),
+            Some(home.join(".local/share/bash-completion/completions/cc-switch"))
+        );
+        assert_eq!(
+            completion_install_path(Shell::Zsh, home),
+            Some(home.join(".zfunc/_cc-switch"))
+        );
+        assert_eq!(
+            completion_install_path(Shell::Fish, home),
+            Some(home.join(".config/fish/completions/cc-switch.fish"))
+        );
+        assert_eq!(completion_install_path(Shell::PowerShell, home), None);
+    }
+
+    #[test]
+    fn selected_apps_expands_all_and_falls_back_to_default() {
+        use super::{selected_apps, single_app, AppSelection, AppType};
+
+        let all: Vec<AppType> = AppType::all().collect();
+        assert_eq!(selected_apps(Some(&AppSelection::All), &[]), all);
+        assert_eq!(
+            selected_apps(Some(&AppSelection::One(AppType::Codex)), &all),
+            vec![AppType::Codex]
+        );
+        assert_eq!(
+            selected_apps(None, &[AppType::Claude]),
+            vec![AppType::Claude]
+        );
+
+        let cli = Cli::parse_from(["cc-switch", "config", "validate", "--app", "all"]);
+        assert_eq!(cli.app, Some(AppSelection::All));
+        let err = single_app(cli.app).expect_err("mutating commands reject --app all");
+        assert_eq!(err.code(), "cli.app_all_unsupported");
+    }
+
+    #[test]
+    fn parses_provider_list_current_only_for_all_apps() {
+        let cli = Cli::parse_from([
+            "cc-switch",
+            "provider",
+            "list",
+            "--current-only",
+            "--json",
+            "--app",
+            "all",
+        ]);
+
+        assert_eq!(cli.app, Some(super::AppSelection::All));
+        match cli.command {
+            Some(Commands::Provider(super::commands::provider::ProviderCommand::List {
+                current_only,
+                json,
+                ..
+            })) => {
+                assert!(current_only);
+                assert!(json);
+            }
+            _ => panic!("expected provider list command"),
+        }
+    }
+
+    #[test]
+    fn parses_provider_list_sort_by_last_used() {
+        let cli = Cli::parse_from(["cc-switch", "provider", "list", "--sort-by", "last-used"]);
+        match cli.command {
+            Some(Commands::Provider(super::commands::provider::ProviderCommand::List {
+                sort_by,
+                ..
+            })) => {
+                assert_eq!(sort_by, super::commands::provider::ProviderSortBy::LastUsed);
+            }
+            _ => panic!("expected provider list command"),
+        }
+    }
+
+    #[test]
+    fn provider_list_json_requires_current_only() {
+        assert!(Cli::try_parse_from(["cc-switch", "provider", "list", "--json"]).is_err());
+    }
+
+    #[test]
+    fn single_app_rejects_app_all() {
+        let cli = Cli::parse_from(["cc-switch", "--app", "codex", "mcp", "list"]);
+        assert_eq!(
+            super::single_app(cli.app).expect("single app"),
+            Some(super::AppType::Codex)
+        );
+        assert!(super::single_app(Some(super::AppSelection::All)).is_err());
+    }
+
+    fn parse_switch_target(args: &[&str]) -> (super::AppType, String) {
+        let cli = Cli::parse_from(args);
+        let explicit_app = super::single_app(cli.app).expect("single app");
+        match cli.command {
+            Some(Commands::Provider(super::commands::provider::ProviderCommand::Switch {
+                id,
+                provider,
+                ..
+            })) => super::commands::provider::resolve_switch_target(
+                explicit_app,
+                id.expect("provider id"),
+                provider,
+            )
+            .expect("switch target"),
+            _ => panic!("expected provider switch command"),
+        }
+    }
+
+    #[test]
+    fn provider_switch_accepts_positional_app_before_id() {
+        assert_eq!(
+            parse_switch_target(&["cc-switch", "provider", "switch", "codex", "p1"]),
+            (super::AppType::Codex, "p1".to_string())
+        );
+        assert_eq!(
+            parse_switch_target(&["cc-switch", "provider", "switch", "p1", "--app", "codex"]),
+            (super::AppType::Codex, "p1".to_string())
+        );
+        // 单个位置参数即使与应用同名也按 ID 处理
+        assert_eq!(
+            parse_switch_target(&["cc-switch", "provider", "switch", "codex"]),
+            (super::AppType::Claude, "codex".to_string())
+        );
+    }
+
+    #[test]
+    fn provider_switch_rejects_unknown_or_conflicting_positional_app() {
+        use super::commands::provider::resolve_switch_target;
+
+        assert!(resolve_switch_target(None, "p0".into(), Some("p1".into())).is_err());
+        assert!(resolve_switch_target(
+            Some(super::AppType::Gemini),
+            "codex".into(),
+            Some("p1".into())
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn parses_provider_switch_force_init_flag() {
+        let cli = Cli::parse_from(["cc-switch", "provider", "switch", "demo", "--force-init"]);
+
+        match cli.command {
+            Some(Commands::Provider(super::commands::provider::ProviderCommand::Switch {
+                id,
+                force_init,
+                ..
+            })) => {
+                assert_eq!(id.as_deref(), Some("demo"));
+                assert!(force_init);
+            }
+            _ => panic!("expected provider switch command"),
+        }
+    }
+
+    #[test]
+    fn provider_switch_next_and_prev_replace_the_id() {
+        let cli = Cli::parse_from(["cc-switch", "provider", "switch", "--next"]);
+        match cli.command {
+            Some(Commands::Provider(super::commands::provider::ProviderCommand::Switch {
+                id,
+                next,
+                prev,
+                ..
+            })) => assert_eq!((id, next, prev), (None, true, false)),
+            _ => panic!("expected provider switch command"),
+        }
+
+        for args in [
+            &["cc-switch", "provider", "switch"][..],
+            &["cc-switch", "provider", "switch", "demo", "--prev"],
+            &["cc-switch", "provider", "switch", "--next", "--prev"],
+        ] {
+            assert!(Cli::try_parse_from(args).is_err(), "{args:?}");
+        }
+    }
+
+    #[test]
+    fn provider_switch_id_flag_accepts_only_all_apps_options() {
+        let cli = Cli::parse_from([
+            "cc-switch",
+            "--app",
+            "all",
+            "provider",
+            "switch",
+            "--id",
+            "x",
+        ]);
+        match cli.command {
+            Some(Commands::Provider(super::commands::provider::ProviderCommand::Switch {
+                target_id,
+                ..
+            })) => assert_eq!(target_id.as_deref(), Some("x")),
+            _ => panic!("expected provider switch command"),
+        }
+
+        for flag in ["--wait-healthy", "--strict-mcp", "--no-backfill"] {
+            let args = [
+                "cc-switch",
+                "--app",
+                "all",
+                "provider",
+                "switch",
+                "--id",
+                "x",
+                flag,
+            ];
+            assert!(Cli::try_parse_from(args).is_err(), "{flag}");
+        }
+        for flag in ["--confirm", "--force-init", "--no-refresh-snapshot"] {
+            let args = [
+                "cc-switch",
+                "--app",
+                "all",
+                "provider",
+                "switch",
+                "--id",
+                "x",
+                flag,
+            ];
+            assert!(Cli::try_parse_from(args).is_ok(), "{flag}");
+        }
+        let args = [
+            "cc-switch",
+            "--app",
+            "all",
+            "provider",
+            "switch",
+            "--id",
+            "x",
+            "--set",
+            "a.b=1",
+        ];
+        assert!(Cli::try_parse_from(args).is_err());
+    }
+
+    #[test]
+    fn global_network_timeout_does_not_clash_with_command_timeout_flags() {
+        let cli = Cli::parse_from([
+            "cc-switch",
+            "provider",
+            "switch",
+            "p1",
+            "--network-timeout-ms",
+            "100",
+        ]);
+        assert_eq!(cli.network_timeout_ms, Some(100));
+
+        // `provider switch --timeout-ms` 仍只配合 --wait-healthy 使用
+        assert!(Cli::try_parse_from([
+            "cc-switch",
+            "provider",
+            "switch",
+            "p1",
+            "--timeout-ms",
+            "100"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn mcp_export_writes_to_output_or_stdout() {
+        use super::commands::mcp::McpCommand;
+
+        let cli = Cli::parse_from(["cc-switch", "mcp", "export", "--output", "mcp.json"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Mcp(McpCommand::Export { output: Some(ref path) }))
+                if path.as_os_str() == "mcp.json"
+        ));
+        let cli = Cli::parse_from(["cc-switch", "mcp", "export"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Mcp(McpCommand::Export { output: None }))
+        ));
+    }
+
+    #[test]
+    fn parses_config_secret_reveal_timeout() {
+        use super::commands::config::ConfigCommand;
+
+        let cli = Cli::parse_from(["cc-switch", "config", "secret-reveal-timeout", "--set", "0"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Config(ConfigCommand::SecretRevealTimeout {
+                secs: Some(0),
+                clear: false
+            }))
+        ));
+        assert!(Cli::try_parse_from([
+            "cc-switch",
+            "config",
+            "secret-reveal-timeout",
+            "--set",
+            "5",
+            "--clear"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn parses_config_switch_hooks() {
+        use super::commands::config::ConfigCommand;
+
+        let cli = Cli::parse_from(["cc-switch", "config", "switch-hooks", "--post", "notify"]);
+        match cli.command {
+            Some(Commands::Config(ConfigCommand::SwitchHooks {
+                pre_switch,
+                post_switch,
+                clear,
+            })) => {
+                assert_eq!(pre_switch, None);
+                assert_eq!(post_switch.as_deref(), Some("notify"));
+                assert!(!clear);
+            }
+            _ => panic!("expected config switch-hooks command"),
+        }
+        assert!(Cli::try_parse_from([
+            "cc-switch",
+            "config",
+            "switch-hooks",
+            "--pre",
+            "x",
+            "--clear"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn parses_config_show_redact_level_with_secrets_default() {
+        use super::commands::config::ConfigCommand;
+        use crate::redact::RedactLevel;
+
+        let cli = Cli::parse_from(["cc-switch", "config", "show"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Config(ConfigCommand::Show {
+                redact_level: RedactLevel::Secrets,
+                output: None,
+                only_current_providers: false
+            }))
+        ));
+
+        let cli = Cli::parse_from(["cc-switch", "config", "show", "--redact-level", "all"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Config(ConfigCommand::Show {
+                redact_level: RedactLevel::All,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn parses_config_backup_note_and_list_search() {
+        use super::commands::config::{BackupCommand, ConfigCommand};
+
+        let cli = Cli::parse_from(["cc-switch", "config", "backup", "--note", "before codex"]);
+        match cli.command {
+            Some(Commands::Config(ConfigCommand::Backup {
+                action: None, note, ..
+            })) => assert_eq!(note.as_deref(), Some("before codex")),
+            _ => panic!("expected config backup command"),
+        }
+
+        let cli = Cli::parse_from(["cc-switch", "config", "backup", "list", "--search", "codex"]);
+        match cli.command {
+            Some(Commands::Config(ConfigCommand::Backup {
+                action: Some(BackupCommand::List { search }),
+                ..
+            })) => assert_eq!(search.as_deref(), Some("codex")),
+            _ => panic!("expected config backup list command"),
+        }
+    }
+
     #[test]
     fn parses_provider_stream_check_subcommand() {
         let cli = Cli::parse_from(["cc-switch", "provider", "stream-check", "demo"]);
@@ -1,13 +1,15 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::prelude::Size;
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
 use unicode_width::UnicodeWidthChar;
 
 use crate::app_config::AppType;
 use crate::cli::i18n::current_language;
 use crate::cli::i18n::texts;
 use crate::cli::i18n::Language;
+use crate::services::provider::relative_provider_id;
 use crate::services::skill::SyncMethod;
+use crate::services::McpSortBy;
 
 use super::data::UiData;
 use super::form::{
@@ -38,8 +40,9 @@ pub(crate) use app_state::{
 pub use editor_state::{EditorKind, EditorMode, EditorState, EditorSubmit};
 use helpers::*;
 pub use types::{
-    ConfirmAction, ConfirmOverlay, FilterState, Focus, LoadingKind, Overlay, TextInputState,
-    TextSubmit, TextViewAction, TextViewState, Toast, ToastKind,
+    ConfirmAction, ConfirmOverlay, FilterState, Focus, LoadingKind, Overlay, ProviderGrouping,
+    SecretReveal, TextInputState, TextSubmit, TextViewAction, TextViewState, Toast, ToastKind,
+    ToastRecord, TOAST_HISTORY_CAP,
 };
 
 const PROVIDER_NOTES_MAX_CHARS: usize = 120;
@@ -56,6 +56,10 @@ pub enum Action {
     ProviderDelete {
         id: String,
     },
+    ProviderSetFavorite {
+        id: String,
+        favorite: bool,
+    },
     ProviderSpeedtest {
         url: String,
     },
@@ -91,6 +95,9 @@ pub enum Action {
     PromptDelete {
         id: String,
     },
+    PromptReorder {
+        ids: Vec<String>,
+    },
 
     ConfigExport {
         path: String,
@@ -122,6 +129,7 @@ pub enum Action {
         username: String,
         password: String,
     },
+    ConfigForceInitLive,
     ConfigReset,
 
     EditorSubmit {
@@ -134,6 +142,12 @@ pub enum Action {
     SetSkipClaudeOnboarding {
         enabled: bool,
     },
+    SetSkipSkillUninstallConfirm {
+        enabled: bool,
+    },
+    SetSecretRevealTimeout {
+        secs: Option<u64>,
+    },
     SetClaudePluginIntegration {
         enabled: bool,
     },
@@ -156,6 +170,26 @@ pub enum Action {
     CancelUpdateCheck,
 }
 
+impl Action {
+    /// 可通过“重复上次操作”再次执行的变更类操作（不含删除、导入等破坏性或交互式操作）
+    pub fn is_repeatable(&self) -> bool {
+        matches!(
+            self,
+            Action::ProviderSwitch { .. }
+                | Action::ProviderSetFavorite { .. }
+                | Action::McpToggle { .. }
+                | Action::McpSetApps { .. }
+                | Action::SkillsToggle { .. }
+                | Action::SkillsSetApps { .. }
+                | Action::SkillsSync { .. }
+                | Action::PromptActivate { .. }
+                | Action::PromptDeactivate { .. }
+                | Action::ConfigBackup { .. }
+                | Action::ConfigCommonSnippetApply { .. }
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ConfigItem {
     Path,
@@ -168,11 +202,12 @@ pub enum ConfigItem {
     CommonSnippet,
     Proxy,
     WebDavSync,
+    InitLive,
     Reset,
 }
 
 impl ConfigItem {
-    pub const ALL: [ConfigItem; 10] = [
+    pub const ALL: [ConfigItem; 11] = [
         ConfigItem::Path,
         ConfigItem::ShowFull,
         ConfigItem::Export,
@@ -182,6 +217,7 @@ impl ConfigItem {
         ConfigItem::Validate,
         ConfigItem::CommonSnippet,
         ConfigItem::WebDavSync,
+        ConfigItem::InitLive,
         ConfigItem::Reset,
     ];
 }
@@ -191,15 +227,21 @@ pub enum SettingsItem {
     Language,
     SkipClaudeOnboarding,
     ClaudePluginIntegration,
+    SkipSkillUninstallConfirm,
+    SwitchHooks,
+    SecretRevealTimeout,
     Proxy,
     CheckForUpdates,
 }
 
 impl SettingsItem {
-    pub const ALL: [SettingsItem; 4] = [
+    pub const ALL: [SettingsItem; 7] = [
         SettingsItem::Language,
         SettingsItem::SkipClaudeOnboarding,
         SettingsItem::ClaudePluginIntegration,
+        SettingsItem::SkipSkillUninstallConfirm,
+        SettingsItem::SwitchHooks,
+        SettingsItem::SecretRevealTimeout,
         SettingsItem::CheckForUpdates,
     ];
 }
@@ -247,6 +289,11 @@ pub struct App {
     pub form: Option<FormState>,
     pub overlay: Overlay,
     pub toast: Option<Toast>,
+    /// 本次会话的 toast 历史（最多 `TOAST_HISTORY_CAP` 条，仅内存）
+    pub toast_history: VecDeque<ToastRecord>,
+    pub secret_reveal: Option<SecretReveal>,
+    /// 本次会话中最近一次可重复执行的操作（仅内存，不持久化）
+    pub last_action: Option<(AppType, Action)>,
     pub should_quit: bool,
     pub last_size: Size,
     pub tick: u64,
@@ -261,7 +308,11 @@ pub struct App {
     pub local_env_loading: bool,
 
     pub provider_idx: usize,
+    pub provider_grouping: ProviderGrouping,
+    /// 仅显示收藏的供应商（`F`）
+    pub provider_favorites_only: bool,
     pub mcp_idx: usize,
+    pub mcp_sort: McpSortBy,
     pub prompt_idx: usize,
     pub skills_idx: usize,
     pub skills_discover_idx: usize,
@@ -94,6 +94,15 @@ impl App {
                     }
                     ConfigItem::Proxy => Action::ConfigOpenProxyHelp,
                     ConfigItem::WebDavSync => self.push_route_and_switch(Route::ConfigWebDav),
+                    ConfigItem::InitLive => {
+                        let app = self.app_type.as_str();
+                        self.overlay = Overlay::Confirm(ConfirmOverlay {
+                            title: texts::tui_config_init_live_title().to_string(),
+                            message: texts::tui_config_init_live_message(app),
+                            action: ConfirmAction::ConfigForceInitLive,
+                        });
+                        Action::None
+                    }
                     ConfigItem::Reset => {
                         self.overlay = Overlay::Confirm(ConfirmOverlay {
                             title: texts::tui_config_reset_title().to_string(),
@@ -239,6 +248,31 @@ impl App {
                     });
                     Action::None
                 }
+                Some(SettingsItem::SkipSkillUninstallConfirm) => {
+                    Action::SetSkipSkillUninstallConfirm {
+                        enabled: !crate::settings::get_skip_skill_uninstall_confirm(),
+                    }
+                }
+                Some(SettingsItem::SwitchHooks) => {
+                    let hooks = crate::settings::get_switch_hook_settings().unwrap_or_default();
+                    self.open_editor(
+                        texts::tui_switch_hooks_editor_title(),
+                        EditorKind::Json,
+                        serde_json::to_string_pretty(&hooks).unwrap_or_else(|_| "{}".to_string()),
+                        EditorSubmit::SettingsSwitchHooks,
+                    );
+                    Action::None
+                }
+                Some(SettingsItem::SecretRevealTimeout) => {
+                    self.overlay = Overlay::TextInput(TextInputState {
+                        title: texts::secret_reveal_timeout_label().to_string(),
+                        prompt: texts::tui_secret_reveal_timeout_prompt().to_string(),
+                        buffer: crate::settings::get_secret_reveal_timeout_secs().to_string(),
+                        submit: TextSubmit::SettingsSecretRevealTimeout,
+                        secret: false,
+                    });
+                    Action::None
+                }
                 Some(SettingsItem::Proxy) => Action::SetProxyEnabled {
                     enabled: !data.proxy.enabled,
                 },
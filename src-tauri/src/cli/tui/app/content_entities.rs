@@ -1,8 +1,29 @@
 use super::*;
 
 impl App {
+    /// 切换前预检凭证：目标供应商的 API Key 为空时先弹出确认框
+    fn provider_switch_action(&mut self, row: &super::data::ProviderRow) -> Action {
+        if crate::services::ProviderService::credentials_missing(&row.provider, &self.app_type) {
+            self.overlay = Overlay::Confirm(ConfirmOverlay {
+                title: texts::tui_confirm_switch_empty_credentials_title().to_string(),
+                message: texts::tui_confirm_switch_empty_credentials_message(
+                    &row.provider.name,
+                    &row.id,
+                ),
+                action: ConfirmAction::ProviderSwitch { id: row.id.clone() },
+            });
+            return Action::None;
+        }
+        Action::ProviderSwitch { id: row.id.clone() }
+    }
+
     pub(crate) fn on_providers_key(&mut self, key: KeyEvent, data: &UiData) -> Action {
-        let visible = visible_providers(&self.filter, data);
+        let visible = visible_providers(
+            &self.filter,
+            self.provider_grouping,
+            self.provider_favorites_only,
+            data,
+        );
         match key.code {
             KeyCode::Up => {
                 self.provider_idx = self.provider_idx.saturating_sub(1);
@@ -24,6 +45,53 @@ impl App {
                 self.open_provider_add_form();
                 Action::None
             }
+            KeyCode::Char('g') => {
+                let selected = visible.get(self.provider_idx).map(|row| row.id.clone());
+                self.provider_grouping = self.provider_grouping.next();
+                let regrouped = visible_providers(
+                    &self.filter,
+                    self.provider_grouping,
+                    self.provider_favorites_only,
+                    data,
+                );
+                self.provider_idx = selected
+                    .and_then(|id| regrouped.iter().position(|row| row.id == id))
+                    .unwrap_or(0);
+                let message = match self.provider_grouping {
+                    ProviderGrouping::Off => texts::tui_toast_provider_grouping_off(),
+                    ProviderGrouping::Tag => texts::tui_toast_provider_grouping_by_tag(),
+                    ProviderGrouping::Category => texts::tui_toast_provider_grouping_by_category(),
+                };
+                self.push_toast(message, ToastKind::Info);
+                Action::None
+            }
+            KeyCode::Char('f') => {
+                let Some(row) = visible.get(self.provider_idx) else {
+                    return Action::None;
+                };
+                Action::ProviderSetFavorite {
+                    id: row.id.clone(),
+                    favorite: !row.provider.is_favorite(),
+                }
+            }
+            KeyCode::Char('F') => {
+                let selected = visible.get(self.provider_idx).map(|row| row.id.clone());
+                self.provider_favorites_only = !self.provider_favorites_only;
+                let filtered = visible_providers(
+                    &self.filter,
+                    self.provider_grouping,
+                    self.provider_favorites_only,
+                    data,
+                );
+                self.provider_idx = selected
+                    .and_then(|id| filtered.iter().position(|row| row.id == id))
+                    .unwrap_or(0);
+                self.push_toast(
+                    texts::tui_toast_provider_favorites_only(self.provider_favorites_only),
+                    ToastKind::Info,
+                );
+                Action::None
+            }
             KeyCode::Char('e') => {
                 let Some(row) = visible.get(self.provider_idx) else {
                     return Action::None;
@@ -39,7 +107,27 @@ impl App {
                     self.push_toast(texts::tui_toast_provider_already_in_use(), ToastKind::Info);
                     return Action::None;
                 }
-                Action::ProviderSwitch { id: row.id.clone() }
+                self.provider_switch_action(row)
+            }
+            KeyCode::Char('n') | KeyCode::Char('p') => {
+                // 与 CLI `--next/--prev` 相同的排序，不受筛选与分组影响
+                let rows = &data.providers.rows;
+                let delta = if key.code == KeyCode::Char('n') {
+                    1
+                } else {
+                    -1
+                };
+                let entries = rows.iter().map(|row| (row.id.as_str(), &row.provider));
+                let Some(row) = relative_provider_id(entries, &data.providers.current_id, delta)
+                    .and_then(|id| rows.iter().find(|row| row.id == id))
+                else {
+                    return Action::None;
+                };
+                if row.is_current {
+                    self.push_toast(texts::tui_toast_provider_already_in_use(), ToastKind::Info);
+                    return Action::None;
+                }
+                self.provider_switch_action(row)
             }
             KeyCode::Char('d') => {
                 let Some(row) = visible.get(self.provider_idx) else {
@@ -108,7 +196,7 @@ impl App {
                     self.push_toast(texts::tui_toast_provider_already_in_use(), ToastKind::Info);
                     return Action::None;
                 }
-                Action::ProviderSwitch { id: row.id.clone() }
+                self.provider_switch_action(row)
             }
             KeyCode::Char('t') => {
                 let Some(url) = row.api_url.clone() else {
@@ -125,12 +213,21 @@ impl App {
                 };
                 Action::ProviderStreamCheck { id: row.id.clone() }
             }
+            KeyCode::Char('v') => {
+                self.secret_reveal = match self.secret_reveal {
+                    Some(_) => None,
+                    None => Some(SecretReveal::new(
+                        crate::settings::get_secret_reveal_timeout_secs(),
+                    )),
+                };
+                Action::None
+            }
             _ => Action::None,
         }
     }
 
     pub(crate) fn on_mcp_key(&mut self, key: KeyEvent, data: &UiData) -> Action {
-        let visible = visible_mcp(&self.filter, data);
+        let visible = visible_mcp(&self.filter, self.mcp_sort, &self.app_type, data);
         match key.code {
             KeyCode::Up => {
                 self.mcp_idx = self.mcp_idx.saturating_sub(1);
@@ -175,6 +272,21 @@ impl App {
                 };
                 Action::None
             }
+            KeyCode::Char('s') => {
+                let selected = visible.get(self.mcp_idx).map(|row| row.id.clone());
+                self.mcp_sort = self.mcp_sort.next();
+                let resorted = visible_mcp(&self.filter, self.mcp_sort, &self.app_type, data);
+                self.mcp_idx = selected
+                    .and_then(|id| resorted.iter().position(|row| row.id == id))
+                    .unwrap_or(0);
+                let message = match self.mcp_sort {
+                    McpSortBy::Id => texts::tui_toast_mcp_sort_by_id(),
+                    McpSortBy::Name => texts::tui_toast_mcp_sort_by_name(),
+                    McpSortBy::Enabled => texts::tui_toast_mcp_sort_by_enabled(),
+                };
+                self.push_toast(message, ToastKind::Info);
+                Action::None
+            }
             KeyCode::Char('i') => Action::McpImport,
             KeyCode::Char('d') => {
                 let Some(row) = visible.get(self.mcp_idx) else {
@@ -265,6 +377,26 @@ impl App {
                 );
                 Action::None
             }
+            KeyCode::Char('K') | KeyCode::Char('J') => {
+                // 与可见列表中的相邻项交换位置，过滤时也保持直观
+                let target = if key.code == KeyCode::Char('K') {
+                    self.prompt_idx.checked_sub(1)
+                } else {
+                    Some(self.prompt_idx + 1).filter(|idx| *idx < visible.len())
+                };
+                let (Some(row), Some(target)) = (visible.get(self.prompt_idx), target) else {
+                    return Action::None;
+                };
+                let mut ids: Vec<String> = data.prompts.rows.iter().map(|p| p.id.clone()).collect();
+                let from = ids.iter().position(|id| *id == row.id);
+                let to = ids.iter().position(|id| *id == visible[target].id);
+                let (Some(from), Some(to)) = (from, to) else {
+                    return Action::None;
+                };
+                ids.swap(from, to);
+                self.prompt_idx = target;
+                Action::PromptReorder { ids }
+            }
             _ => Action::None,
         }
     }
@@ -65,17 +65,7 @@ impl App {
                 let Some(skill) = visible.get(self.skills_idx) else {
                     return Action::None;
                 };
-                self.overlay = Overlay::Confirm(ConfirmOverlay {
-                    title: texts::tui_skills_uninstall_title().to_string(),
-                    message: texts::tui_confirm_uninstall_skill_message(
-                        &skill.name,
-                        &skill.directory,
-                    ),
-                    action: ConfirmAction::SkillsUninstall {
-                        directory: skill.directory.clone(),
-                    },
-                });
-                Action::None
+                self.request_skill_uninstall(skill)
             }
             KeyCode::Char('i') => Action::SkillsOpenImport,
             KeyCode::Char('f') => self.push_route_and_switch(Route::SkillsDiscover),
@@ -205,19 +195,7 @@ impl App {
                 };
                 Action::None
             }
-            KeyCode::Char('d') => {
-                self.overlay = Overlay::Confirm(ConfirmOverlay {
-                    title: texts::tui_skills_uninstall_title().to_string(),
-                    message: texts::tui_confirm_uninstall_skill_message(
-                        &skill.name,
-                        &skill.directory,
-                    ),
-                    action: ConfirmAction::SkillsUninstall {
-                        directory: skill.directory.clone(),
-                    },
-                });
-                Action::None
-            }
+            KeyCode::Char('d') => self.request_skill_uninstall(skill),
             KeyCode::Char('s') => Action::SkillsSync {
                 app: Some(self.app_type.clone()),
             },
@@ -225,4 +203,38 @@ impl App {
             _ => Action::None,
         }
     }
+
+    /// 卸载前弹出确认（列出已启用的应用）；设置了跳过确认时直接卸载
+    fn request_skill_uninstall(
+        &mut self,
+        skill: &crate::services::skill::InstalledSkill,
+    ) -> Action {
+        if crate::settings::get_skip_skill_uninstall_confirm() {
+            return Action::SkillsUninstall {
+                directory: skill.directory.clone(),
+            };
+        }
+
+        let enabled_apps = AppType::all()
+            .filter(|app| skill.apps.is_enabled_for(app))
+            .map(|app| app.as_str())
+            .collect::<Vec<_>>();
+        let enabled_apps = if enabled_apps.is_empty() {
+            texts::none().to_string()
+        } else {
+            enabled_apps.join(", ")
+        };
+        self.overlay = Overlay::Confirm(ConfirmOverlay {
+            title: texts::tui_skills_uninstall_title().to_string(),
+            message: texts::tui_confirm_uninstall_skill_message(
+                &skill.name,
+                &skill.directory,
+                &enabled_apps,
+            ),
+            action: ConfirmAction::SkillsUninstall {
+                directory: skill.directory.clone(),
+            },
+        });
+        Action::None
+    }
 }
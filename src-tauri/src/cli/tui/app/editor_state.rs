@@ -18,6 +18,7 @@ pub enum EditorSubmit {
     McpEdit { id: String },
     ConfigCommonSnippet { app_type: AppType },
     ConfigWebDavSettings,
+    SettingsSwitchHooks,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -24,29 +24,43 @@ pub(crate) fn route_default_focus(route: &Route) -> Focus {
     }
 }
 
+/// 过滤后的供应商，按分组后的显示顺序排列（导航索引与此一一对应）
 pub(crate) fn visible_providers<'a>(
     filter: &FilterState,
+    grouping: ProviderGrouping,
+    favorites_only: bool,
     data: &'a UiData,
 ) -> Vec<&'a super::data::ProviderRow> {
     let query = filter.query_lower();
-    data.providers
+    let rows = data
+        .providers
         .rows
         .iter()
+        .filter(|row| !favorites_only || row.provider.is_favorite())
         .filter(|row| match &query {
             None => true,
             Some(q) => {
                 row.provider.name.to_lowercase().contains(q) || row.id.to_lowercase().contains(q)
             }
         })
+        .collect();
+    grouping
+        .group(rows)
+        .into_iter()
+        .flat_map(|(_, members)| members)
         .collect()
 }
 
+/// 过滤后的 MCP 服务器，按 `sort` 排列（导航索引与此一一对应）
 pub(crate) fn visible_mcp<'a>(
     filter: &FilterState,
+    sort: McpSortBy,
+    app_type: &AppType,
     data: &'a UiData,
 ) -> Vec<&'a super::data::McpRow> {
     let query = filter.query_lower();
-    data.mcp
+    let mut rows: Vec<_> = data
+        .mcp
         .rows
         .iter()
         .filter(|row| match &query {
@@ -55,7 +69,9 @@ pub(crate) fn visible_mcp<'a>(
                 row.server.name.to_lowercase().contains(q) || row.id.to_lowercase().contains(q)
             }
         })
-        .collect()
+        .collect();
+    rows.sort_by(|a, b| sort.compare(app_type, (&a.id, &a.server), (&b.id, &b.server)));
+    rows
 }
 
 pub(crate) fn visible_prompts<'a>(
@@ -178,6 +194,7 @@ pub(crate) fn config_item_label(item: &ConfigItem) -> &'static str {
         ConfigItem::CommonSnippet => crate::cli::i18n::texts::tui_config_item_common_snippet(),
         ConfigItem::Proxy => crate::cli::i18n::texts::tui_config_item_proxy(),
         ConfigItem::WebDavSync => crate::cli::i18n::texts::tui_config_item_webdav_sync(),
+        ConfigItem::InitLive => crate::cli::i18n::texts::tui_config_item_init_live(),
         ConfigItem::Reset => crate::cli::i18n::texts::tui_config_item_reset(),
     }
 }
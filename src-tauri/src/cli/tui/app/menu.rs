@@ -17,6 +17,9 @@ impl App {
             form: None,
             overlay: Overlay::None,
             toast: None,
+            toast_history: VecDeque::new(),
+            secret_reveal: None,
+            last_action: None,
             should_quit: false,
             last_size: Size::new(0, 0),
             tick: 0,
@@ -29,7 +32,10 @@ impl App {
             local_env_results: Vec::new(),
             local_env_loading: true,
             provider_idx: 0,
+            provider_grouping: ProviderGrouping::Off,
+            provider_favorites_only: false,
             mcp_idx: 0,
+            mcp_sort: McpSortBy::Id,
             prompt_idx: 0,
             skills_idx: 0,
             skills_discover_idx: 0,
@@ -76,6 +82,7 @@ impl App {
 
         self.route = route.clone();
         self.focus = route_default_focus(&route);
+        self.secret_reveal = None;
 
         let nav_item = Self::nav_item_for_route(&route);
         if let Some(idx) = NavItem::ALL.iter().position(|item| *item == nav_item) {
@@ -117,6 +124,15 @@ impl App {
             }
         }
 
+        if let Some(reveal) = &mut self.secret_reveal {
+            if let Some(ticks) = &mut reveal.remaining_ticks {
+                *ticks = ticks.saturating_sub(1);
+                if *ticks == 0 {
+                    self.secret_reveal = None;
+                }
+            }
+        }
+
         if let Some(transition) = self.proxy_visual_transition {
             if self.tick.saturating_sub(transition.started_tick) >= PROXY_HERO_TRANSITION_TICKS {
                 self.proxy_visual_transition = None;
@@ -189,9 +205,68 @@ impl App {
     }
 
     pub fn push_toast(&mut self, message: impl Into<String>, kind: ToastKind) {
+        let message = message.into();
+        if self.toast_history.len() >= TOAST_HISTORY_CAP {
+            self.toast_history.pop_front();
+        }
+        self.toast_history.push_back(ToastRecord {
+            at: chrono::Local::now(),
+            message: message.clone(),
+            kind,
+        });
         self.toast = Some(Toast::new(message, kind));
     }
 
+    /// 打开 toast 历史（最新的在前）
+    pub fn open_toast_history(&mut self) {
+        let lines = if self.toast_history.is_empty() {
+            vec![texts::tui_toast_history_empty().to_string()]
+        } else {
+            self.toast_history
+                .iter()
+                .rev()
+                .map(|record| {
+                    format!(
+                        "{} [{:<5}] {}",
+                        record.at.format("%H:%M:%S"),
+                        record.kind.label(),
+                        record.message
+                    )
+                })
+                .collect()
+        };
+        self.overlay = Overlay::TextView(TextViewState {
+            title: texts::tui_toast_history_title().to_string(),
+            lines,
+            scroll: 0,
+            action: None,
+        });
+    }
+
+    /// 记录成功执行的操作，供“重复上次操作”使用
+    pub fn remember_action(&mut self, action: &Action) {
+        if action.is_repeatable() {
+            self.last_action = Some((self.app_type.clone(), action.clone()));
+        }
+    }
+
+    pub fn repeat_last_action(&mut self) -> Action {
+        match self.last_action.clone() {
+            None => {
+                self.push_toast(texts::tui_toast_no_last_action(), ToastKind::Info);
+                Action::None
+            }
+            Some((app_type, _)) if app_type != self.app_type => {
+                self.push_toast(
+                    texts::tui_toast_last_action_other_app(app_type.as_str()),
+                    ToastKind::Warning,
+                );
+                Action::None
+            }
+            Some((_, action)) => action,
+        }
+    }
+
     pub fn open_help(&mut self) {
         self.overlay = Overlay::Help;
     }
@@ -224,6 +299,14 @@ impl App {
             return self.on_filter_key(key);
         }
 
+        // 须在 hjkl 映射之前处理，否则 Ctrl+L 会被当作 Ctrl+Right
+        if key.modifiers.contains(KeyModifiers::CONTROL)
+            && matches!(key.code, KeyCode::Char('l' | 'L'))
+        {
+            self.open_toast_history();
+            return Action::None;
+        }
+
         // Vim-style hjkl navigation
         let key = match key.code {
             KeyCode::Char('h') => KeyEvent::new(KeyCode::Left, key.modifiers),
@@ -269,6 +352,13 @@ impl App {
             return self.main_proxy_action(data);
         }
 
+        if self.focus == Focus::Content
+            && matches!(key.code, KeyCode::PageUp | KeyCode::PageDown)
+            && self.on_list_page_key(key, data)
+        {
+            return Action::None;
+        }
+
         // Navigation + route-specific actions.
         match self.focus {
             Focus::Nav => self.on_nav_key(key),
@@ -325,6 +415,8 @@ impl App {
             KeyCode::Enter => {
                 if let Some(route) = self.nav_item().to_route() {
                     self.push_route_and_switch(route)
+                } else if self.nav_item() == NavItem::RepeatLast {
+                    self.repeat_last_action()
                 } else {
                     self.overlay = Overlay::Confirm(ConfirmOverlay {
                         title: crate::cli::i18n::texts::tui_confirm_exit_title().to_string(),
@@ -338,6 +430,44 @@ impl App {
         }
     }
 
+    /// 长列表按一屏翻页；返回 false 表示当前页面不是可翻页的列表
+    pub(crate) fn on_list_page_key(&mut self, key: KeyEvent, data: &UiData) -> bool {
+        let step = self.list_page_rows();
+        let idx = match self.route {
+            Route::Providers => &mut self.provider_idx,
+            Route::Mcp => &mut self.mcp_idx,
+            Route::Skills => &mut self.skills_idx,
+            Route::SkillsDiscover => &mut self.skills_discover_idx,
+            Route::SkillsRepos => &mut self.skills_repo_idx,
+            _ => return false,
+        };
+        *idx = match key.code {
+            KeyCode::PageUp => idx.saturating_sub(step),
+            _ => idx.saturating_add(step),
+        };
+        self.clamp_selections(data);
+        true
+    }
+
+    /// 当前列表页一屏可显示的数据行数
+    pub(crate) fn list_page_rows(&self) -> usize {
+        // Matches `render()` + `render_content()` layout math in `ui.rs`, like `editor_viewport_size`.
+        let mut height = self.last_size.height.saturating_sub(3).saturating_sub(1);
+        if self.filter.active || !self.filter.buffer.trim().is_empty() {
+            height = height.saturating_sub(5);
+        }
+
+        // outer borders (2) + table header (1) + the page's key bar / summary rows
+        let chrome = match self.route {
+            Route::Providers => 2 + 1 + 1,
+            Route::Mcp | Route::Skills => 2 + 1 + 1 + 3,
+            Route::SkillsDiscover => 2 + 1 + 2,
+            Route::SkillsRepos => 2 + 1 + 2 + 1,
+            _ => 2 + 1,
+        };
+        height.saturating_sub(chrome).max(1) as usize
+    }
+
     pub(crate) fn on_content_key(&mut self, key: KeyEvent, data: &UiData) -> Action {
         match self.route.clone() {
             Route::Providers => self.on_providers_key(key, data),
@@ -359,14 +489,20 @@ impl App {
         }
     }
     pub(crate) fn clamp_selections(&mut self, data: &UiData) {
-        let providers_len = visible_providers(&self.filter, data).len();
+        let providers_len = visible_providers(
+            &self.filter,
+            self.provider_grouping,
+            self.provider_favorites_only,
+            data,
+        )
+        .len();
         if providers_len == 0 {
             self.provider_idx = 0;
         } else {
             self.provider_idx = self.provider_idx.min(providers_len - 1);
         }
 
-        let mcp_len = visible_mcp(&self.filter, data).len();
+        let mcp_len = visible_mcp(&self.filter, self.mcp_sort, &self.app_type, data).len();
         if mcp_len == 0 {
             self.mcp_idx = 0;
         } else {
@@ -30,6 +30,9 @@ impl App {
                     ConfirmAction::ProviderDelete { id } => {
                         Action::ProviderDelete { id: id.clone() }
                     }
+                    ConfirmAction::ProviderSwitch { id } => {
+                        Action::ProviderSwitch { id: id.clone() }
+                    }
                     ConfirmAction::McpDelete { id } => Action::McpDelete { id: id.clone() },
                     ConfirmAction::PromptDelete { id } => Action::PromptDelete { id: id.clone() },
                     ConfirmAction::SkillsUninstall { directory } => Action::SkillsUninstall {
@@ -45,6 +48,7 @@ impl App {
                     ConfirmAction::ConfigRestoreBackup { id } => {
                         Action::ConfigRestoreBackup { id: id.clone() }
                     }
+                    ConfirmAction::ConfigForceInitLive => Action::ConfigForceInitLive,
                     ConfirmAction::ConfigReset => Action::ConfigReset,
                     ConfirmAction::SettingsSetSkipClaudeOnboarding { enabled } => {
                         Action::SetSkipClaudeOnboarding { enabled: *enabled }
@@ -175,6 +179,21 @@ impl App {
             }
             TextSubmit::WebDavJianguoyunUsername => self.handle_webdav_username_submit(raw),
             TextSubmit::WebDavJianguoyunPassword => self.handle_webdav_password_submit(raw),
+            TextSubmit::SettingsSecretRevealTimeout => {
+                if raw.is_empty() {
+                    return Action::SetSecretRevealTimeout { secs: None };
+                }
+                match raw.parse::<u64>() {
+                    Ok(secs) => Action::SetSecretRevealTimeout { secs: Some(secs) },
+                    Err(_) => {
+                        self.push_toast(
+                            texts::tui_toast_secret_reveal_timeout_invalid(),
+                            ToastKind::Warning,
+                        );
+                        Action::None
+                    }
+                }
+            }
         }
     }
 
@@ -32,6 +32,70 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn toast_history_evicts_oldest_beyond_cap_and_opens_with_ctrl_l() {
+        let mut app = App::new(Some(AppType::Claude));
+        for i in 0..TOAST_HISTORY_CAP + 3 {
+            app.push_toast(format!("toast {i}"), ToastKind::Info);
+        }
+
+        assert_eq!(app.toast_history.len(), TOAST_HISTORY_CAP);
+        assert_eq!(
+            app.toast_history.front().map(|r| r.message.as_str()),
+            Some("toast 3")
+        );
+
+        app.on_key(ctrl(KeyCode::Char('l')), &data());
+        let Overlay::TextView(view) = &app.overlay else {
+            panic!("Ctrl+L should open the toast history overlay");
+        };
+        assert_eq!(view.lines.len(), TOAST_HISTORY_CAP);
+        assert!(view.lines[0].ends_with(&format!("toast {}", TOAST_HISTORY_CAP + 2)));
+    }
+
+    #[test]
+    fn remember_action_records_only_repeatable_actions() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.remember_action(&Action::ProviderSwitch { id: "p1".into() });
+        app.remember_action(&Action::ProviderDelete { id: "p2".into() });
+        app.remember_action(&Action::ReloadData);
+
+        assert!(matches!(
+            app.last_action.as_ref(),
+            Some((AppType::Claude, Action::ProviderSwitch { id })) if id == "p1"
+        ));
+    }
+
+    #[test]
+    fn repeat_last_nav_entry_replays_recorded_action_for_same_app() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.nav_idx = NavItem::ALL
+            .iter()
+            .position(|item| *item == NavItem::RepeatLast)
+            .expect("repeat nav item should exist");
+
+        assert!(matches!(
+            app.on_key(key(KeyCode::Enter), &data()),
+            Action::None
+        ));
+        assert!(app.toast.is_some());
+
+        app.remember_action(&Action::McpToggle {
+            id: "m1".into(),
+            enabled: true,
+        });
+        assert!(matches!(
+            app.on_key(key(KeyCode::Enter), &data()),
+            Action::McpToggle { id, enabled: true } if id == "m1"
+        ));
+
+        app.app_type = AppType::Codex;
+        assert!(matches!(
+            app.on_key(key(KeyCode::Enter), &data()),
+            Action::None
+        ));
+    }
+
     #[test]
     fn skills_nav_item_routes_to_skills_page() {
         assert_eq!(
@@ -84,6 +148,7 @@ mod tests {
                 repo_owner: None,
                 repo_name: None,
                 repo_branch: None,
+                pinned_ref: None,
                 readme_url: None,
                 apps: crate::app_config::SkillApps::default(),
                 installed_at: 0,
@@ -119,6 +184,7 @@ mod tests {
                 repo_owner: None,
                 repo_name: None,
                 repo_branch: None,
+                pinned_ref: None,
                 readme_url: None,
                 apps: crate::app_config::SkillApps::default(),
                 installed_at: 0,
@@ -158,8 +224,9 @@ mod tests {
                 repo_owner: None,
                 repo_name: None,
                 repo_branch: None,
+                pinned_ref: None,
                 readme_url: None,
-                apps: crate::app_config::SkillApps::default(),
+                apps: crate::app_config::SkillApps::only(&AppType::Codex),
                 installed_at: 0,
             });
 
@@ -169,8 +236,9 @@ mod tests {
             &app.overlay,
             Overlay::Confirm(ConfirmOverlay {
                 action: ConfirmAction::SkillsUninstall { directory },
+                message,
                 ..
-            }) if directory == "hello-skill"
+            }) if directory == "hello-skill" && message.contains("codex") && !message.contains("claude")
         ));
     }
 
@@ -488,7 +556,10 @@ mod tests {
             provider: crate::provider::Provider::with_id(
                 "p1".to_string(),
                 "Provider One".to_string(),
-                json!({"env":{"ANTHROPIC_BASE_URL":"https://example.com"}}),
+                json!({"env":{
+                    "ANTHROPIC_BASE_URL":"https://example.com",
+                    "ANTHROPIC_AUTH_TOKEN":"sk-test"
+                }}),
                 None,
             ),
             api_url: Some("https://example.com".to_string()),
@@ -499,6 +570,37 @@ mod tests {
         assert!(matches!(action, Action::ProviderSwitch { id } if id == "p1"));
     }
 
+    #[test]
+    fn providers_n_and_p_keys_switch_relative_to_current_with_wrap() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Providers;
+        app.focus = Focus::Content;
+
+        let mut data = UiData::default();
+        for (id, is_current) in [("p1", false), ("p2", false), ("p3", true)] {
+            data.providers.rows.push(super::super::data::ProviderRow {
+                id: id.to_string(),
+                provider: crate::provider::Provider::with_id(
+                    id.to_string(),
+                    id.to_string(),
+                    json!({"env":{
+                        "ANTHROPIC_BASE_URL":"https://example.com",
+                        "ANTHROPIC_AUTH_TOKEN":"sk-test"
+                    }}),
+                    None,
+                ),
+                api_url: Some("https://example.com".to_string()),
+                is_current,
+            });
+        }
+        data.providers.current_id = "p3".to_string();
+
+        let action = app.on_key(key(KeyCode::Char('n')), &data);
+        assert!(matches!(action, Action::ProviderSwitch { id } if id == "p1"));
+        let action = app.on_key(key(KeyCode::Char('p')), &data);
+        assert!(matches!(action, Action::ProviderSwitch { id } if id == "p2"));
+    }
+
     #[test]
     fn providers_c_key_requests_stream_check() {
         let mut app = App::new(Some(AppType::Claude));
@@ -553,6 +655,208 @@ mod tests {
         );
     }
 
+    #[test]
+    fn providers_f_toggles_favorite_and_shift_f_shows_favorites_only() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Providers;
+        app.focus = Focus::Content;
+
+        let row = |id: &str, favorite: bool| {
+            let mut provider =
+                crate::provider::Provider::with_id(id.to_string(), id.to_string(), json!({}), None);
+            if favorite {
+                provider.meta = Some(crate::provider::ProviderMeta {
+                    favorite: Some(true),
+                    ..Default::default()
+                });
+            }
+            super::super::data::ProviderRow {
+                id: id.to_string(),
+                provider,
+                api_url: None,
+                is_current: false,
+            }
+        };
+        let mut data = UiData::default();
+        data.providers.rows = vec![row("a", false), row("b", true), row("c", true)];
+
+        app.provider_idx = 0;
+        let action = app.on_key(key(KeyCode::Char('f')), &data);
+        assert!(matches!(
+            action,
+            Action::ProviderSetFavorite { ref id, favorite: true } if id == "a"
+        ));
+
+        // 选中 "c" 后只显示收藏，选中项保持不变
+        app.provider_idx = 2;
+        app.on_key(key(KeyCode::Char('F')), &data);
+        assert!(app.provider_favorites_only);
+        let order: Vec<&str> = visible_providers(&app.filter, app.provider_grouping, true, &data)
+            .iter()
+            .map(|row| row.id.as_str())
+            .collect();
+        assert_eq!(order, vec!["b", "c"]);
+        assert_eq!(app.provider_idx, 1);
+
+        let action = app.on_key(key(KeyCode::Char('f')), &data);
+        assert!(matches!(
+            action,
+            Action::ProviderSetFavorite { ref id, favorite: false } if id == "c"
+        ));
+
+        app.on_key(key(KeyCode::Char('F')), &data);
+        assert!(!app.provider_favorites_only);
+    }
+
+    #[test]
+    fn providers_g_key_groups_by_tag_and_navigation_follows_group_order() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Providers;
+        app.focus = Focus::Content;
+
+        let row = |id: &str, tag: Option<&str>| {
+            let mut provider =
+                crate::provider::Provider::with_id(id.to_string(), id.to_string(), json!({}), None);
+            if let Some(tag) = tag {
+                provider.meta = Some(crate::provider::ProviderMeta {
+                    tags: vec![tag.to_string()],
+                    ..Default::default()
+                });
+            }
+            super::super::data::ProviderRow {
+                id: id.to_string(),
+                provider,
+                api_url: None,
+                is_current: false,
+            }
+        };
+        let mut data = UiData::default();
+        data.providers.rows = vec![
+            row("a", Some("work")),
+            row("b", None),
+            row("c", Some("home")),
+            row("d", Some("work")),
+        ];
+
+        // 选中 "d" 后切换分组，选中项保持不变
+        app.provider_idx = 3;
+        app.on_key(key(KeyCode::Char('g')), &data);
+        assert_eq!(app.provider_grouping, ProviderGrouping::Tag);
+
+        let order: Vec<&str> = visible_providers(&app.filter, app.provider_grouping, false, &data)
+            .iter()
+            .map(|row| row.id.as_str())
+            .collect();
+        assert_eq!(order, vec!["c", "a", "d", "b"]);
+        assert_eq!(app.provider_idx, 2);
+
+        // 导航跳过分组标题，直接移动到下一组的供应商
+        app.on_key(key(KeyCode::Down), &data);
+        let action = app.on_key(key(KeyCode::Enter), &data);
+        assert!(matches!(
+            action,
+            Action::SwitchRoute(Route::ProviderDetail { ref id }) if id == "b"
+        ));
+
+        app.on_key(key(KeyCode::Esc), &data);
+        app.on_key(key(KeyCode::Char('g')), &data);
+        app.on_key(key(KeyCode::Char('g')), &data);
+        assert_eq!(app.provider_grouping, ProviderGrouping::Off);
+    }
+
+    #[test]
+    fn provider_detail_v_key_reveals_secret_until_timeout_expires() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::ProviderDetail {
+            id: "p1".to_string(),
+        };
+        app.focus = Focus::Content;
+
+        let mut data = UiData::default();
+        data.providers.rows.push(super::super::data::ProviderRow {
+            id: "p1".to_string(),
+            provider: crate::provider::Provider::with_id(
+                "p1".to_string(),
+                "Provider One".to_string(),
+                json!({"env":{"ANTHROPIC_AUTH_TOKEN":"sk-demo-secret"}}),
+                None,
+            ),
+            api_url: None,
+            is_current: false,
+        });
+
+        app.on_key(key(KeyCode::Char('v')), &data);
+        assert!(app.secret_reveal.is_some());
+        app.on_key(key(KeyCode::Char('v')), &data);
+        assert!(
+            app.secret_reveal.is_none(),
+            "v should toggle the secret off"
+        );
+
+        // 2 秒 = 10 个 tick（200ms）
+        app.secret_reveal = Some(SecretReveal::new(2));
+        assert_eq!(app.secret_reveal.and_then(|r| r.remaining_secs()), Some(2));
+        for _ in 0..9 {
+            app.on_tick();
+        }
+        assert_eq!(app.secret_reveal.and_then(|r| r.remaining_secs()), Some(1));
+        app.on_tick();
+        assert!(
+            app.secret_reveal.is_none(),
+            "secret should re-mask after timeout"
+        );
+
+        // 0 表示直到手动关闭
+        app.secret_reveal = Some(SecretReveal::new(0));
+        for _ in 0..100 {
+            app.on_tick();
+        }
+        assert!(app.secret_reveal.is_some());
+
+        app.on_key(key(KeyCode::Esc), &data);
+        assert!(
+            app.secret_reveal.is_none(),
+            "leaving the detail view should re-mask"
+        );
+    }
+
+    #[test]
+    fn providers_s_key_confirms_before_switching_to_empty_api_key() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Providers;
+        app.focus = Focus::Content;
+
+        let mut data = UiData::default();
+        data.providers.rows.push(super::super::data::ProviderRow {
+            id: "p1".to_string(),
+            provider: crate::provider::Provider::with_id(
+                "p1".to_string(),
+                "Provider One".to_string(),
+                json!({"env":{
+                    "ANTHROPIC_BASE_URL":"https://example.com",
+                    "ANTHROPIC_AUTH_TOKEN":""
+                }}),
+                None,
+            ),
+            api_url: Some("https://example.com".to_string()),
+            is_current: false,
+        });
+
+        let action = app.on_key(key(KeyCode::Char('s')), &data);
+        assert!(matches!(action, Action::None));
+        assert!(matches!(
+            &app.overlay,
+            Overlay::Confirm(ConfirmOverlay {
+                action: ConfirmAction::ProviderSwitch { id },
+                ..
+            }) if id == "p1"
+        ));
+
+        let action = app.on_key(key(KeyCode::Char('y')), &data);
+        assert!(matches!(action, Action::ProviderSwitch { id } if id == "p1"));
+        assert!(matches!(app.overlay, Overlay::None));
+    }
+
     #[test]
     fn provider_detail_s_key_triggers_switch_action_and_enter_is_noop() {
         let mut app = App::new(Some(AppType::Claude));
@@ -567,7 +871,10 @@ mod tests {
             provider: crate::provider::Provider::with_id(
                 "p1".to_string(),
                 "Provider One".to_string(),
-                json!({"env":{"ANTHROPIC_BASE_URL":"https://example.com"}}),
+                json!({"env":{
+                    "ANTHROPIC_BASE_URL":"https://example.com",
+                    "ANTHROPIC_AUTH_TOKEN":"sk-test"
+                }}),
                 None,
             ),
             api_url: Some("https://example.com".to_string()),
@@ -612,6 +919,52 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn mcp_s_cycles_sort_and_keeps_selection() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Mcp;
+        app.focus = Focus::Content;
+
+        let mut data = UiData::default();
+        for (id, name, claude) in [("a-id", "Zeta", false), ("b-id", "alpha", true)] {
+            data.mcp.rows.push(super::super::data::McpRow {
+                id: id.to_string(),
+                server: crate::app_config::McpServer {
+                    id: id.to_string(),
+                    name: name.to_string(),
+                    server: json!({}),
+                    apps: crate::app_config::McpApps {
+                        claude,
+                        ..Default::default()
+                    },
+                    description: None,
+                    homepage: None,
+                    docs: None,
+                    tags: vec![],
+                },
+            });
+        }
+        let order = |app: &App| {
+            visible_mcp(&app.filter, app.mcp_sort, &app.app_type, &data)
+                .iter()
+                .map(|row| row.id.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(order(&app), vec!["a-id", "b-id"]);
+
+        app.on_key(key(KeyCode::Char('s')), &data);
+        assert_eq!(app.mcp_sort, McpSortBy::Name);
+        assert_eq!(order(&app), vec!["b-id", "a-id"]);
+        assert_eq!(app.mcp_idx, 1, "selection should follow the server");
+
+        app.on_key(key(KeyCode::Char('s')), &data);
+        assert_eq!(app.mcp_sort, McpSortBy::Enabled);
+        assert_eq!(order(&app), vec!["b-id", "a-id"]);
+
+        app.on_key(key(KeyCode::Char('s')), &data);
+        assert_eq!(app.mcp_sort, McpSortBy::Id);
+    }
+
     #[test]
     fn mcp_a_opens_add_form() {
         let mut app = App::new(Some(AppType::Claude));
@@ -794,6 +1147,7 @@ mod tests {
                 enabled: false,
                 created_at: None,
                 updated_at: None,
+                sort_index: None,
             },
         });
 
@@ -801,6 +1155,42 @@ mod tests {
         assert!(matches!(action, Action::PromptActivate { id } if id == "pr1"));
     }
 
+    #[test]
+    fn prompts_shift_j_k_swap_with_neighbor_and_follow_selection() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Prompts;
+        app.focus = Focus::Content;
+
+        let mut data = UiData::default();
+        for id in ["pr1", "pr2", "pr3"] {
+            data.prompts.rows.push(super::super::data::PromptRow {
+                id: id.to_string(),
+                prompt: crate::prompt::Prompt {
+                    id: id.to_string(),
+                    name: id.to_string(),
+                    content: String::new(),
+                    description: None,
+                    enabled: false,
+                    created_at: None,
+                    updated_at: None,
+                    sort_index: None,
+                },
+            });
+        }
+
+        let action = app.on_key(key(KeyCode::Char('J')), &data);
+        assert!(
+            matches!(action, Action::PromptReorder { ref ids } if ids == &["pr2", "pr1", "pr3"])
+        );
+        assert_eq!(app.prompt_idx, 1);
+
+        app.prompt_idx = 0;
+        assert!(matches!(
+            app.on_key(key(KeyCode::Char('K')), &data),
+            Action::None
+        ));
+    }
+
     #[test]
     fn back_from_provider_detail_returns_to_providers() {
         let mut app = App::new(Some(AppType::Claude));
@@ -1267,6 +1657,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn settings_switch_hooks_opens_json_editor() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Settings;
+        app.focus = Focus::Content;
+        app.settings_idx = SettingsItem::ALL
+            .iter()
+            .position(|item| matches!(item, SettingsItem::SwitchHooks))
+            .expect("SwitchHooks missing from SettingsItem::ALL");
+
+        let action = app.on_key(key(KeyCode::Enter), &UiData::default());
+        assert!(matches!(action, Action::None));
+        assert!(matches!(
+            app.editor.as_ref().map(|e| &e.submit),
+            Some(EditorSubmit::SettingsSwitchHooks)
+        ));
+    }
+
+    #[test]
+    fn settings_secret_reveal_timeout_submits_parsed_seconds() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Settings;
+        app.focus = Focus::Content;
+        app.settings_idx = SettingsItem::ALL
+            .iter()
+            .position(|item| matches!(item, SettingsItem::SecretRevealTimeout))
+            .expect("SecretRevealTimeout missing from SettingsItem::ALL");
+
+        let data = UiData::default();
+        app.on_key(key(KeyCode::Enter), &data);
+        let Overlay::TextInput(ref mut input) = app.overlay else {
+            panic!("expected a text input overlay");
+        };
+        assert_eq!(input.submit, TextSubmit::SettingsSecretRevealTimeout);
+        input.buffer = "30".to_string();
+        assert!(matches!(
+            app.on_key(key(KeyCode::Enter), &data),
+            Action::SetSecretRevealTimeout { secs: Some(30) }
+        ));
+
+        app.on_key(key(KeyCode::Enter), &data);
+        if let Overlay::TextInput(ref mut input) = app.overlay {
+            input.buffer = "soon".to_string();
+        }
+        assert!(matches!(
+            app.on_key(key(KeyCode::Enter), &data),
+            Action::None
+        ));
+        assert!(app.toast.is_some(), "invalid input should warn");
+    }
+
     #[test]
     fn config_webdav_settings_opens_json_editor_in_second_level_menu() {
         let mut app = App::new(Some(AppType::Claude));
@@ -1467,6 +1908,7 @@ mod tests {
                 enabled: false,
                 created_at: None,
                 updated_at: None,
+                sort_index: None,
             },
         });
 
@@ -1504,6 +1946,7 @@ mod tests {
                 enabled: false,
                 created_at: None,
                 updated_at: None,
+                sort_index: None,
             },
         });
 
@@ -1542,6 +1985,7 @@ mod tests {
                 enabled: false,
                 created_at: None,
                 updated_at: None,
+                sort_index: None,
             },
         });
 
@@ -1577,6 +2021,7 @@ mod tests {
                 enabled: false,
                 created_at: None,
                 updated_at: None,
+                sort_index: None,
             },
         });
 
@@ -1609,6 +2054,7 @@ mod tests {
                 enabled: false,
                 created_at: None,
                 updated_at: None,
+                sort_index: None,
             },
         });
 
@@ -1642,6 +2088,7 @@ mod tests {
                 enabled: false,
                 created_at: None,
                 updated_at: None,
+                sort_index: None,
             },
         });
 
@@ -1679,6 +2126,7 @@ mod tests {
                 enabled: false,
                 created_at: None,
                 updated_at: None,
+                sort_index: None,
             },
         });
 
@@ -2262,4 +2710,38 @@ mod tests {
             "Enter should exit after a successful update"
         );
     }
+
+    #[test]
+    fn providers_page_keys_jump_by_a_screenful_and_clamp() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Providers;
+        app.focus = Focus::Content;
+        app.last_size = Size::new(120, 30);
+        let mut data = UiData::default();
+        for i in 0..100 {
+            data.providers.rows.push(super::super::data::ProviderRow {
+                id: format!("p{i}"),
+                provider: crate::provider::Provider::with_id(
+                    format!("p{i}"),
+                    format!("Provider {i}"),
+                    json!({}),
+                    None,
+                ),
+                api_url: None,
+                is_current: false,
+            });
+        }
+        let page = app.list_page_rows();
+        assert!(page > 1 && page < 30);
+
+        app.on_key(key(KeyCode::PageDown), &data);
+        assert_eq!(app.provider_idx, page);
+        app.on_key(key(KeyCode::PageUp), &data);
+        assert_eq!(app.provider_idx, 0);
+
+        for _ in 0..20 {
+            app.on_key(key(KeyCode::PageDown), &data);
+        }
+        assert_eq!(app.provider_idx, 99, "PageDown stops at the last row");
+    }
 }
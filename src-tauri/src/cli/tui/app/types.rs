@@ -37,6 +37,28 @@ pub enum ToastKind {
     Error,
 }
 
+impl ToastKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            ToastKind::Info => "info",
+            ToastKind::Success => "ok",
+            ToastKind::Warning => "warn",
+            ToastKind::Error => "error",
+        }
+    }
+}
+
+/// Toast 历史保留条数，超出时淘汰最早的记录
+pub const TOAST_HISTORY_CAP: usize = 50;
+
+/// 已显示过的 toast（`Ctrl+L` 查看历史）
+#[derive(Debug, Clone)]
+pub struct ToastRecord {
+    pub at: chrono::DateTime<chrono::Local>,
+    pub message: String,
+    pub kind: ToastKind,
+}
+
 #[derive(Debug, Clone)]
 pub struct Toast {
     pub message: String,
@@ -54,16 +76,114 @@ impl Toast {
     }
 }
 
+/// 供应商列表分组方式（`g` 键循环切换）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProviderGrouping {
+    #[default]
+    Off,
+    Tag,
+    Category,
+}
+
+impl ProviderGrouping {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Tag,
+            Self::Tag => Self::Category,
+            Self::Category => Self::Off,
+        }
+    }
+
+    /// 按分组方式排列供应商：返回 `(分组标题, 供应商)`，未分组时标题为 None
+    ///
+    /// 按标签分组时使用供应商的第一个标签，保证每个供应商只出现一次；
+    /// 分组按标题排序，无标签/无分类的分组排在最后，组内保持原顺序。
+    pub fn group(
+        self,
+        rows: Vec<&data::ProviderRow>,
+    ) -> Vec<(Option<String>, Vec<&data::ProviderRow>)> {
+        let key = |row: &data::ProviderRow| -> Option<String> {
+            match self {
+                Self::Off => None,
+                Self::Tag => crate::services::provider::provider_tags(&row.provider)
+                    .first()
+                    .cloned(),
+                Self::Category => row
+                    .provider
+                    .category
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|c| !c.is_empty())
+                    .map(str::to_string),
+            }
+        };
+        if self == Self::Off {
+            return vec![(None, rows)];
+        }
+
+        let mut groups: Vec<(Option<String>, Vec<&data::ProviderRow>)> = Vec::new();
+        for row in rows {
+            let label = key(row);
+            match groups.iter_mut().find(|(existing, _)| *existing == label) {
+                Some((_, members)) => members.push(row),
+                None => groups.push((label, vec![row])),
+            }
+        }
+        // None（无标签/无分类）排在最后
+        groups.sort_by(|(a, _), (b, _)| match (a, b) {
+            (Some(a), Some(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        });
+
+        let fallback = match self {
+            Self::Category => texts::tui_provider_group_uncategorized(),
+            _ => texts::tui_provider_group_untagged(),
+        };
+        groups
+            .into_iter()
+            .map(|(label, members)| (Some(label.unwrap_or_else(|| fallback.to_string())), members))
+            .collect()
+    }
+}
+
+/// 供应商详情中临时显示明文密钥
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SecretReveal {
+    /// 剩余 tick 数；None 表示直到手动关闭
+    pub remaining_ticks: Option<u64>,
+}
+
+impl SecretReveal {
+    pub fn new(timeout_secs: u64) -> Self {
+        let tick_ms = crate::cli::tui::TUI_TICK_RATE.as_millis().max(1) as u64;
+        Self {
+            remaining_ticks: (timeout_secs > 0)
+                .then(|| timeout_secs.saturating_mul(1000).div_ceil(tick_ms)),
+        }
+    }
+
+    /// 剩余秒数（向上取整），用于倒计时显示
+    pub fn remaining_secs(&self) -> Option<u64> {
+        let tick_ms = crate::cli::tui::TUI_TICK_RATE.as_millis().max(1) as u64;
+        self.remaining_ticks
+            .map(|ticks| ticks.saturating_mul(tick_ms).div_ceil(1000))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ConfirmAction {
     Quit,
     ProviderDelete { id: String },
+    ProviderSwitch { id: String },
     McpDelete { id: String },
     PromptDelete { id: String },
     SkillsUninstall { directory: String },
     SkillsRepoRemove { owner: String, name: String },
     ConfigImport { path: String },
     ConfigRestoreBackup { id: String },
+    ConfigForceInitLive,
     ConfigReset,
     SettingsSetSkipClaudeOnboarding { enabled: bool },
     SettingsSetClaudePluginIntegration { enabled: bool },
@@ -89,6 +209,7 @@ pub enum TextSubmit {
     SkillsRepoAdd,
     WebDavJianguoyunUsername,
     WebDavJianguoyunPassword,
+    SettingsSecretRevealTimeout,
 }
 
 #[derive(Debug, Clone)]
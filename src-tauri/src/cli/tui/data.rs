@@ -23,6 +23,8 @@ pub struct ProviderRow {
 pub struct ProvidersSnapshot {
     pub current_id: String,
     pub rows: Vec<ProviderRow>,
+    /// 当前供应商的快照与 live 配置不一致（加载数据时计算一次）
+    pub current_drifted: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -154,6 +156,7 @@ fn load_providers(state: &AppState, app_type: &AppType) -> Result<ProvidersSnaps
 
     let rows = sorted
         .into_iter()
+        .filter(|(_, provider)| !provider.is_archived())
         .map(|(id, provider)| ProviderRow {
             api_url: extract_api_url(&provider.settings_config, app_type),
             is_current: id == current_id,
@@ -162,7 +165,15 @@ fn load_providers(state: &AppState, app_type: &AppType) -> Result<ProvidersSnaps
         })
         .collect::<Vec<_>>();
 
-    Ok(ProvidersSnapshot { current_id, rows })
+    // live 缺失或无法解析时不标记，避免未初始化的应用误报
+    let current_drifted =
+        ProviderService::current_live_drift(state, app_type.clone()).unwrap_or(false);
+
+    Ok(ProvidersSnapshot {
+        current_id,
+        rows,
+        current_drifted,
+    })
 }
 
 fn sort_providers(providers: &IndexMap<String, Provider>) -> Vec<(String, Provider)> {
@@ -235,18 +246,11 @@ fn load_mcp(state: &AppState) -> Result<McpSnapshot, AppError> {
 
 fn load_prompts(state: &AppState, app_type: &AppType) -> Result<PromptsSnapshot, AppError> {
     let prompts = PromptService::get_prompts(state, app_type.clone())?;
-    let mut rows = prompts
+    let rows = PromptService::sorted_prompts(prompts)
         .into_iter()
         .map(|(id, prompt)| PromptRow { id, prompt })
         .collect::<Vec<_>>();
 
-    rows.sort_by(|a, b| {
-        b.prompt
-            .updated_at
-            .unwrap_or(0)
-            .cmp(&a.prompt.updated_at.unwrap_or(0))
-    });
-
     Ok(PromptsSnapshot { rows })
 }
 
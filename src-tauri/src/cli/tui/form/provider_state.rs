@@ -13,15 +13,29 @@ use super::{
 
 impl ProviderAddFormState {
     pub fn new(app_type: AppType) -> Self {
+        let default_model = crate::settings::get_default_model(&app_type).unwrap_or_default();
         let codex_defaults = match app_type {
             AppType::Codex => (
                 "https://api.openai.com/v1",
-                "gpt-5.2-codex",
+                if default_model.is_empty() {
+                    "gpt-5.2-codex"
+                } else {
+                    default_model.as_str()
+                },
                 CodexWireApi::Responses,
                 true,
             ),
             _ => ("", "", CodexWireApi::Responses, true),
         };
+        // Claude 的默认模型预填到 Haiku/Sonnet/Opus 三个 DEFAULT_*_MODEL 字段
+        let claude_default_model = match app_type {
+            AppType::Claude => default_model.as_str(),
+            _ => "",
+        };
+        let gemini_default_model = match app_type {
+            AppType::Gemini => default_model.as_str(),
+            _ => "",
+        };
 
         Self {
             app_type,
@@ -35,21 +49,23 @@ impl ProviderAddFormState {
             id_is_manual: false,
             name: TextInput::new(""),
             website_url: TextInput::new(""),
-            notes: TextInput::new(""),
+            notes: TextInput::new(
+                crate::settings::get_provider_notes_template().unwrap_or_default(),
+            ),
             include_common_config: true,
             json_scroll: 0,
             codex_preview_section: CodexPreviewSection::Auth,
             codex_auth_scroll: 0,
             codex_config_scroll: 0,
-            claude_model_config_touched: false,
+            claude_model_config_touched: !claude_default_model.is_empty(),
             claude_api_key: TextInput::new(""),
             claude_base_url: TextInput::new(""),
             claude_api_format: ClaudeApiFormat::Anthropic,
             claude_model: TextInput::new(""),
             claude_reasoning_model: TextInput::new(""),
-            claude_haiku_model: TextInput::new(""),
-            claude_sonnet_model: TextInput::new(""),
-            claude_opus_model: TextInput::new(""),
+            claude_haiku_model: TextInput::new(claude_default_model),
+            claude_sonnet_model: TextInput::new(claude_default_model),
+            claude_opus_model: TextInput::new(claude_default_model),
             codex_base_url: TextInput::new(codex_defaults.0),
             codex_model: TextInput::new(codex_defaults.1),
             codex_wire_api: codex_defaults.2,
@@ -59,7 +75,7 @@ impl ProviderAddFormState {
             gemini_auth_type: GeminiAuthType::ApiKey,
             gemini_api_key: TextInput::new(""),
             gemini_base_url: TextInput::new("https://generativelanguage.googleapis.com"),
-            gemini_model: TextInput::new(""),
+            gemini_model: TextInput::new(gemini_default_model),
             opencode_npm_package: TextInput::new("@ai-sdk/openai-compatible"),
             opencode_api_key: TextInput::new(""),
             opencode_base_url: TextInput::new(""),
@@ -85,9 +101,18 @@ impl ProviderAddFormState {
         if let Some(url) = provider.website_url.as_deref() {
             form.website_url.set(url);
         }
-        if let Some(notes) = provider.notes.as_deref() {
-            form.notes.set(notes);
+        // 编辑时以供应商自身备注为准，不使用新增时的备注模板
+        form.notes
+            .set(provider.notes.as_deref().unwrap_or_default());
+        // 同理，模型以供应商自身配置为准，不使用新增时的默认模型
+        form.claude_model_config_touched = false;
+        form.claude_haiku_model.set("");
+        form.claude_sonnet_model.set("");
+        form.claude_opus_model.set("");
+        if app_type == AppType::Codex {
+            form.codex_model.set("gpt-5.2-codex");
         }
+        form.gemini_model.set("");
         form.include_common_config = provider
             .meta
             .as_ref()
@@ -196,7 +196,8 @@ impl ProviderAddFormState {
             }
 
             self.extra = json!({});
-            self.notes.set("");
+            self.notes
+                .set(crate::settings::get_provider_notes_template().unwrap_or_default());
             match template_id {
                 ProviderTemplateId::Custom => {}
                 ProviderTemplateId::ClaudeOfficial => {
@@ -256,7 +257,8 @@ impl ProviderAddFormState {
         });
         self.name.set(preset.provider_name);
         self.website_url.set(preset.website_url);
-        self.notes.set("");
+        self.notes
+            .set(crate::settings::get_provider_notes_template().unwrap_or_default());
 
         match self.app_type {
             AppType::Claude => {
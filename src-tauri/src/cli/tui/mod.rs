@@ -280,6 +280,7 @@ pub fn run(app_override: Option<AppType>) -> Result<(), AppError> {
                 event::Event::Key(key) if key.kind == KeyEventKind::Press => {
                     let key = normalize_key_event(key);
                     let action = app.on_key(key, &data);
+                    let performed = action.clone();
                     if let Err(err) = handle_action(
                         &mut terminal,
                         &mut app,
@@ -304,6 +305,8 @@ pub fn run(app_override: Option<AppType>) -> Result<(), AppError> {
                             return Err(err);
                         }
                         app.push_toast(err.to_string(), ToastKind::Error);
+                    } else {
+                        app.remember_action(&performed);
                     }
                 }
                 event::Event::Mouse(mouse) => {
@@ -23,11 +23,12 @@ pub enum NavItem {
     Config,
     Skills,
     Settings,
+    RepeatLast,
     Exit,
 }
 
 impl NavItem {
-    pub const ALL: [NavItem; 8] = [
+    pub const ALL: [NavItem; 9] = [
         NavItem::Main,
         NavItem::Providers,
         NavItem::Mcp,
@@ -35,6 +36,7 @@ impl NavItem {
         NavItem::Prompts,
         NavItem::Config,
         NavItem::Settings,
+        NavItem::RepeatLast,
         NavItem::Exit,
     ];
 
@@ -47,7 +49,7 @@ impl NavItem {
             NavItem::Config => Some(Route::Config),
             NavItem::Skills => Some(Route::Skills),
             NavItem::Settings => Some(Route::Settings),
-            NavItem::Exit => None,
+            NavItem::RepeatLast | NavItem::Exit => None,
         }
     }
 }
@@ -17,7 +17,7 @@ pub(super) fn export(ctx: &mut RuntimeActionContext<'_>, path: String) -> Result
     if let Some(parent) = target.parent() {
         std::fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
     }
-    ConfigService::export_config_to_path(&target)?;
+    ConfigService::export_config_to_path(&target, false, false)?;
     ctx.app.push_toast(
         texts::tui_toast_exported_to(&target.display().to_string()),
         ToastKind::Success,
@@ -239,6 +239,26 @@ pub(super) fn webdav_jianguoyun_quick_setup(
     )
 }
 
+pub(super) fn force_init_live(ctx: &mut RuntimeActionContext<'_>) -> Result<(), AppError> {
+    let app_type = ctx.app.app_type.clone();
+    if crate::sync_policy::should_sync_live(&app_type) {
+        ctx.app.push_toast(
+            texts::tui_toast_live_already_initialized(app_type.as_str()),
+            ToastKind::Info,
+        );
+        return Ok(());
+    }
+
+    let state = load_state()?;
+    let dir = ProviderService::force_init_live(&state, app_type.clone())?;
+    ctx.app.push_toast(
+        texts::live_force_initialized_warning(app_type.as_str(), &dir.display().to_string()),
+        ToastKind::Warning,
+    );
+    *ctx.data = UiData::load(&app_type)?;
+    Ok(())
+}
+
 pub(super) fn reset(ctx: &mut RuntimeActionContext<'_>) -> Result<(), AppError> {
     let config_dir = crate::config::get_app_config_dir();
     let db_path = config_dir.join("cc-switch.db");
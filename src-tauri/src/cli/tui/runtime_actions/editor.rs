@@ -5,7 +5,9 @@ use crate::cli::i18n::texts;
 use crate::error::AppError;
 use crate::provider::Provider;
 use crate::services::{McpService, PromptService, ProviderService};
-use crate::settings::{set_webdav_sync_settings, WebDavSyncSettings};
+use crate::settings::{
+    set_switch_hook_settings, set_webdav_sync_settings, SwitchHookSettings, WebDavSyncSettings,
+};
 
 use super::super::app::{EditorSubmit, Overlay, TextViewState, ToastKind};
 use super::super::data::{load_state, UiData};
@@ -41,6 +43,7 @@ pub(super) fn submit(
             submit_config_common_snippet(ctx, app_type, content)
         }
         EditorSubmit::ConfigWebDavSettings => submit_webdav_settings(ctx, content),
+        EditorSubmit::SettingsSwitchHooks => submit_switch_hooks(ctx, content),
     }
 }
 
@@ -509,3 +512,24 @@ fn submit_webdav_settings(
     *ctx.data = UiData::load(&ctx.app.app_type)?;
     Ok(())
 }
+
+fn submit_switch_hooks(
+    ctx: &mut RuntimeActionContext<'_>,
+    content: String,
+) -> Result<(), AppError> {
+    let edited = content.trim();
+    let hooks: Option<SwitchHookSettings> = if edited.is_empty() {
+        None
+    } else {
+        Some(
+            serde_json::from_str(edited)
+                .map_err(|e| AppError::Message(texts::tui_toast_invalid_json(&e.to_string())))?,
+        )
+    };
+    set_switch_hook_settings(hooks)?;
+
+    ctx.app.editor = None;
+    ctx.app
+        .push_toast(texts::tui_toast_switch_hooks_saved(), ToastKind::Success);
+    Ok(())
+}
@@ -150,6 +150,9 @@ pub(crate) fn handle_action(
         Action::EditorSubmit { submit, content } => editor::submit(&mut ctx, submit, content),
         Action::ProviderSwitch { id } => providers::switch(&mut ctx, id),
         Action::ProviderDelete { id } => providers::delete(&mut ctx, id),
+        Action::ProviderSetFavorite { id, favorite } => {
+            providers::set_favorite(&mut ctx, id, favorite)
+        }
         Action::ProviderSpeedtest { url } => providers::speedtest(&mut ctx, url),
         Action::ProviderStreamCheck { id } => providers::stream_check(&mut ctx, id),
         Action::ProviderModelFetch {
@@ -165,6 +168,7 @@ pub(crate) fn handle_action(
         Action::PromptActivate { id } => prompts::activate(&mut ctx, id),
         Action::PromptDeactivate { id } => prompts::deactivate(&mut ctx, id),
         Action::PromptDelete { id } => prompts::delete(&mut ctx, id),
+        Action::PromptReorder { ids } => prompts::reorder(&mut ctx, ids),
         Action::ConfigExport { path } => config::export(&mut ctx, path),
         Action::ConfigShowFull => config::show_full(&mut ctx),
         Action::ConfigImport { path } => config::import(&mut ctx, path),
@@ -186,6 +190,7 @@ pub(crate) fn handle_action(
         Action::ConfigWebDavJianguoyunQuickSetup { username, password } => {
             config::webdav_jianguoyun_quick_setup(&mut ctx, username, password)
         }
+        Action::ConfigForceInitLive => config::force_init_live(&mut ctx),
         Action::ConfigReset => config::reset(&mut ctx),
         Action::SetSkipClaudeOnboarding { enabled } => {
             crate::settings::set_skip_claude_onboarding(enabled)?;
@@ -195,6 +200,22 @@ pub(crate) fn handle_action(
             );
             Ok(())
         }
+        Action::SetSkipSkillUninstallConfirm { enabled } => {
+            crate::settings::set_skip_skill_uninstall_confirm(enabled)?;
+            ctx.app.push_toast(
+                texts::tui_toast_skip_skill_uninstall_confirm_toggled(enabled),
+                ToastKind::Success,
+            );
+            Ok(())
+        }
+        Action::SetSecretRevealTimeout { secs } => {
+            crate::settings::set_secret_reveal_timeout_secs(secs)?;
+            ctx.app.push_toast(
+                texts::tui_toast_secret_reveal_timeout_saved(),
+                ToastKind::Success,
+            );
+            Ok(())
+        }
         Action::SetClaudePluginIntegration { enabled } => {
             crate::settings::set_enable_claude_plugin_integration(enabled)?;
             if let Err(err) = crate::claude_plugin::sync_claude_plugin_on_settings_toggle(enabled) {
@@ -32,3 +32,15 @@ pub(super) fn delete(ctx: &mut RuntimeActionContext<'_>, id: String) -> Result<(
     *ctx.data = UiData::load(&ctx.app.app_type)?;
     Ok(())
 }
+
+pub(super) fn reorder(
+    ctx: &mut RuntimeActionContext<'_>,
+    ids: Vec<String>,
+) -> Result<(), AppError> {
+    let state = load_state()?;
+    PromptService::reorder_prompts(&state, ctx.app.app_type.clone(), &ids)?;
+    ctx.app
+        .push_toast(texts::tui_toast_prompt_reordered(), ToastKind::Success);
+    *ctx.data = UiData::load(&ctx.app.app_type)?;
+    Ok(())
+}
@@ -52,6 +52,21 @@ pub(super) fn delete(ctx: &mut RuntimeActionContext<'_>, id: String) -> Result<(
     Ok(())
 }
 
+pub(super) fn set_favorite(
+    ctx: &mut RuntimeActionContext<'_>,
+    id: String,
+    favorite: bool,
+) -> Result<(), AppError> {
+    let state = load_state()?;
+    ProviderService::set_favorite(&state, ctx.app.app_type.clone(), &id, favorite)?;
+    ctx.app.push_toast(
+        texts::tui_toast_provider_favorite_set(favorite),
+        ToastKind::Success,
+    );
+    *ctx.data = UiData::load(&ctx.app.app_type)?;
+    Ok(())
+}
+
 pub(super) fn speedtest(ctx: &mut RuntimeActionContext<'_>, url: String) -> Result<(), AppError> {
     let Some(tx) = ctx.speedtest_req_tx else {
         if matches!(&ctx.app.overlay, Overlay::SpeedtestRunning { url: running_url } if running_url == &url)
@@ -501,6 +501,16 @@ pub(crate) fn handle_update_msg(app: &mut App, update_check: &mut RequestTracker
                 }
             }
         }
+        UpdateMsg::CheckTimedOut { request_id } => {
+            if !update_check.finish_if_active(request_id) {
+                return;
+            }
+            app.overlay = Overlay::None;
+            app.push_toast(
+                texts::tui_toast_update_check_timed_out(),
+                ToastKind::Warning,
+            );
+        }
         UpdateMsg::DownloadProgress { downloaded, total } => {
             if let Overlay::UpdateDownloading {
                 downloaded: ref mut dl,
@@ -176,6 +176,9 @@ pub(crate) enum UpdateMsg {
         request_id: u64,
         result: Result<crate::cli::commands::update::UpdateCheckInfo, String>,
     },
+    CheckTimedOut {
+        request_id: u64,
+    },
     DownloadProgress {
         downloaded: u64,
         total: Option<u64>,
@@ -315,8 +318,7 @@ pub(crate) async fn fetch_provider_models_for_tui(
         return Err("URL cannot be empty".to_string());
     }
 
-    let client = reqwest::Client::builder()
-        .timeout(Duration::from_secs(5))
+    let client = crate::proxy::client_builder(None, Duration::from_secs(5))
         .build()
         .map_err(|e| format!("build http client failed: {e}"))?;
 
@@ -133,12 +133,17 @@ fn update_worker_loop(rx: mpsc::Receiver<UpdateReq>, tx: mpsc::Sender<UpdateMsg>
     while let Ok(req) = rx.recv() {
         match req {
             UpdateReq::Check { request_id } => {
-                let result = rt
-                    .block_on(crate::cli::commands::update::check_for_update())
-                    .map_err(|e| e.to_string());
+                let result = rt.block_on(crate::cli::commands::update::check_for_update());
+                if let Err(ref e) = result {
+                    if crate::cli::commands::update::is_update_check_timeout(e) {
+                        let _ = tx.send(UpdateMsg::CheckTimedOut { request_id });
+                        continue;
+                    }
+                }
                 if let Ok(ref info) = result {
                     last_tag = Some(info.target_tag.clone());
                 }
+                let result = result.map_err(|e| e.to_string());
                 let _ = tx.send(UpdateMsg::CheckFinished { request_id, result });
             }
             UpdateReq::Download => {
@@ -576,7 +581,7 @@ fn skills_worker_loop(rx: mpsc::Receiver<SkillsReq>, tx: mpsc::Sender<SkillsMsg>
                 let spec_clone = spec.clone();
                 let app_clone = app.clone();
                 let result = rt
-                    .block_on(async { service.install(&spec_clone, &app_clone).await })
+                    .block_on(async { service.install(&spec_clone, &app_clone, None).await })
                     .map_err(|e| e.to_string());
                 let _ = tx.send(SkillsMsg::InstallFinished { spec, result });
             }
@@ -486,6 +486,32 @@ fn update_check_finished_is_processed_when_request_id_matches() {
     ));
 }
 
+#[test]
+fn update_check_timeout_closes_loading_overlay_with_toast() {
+    let mut app = App::new(None);
+    app.overlay = Overlay::Loading {
+        kind: LoadingKind::UpdateCheck,
+        title: texts::tui_update_checking_title().to_string(),
+        message: texts::tui_loading().to_string(),
+    };
+    let mut update_check = RequestTracker {
+        active: Some(3),
+        ..Default::default()
+    };
+
+    handle_update_msg(
+        &mut app,
+        &mut update_check,
+        UpdateMsg::CheckTimedOut { request_id: 3 },
+    );
+
+    assert_eq!(update_check.active, None);
+    assert!(matches!(app.overlay, Overlay::None));
+    let toast = app.toast.as_ref().expect("timeout should show toast");
+    assert_eq!(toast.message, texts::tui_toast_update_check_timed_out());
+    assert!(matches!(toast.kind, ToastKind::Warning));
+}
+
 #[test]
 fn update_check_finished_is_ignored_when_request_id_mismatch() {
     let mut app = App::new(None);
@@ -142,6 +142,7 @@ pub(super) fn nav_label(item: NavItem) -> &'static str {
         NavItem::Config => texts::menu_manage_config(),
         NavItem::Skills => texts::menu_manage_skills(),
         NavItem::Settings => texts::menu_settings(),
+        NavItem::RepeatLast => texts::menu_repeat_last_action(),
         NavItem::Exit => texts::menu_exit(),
     }
 }
@@ -155,6 +156,7 @@ pub(super) fn nav_label_variants(item: NavItem) -> (&'static str, &'static str)
         NavItem::Config => texts::menu_manage_config_variants(),
         NavItem::Skills => texts::menu_manage_skills_variants(),
         NavItem::Settings => texts::menu_settings_variants(),
+        NavItem::RepeatLast => texts::menu_repeat_last_action_variants(),
         NavItem::Exit => texts::menu_exit_variants(),
     }
 }
@@ -23,6 +23,7 @@ pub(super) fn config_item_label(item: &ConfigItem) -> &'static str {
         ConfigItem::CommonSnippet => texts::tui_config_item_common_snippet(),
         ConfigItem::Proxy => texts::tui_config_item_proxy(),
         ConfigItem::WebDavSync => texts::tui_config_item_webdav_sync(),
+        ConfigItem::InitLive => texts::tui_config_item_init_live(),
         ConfigItem::Reset => texts::tui_config_item_reset(),
     }
 }
@@ -150,6 +151,7 @@ pub(super) fn render_settings(
     let language = crate::cli::i18n::current_language();
     let skip_claude_onboarding = crate::settings::get_skip_claude_onboarding();
     let claude_plugin_integration = crate::settings::get_enable_claude_plugin_integration();
+    let skip_skill_uninstall_confirm = crate::settings::get_skip_skill_uninstall_confirm();
     let proxy_enabled = data.proxy.enabled;
 
     let rows_data = super::app::SettingsItem::ALL
@@ -175,6 +177,28 @@ pub(super) fn render_settings(
                     texts::disabled().to_string()
                 },
             ),
+            super::app::SettingsItem::SkipSkillUninstallConfirm => (
+                texts::skip_skill_uninstall_confirm_label().to_string(),
+                if skip_skill_uninstall_confirm {
+                    texts::enabled().to_string()
+                } else {
+                    texts::disabled().to_string()
+                },
+            ),
+            super::app::SettingsItem::SwitchHooks => (
+                texts::switch_hooks_label().to_string(),
+                if crate::settings::get_switch_hook_settings().is_some() {
+                    texts::tui_switch_hooks_configured().to_string()
+                } else {
+                    texts::switch_hooks_none().to_string()
+                },
+            ),
+            super::app::SettingsItem::SecretRevealTimeout => (
+                texts::secret_reveal_timeout_label().to_string(),
+                texts::secret_reveal_timeout_value(
+                    crate::settings::get_secret_reveal_timeout_secs(),
+                ),
+            ),
             super::app::SettingsItem::Proxy => (
                 texts::tui_config_item_proxy().to_string(),
                 if proxy_enabled {
@@ -2,7 +2,8 @@ use super::*;
 
 pub(super) fn mcp_rows_filtered<'a>(app: &App, data: &'a UiData) -> Vec<&'a McpRow> {
     let query = app.filter.query_lower();
-    data.mcp
+    let mut rows: Vec<_> = data
+        .mcp
         .rows
         .iter()
         .filter(|row| match &query {
@@ -11,7 +12,12 @@ pub(super) fn mcp_rows_filtered<'a>(app: &App, data: &'a UiData) -> Vec<&'a McpR
                 row.server.name.to_lowercase().contains(q) || row.id.to_lowercase().contains(q)
             }
         })
-        .collect()
+        .collect();
+    rows.sort_by(|a, b| {
+        app.mcp_sort
+            .compare(&app.app_type, (&a.id, &a.server), (&b.id, &b.server))
+    });
+    rows
 }
 
 pub(super) fn render_mcp(
@@ -62,7 +68,8 @@ pub(super) fn render_mcp(
         .borders(Borders::ALL)
         .border_type(BorderType::Plain)
         .border_style(pane_border_style(app, Focus::Content, theme))
-        .title(texts::menu_manage_mcp());
+        .title(texts::menu_manage_mcp())
+        .title_top(list_position_title(app.mcp_idx, visible.len()));
     frame.render_widget(outer.clone(), area);
     let inner = outer.inner(area);
 
@@ -86,6 +93,7 @@ pub(super) fn render_mcp(
                 ("a", texts::tui_key_add()),
                 ("e", texts::tui_key_edit()),
                 ("i", texts::tui_mcp_action_import_existing()),
+                ("s", texts::tui_key_sort()),
                 ("d", texts::tui_key_delete()),
             ],
         );
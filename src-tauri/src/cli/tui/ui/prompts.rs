@@ -63,6 +63,7 @@ pub(super) fn render_prompts(
                 ("x", texts::tui_key_deactivate_active()),
                 ("e", texts::tui_key_edit()),
                 ("d", texts::tui_key_delete()),
+                ("K/J", texts::tui_key_move_up_down()),
             ],
         );
     }
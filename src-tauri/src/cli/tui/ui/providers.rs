@@ -1,17 +1,23 @@
 use super::*;
 
-pub(super) fn provider_rows_filtered<'a>(app: &App, data: &'a UiData) -> Vec<&'a ProviderRow> {
+fn provider_groups_filtered<'a>(
+    app: &App,
+    data: &'a UiData,
+) -> Vec<(Option<String>, Vec<&'a ProviderRow>)> {
     let query = app.filter.query_lower();
-    data.providers
+    let rows = data
+        .providers
         .rows
         .iter()
+        .filter(|row| !app.provider_favorites_only || row.provider.is_favorite())
         .filter(|row| match &query {
             None => true,
             Some(q) => {
                 row.provider.name.to_lowercase().contains(q) || row.id.to_lowercase().contains(q)
             }
         })
-        .collect()
+        .collect();
+    app.provider_grouping.group(rows)
 }
 
 pub(super) fn render_providers(
@@ -24,11 +30,15 @@ pub(super) fn render_providers(
     let header_style = Style::default().fg(theme.dim).add_modifier(Modifier::BOLD);
     let table_style = Style::default();
 
+    let groups = provider_groups_filtered(app, data);
+    let total = groups.iter().map(|(_, members)| members.len()).sum();
+
     let outer = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Plain)
         .border_style(pane_border_style(app, Focus::Content, theme))
-        .title(texts::menu_manage_providers());
+        .title(texts::menu_manage_providers())
+        .title_top(list_position_title(app.provider_idx, total));
     frame.render_widget(outer.clone(), area);
     let inner = outer.inner(area);
 
@@ -45,17 +55,19 @@ pub(super) fn render_providers(
             &[
                 ("Enter", texts::tui_key_details()),
                 ("s", texts::tui_key_switch()),
+                ("n/p", texts::tui_key_switch_next_prev()),
                 ("a", texts::tui_key_add()),
                 ("e", texts::tui_key_edit()),
                 ("d", texts::tui_key_delete()),
                 ("t", texts::tui_key_speedtest()),
                 ("c", texts::tui_key_stream_check()),
+                ("g", texts::tui_key_group()),
+                ("f", texts::tui_key_favorite()),
+                ("F", texts::tui_key_favorites_only()),
             ],
         );
     }
 
-    let visible = provider_rows_filtered(app, data);
-
     let header = Row::new(vec![
         Cell::from(""),
         Cell::from(texts::header_name()),
@@ -63,19 +75,58 @@ pub(super) fn render_providers(
     ])
     .style(header_style);
 
-    let rows = visible.iter().map(|row| {
-        let marker = if row.is_current {
-            texts::tui_marker_active()
-        } else {
-            texts::tui_marker_inactive()
-        };
-        let api = row.api_url.as_deref().unwrap_or(texts::tui_na());
-        Row::new(vec![
-            Cell::from(marker),
-            Cell::from(row.provider.name.clone()),
-            Cell::from(api),
-        ])
-    });
+    // 分组标题行不可选中：选中索引需跳过其前面的标题行
+    let group_header_style = Style::default()
+        .fg(theme.accent)
+        .add_modifier(Modifier::BOLD);
+    let mut rows = Vec::new();
+    let mut selected_row = None;
+    let mut provider_pos = 0;
+    for (label, members) in groups {
+        if let Some(label) = label {
+            rows.push(
+                Row::new(vec![
+                    Cell::from(""),
+                    Cell::from(format!("── {label}")),
+                    Cell::from(""),
+                ])
+                .style(group_header_style),
+            );
+        }
+        for row in members {
+            if provider_pos == app.provider_idx {
+                selected_row = Some(rows.len());
+            }
+            provider_pos += 1;
+            let marker = if row.is_current {
+                texts::tui_marker_active()
+            } else {
+                texts::tui_marker_inactive()
+            };
+            let api = row.api_url.as_deref().unwrap_or(texts::tui_na());
+            let name = if row.provider.is_favorite() {
+                format!("★ {}", row.provider.name)
+            } else {
+                row.provider.name.clone()
+            };
+            let name_cell = if row.is_current && data.providers.current_drifted {
+                Cell::from(Line::from(vec![
+                    Span::raw(name),
+                    Span::styled(
+                        format!(" {}", texts::tui_provider_drift_marker()),
+                        Style::default().fg(theme.warn),
+                    ),
+                ]))
+            } else {
+                Cell::from(name)
+            };
+            rows.push(Row::new(vec![
+                Cell::from(marker),
+                name_cell,
+                Cell::from(api),
+            ]));
+        }
+    }
 
     let table = Table::new(
         rows,
@@ -92,7 +143,7 @@ pub(super) fn render_providers(
     .highlight_symbol(highlight_symbol(theme));
 
     let mut state = TableState::default();
-    state.select(Some(app.provider_idx));
+    state.select(selected_row.or(Some(app.provider_idx)));
 
     frame.render_stateful_widget(table, inset_left(chunks[1], CONTENT_INSET_LEFT), &mut state);
 }
@@ -142,6 +193,7 @@ pub(super) fn render_provider_detail(
                 ("e", texts::tui_key_edit()),
                 ("t", texts::tui_key_speedtest()),
                 ("c", texts::tui_key_stream_check()),
+                ("v", texts::tui_key_reveal_secret()),
             ],
         );
     }
@@ -182,7 +234,13 @@ pub(super) fn render_provider_detail(
                 .get("ANTHROPIC_AUTH_TOKEN")
                 .or_else(|| env.get("ANTHROPIC_API_KEY"))
                 .and_then(|v| v.as_str())
-                .map(mask_api_key)
+                .map(|key| {
+                    if app.secret_reveal.is_some() {
+                        key.to_string()
+                    } else {
+                        mask_api_key(key)
+                    }
+                })
                 .unwrap_or_else(|| texts::tui_na().to_string());
             let base_url = env
                 .get("ANTHROPIC_BASE_URL")
@@ -213,14 +271,24 @@ pub(super) fn render_provider_detail(
                 Span::raw(": "),
                 Span::raw(api_format),
             ]));
-            lines.push(Line::from(vec![
+            let mut api_key_line = vec![
                 Span::styled(
                     texts::tui_label_api_key(),
                     Style::default().fg(theme.accent),
                 ),
                 Span::raw(": "),
                 Span::raw(api_key),
-            ]));
+            ];
+            if let Some(reveal) = app.secret_reveal {
+                api_key_line.push(Span::styled(
+                    format!(
+                        "  {}",
+                        texts::tui_secret_reveal_countdown(reveal.remaining_secs())
+                    ),
+                    Style::default().fg(theme.dim),
+                ));
+            }
+            lines.push(Line::from(api_key_line));
         }
     }
 
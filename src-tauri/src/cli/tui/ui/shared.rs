@@ -254,6 +254,15 @@ pub(super) fn render_key_bar_center(
     );
 }
 
+/// Right-aligned "12/240" position indicator for a list pane's top border.
+/// Empty lists render nothing.
+pub(super) fn list_position_title(selected: usize, total: usize) -> Line<'static> {
+    if total == 0 {
+        return Line::default();
+    }
+    Line::from(format!(" {}/{} ", selected.min(total - 1) + 1, total)).right_aligned()
+}
+
 pub(super) fn render_summary_bar(
     frame: &mut Frame<'_>,
     area: Rect,
@@ -320,11 +329,5 @@ where
 }
 
 pub(super) fn mask_api_key(key: &str) -> String {
-    let mut iter = key.chars();
-    let prefix: String = iter.by_ref().take(8).collect();
-    if iter.next().is_some() {
-        format!("{prefix}...")
-    } else {
-        prefix
-    }
+    crate::redact::mask_secret(key)
 }
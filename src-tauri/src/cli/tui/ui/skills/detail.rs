@@ -108,6 +108,16 @@ pub(super) fn render_skill_detail(
             Span::raw(format!("{owner}/{name}")),
         ]));
     }
+    if let Some(pin) = skill.pinned_ref.as_deref() {
+        lines.push(Line::from(vec![
+            Span::styled(
+                texts::tui_label_pinned_ref(),
+                Style::default().fg(theme.accent),
+            ),
+            Span::raw(": "),
+            Span::raw(pin.to_string()),
+        ]));
+    }
     if let Some(url) = skill.readme_url.as_deref().filter(|s| !s.trim().is_empty()) {
         lines.push(Line::from(vec![
             Span::styled(texts::tui_label_readme(), Style::default().fg(theme.accent)),
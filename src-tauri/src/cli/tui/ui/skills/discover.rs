@@ -7,6 +7,21 @@ pub(super) fn render_skills_discover(
     area: Rect,
     theme: &super::theme::Theme,
 ) {
+    let query = app.filter.query_lower();
+    let visible = app
+        .skills_discover_results
+        .iter()
+        .filter(|skill| match &query {
+            None => true,
+            Some(q) => {
+                skill.name.to_lowercase().contains(q)
+                    || skill.directory.to_lowercase().contains(q)
+                    || skill.key.to_lowercase().contains(q)
+                    || skill.description.to_lowercase().contains(q)
+            }
+        })
+        .collect::<Vec<_>>();
+
     let title = format!(
         "{} — {}",
         texts::tui_skills_discover_title(),
@@ -21,7 +36,8 @@ pub(super) fn render_skills_discover(
         .borders(Borders::ALL)
         .border_type(BorderType::Plain)
         .border_style(pane_border_style(app, Focus::Content, theme))
-        .title(title);
+        .title(title)
+        .title_top(list_position_title(app.skills_discover_idx, visible.len()));
     frame.render_widget(outer.clone(), area);
     let inner = outer.inner(area);
 
@@ -43,21 +59,6 @@ pub(super) fn render_skills_discover(
         );
     }
 
-    let query = app.filter.query_lower();
-    let visible = app
-        .skills_discover_results
-        .iter()
-        .filter(|skill| match &query {
-            None => true,
-            Some(q) => {
-                skill.name.to_lowercase().contains(q)
-                    || skill.directory.to_lowercase().contains(q)
-                    || skill.key.to_lowercase().contains(q)
-                    || skill.description.to_lowercase().contains(q)
-            }
-        })
-        .collect::<Vec<_>>();
-
     if visible.is_empty() {
         frame.render_widget(
             Paragraph::new(texts::tui_skills_discover_hint())
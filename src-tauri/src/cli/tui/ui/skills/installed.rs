@@ -7,11 +7,14 @@ pub(super) fn render_skills_installed(
     area: Rect,
     theme: &super::theme::Theme,
 ) {
+    let visible = skills_installed_filtered(app, data);
+
     let outer = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Plain)
         .border_style(pane_border_style(app, Focus::Content, theme))
-        .title(texts::skills_management());
+        .title(texts::skills_management())
+        .title_top(list_position_title(app.skills_idx, visible.len()));
     frame.render_widget(outer.clone(), area);
     let inner = outer.inner(area);
 
@@ -42,7 +45,6 @@ pub(super) fn render_skills_installed(
 
     render_summary_bar(frame, chunks[1], theme, installed_summary(data));
 
-    let visible = skills_installed_filtered(app, data);
     if visible.is_empty() {
         render_installed_empty_state(frame, chunks[2], theme);
         return;
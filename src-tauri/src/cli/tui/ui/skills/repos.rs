@@ -7,11 +7,27 @@ pub(super) fn render_skills_repos(
     area: Rect,
     theme: &super::theme::Theme,
 ) {
+    let query = app.filter.query_lower();
+    let visible = data
+        .skills
+        .repos
+        .iter()
+        .filter(|repo| match &query {
+            None => true,
+            Some(q) => {
+                repo.owner.to_lowercase().contains(q)
+                    || repo.name.to_lowercase().contains(q)
+                    || repo.branch.to_lowercase().contains(q)
+            }
+        })
+        .collect::<Vec<_>>();
+
     let outer = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Plain)
         .border_style(pane_border_style(app, Focus::Content, theme))
-        .title(texts::tui_skills_repos_title());
+        .title(texts::tui_skills_repos_title())
+        .title_top(list_position_title(app.skills_repo_idx, visible.len()));
     frame.render_widget(outer.clone(), area);
     let inner = outer.inner(area);
 
@@ -44,21 +60,6 @@ pub(super) fn render_skills_repos(
         inset_left(chunks[1], CONTENT_INSET_LEFT),
     );
 
-    let query = app.filter.query_lower();
-    let visible = data
-        .skills
-        .repos
-        .iter()
-        .filter(|repo| match &query {
-            None => true,
-            Some(q) => {
-                repo.owner.to_lowercase().contains(q)
-                    || repo.name.to_lowercase().contains(q)
-                    || repo.branch.to_lowercase().contains(q)
-            }
-        })
-        .collect::<Vec<_>>();
-
     if visible.is_empty() {
         frame.render_widget(
             Paragraph::new(texts::tui_skills_repos_empty())
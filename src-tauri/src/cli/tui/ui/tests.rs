@@ -28,11 +28,11 @@ use crate::{
 fn mask_api_key_handles_multibyte_safely() {
     let short = "你你你"; // 3 chars, 9 bytes
     let masked = super::mask_api_key(short);
-    assert_eq!(masked, short);
+    assert!(!masked.contains('你'));
 
     let long = "你".repeat(9);
     let masked = super::mask_api_key(&long);
-    assert!(masked.ends_with("..."));
+    assert!(masked.starts_with("****"));
 }
 
 #[test]
@@ -139,6 +139,7 @@ fn minimal_data(_app_type: &AppType) -> UiData {
                 api_url: Some("https://example.com".to_string()),
                 is_current: false,
             }],
+            current_drifted: false,
         },
         mcp: McpSnapshot::default(),
         prompts: PromptsSnapshot::default(),
@@ -158,6 +159,7 @@ fn installed_skill(directory: &str, name: &str) -> InstalledSkill {
         repo_owner: None,
         repo_name: None,
         repo_branch: None,
+        pinned_ref: None,
         apps: SkillApps {
             claude: true,
             codex: false,
@@ -541,6 +543,7 @@ fn home_connection_card_labels_mcp_and_skills_with_active_counts() {
             repo_owner: None,
             repo_name: None,
             repo_branch: None,
+            pinned_ref: None,
             readme_url: None,
             apps: crate::app_config::SkillApps {
                 claude: true,
@@ -558,6 +561,7 @@ fn home_connection_card_labels_mcp_and_skills_with_active_counts() {
             repo_owner: None,
             repo_name: None,
             repo_branch: None,
+            pinned_ref: None,
             readme_url: None,
             apps: crate::app_config::SkillApps::default(),
             installed_at: 0,
@@ -1307,6 +1311,26 @@ fn skill_detail_page_shows_opencode_enabled_state() {
     assert!(!all.contains("opencode=true"));
 }
 
+#[test]
+fn skill_detail_page_shows_pinned_ref() {
+    let _lock = lock_env();
+    let _no_color = EnvGuard::remove("NO_COLOR");
+
+    let mut app = App::new(Some(AppType::Claude));
+    app.route = Route::SkillDetail {
+        directory: "hello-skill".to_string(),
+    };
+    app.focus = Focus::Content;
+
+    let mut data = minimal_data(&app.app_type);
+    let mut skill = installed_skill("hello-skill", "Hello Skill");
+    skill.pinned_ref = Some("v1.2.0".to_string());
+    data.skills.installed = vec![skill];
+
+    let all = all_text(&render(&app, &data));
+    assert!(all.contains(&format!("{}: v1.2.0", texts::tui_label_pinned_ref())));
+}
+
 #[test]
 fn skills_import_overlay_uses_friendly_copy() {
     let _lock = lock_env();
@@ -2075,6 +2099,7 @@ fn backup_picker_overlay_shows_hint() {
         path: std::path::PathBuf::from("/tmp/b1.json"),
         timestamp: "20260131_000000".to_string(),
         display_name: "backup".to_string(),
+        note: None,
     }];
 
     let buf = render(&app, &data);
@@ -2157,3 +2182,102 @@ fn provider_detail_keys_line_does_not_include_q_back() {
         "provider detail inline keys should not include q=back"
     );
 }
+
+#[test]
+fn providers_grouped_by_category_render_section_headers() {
+    let _lock = lock_env();
+    let _no_color = EnvGuard::remove("NO_COLOR");
+
+    let mut app = App::new(Some(AppType::Claude));
+    app.route = Route::Providers;
+    app.focus = Focus::Content;
+    app.provider_grouping = crate::cli::tui::app::ProviderGrouping::Category;
+
+    let mut data = minimal_data(&app.app_type);
+    let mut official = Provider::with_id(
+        "p2".to_string(),
+        "Official One".to_string(),
+        json!({}),
+        None,
+    );
+    official.category = Some("official".to_string());
+    data.providers.rows.push(ProviderRow {
+        id: "p2".to_string(),
+        provider: official,
+        api_url: None,
+        is_current: false,
+    });
+
+    let buf = render(&app, &data);
+    let lines: Vec<String> = (0..buf.area.height).map(|y| line_at(&buf, y)).collect();
+    let header_y = lines
+        .iter()
+        .position(|line| line.contains("── official"))
+        .expect("category header missing");
+    assert!(lines[header_y + 1].contains("Official One"));
+    let fallback_y = lines
+        .iter()
+        .position(|line| line.contains("Demo Provider"))
+        .expect("uncategorized provider missing");
+    assert!(
+        fallback_y > header_y + 1,
+        "uncategorized group should render last"
+    );
+}
+
+#[test]
+fn current_provider_shows_drift_marker_when_snapshot_differs_from_live() {
+    let _lock = lock_env();
+    let _no_color = EnvGuard::remove("NO_COLOR");
+
+    let mut app = App::new(Some(AppType::Claude));
+    app.route = Route::Providers;
+    app.focus = Focus::Content;
+
+    let mut data = minimal_data(&app.app_type);
+    let buf = render(&app, &data);
+    assert!(!all_text(&buf).contains("live drift"));
+
+    data.providers.rows[0].is_current = true;
+    data.providers.current_drifted = true;
+    let buf = render(&app, &data);
+    let lines: Vec<String> = (0..buf.area.height).map(|y| line_at(&buf, y)).collect();
+    let row = lines
+        .iter()
+        .find(|line| line.contains("≠ live drift"))
+        .expect("drift marker missing");
+    assert!(
+        row.contains("Demo Provider"),
+        "marker should follow the name: {row}"
+    );
+}
+
+#[test]
+fn long_provider_list_scrolls_selection_into_view_with_position_indicator() {
+    let _lock = lock_env();
+    let _no_color = EnvGuard::remove("NO_COLOR");
+
+    let mut app = App::new(Some(AppType::Claude));
+    app.route = Route::Providers;
+    app.focus = Focus::Content;
+    app.provider_idx = 149;
+
+    let mut data = minimal_data(&app.app_type);
+    data.providers.rows.clear();
+    for i in 0..240 {
+        data.providers.rows.push(ProviderRow {
+            id: format!("p{i}"),
+            provider: Provider::with_id(format!("p{i}"), format!("Row {i:03}"), json!({}), None),
+            api_url: None,
+            is_current: false,
+        });
+    }
+
+    let all = all_text(&render(&app, &data));
+    assert!(
+        all.contains("Row 149"),
+        "selected row should be scrolled into view"
+    );
+    assert!(!all.contains("Row 000"), "top rows scroll out of view");
+    assert!(all.contains("150/240"), "position indicator missing");
+}
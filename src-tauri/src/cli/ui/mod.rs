@@ -1,7 +1,9 @@
 pub mod colors;
 pub mod formatters;
+pub mod output;
 pub mod table;
 
 pub use colors::*;
 pub use formatters::*;
+pub use output::*;
 pub use table::*;
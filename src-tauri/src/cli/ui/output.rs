@@ -0,0 +1,53 @@
+//! 输出类命令共享的 `--output <file>` 处理
+
+use std::path::Path;
+
+use crate::cli::i18n::texts;
+use crate::error::AppError;
+
+use super::success;
+
+/// 输出目标为 stdout：未指定 `--output`，或指定为 `-`
+pub fn output_is_stdout(output: Option<&Path>) -> bool {
+    output.is_none_or(|path| path == Path::new("-"))
+}
+
+/// 将命令输出写到 `--output` 指定的文件（原子写入，自动创建父目录）；目标为 stdout 时直接打印
+pub fn emit_output(output: Option<&Path>, text: &str) -> Result<(), AppError> {
+    match output {
+        Some(path) if !output_is_stdout(output) => {
+            let mut text = text.to_string();
+            if !text.ends_with('\n') {
+                text.push('\n');
+            }
+            crate::config::write_text_file(path, &text)?;
+            eprintln!(
+                "{}",
+                success(&texts::output_written_to(&path.display().to_string()))
+            );
+        }
+        _ => println!("{}", text.trim_end_matches('\n')),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emit_output_writes_file_and_creates_parent_dirs() {
+        let dir = tempfile::TempDir::new().expect("create temp dir");
+        let path = dir.path().join("nested").join("out.txt");
+
+        emit_output(Some(&path), "hello").expect("write output");
+
+        assert_eq!(
+            std::fs::read_to_string(&path).expect("read output"),
+            "hello\n"
+        );
+        assert!(output_is_stdout(None));
+        assert!(output_is_stdout(Some(Path::new("-"))));
+        assert!(!output_is_stdout(Some(&path)));
+    }
+}
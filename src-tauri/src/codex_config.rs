@@ -23,6 +23,11 @@ pub fn get_codex_auth_path() -> PathBuf {
     get_codex_config_dir().join("auth.json")
 }
 
+/// 获取 Codex `.env` 路径（环境变量方式写入 API Key）
+pub fn get_codex_env_path() -> PathBuf {
+    get_codex_config_dir().join(".env")
+}
+
 /// 获取 Codex config.toml 路径
 pub fn get_codex_config_path() -> PathBuf {
     get_codex_config_dir().join("config.toml")
@@ -106,6 +111,58 @@ pub fn write_codex_live_atomic(
     Ok(())
 }
 
+/// 在 `.env` 文本中设置 `name`：替换已有的赋值（保留其它行），不存在时追加
+pub fn upsert_env_assignment(existing: &str, name: &str, value: &str) -> String {
+    let entry = format_env_assignment(name, value);
+    let mut replaced = false;
+    let mut lines: Vec<String> = Vec::new();
+    for line in existing.lines() {
+        let assigned = line
+            .trim_start()
+            .trim_start_matches("export ")
+            .split_once('=')
+            .map(|(key, _)| key.trim());
+        if assigned == Some(name) {
+            if !replaced {
+                lines.push(entry.clone());
+                replaced = true;
+            }
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+    if !replaced {
+        lines.push(entry);
+    }
+
+    format!("{}\n", lines.join("\n"))
+}
+
+/// 从 `.env` 文本中移除 `name` 的赋值，保留其它行；没有剩余内容时返回空字符串
+pub fn remove_env_assignment(existing: &str, name: &str) -> String {
+    let lines: Vec<&str> = existing
+        .lines()
+        .filter(|line| {
+            line.trim_start()
+                .trim_start_matches("export ")
+                .split_once('=')
+                .map(|(key, _)| key.trim())
+                != Some(name)
+        })
+        .collect();
+    if lines.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n", lines.join("\n"))
+    }
+}
+
+/// `NAME="value"`（转义双引号与反斜杠），同时适用于 `.env` 与 shell `export`
+pub fn format_env_assignment(name: &str, value: &str) -> String {
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("{name}=\"{escaped}\"")
+}
+
 /// 读取 `~/.codex/config.toml`，若不存在返回空字符串
 pub fn read_codex_config_text() -> Result<String, AppError> {
     let path = get_codex_config_path();
@@ -38,14 +38,71 @@ impl Database {
     }
 
     pub fn export_sql_string_for_sync(&self) -> Result<String, AppError> {
+        self.export_sql_string_for_sync_with(false)
+    }
+
+    /// 同步导出；`exclude_history` 时去掉每台机器各自的切换历史（供应商 `lastUsedAt`）
+    pub(crate) fn export_sql_string_for_sync_with(
+        &self,
+        exclude_history: bool,
+    ) -> Result<String, AppError> {
         let snapshot = self.snapshot_to_memory()?;
+        if exclude_history {
+            Self::strip_switch_history(&snapshot)?;
+        }
         Self::dump_sql(&snapshot, SYNC_SKIP_TABLES)
     }
 
     /// 导出为 SQLite 兼容的 SQL 文本文件
     pub fn export_sql(&self, target_path: &Path) -> Result<(), AppError> {
         let dump = self.export_sql_string()?;
+        Self::write_sql_dump(target_path, &dump)
+    }
+
+    /// 导出为 SQL 文本文件，但不包含已归档的供应商（及其端点）
+    pub fn export_sql_without_archived(&self, target_path: &Path) -> Result<(), AppError> {
+        let snapshot = self.snapshot_to_memory()?;
+        Self::strip_archived_providers(&snapshot)?;
+        let dump = Self::dump_sql(&snapshot, &[])?;
+        Self::write_sql_dump(target_path, &dump)
+    }
+
+    /// 导出确定性的 SQL 文本（`config export --canonical`）：省略生成时间，各表的行按列值排序，
+    /// JSON 文本字段按键名重新序列化；内容相同的数据库总是得到逐字节相同的输出
+    pub fn export_sql_string_canonical(&self, include_archived: bool) -> Result<String, AppError> {
+        let snapshot = self.snapshot_to_memory()?;
+        if !include_archived {
+            Self::strip_archived_providers(&snapshot)?;
+        }
+        Self::dump_sql_with(&snapshot, &[], true)
+    }
+
+    pub fn export_sql_canonical(
+        &self,
+        target_path: &Path,
+        include_archived: bool,
+    ) -> Result<(), AppError> {
+        let dump = self.export_sql_string_canonical(include_archived)?;
+        Self::write_sql_dump(target_path, &dump)
+    }
+
+    fn strip_archived_providers(snapshot: &Connection) -> Result<(), AppError> {
+        snapshot
+            .execute_batch(
+                "DELETE FROM providers
+                     WHERE json_valid(meta) AND json_extract(meta, '$.archived') = 1;
+                 DELETE FROM provider_endpoints
+                     WHERE NOT EXISTS (
+                         SELECT 1 FROM providers p
+                         WHERE p.id = provider_endpoints.provider_id
+                           AND p.app_type = provider_endpoints.app_type
+                     );",
+            )
+            .map_err(|e| AppError::Database(format!("排除已归档供应商失败: {e}")))?;
+        Ok(())
+    }
 
+    fn write_sql_dump(target_path: &Path, dump: &str) -> Result<(), AppError> {
         if let Some(parent) = target_path.parent() {
             fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
         }
@@ -55,17 +112,23 @@ impl Database {
 
     /// 从 SQL 字符串导入，返回生成的备份 ID（若无备份则为空字符串）
     pub fn import_sql_string(&self, sql_raw: &str) -> Result<String, AppError> {
-        self.import_sql_string_inner(sql_raw, &[])
+        self.import_sql_string_inner(sql_raw, &[], false)
     }
 
-    pub(crate) fn import_sql_string_for_sync(&self, sql_raw: &str) -> Result<String, AppError> {
-        self.import_sql_string_inner(sql_raw, SYNC_PRESERVE_TABLES)
+    /// 同步导入；`keep_local_history` 时保留本机的切换历史，不采用远端的 `lastUsedAt`
+    pub(crate) fn import_sql_string_for_sync(
+        &self,
+        sql_raw: &str,
+        keep_local_history: bool,
+    ) -> Result<String, AppError> {
+        self.import_sql_string_inner(sql_raw, SYNC_PRESERVE_TABLES, keep_local_history)
     }
 
     fn import_sql_string_inner(
         &self,
         sql_raw: &str,
         preserve_tables: &[&str],
+        keep_local_history: bool,
     ) -> Result<String, AppError> {
         let sql_content = sql_raw.trim_start_matches('\u{feff}');
         Self::validate_cc_switch_sql_export(sql_content)?;
@@ -73,7 +136,7 @@ impl Database {
         // 导入前备份现有数据库
         let backup_path = self.backup_database_file()?;
 
-        let local_snapshot = if preserve_tables.is_empty() {
+        let local_snapshot = if preserve_tables.is_empty() && !keep_local_history {
             None
         } else {
             Some(self.snapshot_to_memory()?)
@@ -98,6 +161,9 @@ impl Database {
         Self::validate_basic_state(&temp_conn)?;
         if let Some(local_snapshot) = local_snapshot.as_ref() {
             Self::restore_tables(local_snapshot, &temp_conn, preserve_tables)?;
+            if keep_local_history {
+                Self::carry_over_switch_history(local_snapshot, &temp_conn)?;
+            }
         }
 
         // 使用 Backup 将临时库原子写回主库
@@ -147,7 +213,53 @@ impl Database {
         Ok(snapshot)
     }
 
-    fn validate_cc_switch_sql_export(sql: &str) -> Result<(), AppError> {
+    /// 清除供应商元数据中的切换历史
+    pub(crate) fn strip_switch_history(conn: &Connection) -> Result<(), AppError> {
+        conn.execute(
+            "UPDATE providers SET meta = json_remove(meta, '$.lastUsedAt') WHERE json_valid(meta)",
+            [],
+        )
+        .map_err(|e| AppError::Database(format!("清除切换历史失败: {e}")))?;
+        Ok(())
+    }
+
+    /// 用本机的切换历史覆盖导入结果中的 `lastUsedAt`（本机没有记录的供应商则清除）
+    pub(crate) fn carry_over_switch_history(
+        local: &Connection,
+        target: &Connection,
+    ) -> Result<(), AppError> {
+        Self::strip_switch_history(target)?;
+
+        let mut stmt = local
+            .prepare(
+                "SELECT id, app_type, json_extract(meta, '$.lastUsedAt') FROM providers
+                 WHERE json_valid(meta) AND json_extract(meta, '$.lastUsedAt') IS NOT NULL",
+            )
+            .map_err(|e| AppError::Database(e.to_string()))?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, i64>(2)?,
+                ))
+            })
+            .map_err(|e| AppError::Database(e.to_string()))?;
+        for row in rows {
+            let (id, app_type, last_used_at) =
+                row.map_err(|e| AppError::Database(e.to_string()))?;
+            target
+                .execute(
+                    "UPDATE providers SET meta = json_set(meta, '$.lastUsedAt', ?3)
+                     WHERE id = ?1 AND app_type = ?2 AND json_valid(meta)",
+                    rusqlite::params![id, app_type, last_used_at],
+                )
+                .map_err(|e| AppError::Database(format!("恢复切换历史失败: {e}")))?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn validate_cc_switch_sql_export(sql: &str) -> Result<(), AppError> {
         let trimmed = sql.trim_start();
         if trimmed.starts_with(CC_SWITCH_SQL_EXPORT_HEADER) {
             return Ok(());
@@ -306,15 +418,29 @@ impl Database {
 
     /// 导出数据库为 SQL 文本
     fn dump_sql(conn: &Connection, skip_data_tables: &[&str]) -> Result<String, AppError> {
+        Self::dump_sql_with(conn, skip_data_tables, false)
+    }
+
+    fn dump_sql_with(
+        conn: &Connection,
+        skip_data_tables: &[&str],
+        canonical: bool,
+    ) -> Result<String, AppError> {
         let mut output = String::new();
-        let timestamp = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
         let user_version: i64 = conn
             .query_row("PRAGMA user_version;", [], |row| row.get(0))
             .unwrap_or(0);
 
-        output.push_str(&format!(
-            "-- CC Switch SQLite 导出\n-- 生成时间: {timestamp}\n-- user_version: {user_version}\n"
-        ));
+        if canonical {
+            output.push_str(&format!(
+                "-- CC Switch SQLite 导出\n-- user_version: {user_version}\n"
+            ));
+        } else {
+            let timestamp = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+            output.push_str(&format!(
+                "-- CC Switch SQLite 导出\n-- 生成时间: {timestamp}\n-- user_version: {user_version}\n"
+            ));
+        }
         output.push_str("PRAGMA foreign_keys=OFF;\n");
         output.push_str(&format!("PRAGMA user_version={user_version};\n"));
         output.push_str("BEGIN TRANSACTION;\n");
@@ -362,8 +488,17 @@ impl Database {
                 continue;
             }
 
+            let query = if canonical {
+                let order = (1..=columns.len())
+                    .map(|idx| idx.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("SELECT * FROM \"{table}\" ORDER BY {order}")
+            } else {
+                format!("SELECT * FROM \"{table}\"")
+            };
             let mut stmt = conn
-                .prepare(&format!("SELECT * FROM \"{table}\""))
+                .prepare(&query)
                 .map_err(|e| AppError::Database(e.to_string()))?;
             let mut rows = stmt
                 .query([])
@@ -375,7 +510,13 @@ impl Database {
                     let value = row
                         .get_ref(idx)
                         .map_err(|e| AppError::Database(e.to_string()))?;
-                    values.push(Self::format_sql_value(value)?);
+                    let canonical_json = canonical
+                        .then(|| Self::canonical_json_text(value))
+                        .flatten();
+                    values.push(match canonical_json {
+                        Some(text) => Self::format_sql_value(ValueRef::Text(text.as_bytes()))?,
+                        None => Self::format_sql_value(value)?,
+                    });
                 }
 
                 let cols = columns
@@ -411,6 +552,19 @@ impl Database {
     }
 
     /// 格式化 SQL 值
+    /// JSON 对象/数组文本按键名重新序列化（serde_json 的 Map 按键排序），其余值原样返回
+    fn canonical_json_text(value: ValueRef<'_>) -> Option<String> {
+        let ValueRef::Text(bytes) = value else {
+            return None;
+        };
+        let text = std::str::from_utf8(bytes).ok()?;
+        if !text.trim_start().starts_with(['{', '[']) {
+            return None;
+        }
+        let parsed: serde_json::Value = serde_json::from_str(text).ok()?;
+        serde_json::to_string(&parsed).ok()
+    }
+
     fn format_sql_value(value: ValueRef<'_>) -> Result<String, AppError> {
         match value {
             ValueRef::Null => Ok("NULL".to_string()),
@@ -440,6 +594,56 @@ mod tests {
     use super::Database;
     use crate::error::AppError;
 
+    #[test]
+    fn canonical_export_ignores_row_and_json_key_order() -> Result<(), AppError> {
+        let insert = |db: &Database, rows: &[(&str, &str)]| -> Result<(), AppError> {
+            let conn = crate::database::lock_conn!(db.conn);
+            for (id, settings) in rows {
+                conn.execute(
+                    "INSERT INTO providers (id, app_type, name, settings_config, meta)
+                     VALUES (?1, 'claude', ?1, ?2, '{}')",
+                    rusqlite::params![id, settings],
+                )?;
+            }
+            // 种子行的时间戳取自建库时刻，两个库跨秒创建时会不同
+            conn.execute(
+                "UPDATE proxy_config SET created_at = '2026-01-01 00:00:00',
+                     updated_at = '2026-01-01 00:00:00'",
+                [],
+            )?;
+            Ok(())
+        };
+        let first = Database::memory()?;
+        insert(
+            &first,
+            &[
+                (
+                    "a",
+                    r#"{"env":{"ANTHROPIC_MODEL":"m","ANTHROPIC_BASE_URL":"u"}}"#,
+                ),
+                ("b", r#"{"model":"x","env":{}}"#),
+            ],
+        )?;
+        let second = Database::memory()?;
+        insert(
+            &second,
+            &[
+                ("b", r#"{"env":{},"model":"x"}"#),
+                (
+                    "a",
+                    r#"{"env":{"ANTHROPIC_BASE_URL":"u","ANTHROPIC_MODEL":"m"}}"#,
+                ),
+            ],
+        )?;
+
+        assert_ne!(first.export_sql_string()?, second.export_sql_string()?);
+        assert_eq!(
+            first.export_sql_string_canonical(true)?,
+            second.export_sql_string_canonical(true)?
+        );
+        Ok(())
+    }
+
     #[test]
     fn sync_import_preserves_local_only_tables() -> Result<(), AppError> {
         let remote_db = Database::memory()?;
@@ -486,7 +690,7 @@ mod tests {
             )?;
         }
 
-        local_db.import_sql_string_for_sync(&remote_sql)?;
+        local_db.import_sql_string_for_sync(&remote_sql, false)?;
 
         let remote_provider_exists: i64 = {
             let conn = crate::database::lock_conn!(local_db.conn);
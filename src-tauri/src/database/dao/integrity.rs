@@ -0,0 +1,126 @@
+//! 数据完整性检查
+//!
+//! 检测 providers 表中 `(id, app_type)` 重复的行（旧版无主键的表或原始 SQL 导入可能引入），
+//! 并通过为重复行追加后缀的方式修复，避免载入 `IndexMap` 时静默丢弃数据。
+
+use crate::database::{lock_conn, Database};
+use crate::error::AppError;
+use rusqlite::params;
+
+/// 同一应用下出现多次的供应商 ID
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateProviderId {
+    pub app_type: String,
+    pub id: String,
+    pub count: usize,
+}
+
+/// 修复时被重新分配 ID 的行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderIdRekey {
+    pub app_type: String,
+    pub old_id: String,
+    pub new_id: String,
+}
+
+impl Database {
+    /// 查找 `(id, app_type)` 重复的供应商行
+    pub fn find_duplicate_provider_ids(&self) -> Result<Vec<DuplicateProviderId>, AppError> {
+        let conn = lock_conn!(self.conn);
+        let mut stmt = conn
+            .prepare(
+                "SELECT app_type, id, COUNT(*) FROM providers
+                 GROUP BY app_type, id HAVING COUNT(*) > 1
+                 ORDER BY app_type, id",
+            )
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok(DuplicateProviderId {
+                    app_type: row.get(0)?,
+                    id: row.get(1)?,
+                    count: row.get::<_, i64>(2)? as usize,
+                })
+            })
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AppError::Database(e.to_string()))
+    }
+
+    /// 为重复行重新分配 ID（保留最早的一行，其余追加 `-dupN` 后缀），返回修改列表
+    ///
+    /// 被重新分配的行不再标记为当前供应商。
+    pub fn rekey_duplicate_provider_ids(&self) -> Result<Vec<ProviderIdRekey>, AppError> {
+        let duplicates = self.find_duplicate_provider_ids()?;
+        if duplicates.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = lock_conn!(self.conn);
+        // 旧表没有主键时外键父表不唯一，更新会触发 foreign key mismatch；修复期间临时关闭
+        conn.execute("PRAGMA foreign_keys = OFF;", [])
+            .map_err(|e| AppError::Database(e.to_string()))?;
+
+        let result = (|| {
+            let tx = conn
+                .transaction()
+                .map_err(|e| AppError::Database(e.to_string()))?;
+            let mut changes = Vec::new();
+
+            for dup in &duplicates {
+                let rowids: Vec<i64> = {
+                    let mut stmt = tx
+                        .prepare(
+                            "SELECT rowid FROM providers WHERE app_type = ?1 AND id = ?2
+                             ORDER BY rowid",
+                        )
+                        .map_err(|e| AppError::Database(e.to_string()))?;
+                    let rows = stmt
+                        .query_map(params![dup.app_type, dup.id], |row| row.get(0))
+                        .map_err(|e| AppError::Database(e.to_string()))?;
+                    rows.collect::<Result<Vec<_>, _>>()
+                        .map_err(|e| AppError::Database(e.to_string()))?
+                };
+
+                let mut suffix = 1;
+                for rowid in rowids.into_iter().skip(1) {
+                    let new_id = loop {
+                        let candidate = format!("{}-dup{suffix}", dup.id);
+                        suffix += 1;
+                        let taken: i64 = tx
+                            .query_row(
+                                "SELECT COUNT(*) FROM providers WHERE app_type = ?1 AND id = ?2",
+                                params![dup.app_type, candidate],
+                                |row| row.get(0),
+                            )
+                            .map_err(|e| AppError::Database(e.to_string()))?;
+                        if taken == 0 {
+                            break candidate;
+                        }
+                    };
+
+                    tx.execute(
+                        "UPDATE providers SET id = ?1, is_current = 0 WHERE rowid = ?2",
+                        params![new_id, rowid],
+                    )
+                    .map_err(|e| AppError::Database(e.to_string()))?;
+
+                    changes.push(ProviderIdRekey {
+                        app_type: dup.app_type.clone(),
+                        old_id: dup.id.clone(),
+                        new_id,
+                    });
+                }
+            }
+
+            tx.commit().map_err(|e| AppError::Database(e.to_string()))?;
+            Ok(changes)
+        })();
+
+        conn.execute("PRAGMA foreign_keys = ON;", [])
+            .map_err(|e| AppError::Database(e.to_string()))?;
+        result
+    }
+}
@@ -3,6 +3,7 @@
 //! Database access operations for each domain
 
 pub mod failover;
+pub mod integrity;
 pub mod mcp;
 pub mod prompts;
 pub mod providers;
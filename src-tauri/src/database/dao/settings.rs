@@ -5,6 +5,19 @@
 use crate::database::{lock_conn, Database};
 use crate::error::AppError;
 use rusqlite::params;
+use serde::{Deserialize, Serialize};
+
+/// 通用配置片段历史最多保留的版本数
+pub const CONFIG_SNIPPET_HISTORY_LIMIT: usize = 5;
+
+/// 被覆盖的通用配置片段版本
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigSnippetVersion {
+    pub snippet: String,
+    /// 被替换的时间（Unix 秒）
+    pub replaced_at: i64,
+}
 
 impl Database {
     /// 获取设置值
@@ -53,13 +66,32 @@ impl Database {
         self.get_setting(&format!("common_config_{app_type}"))
     }
 
-    /// 设置通用配置片段
+    /// 设置通用配置片段；内容变化时把旧版本记入历史（最多保留 `CONFIG_SNIPPET_HISTORY_LIMIT` 个）
     pub fn set_config_snippet(
         &self,
         app_type: &str,
         snippet: Option<String>,
     ) -> Result<(), AppError> {
         let key = format!("common_config_{app_type}");
+        let previous = self.get_setting(&key)?;
+        if previous == snippet {
+            return Ok(());
+        }
+        if let Some(previous) = previous.filter(|value| !value.trim().is_empty()) {
+            let mut history = self.get_config_snippet_history(app_type)?;
+            history.insert(
+                0,
+                ConfigSnippetVersion {
+                    snippet: previous,
+                    replaced_at: chrono::Utc::now().timestamp(),
+                },
+            );
+            history.truncate(CONFIG_SNIPPET_HISTORY_LIMIT);
+            let raw = serde_json::to_string(&history)
+                .map_err(|e| AppError::Database(format!("序列化片段历史失败: {e}")))?;
+            self.set_setting(&format!("common_config_history_{app_type}"), &raw)?;
+        }
+
         if let Some(value) = snippet {
             self.set_setting(&key, &value)
         } else {
@@ -67,6 +99,20 @@ impl Database {
         }
     }
 
+    /// 获取通用配置片段的历史版本（最新被替换的在前）
+    pub fn get_config_snippet_history(
+        &self,
+        app_type: &str,
+    ) -> Result<Vec<ConfigSnippetVersion>, AppError> {
+        let Some(raw) = self.get_setting(&format!("common_config_history_{app_type}"))? else {
+            return Ok(Vec::new());
+        };
+        Ok(serde_json::from_str(&raw).unwrap_or_else(|e| {
+            log::warn!("通用配置片段历史已损坏，忽略: {e}");
+            Vec::new()
+        }))
+    }
+
     // --- 全局出站代理 ---
 
     /// 全局代理 URL 的存储键名
@@ -2,6 +2,8 @@
 //!
 //! 包含 Schema 迁移和基本功能的测试。
 
+use super::dao::integrity::{DuplicateProviderId, ProviderIdRekey};
+use super::dao::settings::CONFIG_SNIPPET_HISTORY_LIMIT;
 use super::*;
 use crate::app_config::MultiAppConfig;
 use crate::provider::{Provider, ProviderManager};
@@ -744,3 +746,168 @@ fn schema_model_pricing_is_seeded_on_init() {
         gemini_count
     );
 }
+
+#[test]
+fn duplicate_provider_ids_are_detected_and_rekeyed_without_data_loss() {
+    let db = Database::memory().expect("create memory db");
+    {
+        let conn = db.conn.lock().expect("lock conn");
+        // 模拟旧版/原始导入的无主键 providers 表
+        conn.execute_batch(
+            "PRAGMA foreign_keys = OFF;
+             DROP TABLE providers;
+             CREATE TABLE providers (
+                 id TEXT NOT NULL,
+                 app_type TEXT NOT NULL,
+                 name TEXT NOT NULL,
+                 settings_config TEXT NOT NULL,
+                 website_url TEXT,
+                 category TEXT,
+                 created_at INTEGER,
+                 sort_index INTEGER,
+                 notes TEXT,
+                 icon TEXT,
+                 icon_color TEXT,
+                 meta TEXT NOT NULL DEFAULT '{}',
+                 is_current BOOLEAN NOT NULL DEFAULT 0,
+                 in_failover_queue BOOLEAN NOT NULL DEFAULT 0
+             );
+             INSERT INTO providers (id, app_type, name, settings_config, is_current)
+                 VALUES ('p1', 'claude', 'First', '{}', 1);
+             INSERT INTO providers (id, app_type, name, settings_config, is_current)
+                 VALUES ('p1', 'claude', 'Second', '{}', 1);
+             INSERT INTO providers (id, app_type, name, settings_config)
+                 VALUES ('p1-dup1', 'claude', 'Existing', '{}');
+             INSERT INTO providers (id, app_type, name, settings_config)
+                 VALUES ('p1', 'codex', 'Codex', '{}');
+             PRAGMA foreign_keys = ON;",
+        )
+        .expect("seed duplicate rows");
+    }
+
+    let duplicates = db.find_duplicate_provider_ids().expect("find duplicates");
+    assert_eq!(
+        duplicates,
+        vec![DuplicateProviderId {
+            app_type: "claude".to_string(),
+            id: "p1".to_string(),
+            count: 2,
+        }]
+    );
+
+    let changes = db.rekey_duplicate_provider_ids().expect("rekey duplicates");
+    assert_eq!(
+        changes,
+        vec![ProviderIdRekey {
+            app_type: "claude".to_string(),
+            old_id: "p1".to_string(),
+            new_id: "p1-dup2".to_string(),
+        }]
+    );
+    assert!(db
+        .find_duplicate_provider_ids()
+        .expect("find after repair")
+        .is_empty());
+
+    let providers = db.get_all_providers("claude").expect("load providers");
+    assert_eq!(providers.len(), 3, "both duplicates must be preserved");
+    assert_eq!(providers["p1"].name, "First");
+    assert_eq!(providers["p1-dup2"].name, "Second");
+    assert_eq!(
+        db.get_current_provider("claude").expect("current"),
+        Some("p1".to_string())
+    );
+}
+
+#[test]
+fn sync_export_can_exclude_switch_history_and_import_keeps_local_history() {
+    let seed = |db: &Database, rows: &str| {
+        let conn = db.conn.lock().expect("lock conn");
+        conn.execute_batch(rows).expect("seed providers");
+    };
+
+    let remote = Database::memory().expect("create remote db");
+    seed(
+        &remote,
+        r#"INSERT INTO providers (id, app_type, name, settings_config, meta)
+               VALUES ('a', 'claude', 'A', '{}', '{"lastUsedAt":200,"costMultiplier":"1.5"}');
+           INSERT INTO providers (id, app_type, name, settings_config, meta)
+               VALUES ('b', 'claude', 'B', '{}', '{"lastUsedAt":300}');"#,
+    );
+
+    let full = remote
+        .export_sql_string_for_sync_with(false)
+        .expect("export full");
+    assert!(full.contains("lastUsedAt"));
+    let filtered = remote
+        .export_sql_string_for_sync_with(true)
+        .expect("export without history");
+    assert!(!filtered.contains("lastUsedAt"));
+    assert!(filtered.contains("costMultiplier"));
+
+    let local = Database::memory().expect("create local db");
+    seed(
+        &local,
+        r#"INSERT INTO providers (id, app_type, name, settings_config, meta)
+               VALUES ('a', 'claude', 'A', '{}', '{"lastUsedAt":100}');"#,
+    );
+
+    let local_conn = local.conn.lock().expect("lock local");
+    let remote_conn = remote.conn.lock().expect("lock remote");
+    Database::carry_over_switch_history(&local_conn, &remote_conn).expect("carry over history");
+
+    let meta_of = |id: &str| -> serde_json::Value {
+        let raw: String = remote_conn
+            .query_row(
+                "SELECT meta FROM providers WHERE id = ?1 AND app_type = 'claude'",
+                params![id],
+                |row| row.get(0),
+            )
+            .expect("read meta");
+        serde_json::from_str(&raw).expect("parse meta")
+    };
+    assert_eq!(
+        meta_of("a"),
+        json!({"lastUsedAt": 100, "costMultiplier": "1.5"})
+    );
+    assert_eq!(meta_of("b"), json!({}));
+}
+
+#[test]
+fn config_snippet_history_keeps_replaced_versions_up_to_limit() {
+    let db = Database::memory().expect("create memory db");
+
+    db.set_config_snippet("claude", Some("v0".to_string()))
+        .expect("set first snippet");
+    assert!(db
+        .get_config_snippet_history("claude")
+        .expect("empty history")
+        .is_empty());
+
+    // 内容不变时不记录历史
+    db.set_config_snippet("claude", Some("v0".to_string()))
+        .expect("set same snippet");
+    assert!(db
+        .get_config_snippet_history("claude")
+        .expect("still empty")
+        .is_empty());
+
+    for version in 1..=CONFIG_SNIPPET_HISTORY_LIMIT + 1 {
+        db.set_config_snippet("claude", Some(format!("v{version}")))
+            .expect("replace snippet");
+    }
+    db.set_config_snippet("claude", None)
+        .expect("clear snippet");
+
+    let history = db.get_config_snippet_history("claude").expect("history");
+    let snippets: Vec<String> = history.into_iter().map(|v| v.snippet).collect();
+    let expected: Vec<String> = (2..=CONFIG_SNIPPET_HISTORY_LIMIT + 1)
+        .rev()
+        .map(|version| format!("v{version}"))
+        .collect();
+    assert_eq!(snippets, expected);
+    assert!(db
+        .get_config_snippet_history("codex")
+        .expect("other app")
+        .is_empty());
+}
@@ -10,7 +10,9 @@ mod utils;
 use serde::{Deserialize, Serialize};
 
 pub use parser::parse_deeplink_url;
-pub use provider::import_provider_from_deeplink;
+pub use provider::{
+    import_provider_from_deeplink, prepare_provider_from_deeplink, DeepLinkProviderImport,
+};
 
 /// Deep link import request model.
 ///
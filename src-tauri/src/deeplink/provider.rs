@@ -10,11 +10,40 @@ use crate::AppType;
 use serde_json::json;
 use std::str::FromStr;
 
+/// Provider built from a deep link, validated but not yet saved.
+#[derive(Debug, Clone)]
+pub struct DeepLinkProviderImport {
+    pub app_type: AppType,
+    pub provider: Provider,
+    /// The link asks to switch to the provider right after import (`enabled=true`).
+    pub switch_after_import: bool,
+}
+
 /// Import a provider from a deep link request.
 pub fn import_provider_from_deeplink(
     state: &AppState,
     request: DeepLinkImportRequest,
 ) -> Result<String, AppError> {
+    let DeepLinkProviderImport {
+        app_type,
+        provider,
+        switch_after_import,
+    } = prepare_provider_from_deeplink(&request)?;
+    let provider_id = provider.id.clone();
+
+    ProviderService::add(state, app_type.clone(), provider)?;
+
+    if switch_after_import {
+        ProviderService::switch(state, app_type, &provider_id)?;
+    }
+
+    Ok(provider_id)
+}
+
+/// Validate a deep link request and build the provider it describes without touching any state.
+pub fn prepare_provider_from_deeplink(
+    request: &DeepLinkImportRequest,
+) -> Result<DeepLinkProviderImport, AppError> {
     if request.resource != "provider" {
         return Err(AppError::InvalidInput(format!(
             "Expected provider resource, got '{}'",
@@ -22,7 +51,7 @@ pub fn import_provider_from_deeplink(
         )));
     }
 
-    let mut merged_request = parse_and_merge_config(&request)?;
+    let mut merged_request = parse_and_merge_config(request)?;
 
     let app_str = merged_request
         .app
@@ -119,15 +148,12 @@ pub fn import_provider_from_deeplink(
         .collect::<String>()
         .to_lowercase();
     provider.id = format!("{sanitized_name}-{timestamp}");
-    let provider_id = provider.id.clone();
-
-    ProviderService::add(state, app_type.clone(), provider)?;
 
-    if merged_request.enabled == Some(true) {
-        ProviderService::switch(state, app_type, &provider_id)?;
-    }
-
-    Ok(provider_id)
+    Ok(DeepLinkProviderImport {
+        app_type,
+        provider,
+        switch_after_import: merged_request.enabled == Some(true),
+    })
 }
 
 fn build_provider_from_request(
@@ -52,6 +52,8 @@ pub enum AppError {
         zh: String,
         en: String,
     },
+    #[error("供应商不存在: {id} (Provider not found: {id})")]
+    ProviderNotFound { id: String },
 }
 
 impl AppError {
@@ -83,6 +85,47 @@ impl AppError {
             en: en.into(),
         }
     }
+
+    pub fn provider_not_found(id: impl Into<String>) -> Self {
+        Self::ProviderNotFound { id: id.into() }
+    }
+
+    /// 稳定的错误码：本地化错误使用其 key，其余按变体区分
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Config(_) => "config",
+            Self::Database(_) => "database",
+            Self::InvalidInput(_) => "invalid_input",
+            Self::Io { .. } | Self::IoContext { .. } => "io",
+            Self::Json { .. } => "json",
+            Self::JsonSerialize { .. } => "json_serialize",
+            Self::Toml { .. } => "toml",
+            Self::Lock(_) => "lock",
+            Self::McpValidation(_) => "mcp_validation",
+            Self::Message(_) => "message",
+            Self::Localized { key, .. } => key,
+            Self::ProviderNotFound { .. } => "provider.not_found",
+        }
+    }
+
+    /// 结构化错误（`--errors json`）：`code`、`message` 以及变体携带的字段
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::json;
+
+        let mut obj = serde_json::Map::new();
+        obj.insert("code".to_string(), json!(self.code()));
+        obj.insert("message".to_string(), json!(self.to_string()));
+        match self {
+            Self::Io { path, .. } | Self::Json { path, .. } | Self::Toml { path, .. } => {
+                obj.insert("path".to_string(), json!(path));
+            }
+            Self::ProviderNotFound { id } => {
+                obj.insert("providerId".to_string(), json!(id));
+            }
+            _ => {}
+        }
+        serde_json::Value::Object(obj)
+    }
 }
 
 impl<T> From<PoisonError<T>> for AppError {
@@ -127,3 +170,29 @@ pub fn format_skill_error(
         format!("ERROR:{code}")
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn provider_not_found_serializes_code_message_and_id() {
+        let err = AppError::provider_not_found("missing");
+        assert_eq!(
+            err.to_json(),
+            json!({
+                "code": "provider.not_found",
+                "message": "供应商不存在: missing (Provider not found: missing)",
+                "providerId": "missing",
+            })
+        );
+    }
+
+    #[test]
+    fn localized_error_uses_key_as_code() {
+        let err = AppError::localized("provider.tag.no_match", "中文", "english");
+        assert_eq!(err.to_json()["code"], json!("provider.tag.no_match"));
+        assert_eq!(AppError::Message("x".into()).code(), "message");
+    }
+}
@@ -18,6 +18,7 @@ mod prompt_files;
 mod provider;
 mod provider_defaults;
 mod proxy;
+mod redact;
 mod services;
 mod settings;
 mod store;
@@ -28,14 +29,17 @@ mod usage_script;
 pub mod cli;
 
 // Public exports
-pub use app_config::{AppType, McpApps, McpServer, MultiAppConfig};
+pub use app_config::{AppType, InstalledSkill, McpApps, McpServer, MultiAppConfig, SkillApps};
 pub use claude_plugin::{
     sync_claude_plugin_on_provider_switch, sync_claude_plugin_on_settings_toggle,
 };
 pub use codex_config::{get_codex_auth_path, get_codex_config_path, write_codex_live_atomic};
 pub use config::{get_claude_mcp_path, get_claude_settings_path, read_json_file};
 pub use database::{Database, FailoverQueueItem};
-pub use deeplink::{import_provider_from_deeplink, parse_deeplink_url, DeepLinkImportRequest};
+pub use deeplink::{
+    import_provider_from_deeplink, parse_deeplink_url, prepare_provider_from_deeplink,
+    DeepLinkImportRequest, DeepLinkProviderImport,
+};
 pub use error::AppError;
 pub use import_export::export_config_to_file;
 pub use mcp::{
@@ -44,17 +48,29 @@ pub use mcp::{
     sync_enabled_to_codex, sync_enabled_to_gemini, sync_single_server_to_claude,
     sync_single_server_to_codex, sync_single_server_to_gemini,
 };
-pub use provider::{Provider, ProviderMeta};
+pub use prompt::Prompt;
+pub use provider::{CodexAuthTarget, Provider, ProviderMeta};
+pub use proxy::http_client::{
+    resolve_timeout, set_network_timeout_override, transfer_client_builder,
+};
 pub use proxy::{ProxyConfig, ProxyServerInfo, ProxyStatus};
 pub use services::{
-    ConfigService, EndpointLatency, HealthStatus, McpService, PromptService, ProviderService,
-    ProxyService, SkillService, SpeedtestService, StreamCheckConfig, StreamCheckResult,
-    StreamCheckService, SyncDecision, WebDavSyncService, WebDavSyncSummary,
+    AppValidationReport, BundleImportSummary, BundleProviderStatus, BundleSelection, ChangeCounts,
+    ConfigService, EndpointLatency, HealthStatus, ImportChangeReport, LegacyImportOutcome,
+    LiveFileWrite, McpCheckLevel, McpFileImportSummary, McpImportPreview, McpServerCheck,
+    McpServerCheckResult, McpServerSyncFailure, McpService, McpSyncAppResult, McpSyncOutcome,
+    PromptService, ProviderService, ProxyService, SettingsPatch, SkillService, SkillUpdateAction,
+    SpeedtestService, StreamCheckConfig, StreamCheckResult, StreamCheckService, SwitchOptions,
+    SwitchOutcome, SyncDecision, WebDavSyncService, WebDavSyncSummary,
 };
 pub use settings::{
-    get_enable_claude_plugin_integration, get_skip_claude_onboarding, get_webdav_sync_settings,
-    set_enable_claude_plugin_integration, set_skip_claude_onboarding, set_webdav_sync_settings,
-    update_settings, update_webdav_sync_status, webdav_jianguoyun_preset, AppSettings,
-    WebDavSyncSettings, WebDavSyncStatus,
+    get_auto_backup_settings, get_default_model, get_enable_claude_plugin_integration,
+    get_no_backfill_extract, get_provider_notes_template, get_skip_claude_onboarding,
+    get_webdav_sync_settings, set_auto_backup_interval_hours, set_backup_max_bytes,
+    set_default_model, set_enable_claude_plugin_integration, set_network_timeout_ms,
+    set_no_backfill_extract, set_provider_notes_template, set_redaction_patterns,
+    set_skip_claude_onboarding, set_webdav_sync_settings, update_settings,
+    update_webdav_sync_status, webdav_jianguoyun_preset, AppSettings, AutoBackupSettings,
+    McpSyncSettings, WebDavSyncSettings, WebDavSyncStatus,
 };
 pub use store::AppState;
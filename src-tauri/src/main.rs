@@ -1,4 +1,4 @@
-use cc_switch_lib::cli::{Cli, Commands};
+use cc_switch_lib::cli::{single_app, Cli, Commands, ErrorFormat};
 use cc_switch_lib::AppError;
 use clap::Parser;
 use std::process;
@@ -15,36 +15,53 @@ fn main() {
     };
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(log_level)).init();
 
+    if let Some(ms) = cli.network_timeout_ms.filter(|ms| *ms > 0) {
+        cc_switch_lib::set_network_timeout_override(std::time::Duration::from_millis(ms));
+    }
+
     // 执行命令
+    let error_format = cli.errors;
     if let Err(e) = run(cli) {
-        eprintln!("Error: {}", e);
+        match error_format {
+            ErrorFormat::Text => eprintln!("Error: {}", e),
+            ErrorFormat::Json => eprintln!("{}", e.to_json()),
+        }
         process::exit(1);
     }
 }
 
 fn run(cli: Cli) -> Result<(), AppError> {
-    if !matches!(cli.command, Some(Commands::Completions { .. })) {
+    if !matches!(cli.command, Some(Commands::Completions(_))) {
         let _state = cc_switch_lib::AppState::try_new_with_startup_recovery()?;
+
+        // 定时自动备份：失败只记录日志，不影响本次命令
+        let now = chrono::Utc::now().timestamp();
+        if let Err(e) = cc_switch_lib::ConfigService::auto_backup_if_due(now) {
+            log::warn!("自动备份失败: {e}");
+        }
     }
 
     match cli.command {
         // Default to interactive mode if no command is provided
-        None | Some(Commands::Interactive) => cc_switch_lib::cli::interactive::run(cli.app),
+        None | Some(Commands::Interactive) => {
+            cc_switch_lib::cli::interactive::run(single_app(cli.app)?)
+        }
         Some(Commands::Provider(cmd)) => {
-            cc_switch_lib::cli::commands::provider::execute(cmd, cli.app)
+            cc_switch_lib::cli::commands::provider::execute(cmd, cli.app, cli.verbose)
+        }
+        Some(Commands::Mcp(cmd)) => {
+            cc_switch_lib::cli::commands::mcp::execute(cmd, single_app(cli.app)?)
         }
-        Some(Commands::Mcp(cmd)) => cc_switch_lib::cli::commands::mcp::execute(cmd, cli.app),
         Some(Commands::Prompts(cmd)) => {
-            cc_switch_lib::cli::commands::prompts::execute(cmd, cli.app)
+            cc_switch_lib::cli::commands::prompts::execute(cmd, single_app(cli.app)?)
+        }
+        Some(Commands::Skills(cmd)) => {
+            cc_switch_lib::cli::commands::skills::execute(cmd, single_app(cli.app)?)
         }
-        Some(Commands::Skills(cmd)) => cc_switch_lib::cli::commands::skills::execute(cmd, cli.app),
         Some(Commands::Config(cmd)) => cc_switch_lib::cli::commands::config::execute(cmd, cli.app),
         Some(Commands::Proxy(cmd)) => cc_switch_lib::cli::commands::proxy::execute(cmd),
         Some(Commands::Env(cmd)) => cc_switch_lib::cli::commands::env::execute(cmd, cli.app),
         Some(Commands::Update(cmd)) => cc_switch_lib::cli::commands::update::execute(cmd),
-        Some(Commands::Completions { shell }) => {
-            cc_switch_lib::cli::generate_completions(shell);
-            Ok(())
-        }
+        Some(Commands::Completions(cmd)) => cc_switch_lib::cli::commands::completions::execute(cmd),
     }
 }
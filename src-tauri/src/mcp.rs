@@ -1,5 +1,5 @@
 use serde_json::{json, Value};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::app_config::{AppType, McpConfig, MultiAppConfig};
 use crate::error::AppError;
@@ -640,6 +640,40 @@ pub fn import_from_codex(config: &mut MultiAppConfig) -> Result<usize, AppError>
     Ok(changed_total)
 }
 
+/// 读取指定应用 live 配置中已存在的 MCP 服务器 ID（配置文件不存在时为空）
+pub fn live_server_ids_for(app: &AppType) -> Result<HashSet<String>, AppError> {
+    let ids = match app {
+        AppType::Claude => crate::claude_mcp::read_mcp_servers_map()?
+            .into_keys()
+            .collect(),
+        AppType::Gemini => crate::gemini_mcp::read_mcp_servers_map()?
+            .into_keys()
+            .collect(),
+        AppType::OpenCode => crate::opencode_config::get_mcp_servers()?
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect(),
+        AppType::Codex => {
+            let text = crate::codex_config::read_and_validate_codex_config_text()?;
+            let root: toml::Table = toml::from_str(&text).map_err(|e| {
+                AppError::McpValidation(format!("解析 ~/.codex/config.toml 失败: {e}"))
+            })?;
+            // 同时识别旧格式 [mcp.servers] 与标准格式 [mcp_servers]
+            let legacy = root
+                .get("mcp")
+                .and_then(|v| v.get("servers"))
+                .and_then(|v| v.as_table());
+            let standard = root.get("mcp_servers").and_then(|v| v.as_table());
+            legacy
+                .into_iter()
+                .chain(standard)
+                .flat_map(|tbl| tbl.keys().cloned())
+                .collect()
+        }
+    };
+    Ok(ids)
+}
+
 /// 将 config.json 中 Codex 的 enabled==true 项以 TOML 形式写入 ~/.codex/config.toml
 ///
 /// 格式策略：
@@ -13,4 +13,7 @@ pub struct Prompt {
     pub created_at: Option<i64>,
     #[serde(rename = "updatedAt", skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<i64>,
+    /// 用户自定义排序位置；未设置的提示词排在最后（按更新时间倒序）
+    #[serde(rename = "sortIndex", default, skip_serializing_if = "Option::is_none")]
+    pub sort_index: Option<usize>,
 }
@@ -65,6 +65,35 @@ impl Provider {
             in_failover_queue: false,
         }
     }
+
+    /// 最近一次切换到该供应商的时间（从未切换过则为 None）
+    pub fn last_used_at(&self) -> Option<i64> {
+        self.meta.as_ref().and_then(|meta| meta.last_used_at)
+    }
+
+    /// 是否已归档（`provider archive`）
+    pub fn is_archived(&self) -> bool {
+        self.meta
+            .as_ref()
+            .and_then(|meta| meta.archived)
+            .unwrap_or(false)
+    }
+
+    /// 是否已收藏（`provider favorite`）
+    pub fn is_favorite(&self) -> bool {
+        self.meta
+            .as_ref()
+            .and_then(|meta| meta.favorite)
+            .unwrap_or(false)
+    }
+
+    /// Codex API Key 写入位置
+    pub fn codex_auth_target(&self) -> CodexAuthTarget {
+        self.meta
+            .as_ref()
+            .and_then(|meta| meta.codex_auth_target)
+            .unwrap_or_default()
+    }
 }
 
 /// 供应商管理器
@@ -191,6 +220,17 @@ pub struct ProviderProxyConfig {
     pub proxy_password: Option<String>,
 }
 
+/// Codex API Key 的写入位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, clap::ValueEnum)]
+#[serde(rename_all = "camelCase")]
+pub enum CodexAuthTarget {
+    /// 写入 `~/.codex/auth.json`（默认）
+    #[default]
+    AuthJson,
+    /// 写入 `~/.codex/.env`，不生成 auth.json（Codex 0.64+ 可从环境变量读取）
+    EnvFile,
+}
+
 /// 供应商元数据
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ProviderMeta {
@@ -241,6 +281,21 @@ pub struct ProviderMeta {
     /// - "openai_chat": OpenAI Chat Completions 格式，需要转换
     #[serde(rename = "apiFormat", skip_serializing_if = "Option::is_none")]
     pub api_format: Option<String>,
+    /// 供应商标签（用于分组与批量修改）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// 最近一次切换到该供应商的时间（Unix 秒）
+    #[serde(rename = "lastUsedAt", skip_serializing_if = "Option::is_none")]
+    pub last_used_at: Option<i64>,
+    /// Codex API Key 写入位置（未设置时写入 auth.json）
+    #[serde(rename = "codexAuthTarget", skip_serializing_if = "Option::is_none")]
+    pub codex_auth_target: Option<CodexAuthTarget>,
+    /// 已归档：默认列表与导出中隐藏，且不可切换
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    /// 收藏：TUI 可只显示收藏的供应商以便快速切换
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub favorite: Option<bool>,
 }
 
 impl ProviderManager {
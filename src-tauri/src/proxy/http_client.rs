@@ -0,0 +1,52 @@
+//! 对外网络请求共用的 HTTP 客户端构建
+//!
+//! 超时优先级：调用方显式传入的值 > 全局 `--network-timeout-ms` > `network_timeout_ms` 设置 >
+//! 各操作的内置默认值。
+//!
+//! 大文件传输（WebDAV 上传下载、更新包下载）的总时长不受全局超时约束，全局值只用于建立连接。
+//! 流式检查与 `--wait-healthy` 探测按请求设置各自配置的超时，同样不使用全局值。
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use reqwest::{Client, ClientBuilder};
+
+use crate::error::AppError;
+
+static TIMEOUT_OVERRIDE: OnceLock<Duration> = OnceLock::new();
+
+/// 记录命令行 `--network-timeout-ms`，本进程内所有网络操作以此为默认超时
+pub fn set_network_timeout_override(timeout: Duration) {
+    let _ = TIMEOUT_OVERRIDE.set(timeout);
+}
+
+/// 解析一次网络操作的超时
+pub fn resolve_timeout(explicit: Option<Duration>, fallback: Duration) -> Duration {
+    explicit
+        .or_else(|| TIMEOUT_OVERRIDE.get().copied())
+        .or_else(|| crate::settings::get_network_timeout_ms().map(Duration::from_millis))
+        .unwrap_or(fallback)
+}
+
+/// 带统一超时的客户端构建器，调用方可继续追加 user-agent、代理等配置
+pub fn client_builder(explicit: Option<Duration>, fallback: Duration) -> ClientBuilder {
+    Client::builder().timeout(resolve_timeout(explicit, fallback))
+}
+
+/// 大文件传输用的构建器：总超时固定为 `transfer`，全局超时只约束建立连接
+pub fn transfer_client_builder(transfer: Duration) -> ClientBuilder {
+    Client::builder()
+        .timeout(transfer)
+        .connect_timeout(resolve_timeout(None, transfer).min(transfer))
+}
+
+/// 构建带统一超时的 HTTP 客户端
+pub fn build_client(explicit: Option<Duration>, fallback: Duration) -> Result<Client, AppError> {
+    client_builder(explicit, fallback).build().map_err(|e| {
+        AppError::localized(
+            "http.client_create_failed",
+            format!("创建 HTTP 客户端失败: {e}"),
+            format!("Failed to create HTTP client: {e}"),
+        )
+    })
+}
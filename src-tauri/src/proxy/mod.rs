@@ -3,6 +3,7 @@ pub mod error;
 pub mod forwarder;
 pub mod handler_context;
 pub mod handlers;
+pub mod http_client;
 pub mod metrics;
 pub mod provider_router;
 pub mod providers;
@@ -11,5 +12,6 @@ pub mod response_handler;
 pub mod server;
 pub mod types;
 
+pub use http_client::{build_client, client_builder, resolve_timeout, transfer_client_builder};
 pub use server::ProxyServer;
 pub use types::{ProxyConfig, ProxyServerInfo, ProxyStatus};
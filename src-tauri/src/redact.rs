@@ -0,0 +1,309 @@
+//! 敏感字段脱敏
+//!
+//! 导出、分享配置时用于遮盖 API Key、Token 等密钥；`all` 级别还会遮盖 API 地址与模型名。
+
+use std::sync::{OnceLock, RwLock};
+
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::AppError;
+
+/// 脱敏后的占位值
+pub const REDACTED_PLACEHOLDER: &str = "<redacted>";
+
+/// 终端中显示密钥时的遮罩样式（settings 中的 `maskStyle`）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum MaskStyle {
+    /// Hide the whole value
+    #[default]
+    Hidden,
+    /// Show only the last 4 characters
+    Last4,
+    /// Show the first 2 and last 2 characters
+    #[value(name = "first2last2")]
+    First2Last2,
+}
+
+const MASK: &str = "********";
+
+/// 按指定样式遮罩密钥（按字符处理，多字节安全）；过短的值总是完全隐藏
+pub fn mask_secret_with(value: &str, style: MaskStyle) -> String {
+    let chars: Vec<char> = value.trim().chars().collect();
+    if chars.is_empty() {
+        return String::new();
+    }
+    if chars.len() <= 8 {
+        return MASK.to_string();
+    }
+    let tail = |n: usize| chars[chars.len() - n..].iter().collect::<String>();
+    match style {
+        MaskStyle::Hidden => MASK.to_string(),
+        MaskStyle::Last4 => format!("****{}", tail(4)),
+        MaskStyle::First2Last2 => {
+            format!("{}****{}", chars[..2].iter().collect::<String>(), tail(2))
+        }
+    }
+}
+
+/// 按用户设置的样式遮罩密钥；列表、详情、差异等显示密钥处统一使用
+pub fn mask_secret(value: &str) -> String {
+    mask_secret_with(value, crate::settings::get_mask_style())
+}
+
+/// 脱敏级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum RedactLevel {
+    /// Show everything as-is
+    None,
+    /// Mask API keys, tokens, secrets and passwords
+    #[default]
+    Secrets,
+    /// Also mask base URLs and model names
+    All,
+}
+
+fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// 编译用户配置的脱敏模式（settings 中的 `redactionPatterns`）
+///
+/// `re:` 前缀按正则匹配，其余按 glob（`*`、`?`）匹配整个键名；均不区分大小写。
+pub fn compile_redaction_patterns(patterns: &[String]) -> Result<Vec<Regex>, AppError> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            let source = match pattern.strip_prefix("re:") {
+                Some(regex) => regex.to_string(),
+                None => {
+                    let body = pattern
+                        .chars()
+                        .map(|c| match c {
+                            '*' => ".*".to_string(),
+                            '?' => ".".to_string(),
+                            other => regex::escape(&other.to_string()),
+                        })
+                        .collect::<String>();
+                    format!("^{body}$")
+                }
+            };
+            RegexBuilder::new(&source)
+                .case_insensitive(true)
+                .build()
+                .map_err(|e| {
+                    AppError::localized(
+                        "redact.invalid_pattern",
+                        format!("无效的脱敏模式 '{pattern}': {e}"),
+                        format!("Invalid redaction pattern '{pattern}': {e}"),
+                    )
+                })
+        })
+        .collect()
+}
+
+/// 用户模式是否匹配键名；按模式列表缓存编译结果，设置变化时才重新编译
+fn matches_custom_pattern(key: &str) -> bool {
+    static CACHE: OnceLock<RwLock<(Vec<String>, Vec<Regex>)>> = OnceLock::new();
+    let patterns = crate::settings::get_redaction_patterns();
+    if patterns.is_empty() {
+        return false;
+    }
+    let cache = CACHE.get_or_init(|| RwLock::new((Vec::new(), Vec::new())));
+
+    if let Ok(guard) = cache.read() {
+        if guard.0 == patterns {
+            return guard.1.iter().any(|re| re.is_match(key));
+        }
+    }
+
+    // 设置文件中可能存在手工写入的无效模式：忽略整组并告警，不影响内置规则
+    let compiled = compile_redaction_patterns(&patterns).unwrap_or_else(|e| {
+        log::warn!("忽略自定义脱敏模式: {e}");
+        Vec::new()
+    });
+    let matched = compiled.iter().any(|re| re.is_match(key));
+    if let Ok(mut guard) = cache.write() {
+        *guard = (patterns, compiled);
+    }
+    matched
+}
+
+/// 判断 JSON 键名是否为密钥字段（`ANTHROPIC_AUTH_TOKEN`、`OPENAI_API_KEY`、`apiKey` 等），
+/// 以及是否匹配用户配置的脱敏模式
+pub fn is_secret_key(key: &str) -> bool {
+    let normalized = normalize_key(key);
+    normalized.contains("apikey")
+        || normalized.contains("secret")
+        || normalized.contains("password")
+        || normalized.ends_with("token")
+        || matches_custom_pattern(key)
+}
+
+/// 判断 `(键名, 字符串值)` 在给定级别下是否需要遮盖
+fn should_mask(key: &str, value: &str, level: RedactLevel) -> bool {
+    if value.is_empty() {
+        return false;
+    }
+    match level {
+        RedactLevel::None => false,
+        RedactLevel::Secrets => is_secret_key(key),
+        RedactLevel::All => {
+            let normalized = normalize_key(key);
+            is_secret_key(key)
+                || normalized.contains("url")
+                || normalized.contains("endpoint")
+                || normalized.contains("model")
+                || value.starts_with("http://")
+                || value.starts_with("https://")
+        }
+    }
+}
+
+/// 遮盖 TOML 文本（Codex `config.toml`）中的字段；解析失败时返回 None
+fn redact_toml_text(text: &str, level: RedactLevel) -> Option<String> {
+    fn walk(table: &mut dyn toml_edit::TableLike, level: RedactLevel) {
+        for (key, item) in table.iter_mut() {
+            if let Some(child) = item.as_table_like_mut() {
+                walk(child, level);
+            } else if let Some(value) = item.as_value_mut() {
+                if value
+                    .as_str()
+                    .is_some_and(|s| should_mask(key.get(), s, level))
+                {
+                    *value = toml_edit::Value::from(REDACTED_PLACEHOLDER);
+                }
+            }
+        }
+    }
+
+    let mut doc = text.parse::<toml_edit::DocumentMut>().ok()?;
+    walk(doc.as_table_mut(), level);
+    Some(doc.to_string())
+}
+
+/// 按级别递归遮盖 JSON 中的字符串值（空字符串保持不变）
+///
+/// Codex 供应商的 `config` 字段是 TOML 文本，会解析后逐项处理。
+pub fn redact(value: &mut Value, level: RedactLevel) {
+    if level == RedactLevel::None {
+        return;
+    }
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                match child {
+                    Value::String(s) if should_mask(key, s, level) => {
+                        *s = REDACTED_PLACEHOLDER.to_string();
+                    }
+                    Value::String(s) if key == "config" => {
+                        if let Some(redacted) = redact_toml_text(s, level) {
+                            *s = redacted;
+                        }
+                    }
+                    _ => redact(child, level),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| redact(item, level)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample() -> Value {
+        json!({
+            "env": {
+                "ANTHROPIC_AUTH_TOKEN": "sk-ant",
+                "ANTHROPIC_BASE_URL": "https://api.example.com",
+                "ANTHROPIC_MODEL": "claude-sonnet",
+                "MAX_TOKENS": "4096"
+            },
+            "auth": { "OPENAI_API_KEY": "sk-openai", "empty_api_key": "" },
+            "config": "model = \"gpt-5\"\n\n[model_providers.p]\nbase_url = \"https://codex.example\"\nwire_api = \"responses\"\nexperimental_bearer_token = \"tok\"\n",
+            "options": [{ "apiKey": "k", "baseURL": "https://x" }]
+        })
+    }
+
+    #[test]
+    fn mask_secret_with_applies_each_style_safely() {
+        let key = "sk-abcdefgh1234";
+        assert_eq!(mask_secret_with(key, MaskStyle::Hidden), "********");
+        assert_eq!(mask_secret_with(key, MaskStyle::Last4), "****1234");
+        assert_eq!(mask_secret_with(key, MaskStyle::First2Last2), "sk****34");
+
+        // 短值与多字节字符：不泄露内容，也不会在字符中间截断
+        assert_eq!(mask_secret_with("你你你", MaskStyle::Last4), "********");
+        assert_eq!(
+            mask_secret_with(&"你".repeat(9), MaskStyle::Last4),
+            "****你你你你"
+        );
+        assert_eq!(mask_secret_with("", MaskStyle::Hidden), "");
+    }
+
+    #[test]
+    fn redact_level_none_keeps_everything() {
+        let mut value = sample();
+        redact(&mut value, RedactLevel::None);
+        assert_eq!(value, sample());
+    }
+
+    #[test]
+    fn redact_level_secrets_masks_known_secret_keys_recursively() {
+        let mut value = sample();
+        redact(&mut value, RedactLevel::Secrets);
+
+        assert_eq!(
+            value["env"]["ANTHROPIC_AUTH_TOKEN"],
+            json!(REDACTED_PLACEHOLDER)
+        );
+        assert_eq!(
+            value["env"]["ANTHROPIC_BASE_URL"],
+            json!("https://api.example.com")
+        );
+        assert_eq!(value["env"]["ANTHROPIC_MODEL"], json!("claude-sonnet"));
+        assert_eq!(value["env"]["MAX_TOKENS"], json!("4096"));
+        assert_eq!(value["auth"]["OPENAI_API_KEY"], json!(REDACTED_PLACEHOLDER));
+        assert_eq!(value["auth"]["empty_api_key"], json!(""));
+        assert_eq!(value["options"][0]["apiKey"], json!(REDACTED_PLACEHOLDER));
+        assert_eq!(value["options"][0]["baseURL"], json!("https://x"));
+
+        let config = value["config"].as_str().expect("config text");
+        assert!(config.contains("experimental_bearer_token = \"<redacted>\""));
+        assert!(config.contains("https://codex.example"));
+        assert!(config.contains("gpt-5"));
+    }
+
+    #[test]
+    fn redact_level_all_also_masks_urls_and_models() {
+        let mut value = sample();
+        redact(&mut value, RedactLevel::All);
+
+        for path in [
+            "/env/ANTHROPIC_AUTH_TOKEN",
+            "/env/ANTHROPIC_BASE_URL",
+            "/env/ANTHROPIC_MODEL",
+            "/auth/OPENAI_API_KEY",
+            "/options/0/apiKey",
+            "/options/0/baseURL",
+        ] {
+            assert_eq!(value.pointer(path), Some(&json!(REDACTED_PLACEHOLDER)));
+        }
+        assert_eq!(value["env"]["MAX_TOKENS"], json!("4096"));
+
+        let config = value["config"].as_str().expect("config text");
+        assert!(!config.contains("https://codex.example"));
+        assert!(!config.contains("gpt-5"));
+        assert!(!config.contains("\"tok\""));
+        assert!(config.contains("wire_api = \"responses\""));
+    }
+}
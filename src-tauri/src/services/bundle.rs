@@ -0,0 +1,266 @@
+//! 团队配置包（`config export-bundle` / `config import-bundle`）
+//!
+//! 按标签或 ID 挑选供应商，连同 MCP 服务器与提示词打包为单个 JSON 文件，
+//! 可选脱敏后分享给新成员；导入时只合并本地尚不存在的条目，`--preview` 只报告与本地的重叠。
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::app_config::{AppType, McpServer};
+use crate::error::AppError;
+use crate::prompt::Prompt;
+use crate::provider::Provider;
+use crate::redact::{redact, RedactLevel, REDACTED_PLACEHOLDER};
+use crate::store::AppState;
+
+use super::{ConfigService, McpService, PromptService, ProviderService};
+
+const BUNDLE_VERSION: u32 = 1;
+
+/// 团队配置包文件内容
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamBundle {
+    pub version: u32,
+    /// 按应用分组的供应商
+    #[serde(default)]
+    pub providers: BTreeMap<String, Vec<Provider>>,
+    #[serde(default)]
+    pub mcp_servers: Vec<McpServer>,
+    /// 按应用分组的提示词
+    #[serde(default)]
+    pub prompts: BTreeMap<String, Vec<Prompt>>,
+}
+
+/// 导出时选择供应商的条件；标签与 ID 均为空时导出全部未归档供应商
+#[derive(Debug, Clone, Default)]
+pub struct BundleSelection {
+    pub tags: Vec<String>,
+    pub ids: Vec<String>,
+}
+
+impl BundleSelection {
+    fn matches(&self, provider: &Provider) -> bool {
+        if self.tags.is_empty() && self.ids.is_empty() {
+            return true;
+        }
+        if self.ids.iter().any(|id| id == &provider.id) {
+            return true;
+        }
+        let tags = provider
+            .meta
+            .as_ref()
+            .map(|meta| meta.tags.as_slice())
+            .unwrap_or_default();
+        self.tags.iter().any(|tag| tags.contains(tag))
+    }
+}
+
+/// 导入结果：新增数量与因 ID 已存在而跳过的数量
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BundleImportSummary {
+    pub providers_added: usize,
+    pub providers_skipped: usize,
+    pub mcp_added: usize,
+    pub mcp_skipped: usize,
+    pub prompts_added: usize,
+    pub prompts_skipped: usize,
+}
+
+/// 配置包中的供应商与本地同 ID 供应商的关系
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundleProviderStatus {
+    /// 本地不存在，导入时会新增
+    New,
+    /// `settings_config` 结构一致
+    Identical,
+    /// 同 ID 但 `settings_config` 不同；导入时保留本地版本
+    Conflict,
+}
+
+/// `import-bundle --preview` 中的单个供应商
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleProviderPreview {
+    pub app: String,
+    pub id: String,
+    pub name: String,
+    pub status: BundleProviderStatus,
+}
+
+/// 结构比较 `settings_config`；配置包中脱敏的占位值视为与任意本地值一致
+fn settings_match(incoming: &Value, local: &Value) -> bool {
+    match (incoming, local) {
+        (Value::String(text), _) if text == REDACTED_PLACEHOLDER => true,
+        (Value::Object(incoming), Value::Object(local)) => {
+            incoming.len() == local.len()
+                && incoming.iter().all(|(key, value)| {
+                    local
+                        .get(key)
+                        .is_some_and(|local| settings_match(value, local))
+                })
+        }
+        (Value::Array(incoming), Value::Array(local)) => {
+            incoming.len() == local.len()
+                && incoming
+                    .iter()
+                    .zip(local)
+                    .all(|(incoming, local)| settings_match(incoming, local))
+        }
+        _ => incoming == local,
+    }
+}
+
+fn read_bundle(source_path: &Path) -> Result<TeamBundle, AppError> {
+    let bundle: TeamBundle = crate::config::read_json_file(source_path)?;
+    if bundle.version != BUNDLE_VERSION {
+        return Err(AppError::localized(
+            "config.bundle.unsupported_version",
+            format!("不支持的配置包版本: {}", bundle.version),
+            format!("Unsupported bundle version: {}", bundle.version),
+        ));
+    }
+    Ok(bundle)
+}
+
+impl ConfigService {
+    /// 按选择条件生成团队配置包（未脱敏）
+    pub fn build_bundle(
+        state: &AppState,
+        selection: &BundleSelection,
+    ) -> Result<TeamBundle, AppError> {
+        let mut bundle = TeamBundle {
+            version: BUNDLE_VERSION,
+            ..TeamBundle::default()
+        };
+
+        for app_type in AppType::all() {
+            let providers: Vec<Provider> = ProviderService::list(state, app_type.clone())?
+                .into_values()
+                .filter(|provider| !provider.is_archived() && selection.matches(provider))
+                .collect();
+            if !providers.is_empty() {
+                bundle
+                    .providers
+                    .insert(app_type.as_str().to_string(), providers);
+            }
+
+            let mut prompts: Vec<Prompt> = PromptService::get_prompts(state, app_type.clone())?
+                .into_values()
+                .collect();
+            prompts.sort_by(|a, b| a.id.cmp(&b.id));
+            if !prompts.is_empty() {
+                bundle
+                    .prompts
+                    .insert(app_type.as_str().to_string(), prompts);
+            }
+        }
+
+        bundle.mcp_servers = McpService::get_all_servers(state)?.into_values().collect();
+        bundle.mcp_servers.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(bundle)
+    }
+
+    /// 导出团队配置包到文件，返回写入的内容
+    pub fn export_bundle_to_path(
+        state: &AppState,
+        selection: &BundleSelection,
+        redact_secrets: bool,
+        target_path: &Path,
+    ) -> Result<TeamBundle, AppError> {
+        let bundle = Self::build_bundle(state, selection)?;
+        let mut value =
+            serde_json::to_value(&bundle).map_err(|source| AppError::JsonSerialize { source })?;
+        if redact_secrets {
+            redact(&mut value, RedactLevel::Secrets);
+        }
+        crate::config::write_json_file(target_path, &value)?;
+        Ok(bundle)
+    }
+
+    /// 合并团队配置包：只新增本地不存在的供应商、MCP 服务器与提示词
+    ///
+    /// 导入的提示词一律未启用，避免覆盖本地正在使用的提示词文件。
+    pub fn import_bundle_from_path(
+        state: &AppState,
+        source_path: &Path,
+    ) -> Result<BundleImportSummary, AppError> {
+        let bundle = read_bundle(source_path)?;
+
+        let mut summary = BundleImportSummary::default();
+
+        for (app, providers) in bundle.providers {
+            let app_type: AppType = app.parse()?;
+            let existing = ProviderService::list(state, app_type.clone())?;
+            for provider in providers {
+                if existing.contains_key(&provider.id) {
+                    summary.providers_skipped += 1;
+                    continue;
+                }
+                ProviderService::add_with(state, app_type.clone(), provider, false)?;
+                summary.providers_added += 1;
+            }
+        }
+
+        let existing = McpService::get_all_servers(state)?;
+        for server in bundle.mcp_servers {
+            if existing.contains_key(&server.id) {
+                summary.mcp_skipped += 1;
+                continue;
+            }
+            McpService::upsert_server(state, server)?;
+            summary.mcp_added += 1;
+        }
+
+        for (app, prompts) in bundle.prompts {
+            let app_type: AppType = app.parse()?;
+            let existing = PromptService::get_prompts(state, app_type.clone())?;
+            for mut prompt in prompts {
+                if existing.contains_key(&prompt.id) {
+                    summary.prompts_skipped += 1;
+                    continue;
+                }
+                prompt.enabled = false;
+                let id = prompt.id.clone();
+                PromptService::upsert_prompt(state, app_type.clone(), &id, prompt)?;
+                summary.prompts_added += 1;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// 逐个比较配置包中的供应商与本地配置，不做任何修改
+    pub fn preview_bundle_from_path(
+        state: &AppState,
+        source_path: &Path,
+    ) -> Result<Vec<BundleProviderPreview>, AppError> {
+        let bundle = read_bundle(source_path)?;
+
+        let mut previews = Vec::new();
+        for (app, providers) in bundle.providers {
+            let app_type: AppType = app.parse()?;
+            let existing = ProviderService::list(state, app_type)?;
+            for provider in providers {
+                let status = match existing.get(&provider.id) {
+                    None => BundleProviderStatus::New,
+                    Some(local)
+                        if settings_match(&provider.settings_config, &local.settings_config) =>
+                    {
+                        BundleProviderStatus::Identical
+                    }
+                    Some(_) => BundleProviderStatus::Conflict,
+                };
+                previews.push(BundleProviderPreview {
+                    app: app.clone(),
+                    id: provider.id,
+                    name: provider.name,
+                    status,
+                });
+            }
+        }
+        Ok(previews)
+    }
+}
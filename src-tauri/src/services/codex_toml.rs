@@ -0,0 +1,178 @@
+//! Codex 供应商的 TOML 导入导出（`config export/import --app codex --format toml`）
+//!
+//! 每个供应商一张表：名称、网址、备注等元信息为普通键，`auth` 与 `config` 为解码后的
+//! 子表（`config` 即 config.toml 的内容），方便直接手工编辑。导入时按 ID 新增或覆盖，
+//! 本地的元数据与排序保持不变。
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::store::AppState;
+
+use super::{ConfigService, ProviderService};
+
+/// TOML 文档顶层：`[providers.<id>]`
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CodexTomlDocument {
+    #[serde(default)]
+    providers: BTreeMap<String, CodexTomlProvider>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CodexTomlProvider {
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    website_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    category: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    notes: Option<String>,
+    /// auth.json 内容（如 `OPENAI_API_KEY`）
+    #[serde(default, skip_serializing_if = "toml::Table::is_empty")]
+    auth: toml::Table,
+    /// 解码后的 config.toml
+    #[serde(default)]
+    config: toml::Table,
+}
+
+/// TOML 导入结果
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CodexTomlImportSummary {
+    pub added: usize,
+    pub updated: usize,
+}
+
+fn invalid_toml(path: &Path, err: impl std::fmt::Display) -> AppError {
+    AppError::localized(
+        "config.codex_toml.invalid",
+        format!("无法解析 Codex TOML 文件 {}: {err}", path.display()),
+        format!("Failed to parse Codex TOML file {}: {err}", path.display()),
+    )
+}
+
+impl CodexTomlProvider {
+    fn from_provider(provider: &Provider) -> Result<Self, AppError> {
+        let auth = provider
+            .settings_config
+            .get("auth")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .filter(|(_, value)| !value.is_null())
+            .map(|(key, value)| {
+                toml::Value::try_from(value)
+                    .map(|value| (key.clone(), value))
+                    .map_err(|e| AppError::Message(format!("auth.{key}: {e}")))
+            })
+            .collect::<Result<toml::Table, _>>()?;
+        let config = match provider
+            .settings_config
+            .get("config")
+            .and_then(Value::as_str)
+        {
+            Some(text) => text.parse::<toml::Table>().map_err(|e| {
+                AppError::localized(
+                    "config.codex_toml.provider_config_invalid",
+                    format!("供应商 '{}' 的 config.toml 无法解析: {e}", provider.id),
+                    format!("Provider '{}' has an invalid config.toml: {e}", provider.id),
+                )
+            })?,
+            None => toml::Table::new(),
+        };
+
+        Ok(Self {
+            name: provider.name.clone(),
+            website_url: provider.website_url.clone(),
+            category: provider.category.clone(),
+            notes: provider.notes.clone(),
+            auth,
+            config,
+        })
+    }
+
+    fn into_provider(self, id: String) -> Result<Provider, AppError> {
+        let config = if self.config.is_empty() {
+            String::new()
+        } else {
+            toml::to_string(&self.config).map_err(|e| AppError::Message(e.to_string()))?
+        };
+        let mut settings_config = json!({ "config": config });
+        if !self.auth.is_empty() {
+            settings_config["auth"] = serde_json::to_value(&self.auth)
+                .map_err(|source| AppError::JsonSerialize { source })?;
+        }
+
+        let mut provider = Provider::with_id(id, self.name, settings_config, self.website_url);
+        provider.category = self.category;
+        provider.notes = self.notes;
+        Ok(provider)
+    }
+}
+
+impl ConfigService {
+    /// 导出 Codex 供应商为 TOML 文档
+    pub fn export_codex_toml(state: &AppState, include_archived: bool) -> Result<String, AppError> {
+        let providers = ProviderService::list(state, AppType::Codex)?;
+        let document = CodexTomlDocument {
+            providers: providers
+                .values()
+                .filter(|provider| include_archived || !provider.is_archived())
+                .map(|provider| {
+                    Ok((
+                        provider.id.clone(),
+                        CodexTomlProvider::from_provider(provider)?,
+                    ))
+                })
+                .collect::<Result<_, AppError>>()?,
+        };
+        toml::to_string_pretty(&document).map_err(|e| AppError::Message(e.to_string()))
+    }
+
+    /// 导出 Codex 供应商到 TOML 文件（原子写入）
+    pub fn export_codex_toml_to_path(
+        state: &AppState,
+        target_path: &Path,
+        include_archived: bool,
+    ) -> Result<(), AppError> {
+        let text = Self::export_codex_toml(state, include_archived)?;
+        crate::config::write_text_file(target_path, &text)
+    }
+
+    /// 从 TOML 文件导入 Codex 供应商；ID 已存在时覆盖该供应商
+    pub fn import_codex_toml_from_path(
+        state: &AppState,
+        source_path: &Path,
+    ) -> Result<CodexTomlImportSummary, AppError> {
+        let text =
+            std::fs::read_to_string(source_path).map_err(|e| AppError::io(source_path, e))?;
+        let document: CodexTomlDocument =
+            toml::from_str(&text).map_err(|e| invalid_toml(source_path, e))?;
+
+        let existing = ProviderService::list(state, AppType::Codex)?;
+        let mut summary = CodexTomlImportSummary::default();
+        for (id, entry) in document.providers {
+            let mut provider = entry.into_provider(id)?;
+            match existing.get(&provider.id) {
+                Some(current) => {
+                    // TOML 中没有的字段（元数据、排序、创建时间等）沿用本地值
+                    provider.meta = current.meta.clone();
+                    provider.sort_index = current.sort_index;
+                    provider.created_at = current.created_at;
+                    provider.icon = current.icon.clone();
+                    provider.icon_color = current.icon_color.clone();
+                    provider.in_failover_queue = current.in_failover_queue;
+                    summary.updated += 1;
+                }
+                None => summary.added += 1,
+            }
+            ProviderService::add_with(state, AppType::Codex, provider, true)?;
+        }
+        Ok(summary)
+    }
+}
@@ -1,4 +1,4 @@
-use super::provider::ProviderService;
+use super::provider::{AppValidationReport, ProviderService};
 use crate::app_config::{AppType, MultiAppConfig};
 use crate::database::Database;
 use crate::error::AppError;
@@ -22,6 +22,30 @@ pub struct BackupInfo {
     pub timestamp: String,
     /// 显示名称（用于 UI）
     pub display_name: String,
+    /// 创建时填写的备注（保存在同名 `.note` 文件中）
+    pub note: Option<String>,
+}
+
+/// 按备份目录总大小（`backup_max_bytes`）裁剪的结果
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BackupSizePrune {
+    /// 被删除的旧备份数量
+    pub pruned_files: usize,
+    /// 释放的字节数（含 `.note` 备注文件）
+    pub pruned_bytes: u64,
+    /// 新备份本身已超过上限（此时不删除其他备份）
+    pub oversized: bool,
+}
+
+impl BackupInfo {
+    /// 按 ID、显示名称或备注匹配（不区分大小写）
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        [Some(&self.id), Some(&self.display_name), self.note.as_ref()]
+            .into_iter()
+            .flatten()
+            .any(|text| text.to_lowercase().contains(&query))
+    }
 }
 
 /// 配置导入导出相关业务逻辑
@@ -41,9 +65,27 @@ impl ConfigService {
         config_path: &Path,
         custom_name: Option<String>,
     ) -> Result<String, AppError> {
+        Self::create_backup_with_report(config_path, custom_name, None).map(|(id, _)| id)
+    }
+
+    /// 创建备份并附加备注；备注写入备份旁的 `{backup_id}.note` 文件
+    pub fn create_backup_with_note(
+        config_path: &Path,
+        custom_name: Option<String>,
+        note: Option<&str>,
+    ) -> Result<String, AppError> {
+        Self::create_backup_with_report(config_path, custom_name, note).map(|(id, _)| id)
+    }
+
+    /// 创建备份（可附备注），并返回按 `backup_max_bytes` 裁剪旧备份的结果
+    pub fn create_backup_with_report(
+        config_path: &Path,
+        custom_name: Option<String>,
+        note: Option<&str>,
+    ) -> Result<(String, BackupSizePrune), AppError> {
         let db_path = crate::config::get_app_config_dir().join("cc-switch.db");
         if !db_path.exists() {
-            return Ok(String::new());
+            return Ok((String::new(), BackupSizePrune::default()));
         }
 
         let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
@@ -53,21 +95,53 @@ impl ConfigService {
             format!("backup_{}", timestamp)
         };
 
-        let backup_dir = config_path
-            .parent()
-            .or_else(|| db_path.parent())
-            .ok_or_else(|| AppError::Config("Invalid config path".into()))?
-            .join("backups");
-
+        let backup_dir = Self::backup_dir(config_path);
         fs::create_dir_all(&backup_dir).map_err(|e| AppError::io(&backup_dir, e))?;
 
         let backup_path = backup_dir.join(format!("{backup_id}.sql"));
         let db = Database::init()?;
         db.export_sql(&backup_path)?;
 
+        if let Some(note) = note.map(str::trim).filter(|note| !note.is_empty()) {
+            let note_path = backup_path.with_extension("note");
+            fs::write(&note_path, note).map_err(|e| AppError::io(&note_path, e))?;
+        }
+
         Self::cleanup_old_backups(&backup_dir, MAX_BACKUPS)?;
+        let prune = match crate::settings::get_backup_max_bytes() {
+            Some(max_bytes) => Self::prune_backups_to_size(&backup_dir, &backup_path, max_bytes),
+            None => BackupSizePrune::default(),
+        };
 
-        Ok(backup_id)
+        Ok((backup_id, prune))
+    }
+
+    fn backup_dir(config_path: &Path) -> PathBuf {
+        config_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(crate::config::get_app_config_dir)
+            .join("backups")
+    }
+
+    /// 若已开启自动备份且距上次自动备份超过间隔，则创建一次 `auto_*` 备份并记录时间。
+    ///
+    /// 未到期时只读取内存中的设置，开销可忽略；备份仍受 `MAX_BACKUPS` 保留数量限制。
+    pub fn auto_backup_if_due(now: i64) -> Result<Option<String>, AppError> {
+        let Some(auto) = crate::settings::get_auto_backup_settings() else {
+            return Ok(None);
+        };
+        if !auto.is_due(now) {
+            return Ok(None);
+        }
+
+        let backup_id =
+            Self::create_backup(&crate::config::get_app_config_path(), Some("auto".into()))?;
+        if backup_id.is_empty() {
+            return Ok(None);
+        }
+        crate::settings::set_last_auto_backup_at(now)?;
+        Ok(Some(backup_id))
     }
 
     /// 列出所有可用的备份
@@ -101,12 +175,17 @@ impl ConfigService {
 
                 // 生成显示名称
                 let display_name = Self::format_display_name(&filename, &timestamp);
+                let note = fs::read_to_string(path.with_extension("note"))
+                    .ok()
+                    .map(|note| note.trim().to_string())
+                    .filter(|note| !note.is_empty());
 
                 Some(BackupInfo {
                     id: filename.clone(),
                     path: path.clone(),
                     timestamp,
                     display_name,
+                    note,
                 })
             })
             .collect();
@@ -219,15 +298,95 @@ impl ConfigService {
                     err
                 );
             }
+            let _ = fs::remove_file(entry.path().with_extension("note"));
         }
 
         Ok(())
     }
 
-    /// 将当前 config.json 拷贝到目标路径。
-    pub fn export_config_to_path(target_path: &Path) -> Result<(), AppError> {
+    /// 从最旧的备份开始删除，直到目录总大小不超过 `max_bytes`；`keep` 为刚创建的备份，不会被删除。
+    ///
+    /// 若 `keep` 本身已超过上限，删除其他备份也无济于事，此时只发出警告。
+    fn prune_backups_to_size(backup_dir: &Path, keep: &Path, max_bytes: u64) -> BackupSizePrune {
+        let file_size = |path: &Path| fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        let backup_size = |path: &Path| file_size(path) + file_size(&path.with_extension("note"));
+
+        let mut report = BackupSizePrune::default();
+        if backup_size(keep) > max_bytes {
+            log::warn!(
+                "Backup {} ({} bytes) alone exceeds the backup size limit of {} bytes",
+                keep.display(),
+                backup_size(keep),
+                max_bytes
+            );
+            report.oversized = true;
+            return report;
+        }
+
+        let mut entries = match fs::read_dir(backup_dir) {
+            Ok(iter) => iter
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "sql"))
+                .map(|path| {
+                    let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+                    let size = backup_size(&path);
+                    (path, modified, size)
+                })
+                .collect::<Vec<_>>(),
+            Err(_) => return report,
+        };
+        entries.sort_by_key(|(_, modified, _)| *modified);
+
+        let mut total: u64 = entries.iter().map(|(_, _, size)| size).sum();
+        for (path, _, size) in entries {
+            if total <= max_bytes {
+                break;
+            }
+            if path == keep {
+                continue;
+            }
+            if let Err(err) = fs::remove_file(&path) {
+                log::warn!("Failed to remove old backup {}: {}", path.display(), err);
+                continue;
+            }
+            let _ = fs::remove_file(path.with_extension("note"));
+            total -= size;
+            report.pruned_files += 1;
+            report.pruned_bytes += size;
+        }
+
+        report
+    }
+
+    /// 将数据库完整导出为 SQL 文件（包含切换历史、用量汇总与请求日志等全部表）。
+    ///
+    /// 已归档的供应商默认不导出，`include_archived` 时一并导出；`canonical` 时输出确定性的
+    /// SQL 文本，便于纳入 git 比较。
+    pub fn export_config_to_path(
+        target_path: &Path,
+        include_archived: bool,
+        canonical: bool,
+    ) -> Result<(), AppError> {
         let db = Database::init()?;
-        db.export_sql(target_path)
+        if canonical {
+            db.export_sql_canonical(target_path, include_archived)
+        } else if include_archived {
+            db.export_sql(target_path)
+        } else {
+            db.export_sql_without_archived(target_path)
+        }
+    }
+
+    /// 校验供应商配置、当前供应商 ID 与通用配置片段；`app` 为 `None` 时校验全部应用
+    pub fn validate(
+        state: &AppState,
+        app: Option<&AppType>,
+    ) -> Result<Vec<AppValidationReport>, AppError> {
+        AppType::all()
+            .filter(|app_type| app.is_none_or(|app| app == app_type))
+            .map(|app_type| ProviderService::validate_app(state, &app_type))
+            .collect()
     }
 
     pub fn import_config_from_path(file_path: &Path, state: &AppState) -> Result<String, AppError> {
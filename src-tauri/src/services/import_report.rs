@@ -0,0 +1,116 @@
+//! `config import` 的变更报告
+//!
+//! SQL 导入会整体替换数据库；通过对比导入前后的 `MultiAppConfig`，按应用统计供应商、
+//! 提示词以及 MCP 服务器的新增 / 更新 / 未变 / 移除数量。
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::app_config::{AppType, MultiAppConfig};
+use crate::prompt::Prompt;
+
+use super::ConfigService;
+
+/// 单类条目的变化计数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ChangeCounts {
+    pub added: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub removed: usize,
+}
+
+impl ChangeCounts {
+    /// 按 ID 对比前后两组条目；内容以序列化后的 JSON 判断是否变化
+    fn diff<'a, T: Serialize + 'a>(
+        before: impl IntoIterator<Item = (&'a String, &'a T)>,
+        after: impl IntoIterator<Item = (&'a String, &'a T)>,
+    ) -> Self {
+        let mut remaining: HashMap<&str, Value> = before
+            .into_iter()
+            .map(|(id, item)| {
+                (
+                    id.as_str(),
+                    serde_json::to_value(item).unwrap_or(Value::Null),
+                )
+            })
+            .collect();
+
+        let mut counts = Self::default();
+        for (id, item) in after {
+            match remaining.remove(id.as_str()) {
+                None => counts.added += 1,
+                Some(old) if old == serde_json::to_value(item).unwrap_or(Value::Null) => {
+                    counts.unchanged += 1
+                }
+                Some(_) => counts.updated += 1,
+            }
+        }
+        counts.removed = remaining.len();
+        counts
+    }
+
+    /// 导入前后都没有任何条目
+    pub fn is_empty(&self) -> bool {
+        self.added + self.updated + self.unchanged + self.removed == 0
+    }
+}
+
+/// 导入变更报告：供应商与提示词按应用分组，MCP 服务器为统一存储
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportChangeReport {
+    pub providers: Vec<(AppType, ChangeCounts)>,
+    pub mcp_servers: ChangeCounts,
+    pub prompts: Vec<(AppType, ChangeCounts)>,
+}
+
+fn prompts_of<'a>(config: &'a MultiAppConfig, app: &AppType) -> &'a HashMap<String, Prompt> {
+    match app {
+        AppType::Claude => &config.prompts.claude.prompts,
+        AppType::Codex => &config.prompts.codex.prompts,
+        AppType::Gemini => &config.prompts.gemini.prompts,
+        AppType::OpenCode => &config.prompts.opencode.prompts,
+    }
+}
+
+impl ConfigService {
+    /// 对比导入前后的配置，生成按类型分组的变更报告
+    pub fn import_change_report(
+        before: &MultiAppConfig,
+        after: &MultiAppConfig,
+    ) -> ImportChangeReport {
+        let providers = AppType::all()
+            .map(|app| {
+                let counts = ChangeCounts::diff(
+                    before
+                        .get_manager(&app)
+                        .into_iter()
+                        .flat_map(|m| &m.providers),
+                    after
+                        .get_manager(&app)
+                        .into_iter()
+                        .flat_map(|m| &m.providers),
+                );
+                (app, counts)
+            })
+            .collect();
+        let mcp_servers = ChangeCounts::diff(
+            before.mcp.servers.iter().flatten(),
+            after.mcp.servers.iter().flatten(),
+        );
+        let prompts = AppType::all()
+            .map(|app| {
+                let counts = ChangeCounts::diff(prompts_of(before, &app), prompts_of(after, &app));
+                (app, counts)
+            })
+            .collect();
+
+        ImportChangeReport {
+            providers,
+            mcp_servers,
+            prompts,
+        }
+    }
+}
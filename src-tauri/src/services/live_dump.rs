@@ -0,0 +1,229 @@
+//! 汇总各应用 live 配置文件的当前内容（`config dump`），便于附在问题报告中
+//!
+//! 只包含切换供应商时会写入的文件；默认按 `RedactLevel::Secrets` 遮盖密钥。
+//! 脱敏时 JSON / TOML 会重新格式化（注释不保留），`.env` 逐行处理保持原顺序。
+
+use std::path::PathBuf;
+
+use serde_json::{json, Map, Value};
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::redact::{redact, RedactLevel};
+
+use super::ConfigService;
+
+/// live 配置文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiveFileFormat {
+    Json,
+    Toml,
+    Env,
+}
+
+/// 单个 live 文件的转储结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiveFileDump {
+    pub app: AppType,
+    pub path: PathBuf,
+    pub format: LiveFileFormat,
+    /// 文件不存在时为 None
+    pub text: Option<String>,
+    /// 解析后的内容（`.env` 为键值对象）；文件缺失或无法解析时为 None
+    pub parsed: Option<Value>,
+}
+
+/// 应用对应的 live 配置文件
+pub fn live_config_files(app: &AppType) -> Vec<(PathBuf, LiveFileFormat)> {
+    match app {
+        AppType::Claude => vec![(
+            crate::config::get_claude_settings_path(),
+            LiveFileFormat::Json,
+        )],
+        AppType::Codex => vec![
+            (
+                crate::codex_config::get_codex_auth_path(),
+                LiveFileFormat::Json,
+            ),
+            (
+                crate::codex_config::get_codex_config_path(),
+                LiveFileFormat::Toml,
+            ),
+            (
+                crate::codex_config::get_codex_env_path(),
+                LiveFileFormat::Env,
+            ),
+        ],
+        AppType::Gemini => vec![
+            (
+                crate::gemini_config::get_gemini_env_path(),
+                LiveFileFormat::Env,
+            ),
+            (
+                crate::gemini_config::get_gemini_settings_path(),
+                LiveFileFormat::Json,
+            ),
+        ],
+        AppType::OpenCode => vec![(
+            crate::opencode_config::get_opencode_config_path(),
+            LiveFileFormat::Json,
+        )],
+    }
+}
+
+fn parse(raw: &str, format: LiveFileFormat) -> Option<Value> {
+    match format {
+        LiveFileFormat::Json => serde_json::from_str(raw).ok(),
+        LiveFileFormat::Toml => raw
+            .parse::<toml::Table>()
+            .ok()
+            .and_then(|table| serde_json::to_value(table).ok()),
+        LiveFileFormat::Env => Some(Value::Object(
+            crate::gemini_config::parse_env_file(raw)
+                .into_iter()
+                .map(|(key, value)| (key, Value::String(value)))
+                .collect::<Map<_, _>>(),
+        )),
+    }
+}
+
+/// 逐行遮盖 `.env` 中的密钥，保留注释与顺序
+fn redact_env_text(raw: &str, level: RedactLevel) -> String {
+    raw.lines()
+        .map(|line| {
+            let Some((key, value)) = line.split_once('=') else {
+                return line.to_string();
+            };
+            if line.trim_start().starts_with('#') {
+                return line.to_string();
+            }
+            let mut entry = json!({ key.trim(): value.trim() });
+            redact(&mut entry, level);
+            let masked = entry[key.trim()].as_str().unwrap_or_default();
+            format!("{key}={masked}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render(value: &Value, format: LiveFileFormat) -> Option<String> {
+    match format {
+        LiveFileFormat::Json => serde_json::to_string_pretty(value).ok(),
+        LiveFileFormat::Toml => toml::Value::try_from(value)
+            .ok()
+            .and_then(|value| toml::to_string_pretty(&value).ok()),
+        LiveFileFormat::Env => None,
+    }
+}
+
+fn dump_file(
+    app: &AppType,
+    path: PathBuf,
+    format: LiveFileFormat,
+    level: RedactLevel,
+) -> Result<LiveFileDump, AppError> {
+    if !path.exists() {
+        return Ok(LiveFileDump {
+            app: app.clone(),
+            path,
+            format,
+            text: None,
+            parsed: None,
+        });
+    }
+    let raw = std::fs::read_to_string(&path).map_err(|e| AppError::io(&path, e))?;
+    let mut parsed = parse(&raw, format);
+    if let Some(value) = parsed.as_mut() {
+        redact(value, level);
+    }
+
+    let text = match (level, format, parsed.as_ref()) {
+        (RedactLevel::None, _, _) => raw,
+        (_, LiveFileFormat::Env, _) => redact_env_text(&raw, level),
+        // 无法解析时不输出原文，避免泄露密钥
+        (_, _, None) => format!("<unparsable {}; hidden while redacting>", path.display()),
+        (_, _, Some(value)) => render(value, format).unwrap_or_default(),
+    };
+
+    Ok(LiveFileDump {
+        app: app.clone(),
+        path,
+        format,
+        text: Some(text),
+        parsed,
+    })
+}
+
+impl ConfigService {
+    /// 读取指定应用的 live 配置文件，按 `level` 脱敏
+    pub fn dump_live_configs(
+        apps: &[AppType],
+        level: RedactLevel,
+    ) -> Result<Vec<LiveFileDump>, AppError> {
+        apps.iter()
+            .flat_map(|app| {
+                live_config_files(app)
+                    .into_iter()
+                    .map(move |(path, format)| dump_file(app, path, format, level))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dump_masks_secrets_in_json_and_env_files() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let json_path = dir.path().join("settings.json");
+        std::fs::write(
+            &json_path,
+            r#"{"env":{"ANTHROPIC_AUTH_TOKEN":"sk-live-secret-123456","ANTHROPIC_BASE_URL":"https://api.example.com"}}"#,
+        )
+        .expect("write json");
+        let env_path = dir.path().join(".env");
+        std::fs::write(
+            &env_path,
+            "# comment\nGEMINI_API_KEY=sk-env-secret-123456\nGEMINI_MODEL=gemini-pro",
+        )
+        .expect("write env");
+
+        let json_dump = dump_file(
+            &AppType::Claude,
+            json_path,
+            LiveFileFormat::Json,
+            RedactLevel::Secrets,
+        )
+        .expect("dump json");
+        let text = json_dump.text.expect("json text");
+        assert!(!text.contains("sk-live-secret-123456"));
+        assert!(text.contains("https://api.example.com"));
+        assert_ne!(
+            json_dump.parsed.expect("parsed")["env"]["ANTHROPIC_AUTH_TOKEN"],
+            "sk-live-secret-123456"
+        );
+
+        let env_dump = dump_file(
+            &AppType::Gemini,
+            env_path,
+            LiveFileFormat::Env,
+            RedactLevel::Secrets,
+        )
+        .expect("dump env");
+        let text = env_dump.text.expect("env text");
+        assert!(text.starts_with("# comment\n"));
+        assert!(!text.contains("sk-env-secret-123456"));
+        assert!(text.contains("GEMINI_MODEL=gemini-pro"));
+
+        let missing = dump_file(
+            &AppType::Codex,
+            dir.path().join("missing.toml"),
+            LiveFileFormat::Toml,
+            RedactLevel::Secrets,
+        )
+        .expect("dump missing");
+        assert!(missing.text.is_none());
+    }
+}
@@ -1,14 +1,246 @@
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use crate::app_config::{AppType, McpServer, MultiAppConfig};
+use serde::{Deserialize, Serialize};
+
+use crate::app_config::{AppType, McpApps, McpServer, MultiAppConfig};
 use crate::error::AppError;
 use crate::mcp;
+use crate::settings::McpSyncSettings;
 use crate::store::AppState;
 
 /// MCP 相关业务逻辑（v3.7.0 统一结构）
 pub struct McpService;
 
+/// 单个应用的 MCP 同步结果
+#[derive(Debug)]
+pub enum McpSyncOutcome {
+    /// 已同步的服务器数量
+    Synced(usize),
+    Failed(AppError),
+    /// 前序应用失败且未开启 continue-on-error，未尝试
+    Skipped,
+}
+
+#[derive(Debug)]
+pub struct McpSyncAppResult {
+    pub app: AppType,
+    pub outcome: McpSyncOutcome,
+}
+
+/// 单个 MCP 服务器同步到某个应用失败
+#[derive(Debug)]
+pub struct McpServerSyncFailure {
+    pub server_id: String,
+    pub app: AppType,
+    pub error: AppError,
+}
+
+/// 导入预览（dry-run）中的单个服务器
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McpImportPreview {
+    pub id: String,
+    /// true 表示新增；false 表示已存在、仅为其启用该应用
+    pub is_new: bool,
+    /// 解析后的传输类型（缺省按 stdio 处理）
+    pub transport: String,
+}
+
+const MCP_EXPORT_VERSION: u32 = 1;
+
+/// `mcp export` 文件内容；`app` 存在时表示仅包含该应用启用的服务器
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McpExportFile {
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<String>,
+    #[serde(default)]
+    pub servers: Vec<McpServer>,
+}
+
+/// 从导出文件导入的结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct McpFileImportSummary {
+    /// 新增的服务器数量
+    pub added: usize,
+    /// 已存在、仅为目标应用启用的服务器数量
+    pub enabled: usize,
+    /// 已存在且无需改动的服务器数量
+    pub unchanged: usize,
+}
+
+/// `mcp validate-all` 中单个服务器的检查结论
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum McpServerCheck {
+    /// stdio 命令可在 PATH 中找到
+    CommandFound(PathBuf),
+    CommandMissing(String),
+    /// URL 可达（HTTP 状态码）
+    UrlReachable(u16),
+    /// URL 可达但返回 5xx
+    UrlServerError(u16),
+    UrlUnreachable(String),
+    /// 既没有 command 也没有 url
+    NoTarget,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum McpCheckLevel {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl McpServerCheck {
+    pub fn level(&self) -> McpCheckLevel {
+        match self {
+            McpServerCheck::CommandFound(_) | McpServerCheck::UrlReachable(_) => {
+                McpCheckLevel::Pass
+            }
+            McpServerCheck::UrlServerError(_) => McpCheckLevel::Warn,
+            McpServerCheck::CommandMissing(_)
+            | McpServerCheck::UrlUnreachable(_)
+            | McpServerCheck::NoTarget => McpCheckLevel::Fail,
+        }
+    }
+}
+
+/// MCP 服务器列表排序方式（`mcp list --sort-by`，TUI 中 `s` 键循环切换）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum McpSortBy {
+    /// 按 ID（默认）
+    #[default]
+    Id,
+    /// 按名称（不区分大小写）
+    Name,
+    /// 为当前应用启用的排在前面，其余按名称
+    Enabled,
+}
+
+impl McpSortBy {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Id => Self::Name,
+            Self::Name => Self::Enabled,
+            Self::Enabled => Self::Id,
+        }
+    }
+
+    /// 比较两个服务器；`app` 用于判断 `Enabled` 排序下的启用状态
+    pub fn compare(
+        self,
+        app: &AppType,
+        (id_a, a): (&str, &McpServer),
+        (id_b, b): (&str, &McpServer),
+    ) -> std::cmp::Ordering {
+        let by_name = || {
+            a.name
+                .to_lowercase()
+                .cmp(&b.name.to_lowercase())
+                .then_with(|| id_a.cmp(id_b))
+        };
+        match self {
+            Self::Id => id_a.cmp(id_b),
+            Self::Name => by_name(),
+            Self::Enabled => b
+                .apps
+                .is_enabled_for(app)
+                .cmp(&a.apps.is_enabled_for(app))
+                .then_with(by_name),
+        }
+    }
+}
+
+/// 单个服务器的检查结果
+#[derive(Debug, Clone)]
+pub struct McpServerCheckResult {
+    pub id: String,
+    /// 被检查的命令或 URL
+    pub target: String,
+    pub check: McpServerCheck,
+}
+
 impl McpService {
+    /// 检查为应用启用的每个 MCP 服务器：stdio 命令是否在 PATH 中、http/sse URL 是否可达（按 ID 排序）
+    pub fn validate_all(
+        state: &AppState,
+        app: &AppType,
+        timeout: Duration,
+    ) -> Result<Vec<McpServerCheckResult>, AppError> {
+        let mut servers: Vec<McpServer> = Self::get_all_servers(state)?
+            .into_values()
+            .filter(|server| server.apps.is_enabled_for(app))
+            .collect();
+        servers.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut runtime = None;
+        let mut results = Vec::with_capacity(servers.len());
+        for server in servers {
+            let spec = &server.server;
+            let field = |key: &str| {
+                spec.get(key)
+                    .and_then(|value| value.as_str())
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_string)
+            };
+
+            let (target, check) = if let Some(url) = field("url") {
+                if runtime.is_none() {
+                    runtime = Some(
+                        tokio::runtime::Builder::new_current_thread()
+                            .enable_all()
+                            .build()
+                            .map_err(|e| {
+                                AppError::localized(
+                                    "mcp.validate.runtime_create_failed",
+                                    format!("创建异步运行时失败: {e}"),
+                                    format!("Failed to create async runtime: {e}"),
+                                )
+                            })?,
+                    );
+                }
+                let check = runtime
+                    .as_ref()
+                    .expect("runtime initialized above")
+                    .block_on(probe_mcp_url(&url, timeout));
+                (url, check)
+            } else if let Some(command) = field("command") {
+                let check = match which::which(&command) {
+                    Ok(path) => McpServerCheck::CommandFound(path),
+                    Err(_) => McpServerCheck::CommandMissing(command.clone()),
+                };
+                (command, check)
+            } else {
+                (String::new(), McpServerCheck::NoTarget)
+            };
+
+            results.push(McpServerCheckResult {
+                id: server.id,
+                target,
+                check,
+            });
+        }
+        Ok(results)
+    }
+
+    /// 关闭检查结果中命令不在 PATH 中的服务器在该应用上的启用状态，返回被关闭的服务器 ID
+    pub fn disable_missing_commands(
+        state: &AppState,
+        app: &AppType,
+        results: &[McpServerCheckResult],
+    ) -> Result<Vec<String>, AppError> {
+        let mut disabled = Vec::new();
+        for result in results {
+            if matches!(result.check, McpServerCheck::CommandMissing(_)) {
+                Self::toggle_app(state, &result.id, app.clone(), false)?;
+                disabled.push(result.id.clone());
+            }
+        }
+        Ok(disabled)
+    }
+
     /// 获取所有 MCP 服务器（统一结构）
     pub fn get_all_servers(state: &AppState) -> Result<HashMap<String, McpServer>, AppError> {
         let cfg = state.config.read()?;
@@ -191,14 +423,86 @@ impl McpService {
     }
 
     /// 手动同步所有启用的 MCP 服务器到对应的应用
+    ///
+    /// 按设置中的 MCP 同步策略执行；任一应用失败时返回第一个错误。
     pub fn sync_all_enabled(state: &AppState) -> Result<(), AppError> {
+        let settings = crate::settings::get_mcp_sync_settings();
+        let results = Self::sync_all_enabled_with(state, &settings)?;
+
+        match results.into_iter().find_map(|result| match result.outcome {
+            McpSyncOutcome::Failed(err) => Some(err),
+            _ => None,
+        }) {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// 逐个服务器同步到其启用的应用；单个服务器失败不影响其余服务器，返回失败列表
+    pub fn sync_all_enabled_per_server(
+        state: &AppState,
+    ) -> Result<Vec<McpServerSyncFailure>, AppError> {
+        let settings = crate::settings::get_mcp_sync_settings();
         let servers = Self::get_all_servers(state)?;
+        let mut servers: Vec<&McpServer> = servers.values().collect();
+        servers.sort_by(|a, b| a.id.cmp(&b.id));
 
-        for server in servers.values() {
-            Self::sync_server_to_apps(state, server)?;
+        let cfg = state.config.read()?;
+        let mut failures = Vec::new();
+        for app in settings.resolved_order() {
+            for server in servers
+                .iter()
+                .filter(|server| server.apps.is_enabled_for(&app))
+            {
+                if let Err(error) = Self::sync_server_to_app_internal(&cfg, server, &app) {
+                    failures.push(McpServerSyncFailure {
+                        server_id: server.id.clone(),
+                        app: app.clone(),
+                        error,
+                    });
+                }
+            }
         }
+        Ok(failures)
+    }
 
-        Ok(())
+    /// 按指定顺序逐个应用同步，返回每个应用的结果
+    pub fn sync_all_enabled_with(
+        state: &AppState,
+        settings: &McpSyncSettings,
+    ) -> Result<Vec<McpSyncAppResult>, AppError> {
+        let servers = Self::get_all_servers(state)?;
+        let mut servers: Vec<&McpServer> = servers.values().collect();
+        servers.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let cfg = state.config.read()?;
+        let mut results = Vec::new();
+        let mut stopped = false;
+
+        for app in settings.resolved_order() {
+            if stopped {
+                results.push(McpSyncAppResult {
+                    app,
+                    outcome: McpSyncOutcome::Skipped,
+                });
+                continue;
+            }
+
+            let outcome = servers
+                .iter()
+                .filter(|server| server.apps.is_enabled_for(&app))
+                .try_fold(0, |count, server| {
+                    Self::sync_server_to_app_internal(&cfg, server, &app).map(|_| count + 1)
+                })
+                .map_or_else(McpSyncOutcome::Failed, McpSyncOutcome::Synced);
+
+            if matches!(outcome, McpSyncOutcome::Failed(_)) && !settings.continue_on_error {
+                stopped = true;
+            }
+            results.push(McpSyncAppResult { app, outcome });
+        }
+
+        Ok(results)
     }
 
     // ========================================================================
@@ -223,6 +527,17 @@ impl McpService {
         Ok(result)
     }
 
+    /// 已为应用启用、但 live 配置中缺失的 MCP 服务器（按 ID 排序），说明需要执行 `mcp sync`
+    pub fn list_unsynced(state: &AppState, app: &AppType) -> Result<Vec<McpServer>, AppError> {
+        let live_ids = mcp::live_server_ids_for(app)?;
+        let mut missing: Vec<McpServer> = Self::get_all_servers(state)?
+            .into_values()
+            .filter(|server| server.apps.is_enabled_for(app) && !live_ids.contains(&server.id))
+            .collect();
+        missing.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(missing)
+    }
+
     /// [已废弃] 设置 MCP 服务器在指定应用的启用状态（兼容旧 API）
     #[deprecated(since = "3.7.0", note = "Use toggle_app instead")]
     pub fn set_enabled(
@@ -284,4 +599,159 @@ impl McpService {
         state.save()?;
         Ok(count)
     }
+
+    /// 预览从指定应用 live 配置导入的结果（不修改内存状态与数据库）
+    ///
+    /// 在配置副本上执行与正式导入相同的解析路径，再与当前状态比较得出差异。
+    /// live 配置中存在、但尚未纳入 MCP 存储的服务器 ID（与导入共用解析逻辑）。
+    ///
+    /// 切换供应商会重写 live 文件，这些服务器可能因此丢失。
+    pub fn unmanaged_live_servers(
+        state: &AppState,
+        app: &AppType,
+    ) -> Result<Vec<String>, AppError> {
+        Ok(Self::preview_import(state, app)?
+            .into_iter()
+            .filter(|preview| preview.is_new)
+            .map(|preview| preview.id)
+            .collect())
+    }
+
+    pub fn preview_import(
+        state: &AppState,
+        app: &AppType,
+    ) -> Result<Vec<McpImportPreview>, AppError> {
+        let mut cfg = state.config.read()?.clone();
+        let before = cfg.mcp.servers.clone().unwrap_or_default();
+
+        match app {
+            AppType::Claude => mcp::import_from_claude(&mut cfg)?,
+            AppType::Codex => mcp::import_from_codex(&mut cfg)?,
+            AppType::Gemini => mcp::import_from_gemini(&mut cfg)?,
+            AppType::OpenCode => mcp::import_from_opencode(&mut cfg)?,
+        };
+
+        let mut previews: Vec<McpImportPreview> = cfg
+            .mcp
+            .servers
+            .unwrap_or_default()
+            .into_values()
+            .filter_map(|server| {
+                let is_new = match before.get(&server.id) {
+                    None => true,
+                    Some(existing) if existing.apps != server.apps => false,
+                    Some(_) => return None,
+                };
+                let transport = server
+                    .server
+                    .get("type")
+                    .and_then(|t| t.as_str())
+                    .unwrap_or("stdio")
+                    .to_string();
+                Some(McpImportPreview {
+                    id: server.id,
+                    is_new,
+                    transport,
+                })
+            })
+            .collect();
+        previews.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(previews)
+    }
+
+    /// 导出 MCP 服务器（按 ID 排序）
+    ///
+    /// 指定应用时只包含为该应用启用的服务器，且启用状态仅保留该应用。
+    pub fn export_servers(
+        state: &AppState,
+        app: Option<&AppType>,
+    ) -> Result<Vec<McpServer>, AppError> {
+        let mut servers: Vec<McpServer> = Self::get_all_servers(state)?
+            .into_values()
+            .filter(|server| app.is_none_or(|app| server.apps.is_enabled_for(app)))
+            .map(|mut server| {
+                if let Some(app) = app {
+                    server.apps = McpApps::default();
+                    server.apps.set_enabled_for(app, true);
+                }
+                server
+            })
+            .collect();
+        servers.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(servers)
+    }
+
+    /// 生成 `mcp export` 的 JSON 内容，同时返回导出数量
+    pub fn export_json(
+        state: &AppState,
+        app: Option<&AppType>,
+    ) -> Result<(String, usize), AppError> {
+        let file = McpExportFile {
+            version: MCP_EXPORT_VERSION,
+            app: app.map(|app| app.as_str().to_string()),
+            servers: Self::export_servers(state, app)?,
+        };
+        let json = serde_json::to_string_pretty(&file)
+            .map_err(|e| AppError::JsonSerialize { source: e })?;
+        Ok((json, file.servers.len()))
+    }
+
+    /// 从 `mcp export` 文件导入服务器
+    ///
+    /// 目标应用取 `app`，缺省时使用文件中记录的应用；本地不存在的服务器按文件内容新增，
+    /// 已存在的服务器保留本地定义，仅为目标应用启用。
+    pub fn import_from_path(
+        state: &AppState,
+        source_path: &Path,
+        app: Option<AppType>,
+    ) -> Result<McpFileImportSummary, AppError> {
+        let file: McpExportFile = crate::config::read_json_file(source_path)?;
+        if file.version != MCP_EXPORT_VERSION {
+            return Err(AppError::localized(
+                "mcp.export.unsupported_version",
+                format!("不支持的 MCP 导出文件版本: {}", file.version),
+                format!("Unsupported MCP export file version: {}", file.version),
+            ));
+        }
+        let target = match app {
+            Some(app) => Some(app),
+            None => file.app.as_deref().map(str::parse).transpose()?,
+        };
+
+        let existing = Self::get_all_servers(state)?;
+        let mut summary = McpFileImportSummary::default();
+        for mut server in file.servers {
+            match (existing.get(&server.id), &target) {
+                (Some(current), Some(app)) if !current.apps.is_enabled_for(app) => {
+                    Self::toggle_app(state, &server.id, app.clone(), true)?;
+                    summary.enabled += 1;
+                }
+                (Some(_), _) => summary.unchanged += 1,
+                (None, _) => {
+                    if let Some(app) = &target {
+                        server.apps.set_enabled_for(app, true);
+                    }
+                    Self::upsert_server(state, server)?;
+                    summary.added += 1;
+                }
+            }
+        }
+        Ok(summary)
+    }
+}
+
+/// 任何 HTTP 响应都视为可达（MCP 端点常对 GET 返回 4xx）；5xx 仅告警
+async fn probe_mcp_url(url: &str, timeout: Duration) -> McpServerCheck {
+    let client = match crate::proxy::build_client(Some(timeout), timeout) {
+        Ok(client) => client,
+        Err(e) => return McpServerCheck::UrlUnreachable(e.to_string()),
+    };
+    match client.get(url).send().await {
+        Ok(response) if response.status().is_server_error() => {
+            McpServerCheck::UrlServerError(response.status().as_u16())
+        }
+        Ok(response) => McpServerCheck::UrlReachable(response.status().as_u16()),
+        Err(e) if e.is_timeout() => McpServerCheck::UrlUnreachable("timeout".to_string()),
+        Err(e) => McpServerCheck::UrlUnreachable(e.to_string()),
+    }
 }
@@ -1,23 +1,36 @@
+pub mod bundle;
+pub mod codex_toml;
 pub mod config;
 pub mod env_checker;
 pub mod env_manager;
+pub mod import_report;
+pub mod live_dump;
 pub mod local_env_check;
 pub mod mcp;
 pub mod prompt;
 pub mod provider;
 pub mod proxy;
+pub mod remote_import;
 pub mod skill;
 pub mod speedtest;
 pub mod stream_check;
 pub mod webdav;
 pub mod webdav_sync;
 
+pub use bundle::{BundleImportSummary, BundleProviderStatus, BundleSelection};
 pub use config::ConfigService;
-pub use mcp::McpService;
+pub use import_report::{ChangeCounts, ImportChangeReport};
+pub use mcp::{
+    McpCheckLevel, McpFileImportSummary, McpImportPreview, McpServerCheck, McpServerCheckResult,
+    McpServerSyncFailure, McpService, McpSortBy, McpSyncAppResult, McpSyncOutcome,
+};
 pub use prompt::PromptService;
-pub use provider::ProviderService;
+pub use provider::{
+    AppValidationReport, HealthWait, LegacyImportOutcome, LiveFileWrite, ProviderService,
+    SettingsPatch, SwitchOptions, SwitchOutcome, UsageEnvOverride,
+};
 pub use proxy::ProxyService;
-pub use skill::SkillService;
+pub use skill::{SkillService, SkillUpdateAction};
 pub use speedtest::{EndpointLatency, SpeedtestService};
 pub use stream_check::{HealthStatus, StreamCheckConfig, StreamCheckResult, StreamCheckService};
 pub use webdav_sync::{SyncDecision, WebDavSyncService, WebDavSyncSummary};
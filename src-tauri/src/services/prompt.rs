@@ -125,6 +125,7 @@ impl PromptService {
                                 enabled: false,
                                 created_at: Some(timestamp),
                                 updated_at: Some(timestamp),
+                                sort_index: None,
                             };
                             prompts.insert(backup_id.clone(), backup_prompt);
                             log::info!("回填 live 提示词内容，创建备份: {backup_id}");
@@ -219,12 +220,77 @@ impl PromptService {
             enabled: false,
             created_at: Some(timestamp),
             updated_at: Some(timestamp),
+            sort_index: None,
         };
 
         Self::upsert_prompt(state, app, &id, prompt)?;
         Ok(id)
     }
 
+    /// 按列表显示顺序排列提示词：设置了 `sort_index` 的按其升序排在前面，
+    /// 其余按更新时间倒序排在后面
+    pub fn sorted_prompts(prompts: HashMap<String, Prompt>) -> Vec<(String, Prompt)> {
+        let mut list: Vec<_> = prompts.into_iter().collect();
+        list.sort_by(|(id_a, a), (id_b, b)| match (a.sort_index, b.sort_index) {
+            (Some(idx_a), Some(idx_b)) => idx_a.cmp(&idx_b).then_with(|| id_a.cmp(id_b)),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => b
+                .updated_at
+                .unwrap_or(0)
+                .cmp(&a.updated_at.unwrap_or(0))
+                .then_with(|| id_a.cmp(id_b)),
+        });
+        list
+    }
+
+    /// 重排提示词：`ordered_ids` 中的提示词按给定顺序排在最前，
+    /// 未列出的保持原有相对顺序排在其后。所有提示词的 `sort_index` 均被重写为连续值。
+    ///
+    /// 返回重排后的完整 ID 顺序。
+    pub fn reorder_prompts(
+        state: &AppState,
+        app: AppType,
+        ordered_ids: &[String],
+    ) -> Result<Vec<String>, AppError> {
+        let mut cfg = state.config.write()?;
+        let prompts = match app {
+            AppType::Claude => &mut cfg.prompts.claude.prompts,
+            AppType::Codex => &mut cfg.prompts.codex.prompts,
+            AppType::Gemini => &mut cfg.prompts.gemini.prompts,
+            AppType::OpenCode => &mut cfg.prompts.opencode.prompts,
+        };
+
+        for (pos, id) in ordered_ids.iter().enumerate() {
+            if !prompts.contains_key(id) {
+                return Err(AppError::InvalidInput(format!("提示词 {id} 不存在")));
+            }
+            if ordered_ids[..pos].contains(id) {
+                return Err(AppError::InvalidInput(format!("提示词 {id} 重复出现")));
+            }
+        }
+
+        let current: Vec<String> = Self::sorted_prompts(prompts.clone())
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        let order: Vec<String> = ordered_ids
+            .iter()
+            .cloned()
+            .chain(current.into_iter().filter(|id| !ordered_ids.contains(id)))
+            .collect();
+
+        for (index, id) in order.iter().enumerate() {
+            if let Some(prompt) = prompts.get_mut(id) {
+                prompt.sort_index = Some(index);
+            }
+        }
+
+        drop(cfg);
+        state.save()?;
+        Ok(order)
+    }
+
     pub fn get_current_file_content(app: AppType) -> Result<Option<String>, AppError> {
         let file_path = prompt_file_path(&app)?;
         if !file_path.exists() {
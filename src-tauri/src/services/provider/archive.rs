@@ -0,0 +1,70 @@
+//! 供应商归档（`provider archive` / `provider unarchive`）
+//!
+//! 归档是可恢复的软删除：已归档的供应商在默认列表与导出中隐藏，且不可切换。
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::ProviderMeta;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+impl ProviderService {
+    /// 归档或恢复供应商；当前供应商需先切换走才能归档
+    pub fn set_archived(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        archived: bool,
+    ) -> Result<(), AppError> {
+        let provider_id = provider_id.to_string();
+
+        Self::run_transaction(state, move |config| {
+            let manager = config
+                .get_manager_mut(&app_type)
+                .ok_or_else(|| Self::app_not_found(&app_type))?;
+            if archived && !app_type.is_additive_mode() && manager.current == provider_id {
+                return Err(AppError::localized(
+                    "provider.archive.current",
+                    format!("不能归档当前供应商 '{provider_id}'，请先切换到其他供应商"),
+                    format!(
+                        "Cannot archive the current provider '{provider_id}'; switch to another provider first"
+                    ),
+                ));
+            }
+            let provider = manager
+                .providers
+                .get_mut(&provider_id)
+                .ok_or_else(|| AppError::provider_not_found(&provider_id))?;
+
+            provider
+                .meta
+                .get_or_insert_with(ProviderMeta::default)
+                .archived = archived.then_some(true);
+            Ok(((), None))
+        })
+    }
+
+    /// 切换前检查：已归档的供应商不可切换
+    pub(super) fn ensure_not_archived(
+        state: &AppState,
+        app_type: &AppType,
+        provider_id: &str,
+    ) -> Result<(), AppError> {
+        let config = state.config.read().map_err(AppError::from)?;
+        let archived = config
+            .get_manager(app_type)
+            .and_then(|manager| manager.providers.get(provider_id))
+            .is_some_and(|provider| provider.is_archived());
+        if archived {
+            return Err(AppError::localized(
+                "provider.archived",
+                format!("供应商 '{provider_id}' 已归档，请先执行 `provider unarchive {provider_id}`"),
+                format!(
+                    "Provider '{provider_id}' is archived; run `provider unarchive {provider_id}` first"
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
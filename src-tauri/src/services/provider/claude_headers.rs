@@ -0,0 +1,156 @@
+//! Claude 供应商自定义请求头
+//!
+//! 供应商快照中以 `customHeaders`（`{ "Header-Name": "value" }`）声明额外请求头；
+//! 写入 live 时转换为 Claude Code 识别的 `env.ANTHROPIC_CUSTOM_HEADERS`
+//! （每行一个 `Name: Value`），回填快照时再解析回 `customHeaders`，保证切换往返不丢失。
+
+use serde_json::{Map, Value};
+
+use crate::error::AppError;
+
+/// 供应商快照中声明自定义请求头的键
+pub(crate) const CUSTOM_HEADERS_KEY: &str = "customHeaders";
+/// Claude Code 读取自定义请求头的环境变量
+pub(crate) const CUSTOM_HEADERS_ENV: &str = "ANTHROPIC_CUSTOM_HEADERS";
+
+/// RFC 7230 token：请求头名称允许的字符
+fn is_valid_header_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c))
+}
+
+/// 校验供应商声明的 `customHeaders`
+pub(crate) fn validate_custom_headers(settings: &Value) -> Result<(), AppError> {
+    let Some(headers) = settings.get(CUSTOM_HEADERS_KEY) else {
+        return Ok(());
+    };
+    let headers = headers.as_object().ok_or_else(|| {
+        AppError::localized(
+            "provider.claude.headers.not_object",
+            "customHeaders 必须是 JSON 对象",
+            "customHeaders must be a JSON object",
+        )
+    })?;
+
+    for (name, value) in headers {
+        if !is_valid_header_name(name) {
+            return Err(AppError::localized(
+                "provider.claude.headers.invalid_name",
+                format!("无效的请求头名称: {name}"),
+                format!("Invalid header name: {name}"),
+            ));
+        }
+        let valid_value = value
+            .as_str()
+            .is_some_and(|v| !v.contains('\r') && !v.contains('\n'));
+        if !valid_value {
+            return Err(AppError::localized(
+                "provider.claude.headers.invalid_value",
+                format!("请求头 {name} 的值必须是单行字符串"),
+                format!("Header {name} value must be a single-line string"),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// 写入 live 前：将 `customHeaders` 转换为 `env.ANTHROPIC_CUSTOM_HEADERS`
+pub(crate) fn apply_custom_headers(settings: &mut Value) {
+    let Some(obj) = settings.as_object_mut() else {
+        return;
+    };
+    let Some(headers) = obj.remove(CUSTOM_HEADERS_KEY) else {
+        return;
+    };
+    let Some(headers) = headers.as_object() else {
+        return;
+    };
+
+    let env = obj
+        .entry("env")
+        .or_insert_with(|| Value::Object(Map::new()));
+    let Some(env) = env.as_object_mut() else {
+        return;
+    };
+
+    if headers.is_empty() {
+        env.remove(CUSTOM_HEADERS_ENV);
+        return;
+    }
+
+    let lines: Vec<String> = headers
+        .iter()
+        .filter_map(|(name, value)| value.as_str().map(|v| format!("{name}: {v}")))
+        .collect();
+    env.insert(
+        CUSTOM_HEADERS_ENV.to_string(),
+        Value::String(lines.join("\n")),
+    );
+}
+
+/// 回填快照时：将 `env.ANTHROPIC_CUSTOM_HEADERS` 解析回 `customHeaders`
+pub(crate) fn collect_custom_headers(settings: &mut Value) {
+    let Some(obj) = settings.as_object_mut() else {
+        return;
+    };
+    let Some(raw) = obj
+        .get_mut("env")
+        .and_then(Value::as_object_mut)
+        .and_then(|env| env.remove(CUSTOM_HEADERS_ENV))
+    else {
+        return;
+    };
+
+    let mut headers = Map::new();
+    for line in raw.as_str().unwrap_or_default().lines() {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let name = name.trim();
+        if name.is_empty() {
+            continue;
+        }
+        headers.insert(name.to_string(), Value::String(value.trim().to_string()));
+    }
+
+    if !headers.is_empty() {
+        obj.insert(CUSTOM_HEADERS_KEY.to_string(), Value::Object(headers));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn custom_headers_round_trip_through_env() {
+        let original = json!({
+            "env": { "ANTHROPIC_AUTH_TOKEN": "token" },
+            "customHeaders": { "X-Gateway-Tenant": "team-a", "X-Trace": "on" }
+        });
+
+        let mut live = original.clone();
+        apply_custom_headers(&mut live);
+        assert!(live.get(CUSTOM_HEADERS_KEY).is_none());
+        assert_eq!(
+            live["env"][CUSTOM_HEADERS_ENV],
+            json!("X-Gateway-Tenant: team-a\nX-Trace: on")
+        );
+
+        collect_custom_headers(&mut live);
+        assert_eq!(live, original);
+    }
+
+    #[test]
+    fn validate_custom_headers_rejects_invalid_names_and_values() {
+        assert!(validate_custom_headers(&json!({ "customHeaders": { "X-Ok": "v" } })).is_ok());
+        assert!(validate_custom_headers(&json!({ "customHeaders": { "Bad Name": "v" } })).is_err());
+        assert!(validate_custom_headers(&json!({ "customHeaders": { "X-A": "a\nb" } })).is_err());
+        assert!(validate_custom_headers(&json!({ "customHeaders": { "X-A": 1 } })).is_err());
+        assert!(validate_custom_headers(&json!({ "customHeaders": ["X-A"] })).is_err());
+    }
+}
@@ -0,0 +1,177 @@
+//! Codex API Key 写入位置
+//!
+//! 默认写入 `~/.codex/auth.json`；供应商 `meta.codexAuthTarget = "envFile"` 时改为写入
+//! `~/.codex/.env`，并移除 auth.json。环境变量名遵循 config.toml 中当前 `model_provider`
+//! 的设置：`requires_openai_auth = false` 且声明了 `env_key` 时使用 `env_key`，否则使用
+//! `OPENAI_API_KEY`。
+
+use serde_json::Value;
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::{CodexAuthTarget, Provider, ProviderMeta};
+use crate::store::AppState;
+
+use super::{is_codex_official_provider, PostCommitAction, ProviderService};
+
+const DEFAULT_CODEX_ENV_KEY: &str = "OPENAI_API_KEY";
+
+/// 解析 Codex 读取 API Key 的环境变量名
+fn codex_env_key_name(config_text: &str) -> String {
+    let Ok(doc) = config_text.parse::<toml_edit::DocumentMut>() else {
+        return DEFAULT_CODEX_ENV_KEY.to_string();
+    };
+    let section = doc
+        .get("model_provider")
+        .and_then(|v| v.as_str())
+        .and_then(|key| {
+            doc.get("model_providers")
+                .and_then(|v| v.as_table_like())
+                .and_then(|t| t.get(key))
+                .and_then(|v| v.as_table_like())
+        });
+    let Some(section) = section else {
+        return DEFAULT_CODEX_ENV_KEY.to_string();
+    };
+
+    let requires_openai_auth = section
+        .get("requires_openai_auth")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true);
+    let env_key = section
+        .get("env_key")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|key| !key.is_empty());
+
+    match env_key {
+        Some(key) if !requires_openai_auth => key.to_string(),
+        _ => DEFAULT_CODEX_ENV_KEY.to_string(),
+    }
+}
+
+/// 供应商的 `(环境变量名, API Key)`；未配置 Key 时返回 None
+fn codex_env_assignment(provider: &Provider) -> Option<(String, String)> {
+    let config_text = provider
+        .settings_config
+        .get("config")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let name = codex_env_key_name(config_text);
+    let auth = provider.settings_config.get("auth")?;
+    let key = auth
+        .get(&name)
+        .or_else(|| auth.get(DEFAULT_CODEX_ENV_KEY))
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|key| !key.is_empty())?;
+    Some((name, key.to_string()))
+}
+
+/// auth 只有一个键且其值为空字符串（如 `{"OPENAI_API_KEY": ""}`）；写入这样的 auth.json 没有意义
+pub(super) fn has_only_empty_api_key(provider: &Provider) -> bool {
+    provider
+        .settings_config
+        .get("auth")
+        .and_then(Value::as_object)
+        .filter(|auth| auth.len() == 1)
+        .and_then(|auth| auth.values().next())
+        .and_then(Value::as_str)
+        .is_some_and(|key| key.trim().is_empty())
+}
+
+/// env-file 模式下应写入 `.env` 的 `(变量名, API Key)`；非该模式或没有 Key 时返回 None
+pub(super) fn codex_env_file_entry(provider: &Provider) -> Option<(String, String)> {
+    if provider.codex_auth_target() != CodexAuthTarget::EnvFile
+        || is_codex_official_provider(provider)
+    {
+        return None;
+    }
+    codex_env_assignment(provider)
+}
+
+impl ProviderService {
+    /// 修改 Codex 供应商的 API Key 写入位置；若为当前供应商则立即重写 live 配置
+    pub fn set_codex_auth_target(
+        state: &AppState,
+        provider_id: &str,
+        target: CodexAuthTarget,
+    ) -> Result<(), AppError> {
+        let provider_id = provider_id.to_string();
+        Self::run_transaction(state, move |config| {
+            let common_config_snippet = config.common_config_snippets.get(&AppType::Codex).cloned();
+            let manager = config
+                .get_manager_mut(&AppType::Codex)
+                .ok_or_else(|| Self::app_not_found(&AppType::Codex))?;
+            let is_current = manager.current == provider_id;
+            let provider = manager
+                .providers
+                .get_mut(&provider_id)
+                .ok_or_else(|| AppError::provider_not_found(provider_id))?;
+            if target == CodexAuthTarget::EnvFile && is_codex_official_provider(provider) {
+                return Err(AppError::localized(
+                    "provider.codex_auth.official",
+                    "官方供应商使用 codex login 凭证，无需写入 API Key",
+                    "Official providers use codex login credentials; no API key is written",
+                ));
+            }
+
+            provider
+                .meta
+                .get_or_insert_with(ProviderMeta::default)
+                .codex_auth_target = match target {
+                CodexAuthTarget::AuthJson => None,
+                CodexAuthTarget::EnvFile => Some(CodexAuthTarget::EnvFile),
+            };
+
+            if !is_current {
+                return Ok(((), None));
+            }
+            let action = PostCommitAction {
+                app_type: AppType::Codex,
+                provider: provider.clone(),
+                backup: Self::capture_live_snapshot(&AppType::Codex)?,
+                sync_mcp: false,
+                strict_mcp: false,
+                refresh_snapshot: false,
+                common_config_snippet,
+                takeover_active: false,
+            };
+            Ok(((), Some(action)))
+        })
+    }
+
+    /// 生成 `export NAME="key"` 行，供偏好手动管理环境变量的用户使用
+    pub fn codex_auth_export_line(state: &AppState, provider_id: &str) -> Result<String, AppError> {
+        let provider = Self::list(state, AppType::Codex)?
+            .shift_remove(provider_id)
+            .ok_or_else(|| AppError::provider_not_found(provider_id))?;
+        let (name, key) = codex_env_assignment(&provider).ok_or_else(|| {
+            AppError::localized(
+                "provider.codex_auth.no_key",
+                format!("供应商 {provider_id} 未配置 API Key"),
+                format!("Provider {provider_id} has no API key"),
+            )
+        })?;
+
+        Ok(format!(
+            "export {}",
+            crate::codex_config::format_env_assignment(&name, &key)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_key_name_respects_requires_openai_auth() {
+        let env_mode = "model_provider = \"p\"\n\n[model_providers.p]\nrequires_openai_auth = false\nenv_key = \"P_KEY\"\n";
+        assert_eq!(codex_env_key_name(env_mode), "P_KEY");
+
+        let openai_mode = "model_provider = \"p\"\n\n[model_providers.p]\nrequires_openai_auth = true\nenv_key = \"P_KEY\"\n";
+        assert_eq!(codex_env_key_name(openai_mode), DEFAULT_CODEX_ENV_KEY);
+        assert_eq!(codex_env_key_name(""), DEFAULT_CODEX_ENV_KEY);
+    }
+}
@@ -0,0 +1,232 @@
+//! 跨应用复制 / 移动供应商（`provider add --from-provider / --from-live-app`、`provider move`）
+//!
+//! 只映射各应用共有的 API 地址与 API Key。模型、认证模式、自定义请求头、
+//! 用量脚本等无法在应用间对应的字段不会复制，新供应商使用目标应用的默认值。
+
+use serde_json::{json, Value};
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::{Provider, ProviderMeta};
+use crate::services::StreamCheckService;
+use crate::store::AppState;
+
+use super::{provider_tags, ProviderService};
+
+/// `provider move` 的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderMoveOutcome {
+    /// API 地址与 Key 是否已映射；为 false 时目标供应商只是模板，需要手动编辑
+    pub settings_converted: bool,
+}
+
+/// 无法跨应用映射、转换后保持默认值的字段
+pub const UNMAPPED_PROVIDER_FIELDS: &[&str] = &[
+    "models",
+    "auth mode",
+    "custom headers",
+    "usage script",
+    "proxy / failover settings",
+];
+
+const CODEX_DEFAULT_MODEL: &str = "gpt-5.2-codex";
+
+/// `provider move` 无法取得 API Key 时写入模板的占位值
+const MOVE_PLACEHOLDER_API_KEY: &str = "YOUR_API_KEY";
+
+fn source_api_key(provider: &Provider, app_type: &AppType) -> Option<String> {
+    match app_type {
+        AppType::Claude => StreamCheckService::extract_claude_key(provider),
+        AppType::Codex => StreamCheckService::extract_codex_key(provider),
+        AppType::Gemini => StreamCheckService::extract_gemini_auth(provider)
+            .ok()
+            .map(|auth| auth.api_key)
+            .filter(|key| !key.trim().is_empty()),
+        AppType::OpenCode => provider
+            .settings_config
+            .get("options")
+            .and_then(|options| options.get("apiKey"))
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+            .map(str::to_string),
+    }
+}
+
+fn codex_config_toml(provider_key: &str, base_url: &str) -> String {
+    let mut section = toml_edit::Table::new();
+    section.insert("name", toml_edit::value(provider_key));
+    section.insert("base_url", toml_edit::value(base_url));
+    section.insert("wire_api", toml_edit::value("responses"));
+    section.insert("requires_openai_auth", toml_edit::value(true));
+
+    let mut providers = toml_edit::Table::new();
+    providers.set_implicit(true);
+    providers.insert(provider_key, toml_edit::Item::Table(section));
+
+    let mut doc = toml_edit::DocumentMut::new();
+    doc.insert("model_provider", toml_edit::value(provider_key));
+    doc.insert("model", toml_edit::value(CODEX_DEFAULT_MODEL));
+    doc.insert("model_providers", toml_edit::Item::Table(providers));
+    doc.to_string()
+}
+
+fn target_settings(app_type: &AppType, id: &str, base_url: &str, api_key: &str) -> Value {
+    match app_type {
+        AppType::Claude => json!({
+            "env": {
+                "ANTHROPIC_BASE_URL": base_url,
+                "ANTHROPIC_AUTH_TOKEN": api_key,
+            }
+        }),
+        AppType::Codex => {
+            let provider_key = crate::codex_config::clean_codex_provider_key(id);
+            json!({
+                "auth": { "OPENAI_API_KEY": api_key },
+                "config": codex_config_toml(&provider_key, base_url),
+            })
+        }
+        AppType::Gemini => json!({
+            "env": {
+                "GOOGLE_GEMINI_BASE_URL": base_url,
+                "GEMINI_API_KEY": api_key,
+            }
+        }),
+        AppType::OpenCode => json!({
+            "npm": "@ai-sdk/openai-compatible",
+            "options": { "baseURL": base_url, "apiKey": api_key },
+            "models": {},
+        }),
+    }
+}
+
+impl ProviderService {
+    /// 以另一应用的供应商为模板，构建目标应用的新供应商（仅映射 API 地址与 Key，不保存）
+    pub fn convert_provider(
+        source: &Provider,
+        from: &AppType,
+        to: &AppType,
+        id: String,
+    ) -> Result<Provider, AppError> {
+        let base_url = StreamCheckService::extract_base_url(source, from)?;
+        if base_url.trim().is_empty() {
+            return Err(AppError::localized(
+                "provider.convert.base_url_missing",
+                format!("供应商 '{}' 未配置 API 地址，无法转换", source.id),
+                format!(
+                    "Provider '{}' has no API URL configured; cannot convert",
+                    source.id
+                ),
+            ));
+        }
+        let api_key = source_api_key(source, from).ok_or_else(|| {
+            AppError::localized(
+                "provider.convert.api_key_missing",
+                format!("供应商 '{}' 未配置 API Key，无法转换", source.id),
+                format!(
+                    "Provider '{}' has no API key configured; cannot convert",
+                    source.id
+                ),
+            )
+        })?;
+
+        let settings_config = target_settings(to, &id, &base_url, &api_key);
+        let mut provider = Provider::with_id(
+            id,
+            source.name.clone(),
+            settings_config,
+            source.website_url.clone(),
+        );
+        provider.notes = source.notes.clone();
+        provider.created_at = Some(chrono::Utc::now().timestamp());
+        Ok(provider)
+    }
+
+    /// 以 `from` 应用当前的 live 配置为模板，构建 `to` 应用的新供应商（不保存）。
+    ///
+    /// 同一应用时原样使用 live 配置；跨应用时与 `convert_provider` 一样只映射 API 地址与 Key。
+    /// OpenCode 的 live 配置包含多个供应商，不能作为来源。
+    pub fn provider_from_live(
+        from: &AppType,
+        to: &AppType,
+        id: String,
+        name: String,
+    ) -> Result<Provider, AppError> {
+        if from.is_additive_mode() {
+            return Err(AppError::localized(
+                "provider.from_live.additive",
+                format!("{from} 的 live 配置包含多个供应商，请改用 --from-provider"),
+                format!("{from} live config holds several providers; use --from-provider instead"),
+            ));
+        }
+        let live = Self::read_live_settings(from.clone())?;
+        let source = Provider::with_id(format!("{from} live"), name, live, None);
+        if from == to {
+            let mut provider = Provider::with_id(id, source.name, source.settings_config, None);
+            provider.created_at = Some(chrono::Utc::now().timestamp());
+            return Ok(provider);
+        }
+        Self::convert_provider(&source, from, to, id)
+    }
+
+    /// 将供应商从 `from` 移到 `to`（保留 ID、名称、备注与标签）。
+    ///
+    /// 能映射 API 地址与 Key 时按目标应用格式转换；否则写入目标应用的模板（缺失的 Key 用
+    /// 占位值代替），由用户手动补全。
+    pub fn move_provider(
+        state: &AppState,
+        from: AppType,
+        to: AppType,
+        id: &str,
+    ) -> Result<ProviderMoveOutcome, AppError> {
+        if from == to {
+            return Err(AppError::localized(
+                "provider.move.same_app",
+                format!("供应商 '{id}' 已属于 {}", from.as_str()),
+                format!("Provider '{id}' already belongs to {}", from.as_str()),
+            ));
+        }
+        let source = Self::list(state, from.clone())?
+            .shift_remove(id)
+            .ok_or_else(|| AppError::provider_not_found(id))?;
+        if Self::current(state, from.clone())? == id {
+            return Err(AppError::localized(
+                "provider.move.current",
+                "不能移动当前正在使用的供应商，请先切换到其他供应商",
+                "Cannot move the provider currently in use; switch to another provider first",
+            ));
+        }
+
+        let (mut provider, settings_converted) =
+            match Self::convert_provider(&source, &from, &to, id.to_string()) {
+                Ok(provider) => (provider, true),
+                Err(err) => {
+                    log::warn!("供应商 '{id}' 无法转换为 {} 格式: {err}", to.as_str());
+                    let base_url =
+                        StreamCheckService::extract_base_url(&source, &from).unwrap_or_default();
+                    let api_key = source_api_key(&source, &from)
+                        .unwrap_or_else(|| MOVE_PLACEHOLDER_API_KEY.to_string());
+                    let mut provider = Provider::with_id(
+                        id.to_string(),
+                        source.name.clone(),
+                        target_settings(&to, id, &base_url, &api_key),
+                        source.website_url.clone(),
+                    );
+                    provider.notes = source.notes.clone();
+                    (provider, false)
+                }
+            };
+        provider.created_at = source.created_at.or(provider.created_at);
+        let tags = provider_tags(&source);
+        if !tags.is_empty() {
+            provider.meta = Some(ProviderMeta {
+                tags: tags.to_vec(),
+                ..ProviderMeta::default()
+            });
+        }
+
+        Self::add(state, to, provider)?;
+        Self::delete(state, from, id)?;
+        Ok(ProviderMoveOutcome { settings_converted })
+    }
+}
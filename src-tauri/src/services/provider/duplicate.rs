@@ -0,0 +1,46 @@
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::store::AppState;
+
+use super::{generate_provider_id, ProviderService};
+
+impl ProviderService {
+    /// 以新名称复制供应商，返回新生成的 ID
+    ///
+    /// 深拷贝 `settings_config` 与 `meta`，清空 `sort_index` 与最近使用时间；
+    /// 副本不会被设为当前供应商。
+    pub fn clone(
+        state: &AppState,
+        app_type: AppType,
+        source_id: &str,
+        new_name: &str,
+    ) -> Result<String, AppError> {
+        let new_name = new_name.trim();
+        if new_name.is_empty() {
+            return Err(AppError::localized(
+                "provider.clone.empty_name",
+                "新的供应商名称不能为空",
+                "New provider name cannot be empty",
+            ));
+        }
+
+        let providers = Self::list(state, app_type.clone())?;
+        let source = providers
+            .get(source_id)
+            .ok_or_else(|| AppError::provider_not_found(source_id))?;
+        let existing: Vec<String> = providers.keys().cloned().collect();
+
+        let mut provider = source.clone();
+        provider.id = generate_provider_id(new_name, &existing);
+        provider.name = new_name.to_string();
+        provider.sort_index = None;
+        provider.created_at = Some(chrono::Utc::now().timestamp());
+        if let Some(meta) = provider.meta.as_mut() {
+            meta.last_used_at = None;
+        }
+
+        let id = provider.id.clone();
+        Self::add_with(state, app_type, provider, false)?;
+        Ok(id)
+    }
+}
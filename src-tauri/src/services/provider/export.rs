@@ -0,0 +1,85 @@
+//! 按供应商拆分导出：每个供应商一个 JSON 文件，便于纳入版本控制
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+/// 由供应商 ID 生成安全的文件名主干：仅保留 ASCII 字母数字、`-`、`_`、`.`，其余替换为 `_`
+pub(crate) fn provider_file_stem(id: &str) -> String {
+    let stem: String = id
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    // 去掉开头的 `.`，避免生成隐藏文件或 `..`
+    let stem = stem.trim_start_matches('.');
+    if stem.is_empty() {
+        "provider".to_string()
+    } else {
+        stem.to_string()
+    }
+}
+
+impl ProviderService {
+    /// 将应用下全部供应商逐个导出为 `<dir>/<id>.json`，返回写入的文件路径
+    ///
+    /// 不同 ID 清理后可能得到相同文件名（含大小写不敏感的文件系统），冲突时追加 `-2`、`-3`…
+    pub fn export_split(
+        state: &AppState,
+        app_type: AppType,
+        dir: &Path,
+        redact: bool,
+    ) -> Result<Vec<PathBuf>, AppError> {
+        let providers = Self::list(state, app_type)?;
+        std::fs::create_dir_all(dir).map_err(|e| AppError::io(dir, e))?;
+
+        let mut used = HashSet::new();
+        let mut written = Vec::with_capacity(providers.len());
+        for provider in providers.values() {
+            let stem = provider_file_stem(&provider.id);
+            let mut file_name = format!("{stem}.json");
+            let mut suffix = 2;
+            while !used.insert(file_name.to_lowercase()) {
+                file_name = format!("{stem}-{suffix}.json");
+                suffix += 1;
+            }
+
+            let mut value = serde_json::to_value(provider)
+                .map_err(|source| AppError::JsonSerialize { source })?;
+            if redact {
+                crate::redact::redact(&mut value, crate::redact::RedactLevel::Secrets);
+            }
+
+            let path = dir.join(file_name);
+            crate::config::write_json_file(&path, &value)?;
+            written.push(path);
+        }
+
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn provider_file_stem_sanitizes_odd_ids() {
+        assert_eq!(provider_file_stem("my-provider_1.0"), "my-provider_1.0");
+        assert_eq!(provider_file_stem("team/a b"), "team_a_b");
+        assert_eq!(provider_file_stem("../etc"), "_etc");
+        assert_eq!(provider_file_stem("供应商"), "___");
+        assert_eq!(provider_file_stem("..."), "provider");
+    }
+}
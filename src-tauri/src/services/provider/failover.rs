@@ -0,0 +1,236 @@
+//! 故障转移队列的手动执行（`provider failover run`）
+//!
+//! 按队列顺序探测每个供应商，切换到第一个健康的供应商；全部不健康时保持当前供应商并报错。
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::app_config::AppType;
+use crate::database::FailoverQueueItem;
+use crate::error::AppError;
+use crate::services::StreamCheckService;
+use crate::store::AppState;
+
+use super::health::{health_runtime, probe_provider_endpoint};
+use super::ProviderService;
+
+/// 单个队列条目的探测结果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase", tag = "status", content = "reason")]
+pub enum FailoverOutcome {
+    /// 端点健康（已切换或本就是当前供应商）
+    Healthy,
+    /// 未配置 API 地址，无法探测
+    Skipped(String),
+    /// 探测失败
+    Failed(String),
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailoverAttempt {
+    pub provider_id: String,
+    pub provider_name: String,
+    pub outcome: FailoverOutcome,
+}
+
+/// `failover run` 的执行结果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailoverReport {
+    pub attempts: Vec<FailoverAttempt>,
+    /// 选中的健康供应商
+    pub selected: String,
+    /// 是否发生了切换（选中的就是当前供应商时为 false）
+    pub switched: bool,
+}
+
+impl ProviderService {
+    /// 故障转移队列（按排序）
+    pub fn failover_queue(
+        state: &AppState,
+        app_type: AppType,
+    ) -> Result<Vec<FailoverQueueItem>, AppError> {
+        state.db.get_failover_queue(app_type.as_str())
+    }
+
+    /// 将供应商加入或移出故障转移队列
+    pub fn set_in_failover_queue(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        queued: bool,
+    ) -> Result<(), AppError> {
+        if !Self::list(state, app_type.clone())?.contains_key(provider_id) {
+            return Err(AppError::provider_not_found(provider_id));
+        }
+        if queued {
+            state
+                .db
+                .add_to_failover_queue(app_type.as_str(), provider_id)?;
+        } else {
+            state
+                .db
+                .remove_from_failover_queue(app_type.as_str(), provider_id)?;
+        }
+        // 内存中的配置保持一致，避免后续保存时覆盖
+        let mut config = state.config.write().map_err(AppError::from)?;
+        if let Some(provider) = config
+            .get_manager_mut(&app_type)
+            .and_then(|manager| manager.providers.get_mut(provider_id))
+        {
+            provider.in_failover_queue = queued;
+        }
+        Ok(())
+    }
+
+    /// 依次探测故障转移队列并切换到第一个健康的供应商
+    pub fn failover_run(
+        state: &AppState,
+        app_type: AppType,
+        timeout: Duration,
+    ) -> Result<FailoverReport, AppError> {
+        let queue = state.db.get_failover_queue(app_type.as_str())?;
+        if queue.is_empty() {
+            return Err(AppError::localized(
+                "provider.failover.empty_queue",
+                format!("{} 的故障转移队列为空", app_type.as_str()),
+                format!("Failover queue for {} is empty", app_type.as_str()),
+            ));
+        }
+
+        let providers = Self::list(state, app_type.clone())?;
+        let current = Self::current(state, app_type.clone())?;
+        let runtime = health_runtime()?;
+
+        let mut attempts = Vec::with_capacity(queue.len());
+        for item in queue {
+            let Some(provider) = providers.get(&item.provider_id) else {
+                continue;
+            };
+            let has_url = StreamCheckService::extract_base_url(provider, &app_type)
+                .is_ok_and(|url| !url.trim().is_empty());
+            let outcome = if !has_url {
+                FailoverOutcome::Skipped("no API URL configured".to_string())
+            } else {
+                match runtime.block_on(probe_provider_endpoint(provider, &app_type, timeout)) {
+                    Ok(()) => FailoverOutcome::Healthy,
+                    Err(err) => FailoverOutcome::Failed(err.to_string()),
+                }
+            };
+            let healthy = outcome == FailoverOutcome::Healthy;
+            attempts.push(FailoverAttempt {
+                provider_id: item.provider_id,
+                provider_name: item.provider_name,
+                outcome,
+            });
+            if !healthy {
+                continue;
+            }
+
+            let selected = provider.id.clone();
+            let switched = selected != current;
+            if switched {
+                Self::switch(state, app_type.clone(), &selected)?;
+            }
+            return Ok(FailoverReport {
+                attempts,
+                selected,
+                switched,
+            });
+        }
+
+        let details = attempts
+            .iter()
+            .map(|attempt| match &attempt.outcome {
+                FailoverOutcome::Skipped(reason) | FailoverOutcome::Failed(reason) => {
+                    format!("{}: {reason}", attempt.provider_id)
+                }
+                FailoverOutcome::Healthy => attempt.provider_id.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        Err(AppError::localized(
+            "provider.failover.none_healthy",
+            format!("队列中没有健康的供应商，保持当前供应商 '{current}'：{details}"),
+            format!("No healthy provider in the queue; keeping '{current}': {details}"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::provider::test_support::{
+        dead_url, failover_state, serve_ok_once, EnvGuard,
+    };
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    #[test]
+    #[serial]
+    fn failover_run_switches_to_first_healthy_queued_provider() {
+        let home = TempDir::new().expect("create temp home");
+        let _env = EnvGuard::set_home(home.path());
+
+        let state = failover_state(&[
+            ("main", Some(dead_url())),
+            ("no-url", None),
+            ("down", Some(dead_url())),
+            ("up", Some(serve_ok_once())),
+        ]);
+        for id in ["no-url", "down", "up"] {
+            ProviderService::set_in_failover_queue(&state, AppType::Claude, id, true)
+                .expect("queue provider");
+        }
+
+        let report = ProviderService::failover_run(
+            &state,
+            AppType::Claude,
+            std::time::Duration::from_millis(2000),
+        )
+        .expect("a healthy provider is queued");
+
+        assert!(report.switched);
+        assert_eq!(report.selected, "up");
+        let outcomes: Vec<_> = report
+            .attempts
+            .iter()
+            .map(|a| (a.provider_id.as_str(), &a.outcome))
+            .collect();
+        assert!(matches!(
+            outcomes[0],
+            ("no-url", FailoverOutcome::Skipped(_))
+        ));
+        assert!(matches!(outcomes[1], ("down", FailoverOutcome::Failed(_))));
+        assert_eq!(outcomes[2], ("up", &FailoverOutcome::Healthy));
+        assert_eq!(
+            ProviderService::current(&state, AppType::Claude).expect("current"),
+            "up"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn failover_run_keeps_current_when_no_queued_provider_is_healthy() {
+        let home = TempDir::new().expect("create temp home");
+        let _env = EnvGuard::set_home(home.path());
+
+        let state = failover_state(&[("main", Some(dead_url())), ("down", Some(dead_url()))]);
+        ProviderService::set_in_failover_queue(&state, AppType::Claude, "down", true)
+            .expect("queue provider");
+
+        let err = ProviderService::failover_run(
+            &state,
+            AppType::Claude,
+            std::time::Duration::from_millis(2000),
+        )
+        .expect_err("no healthy provider");
+        assert_eq!(err.code(), "provider.failover.none_healthy");
+        assert_eq!(
+            ProviderService::current(&state, AppType::Claude).expect("current"),
+            "main"
+        );
+    }
+}
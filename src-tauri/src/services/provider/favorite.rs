@@ -0,0 +1,37 @@
+//! 供应商收藏（`provider favorite` / `provider unfavorite`，TUI 中按 `f` 切换）
+//!
+//! 收藏标记保存在 `meta` 中，随导出 / 导入一同保留。
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::ProviderMeta;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+impl ProviderService {
+    /// 收藏或取消收藏供应商
+    pub fn set_favorite(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        favorite: bool,
+    ) -> Result<(), AppError> {
+        let provider_id = provider_id.to_string();
+
+        Self::run_transaction(state, move |config| {
+            let provider = config
+                .get_manager_mut(&app_type)
+                .ok_or_else(|| Self::app_not_found(&app_type))?
+                .providers
+                .get_mut(&provider_id)
+                .ok_or_else(|| AppError::provider_not_found(&provider_id))?;
+
+            provider
+                .meta
+                .get_or_insert_with(ProviderMeta::default)
+                .favorite = favorite.then_some(true);
+            Ok(((), None))
+        })
+    }
+}
@@ -0,0 +1,278 @@
+//! 切换后的端点健康确认（`provider switch --wait-healthy`）
+//!
+//! 写入 live 配置后携带供应商凭证请求 `<base_url>/models`：连接失败、超时、401/403 或 5xx
+//! 视为不健康。在总超时内按间隔重复探测，仍不健康时默认恢复切换前的配置与 live 文件。
+
+use std::time::{Duration, Instant};
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::services::StreamCheckService;
+use crate::store::AppState;
+
+use super::{ProviderService, SwitchOptions, SwitchOutcome};
+
+/// `--wait-healthy` 的轮询参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthWait {
+    /// 等待端点健康的总时长
+    pub timeout: Duration,
+    /// 两次探测之间的间隔
+    pub interval: Duration,
+    /// 超时后是否回滚切换
+    pub rollback: bool,
+}
+
+/// 探测供应商端点；返回 Err 时附带失败原因
+pub(super) async fn probe_provider_endpoint(
+    provider: &Provider,
+    app_type: &AppType,
+    timeout: Duration,
+) -> Result<(), AppError> {
+    let base_url = StreamCheckService::extract_base_url(provider, app_type)?;
+    let base_url = base_url.trim().trim_end_matches('/');
+    if base_url.is_empty() {
+        return Err(AppError::localized(
+            "provider.health.no_url",
+            "供应商未配置 API 地址",
+            "Provider has no API URL configured",
+        ));
+    }
+
+    let client = StreamCheckService::build_client_for_provider(provider)?;
+    let mut request = client.get(format!("{base_url}/models")).timeout(timeout);
+    if let Ok(auth) = StreamCheckService::extract_auth(provider, app_type, base_url) {
+        let key = auth.access_token.as_deref().unwrap_or(&auth.api_key).trim();
+        request = if app_type == &AppType::Gemini && auth.access_token.is_none() {
+            request.header("x-goog-api-key", key)
+        } else {
+            // 与拉取模型列表一致：同时携带 Bearer 与 x-api-key，兼容 OpenAI / Anthropic 风格
+            request
+                .header("Authorization", format!("Bearer {key}"))
+                .header("x-api-key", key)
+        };
+    }
+
+    let response = request.send().await.map_err(|e| {
+        let reason = if e.is_timeout() {
+            "timeout".to_string()
+        } else {
+            e.to_string()
+        };
+        AppError::localized(
+            "provider.health.unreachable",
+            format!("端点不可达 ({base_url}): {reason}"),
+            format!("Endpoint unreachable ({base_url}): {reason}"),
+        )
+    })?;
+
+    let status = response.status();
+    if status.is_server_error() || status.as_u16() == 401 || status.as_u16() == 403 {
+        return Err(AppError::localized(
+            "provider.health.bad_status",
+            format!("端点返回异常状态 ({base_url}): {status}"),
+            format!("Endpoint returned {status} ({base_url})"),
+        ));
+    }
+    Ok(())
+}
+
+/// 在总超时内按间隔重复探测，直到成功；超时返回最后一次失败原因
+async fn poll_provider_endpoint(
+    provider: &Provider,
+    app_type: &AppType,
+    wait: HealthWait,
+) -> Result<(), AppError> {
+    let deadline = Instant::now() + wait.timeout;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let err = match probe_provider_endpoint(provider, app_type, remaining).await {
+            Ok(()) => return Ok(()),
+            Err(err) => err,
+        };
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(err);
+        }
+        tokio::time::sleep(wait.interval.min(remaining)).await;
+        if Instant::now() >= deadline {
+            return Err(err);
+        }
+    }
+}
+
+pub(super) fn health_runtime() -> Result<tokio::runtime::Runtime, AppError> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| {
+            AppError::localized(
+                "provider.health.runtime_create_failed",
+                format!("创建异步运行时失败: {e}"),
+                format!("Failed to create async runtime: {e}"),
+            )
+        })
+}
+
+impl ProviderService {
+    /// 切换供应商并轮询确认新端点可用；超时仍不健康时按需回滚到切换前的供应商与 live 配置
+    pub fn switch_wait_healthy(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        wait: HealthWait,
+        options: SwitchOptions,
+    ) -> Result<SwitchOutcome, AppError> {
+        let original = state.config.read().map_err(AppError::from)?.clone();
+        let backup = Self::capture_live_snapshot(&app_type)?;
+
+        let outcome =
+            Self::switch_reporting_hooks_with(state, app_type.clone(), provider_id, options)?;
+
+        let provider = Self::list(state, app_type.clone())?
+            .shift_remove(provider_id)
+            .ok_or_else(|| AppError::provider_not_found(provider_id))?;
+        let probe = health_runtime()?.block_on(poll_provider_endpoint(&provider, &app_type, wait));
+
+        let Err(probe_err) = probe else {
+            return Ok(outcome);
+        };
+        if !wait.rollback {
+            return Err(AppError::localized(
+                "provider.health.timeout",
+                format!("等待端点健康超时（已保留切换）: {probe_err}"),
+                format!("Timed out waiting for a healthy endpoint (switch kept): {probe_err}"),
+            ));
+        }
+        if let Err(rollback_err) = Self::rollback_after_failure(state, original, backup) {
+            return Err(AppError::localized(
+                "provider.health.rollback_failed",
+                format!("健康检查失败: {probe_err}；回滚失败: {rollback_err}"),
+                format!("Health check failed: {probe_err}; rollback failed: {rollback_err}"),
+            ));
+        }
+        Err(AppError::localized(
+            "provider.health.rolled_back",
+            format!("健康检查失败，已回滚切换: {probe_err}"),
+            format!("Health check failed, switch rolled back: {probe_err}"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app_config::MultiAppConfig;
+    use crate::config::{get_claude_settings_path, read_json_file, write_json_file};
+    use crate::services::provider::test_support::{
+        dead_url, failover_state, serve_unavailable_then_ok, tagged_claude_provider, EnvGuard,
+    };
+    use crate::services::provider::{state_from_config, SwitchOptions};
+    use serde_json::{json, Value};
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    #[test]
+    #[serial]
+    fn switch_wait_healthy_rolls_back_when_endpoint_is_unreachable() {
+        let home = TempDir::new().expect("create temp home");
+        let _env = EnvGuard::set_home(home.path());
+        let settings_path = get_claude_settings_path();
+        std::fs::create_dir_all(settings_path.parent().expect("claude dir"))
+            .expect("create ~/.claude");
+
+        let prior_settings = json!({ "env": { "ANTHROPIC_BASE_URL": "https://prior.example" } });
+        let mut config = MultiAppConfig::default();
+        config.ensure_app(&AppType::Claude);
+        {
+            let manager = config.get_manager_mut(&AppType::Claude).expect("claude");
+            manager.providers.insert(
+                "prior".to_string(),
+                tagged_claude_provider("prior", &[], prior_settings.clone()),
+            );
+            manager.providers.insert(
+                "broken".to_string(),
+                tagged_claude_provider(
+                    "broken",
+                    &[],
+                    json!({ "env": {
+                        "ANTHROPIC_BASE_URL": dead_url(),
+                        "ANTHROPIC_AUTH_TOKEN": "sk-broken"
+                    } }),
+                ),
+            );
+            manager.current = "prior".to_string();
+        }
+        write_json_file(&settings_path, &prior_settings).expect("seed live settings");
+        let state = state_from_config(config);
+
+        let err = ProviderService::switch_wait_healthy(
+            &state,
+            AppType::Claude,
+            "broken",
+            HealthWait {
+                timeout: std::time::Duration::from_millis(2000),
+                interval: std::time::Duration::from_millis(200),
+                rollback: true,
+            },
+            SwitchOptions::default(),
+        )
+        .expect_err("unreachable endpoint should fail the health check");
+        assert_eq!(err.code(), "provider.health.rolled_back");
+
+        assert_eq!(
+            ProviderService::current(&state, AppType::Claude).expect("current"),
+            "prior"
+        );
+        let live: Value = read_json_file(&settings_path).expect("read live settings");
+        assert_eq!(live, prior_settings);
+    }
+
+    #[test]
+    #[serial]
+    fn switch_wait_healthy_polls_until_ready_and_can_keep_unhealthy_switch() {
+        let home = TempDir::new().expect("create temp home");
+        let _env = EnvGuard::set_home(home.path());
+        let wait = HealthWait {
+            timeout: std::time::Duration::from_millis(3000),
+            interval: std::time::Duration::from_millis(50),
+            rollback: false,
+        };
+
+        let state = failover_state(&[
+            ("main", None),
+            ("warming", Some(serve_unavailable_then_ok())),
+            ("down", Some(dead_url())),
+        ]);
+        ProviderService::switch_wait_healthy(
+            &state,
+            AppType::Claude,
+            "warming",
+            wait,
+            SwitchOptions::default(),
+        )
+        .expect("endpoint becomes healthy on a later poll");
+        assert_eq!(
+            ProviderService::current(&state, AppType::Claude).expect("current"),
+            "warming"
+        );
+
+        let err = ProviderService::switch_wait_healthy(
+            &state,
+            AppType::Claude,
+            "down",
+            HealthWait {
+                timeout: std::time::Duration::from_millis(300),
+                ..wait
+            },
+            SwitchOptions::default(),
+        )
+        .expect_err("dead endpoint never becomes healthy");
+        assert_eq!(err.code(), "provider.health.timeout");
+        assert_eq!(
+            ProviderService::current(&state, AppType::Claude).expect("current"),
+            "down"
+        );
+    }
+}
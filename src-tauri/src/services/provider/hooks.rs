@@ -0,0 +1,373 @@
+//! 供应商切换钩子（settings.json 中的 `switchHooks`）
+//!
+//! 钩子命令通过系统 shell 执行，拥有与 cc-switch 相同的权限；只应配置自己信任的命令。
+
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::store::AppState;
+
+use super::{ProviderService, SwitchOptions, SwitchOutcome};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SwitchHookPhase {
+    Pre,
+    Post,
+}
+
+impl SwitchHookPhase {
+    fn as_str(self) -> &'static str {
+        match self {
+            SwitchHookPhase::Pre => "pre_switch",
+            SwitchHookPhase::Post => "post_switch",
+        }
+    }
+}
+
+fn shell_command(command: &str) -> Command {
+    #[cfg(windows)]
+    {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    }
+
+    #[cfg(not(windows))]
+    {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    }
+}
+
+/// 钩子最长运行时间，超时后强制结束
+const SWITCH_HOOK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 钩子退出后等待其输出读完的最长时间
+///
+/// 钩子在后台启动的进程（如 `proxy restart &`）会继承管道，EOF 可能永远不会到来。
+const SWITCH_HOOK_OUTPUT_GRACE: Duration = Duration::from_secs(1);
+
+/// 在后台线程读完管道，避免输出过多时子进程阻塞在写入上
+fn drain<R: Read + Send + 'static>(pipe: Option<R>) -> mpsc::Receiver<String> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        let _ = tx.send(String::from_utf8_lossy(&buf).into_owned());
+    });
+    rx
+}
+
+/// 取管道输出，最多等到 `until`；仍被后台进程占用时放弃（读取线程随管道关闭自行结束）
+fn collect_output(output: &mpsc::Receiver<String>, until: Instant) -> String {
+    output
+        .recv_timeout(until.saturating_duration_since(Instant::now()))
+        .unwrap_or_default()
+}
+
+/// 执行切换钩子，目标供应商信息通过环境变量传入；非零退出或超时视为失败
+pub(super) fn run_switch_hook(
+    phase: SwitchHookPhase,
+    command: &str,
+    app_type: &AppType,
+    provider: &Provider,
+    previous_id: &str,
+) -> Result<(), AppError> {
+    run_switch_hook_with_timeout(
+        phase,
+        command,
+        app_type,
+        provider,
+        previous_id,
+        SWITCH_HOOK_TIMEOUT,
+    )
+}
+
+fn run_switch_hook_with_timeout(
+    phase: SwitchHookPhase,
+    command: &str,
+    app_type: &AppType,
+    provider: &Provider,
+    previous_id: &str,
+    timeout: Duration,
+) -> Result<(), AppError> {
+    let hook = phase.as_str();
+    let mut child = shell_command(command)
+        .env("CC_SWITCH_HOOK", hook)
+        .env("CC_SWITCH_APP", app_type.as_str())
+        .env("CC_SWITCH_PROVIDER_ID", &provider.id)
+        .env("CC_SWITCH_PROVIDER_NAME", &provider.name)
+        .env("CC_SWITCH_PREVIOUS_PROVIDER_ID", previous_id)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            AppError::localized(
+                "provider.hook.spawn_failed",
+                format!("无法执行 {hook} 钩子: {e}"),
+                format!("Failed to run {hook} hook: {e}"),
+            )
+        })?;
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if Instant::now() < deadline => thread::sleep(Duration::from_millis(20)),
+            Ok(None) | Err(_) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                break None;
+            }
+        }
+    };
+    let Some(status) = status else {
+        let secs = timeout.as_secs();
+        return Err(AppError::localized(
+            "provider.hook.timeout",
+            format!("{hook} 钩子超过 {secs} 秒未结束，已终止"),
+            format!("{hook} hook did not finish within {secs}s and was killed"),
+        ));
+    };
+
+    let output_deadline = deadline.min(Instant::now() + SWITCH_HOOK_OUTPUT_GRACE);
+    let stdout = collect_output(&stdout, output_deadline);
+    if !stdout.trim().is_empty() {
+        log::debug!("{hook} 钩子输出: {}", stdout.trim());
+    }
+    if status.success() {
+        return Ok(());
+    }
+
+    let status = status
+        .code()
+        .map_or_else(|| "signal".to_string(), |code| code.to_string());
+    let stderr = collect_output(&stderr, output_deadline).trim().to_string();
+    let detail = if stderr.is_empty() {
+        String::new()
+    } else {
+        format!(": {stderr}")
+    };
+    Err(AppError::localized(
+        "provider.hook.failed",
+        format!("{hook} 钩子退出码 {status}{detail}"),
+        format!("{hook} hook exited with status {status}{detail}"),
+    ))
+}
+
+impl ProviderService {
+    /// 与 `switch` 相同，但返回写入的 live 文件，并将 post_switch 钩子的失败作为告警返回
+    ///
+    /// pre_switch 钩子失败会中止切换；post_switch 钩子失败不会回滚已完成的切换。
+    pub fn switch_reporting_hooks(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+    ) -> Result<SwitchOutcome, AppError> {
+        Self::switch_reporting_hooks_with(state, app_type, provider_id, SwitchOptions::default())
+    }
+
+    /// 同 `switch_reporting_hooks`，按 `options` 切换：`strict_mcp` 时任一 MCP 服务器同步失败
+    /// 即回滚切换，否则失败的服务器记入 `SwitchOutcome::mcp_failures`；`refresh_snapshot`
+    /// 关闭时不回读 live 文件覆盖存储的快照
+    pub fn switch_reporting_hooks_with(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        options: SwitchOptions,
+    ) -> Result<SwitchOutcome, AppError> {
+        Self::ensure_not_archived(state, &app_type, provider_id)?;
+        Self::validate_live_overlay(&app_type, &options.overlay)?;
+        let hooks = crate::settings::get_switch_hooks(&app_type);
+        if hooks.pre_switch.is_none() && hooks.post_switch.is_none() {
+            let report = Self::apply_switch(state, app_type, provider_id, options)?;
+            return Ok(SwitchOutcome {
+                written: report.written,
+                hook_warning: None,
+                mcp_failures: report.mcp_failures,
+                empty_api_key: report.empty_api_key,
+            });
+        }
+
+        let (provider, previous_id) = {
+            let config = state.config.read().map_err(AppError::from)?;
+            let manager = config
+                .get_manager(&app_type)
+                .ok_or_else(|| Self::app_not_found(&app_type))?;
+            let provider = manager
+                .providers
+                .get(provider_id)
+                .cloned()
+                .ok_or_else(|| AppError::provider_not_found(provider_id))?;
+            (provider, manager.current.clone())
+        };
+
+        if let Some(command) = hooks.pre_switch.as_deref() {
+            run_switch_hook(
+                SwitchHookPhase::Pre,
+                command,
+                &app_type,
+                &provider,
+                &previous_id,
+            )?;
+        }
+        let report = Self::apply_switch(state, app_type.clone(), provider_id, options)?;
+
+        let hook_warning = hooks.post_switch.as_deref().and_then(|command| {
+            run_switch_hook(
+                SwitchHookPhase::Post,
+                command,
+                &app_type,
+                &provider,
+                &previous_id,
+            )
+            .err()
+        });
+        Ok(SwitchOutcome {
+            written: report.written,
+            hook_warning,
+            mcp_failures: report.mcp_failures,
+            empty_api_key: report.empty_api_key,
+        })
+    }
+}
+
+#[cfg(all(test, not(windows)))]
+mod tests {
+    use super::*;
+    use crate::services::provider::test_support::{failover_state, EnvGuard};
+    use serde_json::json;
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    #[test]
+    fn hook_is_killed_after_timeout() {
+        let provider = Provider::with_id("p".to_string(), "P".to_string(), json!({}), None);
+        let started = Instant::now();
+        let err = run_switch_hook_with_timeout(
+            SwitchHookPhase::Post,
+            "sleep 5",
+            &AppType::Claude,
+            &provider,
+            "",
+            Duration::from_millis(200),
+        )
+        .expect_err("timed out");
+        assert_eq!(err.code(), "provider.hook.timeout");
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+
+    #[test]
+    fn hook_returns_when_a_background_process_keeps_the_pipes_open() {
+        let provider = Provider::with_id("p".to_string(), "P".to_string(), json!({}), None);
+        let started = Instant::now();
+        run_switch_hook_with_timeout(
+            SwitchHookPhase::Post,
+            "echo restarted; sleep 60 &",
+            &AppType::Claude,
+            &provider,
+            "",
+            Duration::from_secs(10),
+        )
+        .expect("hook exited successfully");
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        let err = run_switch_hook_with_timeout(
+            SwitchHookPhase::Post,
+            "sleep 60 & exit 2",
+            &AppType::Claude,
+            &provider,
+            "",
+            Duration::from_secs(10),
+        )
+        .expect_err("non-zero exit");
+        assert_eq!(err.code(), "provider.hook.failed");
+        assert!(started.elapsed() < Duration::from_secs(8));
+    }
+
+    #[test]
+    #[serial]
+    fn switch_hooks_abort_on_pre_failure_and_only_warn_on_post_failure() {
+        use crate::settings::{AppSettings, SwitchHookCommands, SwitchHookSettings};
+
+        let home = TempDir::new().expect("create temp home");
+        let _env = EnvGuard::set_home(home.path());
+        let state = failover_state(&[("main", None), ("backup", None)]);
+        let marker = home.path().join("post-hook.txt");
+
+        let set_hooks = |global: SwitchHookCommands, claude: Option<SwitchHookCommands>| {
+            let settings = AppSettings {
+                switch_hooks: Some(SwitchHookSettings {
+                    global,
+                    apps: claude
+                        .map(|hooks| {
+                            std::collections::HashMap::from([("claude".to_string(), hooks)])
+                        })
+                        .unwrap_or_default(),
+                }),
+                ..AppSettings::default()
+            };
+            crate::settings::update_settings(settings).expect("save hooks");
+        };
+
+        set_hooks(
+            SwitchHookCommands {
+                pre_switch: Some("exit 3".to_string()),
+                post_switch: None,
+            },
+            None,
+        );
+        let pre_result = ProviderService::switch(&state, AppType::Claude, "backup");
+        let after_pre = ProviderService::current(&state, AppType::Claude).expect("current");
+
+        // 应用级 post_switch 覆盖全局；全局 pre_switch 仍然生效
+        set_hooks(
+            SwitchHookCommands {
+                pre_switch: Some("true".to_string()),
+                post_switch: Some("exit 9".to_string()),
+            },
+            Some(SwitchHookCommands {
+                pre_switch: None,
+                post_switch: Some(format!(
+                    "printf '%s:%s' \"$CC_SWITCH_PROVIDER_ID\" \"$CC_SWITCH_PREVIOUS_PROVIDER_ID\" > '{}'; exit 1",
+                    marker.display()
+                )),
+            }),
+        );
+        let post_result =
+            ProviderService::switch_reporting_hooks(&state, AppType::Claude, "backup");
+        crate::settings::update_settings(AppSettings::default()).expect("reset settings");
+
+        assert_eq!(
+            pre_result.expect_err("pre hook failure aborts").code(),
+            "provider.hook.failed"
+        );
+        assert_eq!(after_pre, "main");
+
+        let warning = post_result
+            .expect("post hook failure keeps the switch")
+            .hook_warning
+            .expect("post hook failure is reported");
+        assert_eq!(warning.code(), "provider.hook.failed");
+        assert_eq!(
+            ProviderService::current(&state, AppType::Claude).expect("current"),
+            "backup"
+        );
+        assert_eq!(
+            std::fs::read_to_string(&marker).expect("post hook ran"),
+            "backup:main"
+        );
+    }
+}
@@ -0,0 +1,146 @@
+//! 根据 live 配置反查对应的存储供应商（`provider identify`）
+//!
+//! 手动编辑 live 文件后，用 base_url / model / wire_api 三个识别字段与各供应商快照比对，
+//! 判断 live 仍对应哪个供应商，或已与所有供应商发生漂移。
+
+use regex::Regex;
+use serde_json::json;
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::services::StreamCheckService;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+/// 用于识别供应商的字段；缺失的字段记为 `None`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProviderFingerprint {
+    pub base_url: Option<String>,
+    pub model: Option<String>,
+    pub wire_api: Option<String>,
+}
+
+/// `provider identify` 的结果
+#[derive(Debug, Clone)]
+pub struct ProviderIdentity {
+    /// 从 live 配置提取的识别字段
+    pub live: ProviderFingerprint,
+    /// 识别字段完全一致的供应商 ID
+    pub matches: Vec<String>,
+    /// 仅 base_url 一致（model 或 wire_api 已漂移）的供应商 ID
+    pub same_endpoint: Vec<String>,
+    pub current: String,
+}
+
+impl ProviderFingerprint {
+    fn of(provider: &Provider, app_type: &AppType) -> Self {
+        let base_url = StreamCheckService::extract_base_url(provider, app_type)
+            .ok()
+            .map(|url| url.trim().trim_end_matches('/').to_string())
+            .filter(|url| !url.is_empty());
+        let (model, wire_api) = match app_type {
+            AppType::Claude => (
+                StreamCheckService::extract_env_model(provider, "ANTHROPIC_MODEL"),
+                None,
+            ),
+            AppType::Codex => (
+                StreamCheckService::extract_codex_model(provider),
+                extract_codex_wire_api(provider),
+            ),
+            AppType::Gemini => (
+                StreamCheckService::extract_env_model(provider, "GEMINI_MODEL"),
+                None,
+            ),
+            AppType::OpenCode => (None, None),
+        };
+        Self {
+            base_url,
+            model,
+            wire_api,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.base_url.is_none() && self.model.is_none() && self.wire_api.is_none()
+    }
+}
+
+fn extract_codex_wire_api(provider: &Provider) -> Option<String> {
+    let config_text = provider.settings_config.get("config")?.as_str()?;
+    let re = Regex::new(r#"(?m)^\s*wire_api\s*=\s*["']([^"']+)["']"#).ok()?;
+    re.captures(config_text)
+        .and_then(|caps| caps.get(1))
+        .map(|m| m.as_str().trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+impl ProviderService {
+    /// 读取 live 配置并找出与之对应的存储供应商
+    pub fn identify_live(
+        state: &AppState,
+        app_type: AppType,
+    ) -> Result<ProviderIdentity, AppError> {
+        if app_type.is_additive_mode() {
+            return Err(AppError::localized(
+                "provider.identify.unsupported",
+                format!("{app_type} 的 live 配置同时包含所有供应商，无需识别"),
+                format!("{app_type} live config holds every provider; nothing to identify"),
+            ));
+        }
+
+        let live = Self::live_fingerprint(&app_type)?;
+
+        let mut matches = Vec::new();
+        let mut same_endpoint = Vec::new();
+        for (id, provider) in Self::list(state, app_type.clone())? {
+            let stored = ProviderFingerprint::of(&provider, &app_type);
+            if stored.is_empty() {
+                continue;
+            }
+            if stored == live {
+                matches.push(id);
+            } else if stored.base_url.is_some() && stored.base_url == live.base_url {
+                same_endpoint.push(id);
+            }
+        }
+
+        Ok(ProviderIdentity {
+            live,
+            matches,
+            same_endpoint,
+            current: Self::current(state, app_type)?,
+        })
+    }
+
+    /// 当前供应商的快照是否已与 live 配置漂移（只比对当前供应商，供 TUI 列表标记）
+    ///
+    /// 累加模式应用、当前供应商不存在或其识别字段为空时视为未漂移。
+    pub fn current_live_drift(state: &AppState, app_type: AppType) -> Result<bool, AppError> {
+        if app_type.is_additive_mode() {
+            return Ok(false);
+        }
+        let current = Self::current(state, app_type.clone())?;
+        let Some(provider) = Self::list(state, app_type.clone())?.shift_remove(&current) else {
+            return Ok(false);
+        };
+        let stored = ProviderFingerprint::of(&provider, &app_type);
+        if stored.is_empty() {
+            return Ok(false);
+        }
+        Ok(Self::live_fingerprint(&app_type)? != stored)
+    }
+
+    fn live_fingerprint(app_type: &AppType) -> Result<ProviderFingerprint, AppError> {
+        // Codex 仅需 config.toml；env-file 模式下 auth.json 可能不存在
+        let live_settings = match app_type {
+            AppType::Codex => json!({
+                "config": crate::codex_config::read_and_validate_codex_config_text()?
+            }),
+            _ => Self::read_live_settings(app_type.clone())?,
+        };
+        let live_provider = Provider::with_id(String::new(), String::new(), live_settings, None);
+        Ok(ProviderFingerprint::of(&live_provider, app_type))
+    }
+}
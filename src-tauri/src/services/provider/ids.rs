@@ -0,0 +1,189 @@
+//! 供应商 ID 的校验与规范化
+//!
+//! ID 会出现在 live 配置、备份文件名与 Codex 的 `model_provider` 推导中，
+//! 因此不允许为空，空白与路径分隔符统一替换为 `-`。
+
+use serde_json::Value;
+
+use crate::error::AppError;
+use crate::provider::Provider;
+
+use super::is_codex_official_provider;
+
+/// 校验并规范化供应商 ID：去除首尾空白，空白与 `/`、`\` 替换为 `-`；结果为空或为 `.`/`..` 时报错
+pub fn normalize_provider_id(raw: &str) -> Result<String, AppError> {
+    let mut normalized = String::with_capacity(raw.len());
+    let mut last_replaced = false;
+    for c in raw.trim().chars() {
+        if c.is_whitespace() || c == '/' || c == '\\' {
+            // 连续的空白/分隔符只替换为一个 `-`
+            if !last_replaced {
+                normalized.push('-');
+            }
+            last_replaced = true;
+        } else {
+            normalized.push(c);
+            last_replaced = false;
+        }
+    }
+
+    if normalized.is_empty() || normalized.chars().all(|c| c == '-') {
+        return Err(AppError::localized(
+            "provider.id.empty",
+            format!("供应商 ID 不能为空: '{raw}'"),
+            format!("Provider id must not be empty: '{raw}'"),
+        ));
+    }
+    if normalized == "." || normalized == ".." {
+        return Err(AppError::localized(
+            "provider.id.invalid",
+            format!("无效的供应商 ID: '{raw}'"),
+            format!("Invalid provider id: '{raw}'"),
+        ));
+    }
+    Ok(normalized)
+}
+
+/// 为已被占用的 ID 推荐一个可用的 `<id>-<n>`
+pub(crate) fn suggest_free_provider_id<'a>(
+    taken: &str,
+    existing: impl IntoIterator<Item = &'a String>,
+) -> String {
+    let existing: std::collections::HashSet<&str> =
+        existing.into_iter().map(String::as_str).collect();
+    (2..)
+        .map(|n| format!("{taken}-{n}"))
+        .find(|candidate| !existing.contains(candidate.as_str()))
+        .unwrap_or_else(|| taken.to_string())
+}
+
+/// 生成唯一的 Provider ID
+/// 基于名称转换为 kebab-case，如有冲突则追加数字后缀
+pub fn generate_provider_id(name: &str, existing_ids: &[String]) -> String {
+    // 转换为 kebab-case
+    let base_id = name
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else if c.is_whitespace() {
+                '-'
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>()
+        .trim_matches('-')
+        .to_string();
+    // 名称中没有可用字符（如全是符号）时使用通用前缀，避免生成空 ID
+    let base_id = if base_id.is_empty() {
+        "provider".to_string()
+    } else {
+        base_id
+    };
+
+    // 检查唯一性
+    if !existing_ids.contains(&base_id) {
+        return base_id;
+    }
+
+    // 追加数字后缀
+    let mut counter = 1;
+    loop {
+        let candidate = format!("{}-{}", base_id, counter);
+        if !existing_ids.contains(&candidate) {
+            return candidate;
+        }
+        counter += 1;
+    }
+}
+
+/// Codex config.toml 声明的 `model_provider` 及其 `base_url`
+fn codex_model_provider_entry(provider: &Provider) -> Option<(String, Option<String>)> {
+    let doc = provider
+        .settings_config
+        .get("config")
+        .and_then(Value::as_str)?
+        .parse::<toml_edit::DocumentMut>()
+        .ok()?;
+    let key = doc.get("model_provider")?.as_str()?.to_string();
+    let base_url = doc
+        .get("model_providers")
+        .and_then(|v| v.as_table_like())
+        .and_then(|t| t.get(&key))
+        .and_then(|v| v.as_table_like())
+        .and_then(|section| section.get("base_url"))
+        .and_then(|v| v.as_str())
+        .map(|url| url.trim().trim_end_matches('/').to_string());
+    Some((key, base_url))
+}
+
+/// 检查 Codex 供应商的 `model_provider` 是否与其他供应商冲突
+///
+/// 名称不同的供应商可能推导出同一个键（如 "Duck Coding" 与 "duck-coding"），
+/// 此时两者的 `[model_providers.<key>]` 指向不同地址会被 Codex 混为一谈。
+pub(crate) fn ensure_unique_codex_model_provider<'a>(
+    provider: &Provider,
+    others: impl IntoIterator<Item = &'a Provider>,
+) -> Result<(), AppError> {
+    if is_codex_official_provider(provider) {
+        return Ok(());
+    }
+    let Some((key, base_url)) = codex_model_provider_entry(provider) else {
+        return Ok(());
+    };
+
+    let others: Vec<(&Provider, String, Option<String>)> = others
+        .into_iter()
+        .filter(|other| other.id != provider.id && !is_codex_official_provider(other))
+        .filter_map(|other| codex_model_provider_entry(other).map(|(key, url)| (other, key, url)))
+        .collect();
+    let Some((other, _, _)) = others
+        .iter()
+        .find(|(_, other_key, other_url)| *other_key == key && *other_url != base_url)
+    else {
+        return Ok(());
+    };
+
+    let suggestion = (2..)
+        .map(|n| format!("{key}_{n}"))
+        .find(|candidate| {
+            others
+                .iter()
+                .all(|(_, other_key, _)| other_key != candidate)
+        })
+        .unwrap_or_else(|| key.clone());
+    Err(AppError::localized(
+        "provider.codex.model_provider_conflict",
+        format!(
+            "供应商 '{}' 的 model_provider '{key}' 与供应商 '{}' 冲突（base_url 不同），请改用可区分的名称，如 '{suggestion}'",
+            provider.id, other.id
+        ),
+        format!(
+            "Provider '{}' uses model_provider '{key}', which provider '{}' already uses with a different base_url; pick a distinguishing name such as '{suggestion}'",
+            provider.id, other.id
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_provider_id_rejects_empty_and_replaces_separators() {
+        assert_eq!(normalize_provider_id(" work ").expect("trimmed"), "work");
+        assert_eq!(
+            normalize_provider_id("my relay/v2\\beta").expect("normalized"),
+            "my-relay-v2-beta"
+        );
+        assert_eq!(normalize_provider_id("a  /  b").expect("collapsed"), "a-b");
+        for bad in ["", "   ", "/", " \\ ", ".."] {
+            assert!(
+                normalize_provider_id(bad).is_err(),
+                "'{bad}' should be rejected"
+            );
+        }
+    }
+}
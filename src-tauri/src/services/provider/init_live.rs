@@ -0,0 +1,122 @@
+use std::path::PathBuf;
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+impl ProviderService {
+    /// 为从未运行过的应用强制创建基础 live 配置。
+    ///
+    /// 注意：这里会创建应用配置目录并写入本应由应用自身首次运行时创建的文件，
+    /// 之后 `sync_policy::should_sync_live` 即视其为已初始化。返回创建的配置目录。
+    pub fn force_init_live(state: &AppState, app_type: AppType) -> Result<PathBuf, AppError> {
+        let (providers, common_config_snippet) = {
+            let config = state.config.read().map_err(AppError::from)?;
+            let manager = config
+                .get_manager(&app_type)
+                .ok_or_else(|| Self::app_not_found(&app_type))?;
+            let providers: Vec<_> = if app_type.is_additive_mode() {
+                manager.providers.values().cloned().collect()
+            } else {
+                manager
+                    .providers
+                    .get(&manager.current)
+                    .cloned()
+                    .into_iter()
+                    .collect()
+            };
+            (
+                providers,
+                config.common_config_snippets.get(&app_type).cloned(),
+            )
+        };
+
+        if providers.is_empty() {
+            return Err(AppError::localized(
+                "provider.force_init.no_current",
+                format!("{} 没有当前供应商，无法初始化 live 配置", app_type.as_str()),
+                format!(
+                    "No current provider for {}; cannot initialize live config",
+                    app_type.as_str()
+                ),
+            ));
+        }
+
+        let dir = crate::sync_policy::live_config_dir(&app_type);
+        std::fs::create_dir_all(&dir).map_err(|e| AppError::io(&dir, e))?;
+
+        for provider in &providers {
+            let apply_common_config = provider
+                .meta
+                .as_ref()
+                .and_then(|meta| meta.apply_common_config)
+                .unwrap_or(true);
+            Self::write_live_snapshot(
+                &app_type,
+                provider,
+                common_config_snippet.as_deref(),
+                apply_common_config,
+            )?;
+        }
+
+        crate::services::mcp::McpService::sync_all_enabled(state)?;
+        Ok(dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app_config::MultiAppConfig;
+    use crate::config::{get_claude_settings_path, read_json_file};
+    use crate::provider::Provider;
+    use crate::services::provider::state_from_config;
+    use crate::services::provider::test_support::EnvGuard;
+    use serde_json::{json, Value};
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    #[test]
+    #[serial]
+    fn force_init_live_creates_baseline_config_for_uninitialized_app() {
+        let home = TempDir::new().expect("create temp home");
+        let _env = EnvGuard::set_home(home.path());
+
+        let mut config = MultiAppConfig::default();
+        config.ensure_app(&AppType::Claude);
+        let state = state_from_config(config);
+
+        let provider = Provider::with_id(
+            "p1".to_string(),
+            "First".to_string(),
+            json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "token1" } }),
+            None,
+        );
+        ProviderService::add(&state, AppType::Claude, provider).expect("add p1");
+        assert!(!crate::sync_policy::should_sync_live(&AppType::Claude));
+        assert!(!get_claude_settings_path().exists());
+
+        let dir = ProviderService::force_init_live(&state, AppType::Claude).expect("force init");
+
+        assert_eq!(dir, crate::config::get_claude_config_dir());
+        assert!(crate::sync_policy::should_sync_live(&AppType::Claude));
+        let live: Value = read_json_file(&get_claude_settings_path()).expect("read live");
+        assert_eq!(live["env"]["ANTHROPIC_AUTH_TOKEN"], json!("token1"));
+    }
+
+    #[test]
+    #[serial]
+    fn force_init_live_requires_current_provider() {
+        let home = TempDir::new().expect("create temp home");
+        let _env = EnvGuard::set_home(home.path());
+
+        let mut config = MultiAppConfig::default();
+        config.ensure_app(&AppType::Codex);
+        let state = state_from_config(config);
+
+        assert!(ProviderService::force_init_live(&state, AppType::Codex).is_err());
+        assert!(!crate::sync_policy::should_sync_live(&AppType::Codex));
+    }
+}
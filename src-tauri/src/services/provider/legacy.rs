@@ -0,0 +1,132 @@
+//! 导入旧版本遗留的 Claude `settings-*.json` 供应商文件（`config migrate-legacy`）
+//!
+//! 早期版本会在 Claude 配置目录内为每个供应商保存一份 `settings-<name>.json`。
+//! 这里把它们导入为供应商；内容与已有供应商相同的文件视为重复，不再导入。
+
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::store::AppState;
+
+use super::{normalize_provider_id, ProviderService};
+
+/// 单个遗留文件的处理结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LegacyImportOutcome {
+    /// 已导入为新供应商（供应商 ID）
+    Imported(String),
+    /// 内容与已有供应商相同（已有供应商 ID）
+    Duplicate(String),
+    /// 无法读取或不是 JSON 对象
+    Invalid(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct LegacyImport {
+    pub path: PathBuf,
+    pub outcome: LegacyImportOutcome,
+    /// 是否已在 `--cleanup` 时删除该文件
+    pub removed: bool,
+}
+
+/// Claude 配置目录中的遗留 `settings-*.json` 文件（按文件名排序）
+fn legacy_settings_files(dir: &Path) -> Result<Vec<PathBuf>, AppError> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map_err(|e| AppError::io(dir, e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| {
+                        name.len() > "settings-.json".len()
+                            && name.starts_with("settings-")
+                            && name.ends_with(".json")
+                    })
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+fn read_legacy_settings(path: &Path) -> Result<Value, String> {
+    let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let value: Value = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+    if value.is_object() {
+        Ok(value)
+    } else {
+        Err("not a JSON object".to_string())
+    }
+}
+
+/// 由文件名生成不与已有供应商冲突的 ID：`settings-work.json` → `work`、`work-2`…
+fn unique_provider_id(path: &Path, state: &AppState) -> Result<String, AppError> {
+    let stem = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .and_then(|stem| stem.strip_prefix("settings-"))
+        .and_then(|stem| normalize_provider_id(stem).ok())
+        .unwrap_or_else(|| "legacy".to_string());
+    let existing = ProviderService::list(state, AppType::Claude)?;
+    let mut id = stem.clone();
+    let mut suffix = 2;
+    while existing.contains_key(&id) {
+        id = format!("{stem}-{suffix}");
+        suffix += 1;
+    }
+    Ok(id)
+}
+
+impl ProviderService {
+    /// 导入 Claude 目录中的遗留 `settings-*.json`；`cleanup` 时删除已导入或重复的文件
+    pub fn migrate_legacy_claude_files(
+        state: &AppState,
+        cleanup: bool,
+    ) -> Result<Vec<LegacyImport>, AppError> {
+        let dir = crate::config::get_claude_config_dir();
+        let mut results = Vec::new();
+
+        for path in legacy_settings_files(&dir)? {
+            let outcome = match read_legacy_settings(&path) {
+                Err(reason) => LegacyImportOutcome::Invalid(reason),
+                Ok(settings) => {
+                    let id = unique_provider_id(&path, state)?;
+                    let mut provider = Provider::with_id(id.clone(), id.clone(), settings, None);
+                    // 与写入时相同的归一化后再比较，避免仅因旧模型键不同而重复导入
+                    Self::normalize_provider_if_claude(&AppType::Claude, &mut provider);
+                    let duplicate = Self::list(state, AppType::Claude)?
+                        .into_iter()
+                        .find(|(_, existing)| existing.settings_config == provider.settings_config)
+                        .map(|(existing_id, _)| existing_id);
+                    match duplicate {
+                        Some(existing_id) => LegacyImportOutcome::Duplicate(existing_id),
+                        None => {
+                            Self::add(state, AppType::Claude, provider)?;
+                            LegacyImportOutcome::Imported(id)
+                        }
+                    }
+                }
+            };
+
+            let removed = cleanup && !matches!(outcome, LegacyImportOutcome::Invalid(_)) && {
+                std::fs::remove_file(&path).map_err(|e| AppError::io(&path, e))?;
+                true
+            };
+            results.push(LegacyImport {
+                path,
+                outcome,
+                removed,
+            });
+        }
+
+        Ok(results)
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::Path;
 
 use serde_json::Value;
 
@@ -15,6 +16,7 @@ pub(super) enum LiveSnapshot {
     Codex {
         auth: Option<Value>,
         config: Option<String>,
+        env: Option<String>,
     },
     Gemini {
         env: Option<HashMap<String, String>>,
@@ -36,7 +38,7 @@ impl LiveSnapshot {
                     delete_file(&path)?;
                 }
             }
-            LiveSnapshot::Codex { auth, config } => {
+            LiveSnapshot::Codex { auth, config, env } => {
                 let auth_path = get_codex_auth_path();
                 let config_path = get_codex_config_path();
                 if let Some(value) = auth {
@@ -45,10 +47,15 @@ impl LiveSnapshot {
                     delete_file(&auth_path)?;
                 }
 
-                if let Some(text) = config {
-                    crate::config::write_text_file(&config_path, text)?;
-                } else if config_path.exists() {
-                    delete_file(&config_path)?;
+                for (path, text) in [
+                    (config_path, config),
+                    (crate::codex_config::get_codex_env_path(), env),
+                ] {
+                    if let Some(text) = text {
+                        crate::config::write_text_file(&path, text)?;
+                    } else if path.exists() {
+                        delete_file(&path)?;
+                    }
                 }
             }
             LiveSnapshot::Gemini { env, config } => {
@@ -87,31 +94,82 @@ impl LiveSnapshot {
     }
 }
 
+/// 读取 live JSON 文件；内容损坏（如崩溃时被截断）时记录警告并返回 None，不中止切换
+pub(super) fn read_live_json_lenient(path: &Path) -> Result<Option<Value>, AppError> {
+    match read_json_file(path) {
+        Ok(value) => Ok(Some(value)),
+        Err(err @ AppError::Json { .. }) => {
+            log::warn!("live 配置已损坏，已跳过: {err}");
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// 同 `read_live_json_lenient`，读取 Codex config.toml
+pub(super) fn read_codex_config_lenient() -> Result<Option<String>, AppError> {
+    let text = crate::codex_config::read_codex_config_text()?;
+    match crate::codex_config::validate_config_toml(&text) {
+        Ok(()) => Ok(Some(text)),
+        Err(err) => {
+            log::warn!(
+                "live 配置 {} 已损坏，已跳过: {err}",
+                get_codex_config_path().display()
+            );
+            Ok(None)
+        }
+    }
+}
+
+/// 快照遇到损坏的 live 文件时按不存在处理，并先复制到 `<文件名>.corrupt` 保留原内容
+/// （回滚会删除按不存在处理的文件）
+fn keep_corrupt_copy(path: &Path) {
+    let Some(name) = path.file_name() else {
+        return;
+    };
+    let copy = path.with_file_name(format!("{}.corrupt", name.to_string_lossy()));
+    match std::fs::copy(path, &copy) {
+        Ok(_) => log::warn!("已将损坏的 live 配置保存到 {}", copy.display()),
+        Err(err) => log::warn!("保存损坏的 live 配置 {} 失败: {err}", path.display()),
+    }
+}
+
+fn capture_json(path: &Path) -> Result<Option<Value>, AppError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let value = read_live_json_lenient(path)?;
+    if value.is_none() {
+        keep_corrupt_copy(path);
+    }
+    Ok(value)
+}
+
 pub(super) fn capture_live_snapshot(app_type: &AppType) -> Result<LiveSnapshot, AppError> {
     match app_type {
         AppType::Claude => {
-            let path = get_claude_settings_path();
-            let settings = if path.exists() {
-                Some(read_json_file(&path)?)
-            } else {
-                None
-            };
+            let settings = capture_json(&get_claude_settings_path())?;
             Ok(LiveSnapshot::Claude { settings })
         }
         AppType::Codex => {
-            let auth_path = get_codex_auth_path();
+            let auth = capture_json(&get_codex_auth_path())?;
             let config_path = get_codex_config_path();
-            let auth = if auth_path.exists() {
-                Some(read_json_file(&auth_path)?)
+            let config = if config_path.exists() {
+                let text = read_codex_config_lenient()?;
+                if text.is_none() {
+                    keep_corrupt_copy(&config_path);
+                }
+                text
             } else {
                 None
             };
-            let config = if config_path.exists() {
-                Some(crate::codex_config::read_and_validate_codex_config_text()?)
+            let env_path = crate::codex_config::get_codex_env_path();
+            let env = if env_path.exists() {
+                Some(std::fs::read_to_string(&env_path).map_err(|e| AppError::io(&env_path, e))?)
             } else {
                 None
             };
-            Ok(LiveSnapshot::Codex { auth, config })
+            Ok(LiveSnapshot::Codex { auth, config, env })
         }
         AppType::Gemini => {
             use crate::gemini_config::{
@@ -124,12 +182,7 @@ pub(super) fn capture_live_snapshot(app_type: &AppType) -> Result<LiveSnapshot,
             } else {
                 None
             };
-            let settings_path = get_gemini_settings_path();
-            let config = if settings_path.exists() {
-                Some(read_json_file(&settings_path)?)
-            } else {
-                None
-            };
+            let config = capture_json(&get_gemini_settings_path())?;
             Ok(LiveSnapshot::Gemini { env, config })
         }
         AppType::OpenCode => {
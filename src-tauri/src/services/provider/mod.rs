@@ -1,8 +1,36 @@
+mod archive;
+mod claude_headers;
+mod codex_auth;
+mod convert;
+mod duplicate;
 mod endpoints;
+mod export;
+mod failover;
+mod favorite;
 mod gemini_auth;
+mod health;
+mod hooks;
+mod identify;
+mod ids;
+mod init_live;
+mod legacy;
 mod live;
 mod models;
+mod overlay;
+mod preview;
+mod relative;
+mod rename;
+mod render;
+mod resolve;
+mod seed;
+mod snapshot;
+mod tags;
+#[cfg(test)]
+mod test_support;
 mod usage;
+mod validate;
+
+use std::path::PathBuf;
 
 use indexmap::IndexMap;
 use serde::Deserialize;
@@ -15,11 +43,22 @@ use crate::config::{
     write_json_file,
 };
 use crate::error::AppError;
-use crate::provider::Provider;
+use crate::provider::{Provider, ProviderMeta};
+use crate::services::mcp::McpServerSyncFailure;
 use crate::store::AppState;
 
+pub use convert::UNMAPPED_PROVIDER_FIELDS;
+pub use failover::FailoverOutcome;
 use gemini_auth::GeminiAuthType;
+pub use health::HealthWait;
+pub use ids::{generate_provider_id, normalize_provider_id};
+pub use legacy::LegacyImportOutcome;
 use live::LiveSnapshot;
+pub use relative::relative_provider_id;
+pub(crate) use tags::provider_tags;
+pub use tags::SettingsPatch;
+pub use usage::UsageEnvOverride;
+pub use validate::AppValidationReport;
 
 /// 供应商相关业务逻辑
 pub struct ProviderService;
@@ -197,12 +236,69 @@ fn is_codex_official_provider(provider: &Provider) -> bool {
         || provider.name.trim().eq_ignore_ascii_case("OpenAI Official")
 }
 
+/// 切换时写入的 live 配置文件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiveFileWrite {
+    pub path: PathBuf,
+    /// 写入后的文件大小（字节）
+    pub bytes: u64,
+}
+
+/// 切换结果：写入的 live 文件与 post_switch 钩子的失败告警
+#[derive(Debug, Default)]
+pub struct SwitchOutcome {
+    pub written: Vec<LiveFileWrite>,
+    pub hook_warning: Option<AppError>,
+    /// 同步失败的 MCP 服务器（非严格模式下不回滚切换，仅作告警）
+    pub mcp_failures: Vec<McpServerSyncFailure>,
+    /// Codex 供应商的 auth 只有一个空的 API Key，未写入 auth.json
+    pub empty_api_key: bool,
+}
+
+/// 切换选项
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwitchOptions {
+    /// 任一 MCP 服务器同步失败即回滚切换
+    pub strict_mcp: bool,
+    /// 写入 live 文件后回读并覆盖存储的供应商快照（默认开启）
+    ///
+    /// 关闭后快照保持原样，不会反映 live 写入时的规范化（如补全的默认字段）。
+    pub refresh_snapshot: bool,
+    /// 仅应用于本次写入 live 的覆盖（`--set`），不修改存储的供应商；非空时不刷新快照
+    pub overlay: Vec<SettingsPatch>,
+    /// 切走前把当前 live 配置回填进上一个供应商的快照（默认开启）
+    ///
+    /// 关闭后上一个供应商的快照保持不变，适合 live 配置已损坏、只想强制写入目标供应商的场景。
+    pub backfill: bool,
+}
+
+impl Default for SwitchOptions {
+    fn default() -> Self {
+        Self {
+            strict_mcp: false,
+            refresh_snapshot: true,
+            overlay: Vec::new(),
+            backfill: true,
+        }
+    }
+}
+
+/// 后置操作的结果：写入的 live 文件与同步失败的 MCP 服务器
+#[derive(Default)]
+struct PostCommitReport {
+    written: Vec<LiveFileWrite>,
+    mcp_failures: Vec<McpServerSyncFailure>,
+    empty_api_key: bool,
+}
+
 #[derive(Clone)]
 struct PostCommitAction {
     app_type: AppType,
     provider: Provider,
     backup: LiveSnapshot,
     sync_mcp: bool,
+    /// MCP 同步任一服务器失败即回滚整个操作
+    strict_mcp: bool,
     refresh_snapshot: bool,
     common_config_snippet: Option<String>,
     takeover_active: bool,
@@ -210,43 +306,11 @@ struct PostCommitAction {
 
 #[cfg(test)]
 mod tests {
+    use super::test_support::EnvGuard;
     use super::*;
     use serial_test::serial;
-    use std::ffi::OsString;
-    use std::path::Path;
     use tempfile::TempDir;
 
-    struct EnvGuard {
-        old_home: Option<OsString>,
-        old_userprofile: Option<OsString>,
-    }
-
-    impl EnvGuard {
-        fn set_home(home: &Path) -> Self {
-            let old_home = std::env::var_os("HOME");
-            let old_userprofile = std::env::var_os("USERPROFILE");
-            std::env::set_var("HOME", home);
-            std::env::set_var("USERPROFILE", home);
-            Self {
-                old_home,
-                old_userprofile,
-            }
-        }
-    }
-
-    impl Drop for EnvGuard {
-        fn drop(&mut self) {
-            match &self.old_home {
-                Some(value) => std::env::set_var("HOME", value),
-                None => std::env::remove_var("HOME"),
-            }
-            match &self.old_userprofile {
-                Some(value) => std::env::set_var("USERPROFILE", value),
-                None => std::env::remove_var("USERPROFILE"),
-            }
-        }
-    }
-
     #[test]
     fn validate_provider_settings_allows_missing_auth_for_codex() {
         let mut provider = Provider::with_id(
@@ -1277,10 +1341,15 @@ impl ProviderService {
         Ok(cleaned.trim().to_string())
     }
 
+    /// 通用片段为空时从 live config.toml 提取；`no_backfill_extract` 开启时跳过，片段只能显式设置
     fn maybe_update_codex_common_config_snippet(
         config: &mut MultiAppConfig,
         config_toml: &str,
     ) -> Result<(), AppError> {
+        if crate::settings::get_no_backfill_extract() {
+            return Ok(());
+        }
+
         let existing = config
             .common_config_snippets
             .codex
@@ -1424,7 +1493,19 @@ impl ProviderService {
             }
         }
     }
+
     fn run_transaction<R, F>(state: &AppState, f: F) -> Result<R, AppError>
+    where
+        F: FnOnce(&mut MultiAppConfig) -> Result<(R, Option<PostCommitAction>), AppError>,
+    {
+        Self::run_transaction_with_report(state, f).map(|(result, _)| result)
+    }
+
+    /// 与 `run_transaction` 相同，另外返回后置操作写入的 live 文件
+    fn run_transaction_with_report<R, F>(
+        state: &AppState,
+        f: F,
+    ) -> Result<(R, PostCommitReport), AppError>
     where
         F: FnOnce(&mut MultiAppConfig) -> Result<(R, Option<PostCommitAction>), AppError>,
     {
@@ -1450,22 +1531,22 @@ impl ProviderService {
             return Err(save_err);
         }
 
+        let mut report = PostCommitReport::default();
         if let Some(action) = action {
-            if let Err(err) = Self::apply_post_commit(state, &action) {
-                if let Err(rollback_err) =
-                    Self::rollback_after_failure(state, original.clone(), action.backup.clone())
-                {
-                    return Err(AppError::localized(
-                        "post_commit.rollback_failed",
-                        format!("后置操作失败: {err}；回滚失败: {rollback_err}"),
-                        format!("Post-commit step failed: {err}; rollback failed: {rollback_err}"),
-                    ));
+            match Self::apply_post_commit(state, &action) {
+                Ok(done) => report = done,
+                Err(err) => {
+                    if let Err(rollback_err) =
+                        Self::rollback_after_failure(state, original.clone(), action.backup.clone())
+                    {
+                        return Err(Self::post_commit_rollback_failed(&err, &rollback_err));
+                    }
+                    return Err(err);
                 }
-                return Err(err);
             }
         }
 
-        Ok(result)
+        Ok((result, report))
     }
 
     fn restore_config_only(state: &AppState, snapshot: MultiAppConfig) -> Result<(), AppError> {
@@ -1476,6 +1557,14 @@ impl ProviderService {
         state.save()
     }
 
+    fn post_commit_rollback_failed(err: &AppError, rollback_err: &AppError) -> AppError {
+        AppError::localized(
+            "post_commit.rollback_failed",
+            format!("后置操作失败: {err}；回滚失败: {rollback_err}"),
+            format!("Post-commit step failed: {err}; rollback failed: {rollback_err}"),
+        )
+    }
+
     fn rollback_after_failure(
         state: &AppState,
         snapshot: MultiAppConfig,
@@ -1485,13 +1574,17 @@ impl ProviderService {
         backup.restore()
     }
 
-    fn apply_post_commit(state: &AppState, action: &PostCommitAction) -> Result<(), AppError> {
+    fn apply_post_commit(
+        state: &AppState,
+        action: &PostCommitAction,
+    ) -> Result<PostCommitReport, AppError> {
         let apply_common_config = action
             .provider
             .meta
             .as_ref()
             .and_then(|meta| meta.apply_common_config)
             .unwrap_or(true);
+        let mut written = Vec::new();
         if action.takeover_active {
             let backup_snapshot = Self::build_live_backup_snapshot(
                 &action.app_type,
@@ -1506,17 +1599,31 @@ impl ProviderService {
             )
             .map_err(AppError::Message)?;
         } else {
-            Self::write_live_snapshot(
+            written = Self::write_live_snapshot(
                 &action.app_type,
                 &action.provider,
                 action.common_config_snippet.as_deref(),
                 apply_common_config,
             )?;
         }
+        let mut mcp_failures = Vec::new();
         if action.sync_mcp {
             // 使用 v3.7.0 统一的 MCP 同步机制，支持所有应用
             use crate::services::mcp::McpService;
-            McpService::sync_all_enabled(state)?;
+            if action.strict_mcp {
+                McpService::sync_all_enabled(state)?;
+            } else {
+                // 单个服务器失败不阻止切换：其余服务器照常同步，失败项交由调用方告警
+                mcp_failures = McpService::sync_all_enabled_per_server(state)?;
+                for failure in &mcp_failures {
+                    log::warn!(
+                        "同步 MCP 服务器 {} 到 {} 失败: {}",
+                        failure.server_id,
+                        failure.app,
+                        failure.error
+                    );
+                }
+            }
         }
         if !action.takeover_active
             && action.refresh_snapshot
@@ -1529,13 +1636,43 @@ impl ProviderService {
         if let Err(e) = crate::services::skill::SkillService::sync_all_enabled_best_effort() {
             log::warn!("同步 Skills 失败: {e}");
         }
-        Ok(())
+        let empty_api_key = action.app_type == AppType::Codex
+            && !action.takeover_active
+            && codex_auth::has_only_empty_api_key(&action.provider);
+        Ok(PostCommitReport {
+            written,
+            mcp_failures,
+            empty_api_key,
+        })
     }
 
     fn refresh_provider_snapshot(
         state: &AppState,
         app_type: &AppType,
         provider_id: &str,
+    ) -> Result<(), AppError> {
+        let stored_before = Self::list(state, app_type.clone())?
+            .get(provider_id)
+            .map(|provider| provider.settings_config.clone());
+        Self::read_live_into_snapshot(state, app_type, provider_id)?;
+
+        // live 带有 `--set` 覆盖时不能把覆盖值固化进快照
+        if let Some(stored_before) = stored_before {
+            let restored = {
+                let mut guard = state.config.write().map_err(AppError::from)?;
+                Self::restore_overlaid_backfill(&mut guard, app_type, provider_id, &stored_before)
+            };
+            if restored {
+                state.save()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_live_into_snapshot(
+        state: &AppState,
+        app_type: &AppType,
+        provider_id: &str,
     ) -> Result<(), AppError> {
         match app_type {
             AppType::Claude => {
@@ -1549,6 +1686,7 @@ impl ProviderService {
                 }
                 let mut live_after = read_json_file::<Value>(&settings_path)?;
                 let _ = Self::normalize_claude_models_in_value(&mut live_after);
+                claude_headers::collect_custom_headers(&mut live_after);
 
                 let common_snippet = {
                     let guard = state.config.read().map_err(AppError::from)?;
@@ -1607,6 +1745,8 @@ impl ProviderService {
                     }
                     if let Some(manager) = guard.get_manager_mut(app_type) {
                         if let Some(target) = manager.providers.get_mut(provider_id) {
+                            let keeps_env_file_key =
+                                codex_auth::codex_env_file_entry(target).is_some();
                             let obj = target.settings_config.as_object_mut().ok_or_else(|| {
                                 AppError::Config(format!(
                                     "供应商 {provider_id} 的 Codex 配置必须是 JSON 对象"
@@ -1614,7 +1754,8 @@ impl ProviderService {
                             })?;
                             if let Some(auth) = auth {
                                 obj.insert("auth".to_string(), auth);
-                            } else {
+                            } else if !keeps_env_file_key {
+                                // env-file 模式下 auth.json 本就不存在，保留快照中的 Key
                                 obj.remove("auth");
                             }
                             obj.insert("config".to_string(), Value::String(cfg_to_store.clone()));
@@ -1758,7 +1899,31 @@ impl ProviderService {
 
     /// 新增供应商
     pub fn add(state: &AppState, app_type: AppType, provider: Provider) -> Result<bool, AppError> {
+        Self::add_with(state, app_type, provider, false)
+    }
+
+    /// 新增供应商；ID 已存在时报 `provider.already_exists`，`overwrite` 为 true 时改为更新该供应商
+    pub fn add_with(
+        state: &AppState,
+        app_type: AppType,
+        provider: Provider,
+        overwrite: bool,
+    ) -> Result<bool, AppError> {
         let mut provider = provider;
+        let id = normalize_provider_id(&provider.id)?;
+        if id != provider.id {
+            log::warn!(
+                "供应商 ID '{}' 含空白或路径分隔符，已规范化为 '{id}'",
+                provider.id
+            );
+            provider.id = id;
+        }
+        if overwrite
+            && Self::list(state, app_type.clone())
+                .is_ok_and(|providers| providers.contains_key(&provider.id))
+        {
+            return Self::update(state, app_type, provider);
+        }
         // 归一化 Claude 模型键
         Self::normalize_provider_if_claude(&app_type, &mut provider);
         Self::validate_provider_settings(&app_type, &provider)?;
@@ -1772,6 +1937,25 @@ impl ProviderService {
                 .get_manager_mut(&app_type_clone)
                 .ok_or_else(|| Self::app_not_found(&app_type_clone))?;
 
+            if manager.providers.contains_key(&provider_clone.id) {
+                let id = &provider_clone.id;
+                let suggestion = ids::suggest_free_provider_id(id, manager.providers.keys());
+                return Err(AppError::localized(
+                    "provider.already_exists",
+                    format!("供应商 ID '{id}' 已存在，可改用 '{suggestion}'，或编辑现有供应商"),
+                    format!(
+                        "Provider id '{id}' is already taken; try '{suggestion}' or edit the existing provider"
+                    ),
+                ));
+            }
+
+            if app_type_clone == AppType::Codex {
+                ids::ensure_unique_codex_model_provider(
+                    &provider_clone,
+                    manager.providers.values(),
+                )?;
+            }
+
             let was_empty = manager.providers.is_empty();
             manager
                 .providers
@@ -1792,6 +1976,7 @@ impl ProviderService {
                     provider: provider_clone.clone(),
                     backup,
                     sync_mcp: false,
+                    strict_mcp: false,
                     refresh_snapshot: false,
                     common_config_snippet,
                     takeover_active: false,
@@ -1824,11 +2009,7 @@ impl ProviderService {
                 .ok_or_else(|| Self::app_not_found(&app_type_clone))?;
 
             if !manager.providers.contains_key(&provider_id) {
-                return Err(AppError::localized(
-                    "provider.not_found",
-                    format!("供应商不存在: {provider_id}"),
-                    format!("Provider not found: {provider_id}"),
-                ));
+                return Err(AppError::provider_not_found(provider_id));
             }
 
             let is_current = app_type_clone.is_additive_mode() || manager.current == provider_id;
@@ -1847,6 +2028,13 @@ impl ProviderService {
                         updated.meta = Some(new_meta);
                     }
                 }
+                // 客户端可能省略或清零 created_at / sort_index，缺失时沿用旧值
+                if updated.created_at.is_none_or(|ts| ts == 0) {
+                    updated.created_at = existing.created_at;
+                }
+                if updated.sort_index.is_none() {
+                    updated.sort_index = existing.sort_index;
+                }
                 updated
             } else {
                 provider_clone.clone()
@@ -1863,6 +2051,7 @@ impl ProviderService {
                     provider: provider_clone.clone(),
                     backup,
                     sync_mcp: false,
+                    strict_mcp: false,
                     refresh_snapshot: false,
                     common_config_snippet,
                     takeover_active: false,
@@ -1945,6 +2134,7 @@ impl ProviderService {
                 }
                 let mut v = read_json_file::<Value>(&settings_path)?;
                 let _ = Self::normalize_claude_models_in_value(&mut v);
+                claude_headers::collect_custom_headers(&mut v);
                 v
             }
             AppType::Gemini => {
@@ -2163,10 +2353,25 @@ impl ProviderService {
         Ok(())
     }
 
-    /// 切换指定应用的供应商
+    /// 切换指定应用的供应商（会执行 settings 中配置的切换钩子）
     pub fn switch(state: &AppState, app_type: AppType, provider_id: &str) -> Result<(), AppError> {
+        if let Some(warning) =
+            Self::switch_reporting_hooks(state, app_type, provider_id)?.hook_warning
+        {
+            log::warn!("{warning}");
+        }
+        Ok(())
+    }
+
+    fn apply_switch(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        options: SwitchOptions,
+    ) -> Result<PostCommitReport, AppError> {
         let app_type_clone = app_type.clone();
         let provider_id_owned = provider_id.to_string();
+        let overlay = options.overlay.clone();
         let takeover_active = if app_type.is_additive_mode() {
             false
         } else {
@@ -2184,27 +2389,25 @@ impl ProviderService {
             }
         };
 
-        Self::run_transaction(state, move |config| {
+        let ((), report) = Self::run_transaction_with_report(state, move |config| {
+            Self::touch_last_used(config, &app_type_clone, &provider_id_owned);
+
             if app_type_clone.is_additive_mode() {
-                let provider = config
+                let mut provider = config
                     .get_manager(&app_type_clone)
                     .ok_or_else(|| Self::app_not_found(&app_type_clone))?
                     .providers
                     .get(&provider_id_owned)
                     .cloned()
-                    .ok_or_else(|| {
-                        AppError::localized(
-                            "provider.not_found",
-                            format!("供应商不存在: {provider_id_owned}"),
-                            format!("Provider not found: {provider_id_owned}"),
-                        )
-                    })?;
+                    .ok_or_else(|| AppError::provider_not_found(&provider_id_owned))?;
+                Self::apply_live_overlay(&mut provider, &options.overlay)?;
 
                 let action = PostCommitAction {
                     app_type: app_type_clone.clone(),
                     provider,
                     backup: Self::capture_live_snapshot(&app_type_clone)?,
                     sync_mcp: true,
+                    strict_mcp: options.strict_mcp,
                     refresh_snapshot: false,
                     common_config_snippet: config
                         .common_config_snippets
@@ -2217,19 +2420,14 @@ impl ProviderService {
             }
 
             if takeover_active {
-                let provider = config
+                let mut provider = config
                     .get_manager(&app_type_clone)
                     .ok_or_else(|| Self::app_not_found(&app_type_clone))?
                     .providers
                     .get(&provider_id_owned)
                     .cloned()
-                    .ok_or_else(|| {
-                        AppError::localized(
-                            "provider.not_found",
-                            format!("供应商不存在: {provider_id_owned}"),
-                            format!("Provider not found: {provider_id_owned}"),
-                        )
-                    })?;
+                    .ok_or_else(|| AppError::provider_not_found(&provider_id_owned))?;
+                Self::apply_live_overlay(&mut provider, &options.overlay)?;
 
                 if let Some(manager) = config.get_manager_mut(&app_type_clone) {
                     manager.current = provider_id_owned.clone();
@@ -2240,6 +2438,7 @@ impl ProviderService {
                     provider,
                     backup: Self::capture_live_snapshot(&app_type_clone)?,
                     sync_mcp: false,
+                    strict_mcp: false,
                     refresh_snapshot: false,
                     common_config_snippet: config
                         .common_config_snippets
@@ -2252,30 +2451,72 @@ impl ProviderService {
             }
 
             let backup = Self::capture_live_snapshot(&app_type_clone)?;
-            let provider = match app_type_clone {
-                AppType::Codex => Self::prepare_switch_codex(config, &provider_id_owned)?,
-                AppType::Claude => Self::prepare_switch_claude(config, &provider_id_owned)?,
-                AppType::Gemini => Self::prepare_switch_gemini(config, &provider_id_owned)?,
+            let previous = config.get_manager(&app_type_clone).and_then(|manager| {
+                manager
+                    .providers
+                    .get(&manager.current)
+                    .map(|p| (p.id.clone(), p.settings_config.clone()))
+            });
+            let mut provider = match app_type_clone {
+                AppType::Codex => {
+                    Self::prepare_switch_codex(config, &provider_id_owned, options.backfill)?
+                }
+                AppType::Claude => {
+                    Self::prepare_switch_claude(config, &provider_id_owned, options.backfill)?
+                }
+                AppType::Gemini => {
+                    Self::prepare_switch_gemini(config, &provider_id_owned, options.backfill)?
+                }
                 AppType::OpenCode => unreachable!("additive mode handled above"),
             };
+            if let Some((previous_id, stored_before)) = previous {
+                if previous_id != provider_id_owned {
+                    let _ = Self::restore_overlaid_backfill(
+                        config,
+                        &app_type_clone,
+                        &previous_id,
+                        &stored_before,
+                    );
+                }
+            }
+            // 覆盖后的 live 不应回读进快照
+            let refresh_snapshot = options.refresh_snapshot && options.overlay.is_empty();
+            Self::apply_live_overlay(&mut provider, &options.overlay)?;
 
             let action = PostCommitAction {
                 app_type: app_type_clone.clone(),
                 provider,
                 backup,
                 sync_mcp: true, // v3.7.0: 所有应用切换时都同步 MCP，防止配置丢失
-                refresh_snapshot: true,
+                strict_mcp: options.strict_mcp,
+                refresh_snapshot,
                 common_config_snippet: config.common_config_snippets.get(&app_type_clone).cloned(),
                 takeover_active: false,
             };
 
             Ok(((), Some(action)))
-        })
+        })?;
+        Self::record_live_overlay(&app_type, provider_id, &overlay)?;
+        Ok(report)
+    }
+
+    /// 记录供应商最近一次被切换到的时间
+    fn touch_last_used(config: &mut MultiAppConfig, app_type: &AppType, provider_id: &str) {
+        if let Some(provider) = config
+            .get_manager_mut(app_type)
+            .and_then(|manager| manager.providers.get_mut(provider_id))
+        {
+            provider
+                .meta
+                .get_or_insert_with(ProviderMeta::default)
+                .last_used_at = Some(chrono::Utc::now().timestamp());
+        }
     }
 
     fn prepare_switch_codex(
         config: &mut MultiAppConfig,
         provider_id: &str,
+        backfill: bool,
     ) -> Result<Provider, AppError> {
         let provider = config
             .get_manager(&AppType::Codex)
@@ -2283,15 +2524,19 @@ impl ProviderService {
             .providers
             .get(provider_id)
             .cloned()
-            .ok_or_else(|| {
-                AppError::localized(
-                    "provider.not_found",
-                    format!("供应商不存在: {provider_id}"),
-                    format!("Provider not found: {provider_id}"),
-                )
-            })?;
+            .ok_or_else(|| AppError::provider_not_found(provider_id))?;
 
-        Self::backfill_codex_current(config, provider_id)?;
+        ids::ensure_unique_codex_model_provider(
+            &provider,
+            config
+                .get_manager(&AppType::Codex)
+                .into_iter()
+                .flat_map(|manager| manager.providers.values()),
+        )?;
+
+        if backfill {
+            Self::backfill_codex_current(config, provider_id)?;
+        }
 
         if let Some(manager) = config.get_manager_mut(&AppType::Codex) {
             manager.current = provider_id.to_string();
@@ -2319,8 +2564,9 @@ impl ProviderService {
             return Ok(());
         }
 
+        // live 文件损坏时跳过对应部分的回填（保留存储的快照），不阻止切换
         let auth = if auth_path.exists() {
-            Some(read_json_file::<Value>(&auth_path)?)
+            live::read_live_json_lenient(&auth_path)?
         } else {
             None
         };
@@ -2329,8 +2575,11 @@ impl ProviderService {
         // This preserves all fields (model_reasoning_effort, disable_response_storage, etc.)
         // and avoids lossy round-trips through snippet extraction.
         let config_text = if config_path.exists() {
-            let text =
-                std::fs::read_to_string(&config_path).map_err(|e| AppError::io(&config_path, e))?;
+            live::read_codex_config_lenient()?
+        } else {
+            None
+        };
+        let config_text = if let Some(text) = config_text {
             Self::maybe_update_codex_common_config_snippet(config, &text)?;
 
             // Strip common config snippet keys so they don't get duplicated
@@ -2364,135 +2613,56 @@ impl ProviderService {
         Ok(())
     }
 
-    /// Write Codex live configuration.
-    ///
-    /// Aligned with upstream: the stored `settings_config.config` is the full config.toml text.
-    /// We write it directly to `~/.codex/config.toml`, optionally merging the common config snippet.
-    /// Auth is handled separately via auth.json.
+    /// Write Codex live configuration (rendered by `render_codex_live`).
     fn write_codex_live(
         provider: &Provider,
         common_config_snippet: Option<&str>,
         apply_common_config: bool,
-    ) -> Result<(), AppError> {
+    ) -> Result<Vec<PathBuf>, AppError> {
         if !crate::sync_policy::should_sync_live(&AppType::Codex) {
-            return Ok(());
+            return Ok(Vec::new());
         }
 
-        let settings = provider
-            .settings_config
-            .as_object()
-            .ok_or_else(|| AppError::Config("Codex 配置必须是 JSON 对象".into()))?;
-
-        // auth 字段现在是可选的（Codex 0.64+ 使用环境变量）
-        let auth = settings.get("auth");
-        let auth_is_empty = auth
-            .map(|a| a.as_object().map(|o| o.is_empty()).unwrap_or(true))
-            .unwrap_or(true);
-
-        // 获取存储的 config TOML 文本
-        let cfg_text = settings.get("config").and_then(Value::as_str).unwrap_or("");
-
-        // For official OpenAI providers, ensure wire_api and requires_openai_auth
-        // have sensible defaults in the model_providers section.
-        let cfg_text_owned;
-        let cfg_text = if is_codex_official_provider(provider) && !cfg_text.trim().is_empty() {
-            if let Ok(mut doc) = cfg_text.parse::<toml_edit::DocumentMut>() {
-                let mp_key = doc
-                    .get("model_provider")
-                    .and_then(|v| v.as_str())
-                    .map(|s| s.to_string());
-                if let Some(key) = mp_key {
-                    if let Some(section) = doc
-                        .get_mut("model_providers")
-                        .and_then(|v| v.as_table_like_mut())
-                        .and_then(|t| t.get_mut(&key))
-                        .and_then(|v| v.as_table_like_mut())
-                    {
-                        if section.get("wire_api").is_none() {
-                            section.insert("wire_api", toml_edit::value("responses"));
-                        }
-                        if section.get("requires_openai_auth").is_none() {
-                            section.insert("requires_openai_auth", toml_edit::value(true));
-                        }
-                    }
-                }
-                cfg_text_owned = doc.to_string();
-                &cfg_text_owned
-            } else {
-                cfg_text
-            }
-        } else {
-            cfg_text
-        };
-
-        // Validate TOML before writing
-        if !cfg_text.trim().is_empty() {
-            crate::codex_config::validate_config_toml(cfg_text)?;
-        }
-
-        // Merge common config snippet if applicable
-        let final_text = if apply_common_config {
-            if let Some(snippet) = common_config_snippet {
-                let snippet = snippet.trim();
-                if !snippet.is_empty() && !cfg_text.trim().is_empty() {
-                    // Parse both as TOML documents and merge
-                    let mut doc = cfg_text
-                        .parse::<toml_edit::DocumentMut>()
-                        .map_err(|e| AppError::Config(format!("TOML parse error: {e}")))?;
-                    let common_doc = snippet.parse::<toml_edit::DocumentMut>().map_err(|e| {
-                        AppError::Config(format!("Common config TOML parse error: {e}"))
-                    })?;
-                    Self::merge_toml_tables(doc.as_table_mut(), common_doc.as_table());
-                    doc.to_string()
-                } else {
-                    cfg_text.to_string()
-                }
-            } else {
-                cfg_text.to_string()
-            }
-        } else {
-            cfg_text.to_string()
-        };
+        let files = Self::render_codex_live(provider, common_config_snippet, apply_common_config)?;
+        if files.empty_api_key {
+            log::warn!(
+                "Codex 供应商 {} 的 API Key 为空，跳过写入 auth.json",
+                provider.id
+            );
+        }
 
         // Write config.toml
         let config_path = get_codex_config_path();
-        if let Some(parent) = config_path.parent() {
-            std::fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
-        }
-        crate::config::write_text_file(&config_path, &final_text)?;
-
-        // auth.json handling:
-        //
-        // Codex has two auth modes:
-        // - API Key mode (auth.json): third-party/custom providers that explicitly carry auth.
-        // - Credential store / OpenAI official mode: auth.json must be absent, otherwise it
-        //   overrides the credential store.
-        //
-        // Align with upstream UI behavior:
-        // - If provider has no auth (or is explicitly marked as official), remove existing auth.json.
-        // - Otherwise, write auth.json from provider.auth.
+        crate::config::write_text_file(&config_path, &files.config)?;
+        let mut written = vec![config_path];
+
+        if let Some(env) = &files.env {
+            let env_path = crate::codex_config::get_codex_env_path();
+            crate::config::write_text_file(&env_path, env)?;
+            written.push(env_path);
+        }
+        crate::settings::set_codex_env_key(files.env_key.clone())?;
         let auth_path = get_codex_auth_path();
-        let should_remove_auth_json = auth_is_empty || is_codex_official_provider(provider);
-        if should_remove_auth_json {
-            if auth_path.exists() {
-                let ts = std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .as_nanos();
-                let backup_path = auth_path.with_file_name(format!("auth.json.cc-switch.bak.{ts}"));
-                copy_file(&auth_path, &backup_path)?;
-                delete_file(&auth_path)?;
-            }
-        } else if let Some(auth_value) = auth {
+        if let Some(auth_value) = &files.auth {
             write_json_file(&auth_path, auth_value)?;
+            written.push(auth_path);
+        } else if auth_path.exists() {
+            let ts = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos();
+            let backup_path = auth_path.with_file_name(format!("auth.json.cc-switch.bak.{ts}"));
+            copy_file(&auth_path, &backup_path)?;
+            delete_file(&auth_path)?;
         }
 
-        Ok(())
+        Ok(written)
     }
 
     fn prepare_switch_claude(
         config: &mut MultiAppConfig,
         provider_id: &str,
+        backfill: bool,
     ) -> Result<Provider, AppError> {
         let provider = config
             .get_manager(&AppType::Claude)
@@ -2500,15 +2670,11 @@ impl ProviderService {
             .providers
             .get(provider_id)
             .cloned()
-            .ok_or_else(|| {
-                AppError::localized(
-                    "provider.not_found",
-                    format!("供应商不存在: {provider_id}"),
-                    format!("Provider not found: {provider_id}"),
-                )
-            })?;
+            .ok_or_else(|| AppError::provider_not_found(provider_id))?;
 
-        Self::backfill_claude_current(config, provider_id)?;
+        if backfill {
+            Self::backfill_claude_current(config, provider_id)?;
+        }
 
         if let Some(manager) = config.get_manager_mut(&AppType::Claude) {
             manager.current = provider_id.to_string();
@@ -2520,6 +2686,7 @@ impl ProviderService {
     fn prepare_switch_gemini(
         config: &mut MultiAppConfig,
         provider_id: &str,
+        backfill: bool,
     ) -> Result<Provider, AppError> {
         let provider = config
             .get_manager(&AppType::Gemini)
@@ -2527,15 +2694,11 @@ impl ProviderService {
             .providers
             .get(provider_id)
             .cloned()
-            .ok_or_else(|| {
-                AppError::localized(
-                    "provider.not_found",
-                    format!("供应商不存在: {provider_id}"),
-                    format!("Provider not found: {provider_id}"),
-                )
-            })?;
+            .ok_or_else(|| AppError::provider_not_found(provider_id))?;
 
-        Self::backfill_gemini_current(config, provider_id)?;
+        if backfill {
+            Self::backfill_gemini_current(config, provider_id)?;
+        }
 
         if let Some(manager) = config.get_manager_mut(&AppType::Gemini) {
             manager.current = provider_id.to_string();
@@ -2561,8 +2724,12 @@ impl ProviderService {
             return Ok(());
         }
 
-        let mut live = read_json_file::<Value>(&settings_path)?;
+        // live 文件损坏时跳过回填（保留存储的快照），不阻止切换
+        let Some(mut live) = live::read_live_json_lenient(&settings_path)? else {
+            return Ok(());
+        };
         let _ = Self::normalize_claude_models_in_value(&mut live);
+        claude_headers::collect_custom_headers(&mut live);
         if let Some(snippet) = config.common_config_snippets.claude.as_deref() {
             let snippet = snippet.trim();
             if !snippet.is_empty() {
@@ -2604,11 +2771,19 @@ impl ProviderService {
         let mut live = env_to_json(&env_map);
 
         let settings_path = get_gemini_settings_path();
-        let config_value = if settings_path.exists() {
-            read_json_file(&settings_path)?
+        let mut config_value = if settings_path.exists() {
+            // 损坏时跳过回填，不阻止切换
+            match live::read_live_json_lenient(&settings_path)? {
+                Some(value) => value,
+                None => return Ok(()),
+            }
         } else {
             json!({})
         };
+        // 回填快照不保存 mcpServers，避免下次切回时写入过期的 MCP 列表
+        if let Some(obj) = config_value.as_object_mut() {
+            obj.remove("mcpServers");
+        }
         if let Some(obj) = live.as_object_mut() {
             obj.insert("config".to_string(), config_value);
         }
@@ -2633,45 +2808,28 @@ impl ProviderService {
     fn write_claude_live(
         provider: &Provider,
         common_config_snippet: Option<&str>,
-    ) -> Result<(), AppError> {
+    ) -> Result<Vec<PathBuf>, AppError> {
         if !crate::sync_policy::should_sync_live(&AppType::Claude) {
-            return Ok(());
+            return Ok(Vec::new());
         }
 
         let settings_path = get_claude_settings_path();
-        let mut provider_content = provider.settings_config.clone();
-        let _ = Self::normalize_claude_models_in_value(&mut provider_content);
-
-        let content_to_write = if let Some(snippet) = common_config_snippet {
-            let snippet = snippet.trim();
-            if snippet.is_empty() {
-                provider_content
-            } else {
-                let common = Self::parse_common_claude_config_snippet(snippet)?;
-                let mut merged = common;
-                merge_json_values(&mut merged, &provider_content);
-                let _ = Self::normalize_claude_models_in_value(&mut merged);
-                merged
-            }
-        } else {
-            provider_content
-        };
-
+        let content_to_write = Self::render_claude_live(provider, common_config_snippet)?;
         write_json_file(&settings_path, &content_to_write)?;
-        Ok(())
+        Ok(vec![settings_path])
     }
 
     pub(crate) fn write_gemini_live(
         provider: &Provider,
         common_config_snippet: Option<&str>,
-    ) -> Result<(), AppError> {
+    ) -> Result<Vec<PathBuf>, AppError> {
         Self::write_gemini_live_impl(provider, common_config_snippet, false)
     }
 
     pub(crate) fn write_gemini_live_force(
         provider: &Provider,
         common_config_snippet: Option<&str>,
-    ) -> Result<(), AppError> {
+    ) -> Result<Vec<PathBuf>, AppError> {
         Self::write_gemini_live_impl(provider, common_config_snippet, true)
     }
 
@@ -2679,130 +2837,46 @@ impl ProviderService {
         provider: &Provider,
         common_config_snippet: Option<&str>,
         force_sync: bool,
-    ) -> Result<(), AppError> {
-        use crate::gemini_config::{
-            get_gemini_settings_path, json_to_env, validate_gemini_settings_strict,
-            write_gemini_env_atomic,
-        };
-
-        // 一次性检测认证类型，避免重复检测
-        let auth_type = Self::detect_gemini_auth_type(provider);
+    ) -> Result<Vec<PathBuf>, AppError> {
+        use crate::gemini_config::{get_gemini_settings_path, write_gemini_env_atomic};
 
         if !force_sync && !crate::sync_policy::should_sync_live(&AppType::Gemini) {
             // still update CC-Switch app-level settings, but do not create any ~/.gemini files
-            match auth_type {
+            match Self::detect_gemini_auth_type(provider) {
                 GeminiAuthType::GoogleOfficial => {
                     Self::ensure_google_oauth_security_flag(provider)?
                 }
                 GeminiAuthType::ApiKey => Self::ensure_api_key_security_flag(provider)?,
             }
-            return Ok(());
+            return Ok(Vec::new());
         }
 
-        let provider_content = provider.settings_config.clone();
-        let content_to_write = if let Some(snippet) = common_config_snippet {
-            let snippet = snippet.trim();
-            if snippet.is_empty() {
-                provider_content
-            } else {
-                let common = Self::parse_common_gemini_config_snippet(snippet)?;
-                let mut merged = common;
-                merge_json_values(&mut merged, &provider_content);
-                merged
-            }
-        } else {
-            provider_content
-        };
-
-        let mut env_map = json_to_env(&content_to_write)?;
-
-        // 准备要写入 ~/.gemini/settings.json 的配置（缺省时保留现有文件内容）
+        let files = Self::render_gemini_live(provider, common_config_snippet)?;
+        write_gemini_env_atomic(&files.env)?;
         let settings_path = get_gemini_settings_path();
-        let mut config_to_write = if let Some(config_value) = content_to_write.get("config") {
-            if config_value.is_null() {
-                None // null → 保留现有文件
-            } else if let Some(provider_config) = config_value.as_object() {
-                if provider_config.is_empty() {
-                    None // 空对象 {} → 保留现有文件
-                } else {
-                    // 有内容 → 合并到现有 settings.json（保留现有 key，如 mcpServers），供应商优先
-                    let mut merged = if settings_path.exists() {
-                        read_json_file(&settings_path)?
-                    } else {
-                        json!({})
-                    };
-
-                    if !merged.is_object() {
-                        merged = json!({});
-                    }
+        write_json_file(&settings_path, &files.settings)?;
 
-                    let merged_map = merged.as_object_mut().ok_or_else(|| {
-                        AppError::localized(
-                            "gemini.validation.invalid_settings",
-                            "Gemini 现有 settings.json 格式错误: 必须是对象",
-                            "Gemini existing settings.json invalid: must be a JSON object",
-                        )
-                    })?;
-                    for (key, value) in provider_config {
-                        merged_map.insert(key.clone(), value.clone());
-                    }
-
-                    Some(merged)
-                }
-            } else {
-                return Err(AppError::localized(
-                    "gemini.validation.invalid_config",
-                    "Gemini 配置格式错误: config 必须是对象或 null",
-                    "Gemini config invalid: config must be an object or null",
-                ));
-            }
-        } else {
-            None
-        };
-
-        if config_to_write.is_none() {
-            if settings_path.exists() {
-                config_to_write = Some(read_json_file(&settings_path)?);
-            } else {
-                config_to_write = Some(json!({})); // 新建空配置
-            }
-        }
-
-        match auth_type {
-            GeminiAuthType::GoogleOfficial => {
-                // Google 官方使用 OAuth，清空 env
-                env_map.clear();
-                write_gemini_env_atomic(&env_map)?;
-            }
-            GeminiAuthType::ApiKey => {
-                // API Key 供应商（所有第三方服务）
-                // 统一处理：验证配置 + 写入 .env 文件
-                validate_gemini_settings_strict(&content_to_write)?;
-                write_gemini_env_atomic(&env_map)?;
-            }
-        }
-
-        if let Some(config_value) = config_to_write {
-            write_json_file(&settings_path, &config_value)?;
-        }
-
-        match auth_type {
+        match files.auth_type {
             GeminiAuthType::GoogleOfficial => Self::ensure_google_oauth_security_flag(provider)?,
             GeminiAuthType::ApiKey => Self::ensure_api_key_security_flag(provider)?,
         }
 
-        Ok(())
+        Ok(vec![
+            crate::gemini_config::get_gemini_env_path(),
+            settings_path,
+        ])
     }
 
+    /// 写入 live 配置，返回实际写入的文件及其写入后的大小
     fn write_live_snapshot(
         app_type: &AppType,
         provider: &Provider,
         common_config_snippet: Option<&str>,
         apply_common_config: bool,
-    ) -> Result<(), AppError> {
-        match app_type {
+    ) -> Result<Vec<LiveFileWrite>, AppError> {
+        let written = match app_type {
             AppType::Codex => {
-                Self::write_codex_live(provider, common_config_snippet, apply_common_config)
+                Self::write_codex_live(provider, common_config_snippet, apply_common_config)?
             }
             AppType::Claude => Self::write_claude_live(
                 provider,
@@ -2811,7 +2885,7 @@ impl ProviderService {
                 } else {
                     None
                 },
-            ),
+            )?,
             AppType::Gemini => Self::write_gemini_live(
                 provider,
                 if apply_common_config {
@@ -2819,28 +2893,42 @@ impl ProviderService {
                 } else {
                     None
                 },
-            ),
+            )?,
             AppType::OpenCode => {
-                let config_to_write = if let Some(obj) = provider.settings_config.as_object() {
-                    if obj.contains_key("$schema") || obj.contains_key("provider") {
-                        obj.get("provider")
-                            .and_then(|providers| providers.get(&provider.id))
-                            .cloned()
-                            .unwrap_or_else(|| provider.settings_config.clone())
-                    } else {
-                        provider.settings_config.clone()
-                    }
-                } else {
-                    provider.settings_config.clone()
-                };
+                let config_to_write = Self::opencode_live_entry(provider);
 
                 match serde_json::from_value::<crate::provider::OpenCodeProviderConfig>(
                     config_to_write.clone(),
                 ) {
-                    Ok(config) => crate::opencode_config::set_typed_provider(&provider.id, &config),
-                    Err(_) => crate::opencode_config::set_provider(&provider.id, config_to_write),
+                    Ok(config) => {
+                        crate::opencode_config::set_typed_provider(&provider.id, &config)?
+                    }
+                    Err(_) => crate::opencode_config::set_provider(&provider.id, config_to_write)?,
                 }
+                vec![crate::opencode_config::get_opencode_config_path()]
             }
+        };
+
+        Ok(written
+            .into_iter()
+            .map(|path| {
+                let bytes = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+                LiveFileWrite { path, bytes }
+            })
+            .collect())
+    }
+
+    /// OpenCode 供应商写入 `opencode.json` 中 `provider.<id>` 的内容
+    ///
+    /// 兼容存储了完整 opencode.json 的旧数据：此时取出其中对应 ID 的条目。
+    fn opencode_live_entry(provider: &Provider) -> Value {
+        match provider.settings_config.as_object() {
+            Some(obj) if obj.contains_key("$schema") || obj.contains_key("provider") => obj
+                .get("provider")
+                .and_then(|providers| providers.get(&provider.id))
+                .cloned()
+                .unwrap_or_else(|| provider.settings_config.clone()),
+            _ => provider.settings_config.clone(),
         }
     }
 
@@ -2854,6 +2942,7 @@ impl ProviderService {
             AppType::Claude => {
                 let mut provider_content = provider.settings_config.clone();
                 let _ = Self::normalize_claude_models_in_value(&mut provider_content);
+                claude_headers::apply_custom_headers(&mut provider_content);
 
                 if !apply_common_config {
                     return Ok(provider_content);
@@ -3041,6 +3130,7 @@ impl ProviderService {
                         "Claude configuration must be a JSON object",
                     ));
                 }
+                claude_headers::validate_custom_headers(&provider.settings_config)?;
             }
             AppType::Codex => {
                 let settings = provider.settings_config.as_object().ok_or_else(|| {
@@ -3161,13 +3251,11 @@ impl ProviderService {
                 ));
             }
 
-            manager.providers.get(provider_id).cloned().ok_or_else(|| {
-                AppError::localized(
-                    "provider.not_found",
-                    format!("供应商不存在: {provider_id}"),
-                    format!("Provider not found: {provider_id}"),
-                )
-            })?
+            manager
+                .providers
+                .get(provider_id)
+                .cloned()
+                .ok_or_else(|| AppError::provider_not_found(provider_id))?
         };
 
         if app_type.is_additive_mode() {
@@ -3239,43 +3327,11 @@ pub struct ProviderSortUpdate {
 
 #[cfg(test)]
 mod codex_openai_auth_tests {
+    use super::test_support::EnvGuard;
     use super::*;
     use serial_test::serial;
-    use std::ffi::OsString;
-    use std::path::Path;
     use tempfile::TempDir;
 
-    struct EnvGuard {
-        old_home: Option<OsString>,
-        old_userprofile: Option<OsString>,
-    }
-
-    impl EnvGuard {
-        fn set_home(home: &Path) -> Self {
-            let old_home = std::env::var_os("HOME");
-            let old_userprofile = std::env::var_os("USERPROFILE");
-            std::env::set_var("HOME", home);
-            std::env::set_var("USERPROFILE", home);
-            Self {
-                old_home,
-                old_userprofile,
-            }
-        }
-    }
-
-    impl Drop for EnvGuard {
-        fn drop(&mut self) {
-            match &self.old_home {
-                Some(value) => std::env::set_var("HOME", value),
-                None => std::env::remove_var("HOME"),
-            }
-            match &self.old_userprofile {
-                Some(value) => std::env::set_var("USERPROFILE", value),
-                None => std::env::remove_var("USERPROFILE"),
-            }
-        }
-    }
-
     #[test]
     #[serial]
     fn switch_codex_provider_writes_stored_config_directly() {
@@ -3323,6 +3379,70 @@ mod codex_openai_auth_tests {
         );
     }
 
+    #[test]
+    #[serial]
+    fn switch_codex_provider_in_env_file_mode_writes_env_instead_of_auth_json() {
+        let temp_home = TempDir::new().expect("create temp home");
+        let _env = EnvGuard::set_home(temp_home.path());
+        std::fs::create_dir_all(crate::codex_config::get_codex_config_dir())
+            .expect("create ~/.codex (initialized)");
+        std::fs::write(
+            crate::codex_config::get_codex_env_path(),
+            "OTHER=keep\nP_KEY=\"stale\"\n",
+        )
+        .expect("seed .env");
+
+        let mut provider = Provider::with_id(
+            "p1".to_string(),
+            "Third Party".to_string(),
+            json!({
+                "auth": { "OPENAI_API_KEY": "sk-env" },
+                "config": "model_provider = \"p1\"\nmodel = \"gpt-4o\"\n\n[model_providers.p1]\nbase_url = \"https://api.example/v1\"\nwire_api = \"responses\"\nrequires_openai_auth = false\nenv_key = \"P_KEY\"\n"
+            }),
+            None,
+        );
+        provider.meta = Some(crate::provider::ProviderMeta {
+            codex_auth_target: Some(crate::provider::CodexAuthTarget::EnvFile),
+            ..Default::default()
+        });
+
+        let mut config = MultiAppConfig::default();
+        config.ensure_app(&AppType::Codex);
+        config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager")
+            .providers
+            .insert("p1".to_string(), provider);
+
+        let state = state_from_config(config);
+        ProviderService::switch(&state, AppType::Codex, "p1").expect("switch should succeed");
+
+        let env_text = std::fs::read_to_string(crate::codex_config::get_codex_env_path())
+            .expect("read ~/.codex/.env");
+        assert_eq!(env_text, "OTHER=keep\nP_KEY=\"sk-env\"\n");
+        assert!(
+            !get_codex_auth_path().exists(),
+            "auth.json must not be written in env-file mode"
+        );
+        assert_eq!(
+            ProviderService::codex_auth_export_line(&state, "p1").expect("export line"),
+            "export P_KEY=\"sk-env\""
+        );
+
+        // 切回 auth.json 模式时立即重写 live
+        ProviderService::set_codex_auth_target(
+            &state,
+            "p1",
+            crate::provider::CodexAuthTarget::AuthJson,
+        )
+        .expect("switch target back");
+        let auth: Value = serde_json::from_str(
+            &std::fs::read_to_string(get_codex_auth_path()).expect("read auth.json"),
+        )
+        .expect("parse auth.json");
+        assert_eq!(auth, json!({ "OPENAI_API_KEY": "sk-env" }));
+    }
+
     #[test]
     #[serial]
     fn switch_codex_provider_migrates_legacy_flat_config() {
@@ -1,4 +1,3 @@
-use reqwest::Client;
 use serde_json::Value;
 use std::collections::HashSet;
 use std::time::Duration;
@@ -35,10 +34,7 @@ impl ProviderService {
             }
         }
 
-        let client = Client::builder()
-            .timeout(Duration::from_secs(5))
-            .build()
-            .map_err(|e| AppError::Message(e.to_string()))?;
+        let client = crate::proxy::build_client(None, Duration::from_secs(5))?;
 
         let mut last_err_zh = None;
         let mut last_err_en = None;
@@ -0,0 +1,137 @@
+//! `provider switch --set` 的一次性覆盖
+//!
+//! 覆盖只作用于写入 live 的供应商副本；存储的供应商保持原样。覆盖的路径记录在设置中，
+//! 下次切走或刷新快照时回读 live 后按存储值还原这些路径，覆盖值不会被写回供应商。
+//!
+//! Codex 的 `config` 以 TOML 文本存储，无法按路径覆盖，只接受 `auth.*` 等 JSON 路径。
+
+use serde_json::Value;
+
+use crate::app_config::{AppType, MultiAppConfig};
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::settings::LiveOverlay;
+
+use super::tags::parse_pointer;
+use super::{ProviderService, SettingsPatch};
+
+/// 将 `base` 中 `path` 处的值写回 `target`；`base` 中不存在时从 `target` 删除
+fn restore_path(target: &mut Value, base: &Value, path: &[&str]) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let base_parent = parents
+        .iter()
+        .try_fold(base, |cursor, segment| cursor.get(*segment));
+    let original = base_parent.and_then(|parent| parent.get(*last)).cloned();
+
+    let mut cursor = target;
+    for segment in parents {
+        if cursor.get(*segment).is_none() && original.is_none() {
+            return;
+        }
+        let Some(obj) = cursor.as_object_mut() else {
+            return;
+        };
+        cursor = obj
+            .entry(segment.to_string())
+            .or_insert_with(|| Value::Object(Default::default()));
+    }
+    let Some(obj) = cursor.as_object_mut() else {
+        return;
+    };
+    match original {
+        Some(value) => {
+            obj.insert(last.to_string(), value);
+        }
+        None => {
+            obj.remove(*last);
+        }
+    }
+}
+
+impl ProviderService {
+    /// 切换前检查覆盖路径：Codex 的 `config` 是 TOML 文本，拒绝落在其中的路径
+    pub(super) fn validate_live_overlay(
+        app_type: &AppType,
+        overlay: &[SettingsPatch],
+    ) -> Result<(), AppError> {
+        let Some(patch) = overlay.iter().find(|patch| {
+            *app_type == AppType::Codex && patch.path.first().map(String::as_str) == Some("config")
+        }) else {
+            return Ok(());
+        };
+        let path = patch.path.join(".");
+        Err(AppError::localized(
+            "provider.overlay.codex_config",
+            format!("Codex 的 config.toml 以文本存储，无法用 --set 覆盖 {path}"),
+            format!("Codex config.toml is stored as text; --set cannot override {path}"),
+        ))
+    }
+
+    /// 在写入 live 的供应商副本上应用覆盖
+    pub(super) fn apply_live_overlay(
+        provider: &mut Provider,
+        overlay: &[SettingsPatch],
+    ) -> Result<(), AppError> {
+        overlay
+            .iter()
+            .try_for_each(|patch| patch.apply(&mut provider.settings_config))
+    }
+
+    /// 回填或刷新快照之后调用：若其 live 带有上次切换的覆盖，按回读前的存储值还原覆盖路径；
+    /// 返回是否做了还原
+    pub(super) fn restore_overlaid_backfill(
+        config: &mut MultiAppConfig,
+        app_type: &AppType,
+        provider_id: &str,
+        stored_before: &Value,
+    ) -> bool {
+        let Some(overlay) = crate::settings::get_live_overlay(app_type)
+            .filter(|overlay| overlay.provider_id == provider_id)
+        else {
+            return false;
+        };
+        let Some(provider) = config
+            .get_manager_mut(app_type)
+            .and_then(|manager| manager.providers.get_mut(provider_id))
+        else {
+            return false;
+        };
+        for path in overlay.paths.iter().filter_map(|path| parse_pointer(path)) {
+            let path: Vec<&str> = path.iter().map(String::as_str).collect();
+            restore_path(&mut provider.settings_config, stored_before, &path);
+        }
+        true
+    }
+
+    /// 切换成功后记录（或清除）本次覆盖
+    pub(super) fn record_live_overlay(
+        app_type: &AppType,
+        provider_id: &str,
+        overlay: &[SettingsPatch],
+    ) -> Result<(), AppError> {
+        let record = (!overlay.is_empty()).then(|| LiveOverlay {
+            provider_id: provider_id.to_string(),
+            paths: overlay.iter().map(SettingsPatch::pointer).collect(),
+        });
+        crate::settings::set_live_overlay(app_type, record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn restore_path_puts_back_stored_value_or_removes_added_key() {
+        let base = json!({ "env": { "ANTHROPIC_MODEL": "base-model" } });
+        let mut live = json!({ "env": { "ANTHROPIC_MODEL": "overlay", "EXTRA": "x" } });
+
+        restore_path(&mut live, &base, &["env", "ANTHROPIC_MODEL"]);
+        restore_path(&mut live, &base, &["env", "EXTRA"]);
+
+        assert_eq!(live, base);
+    }
+}
@@ -0,0 +1,117 @@
+//! 预览切换后实际写入磁盘的 live 配置（`provider preview-live`）
+//!
+//! 与写入路径共用 `render_*_live` 的渲染结果（通用配置片段、Claude 模型规范化、
+//! Codex TOML 与 `.env`、Gemini settings.json 合并），只生成内容不落盘。
+
+use std::path::PathBuf;
+
+use serde_json::{json, Value};
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+/// 预览中的单个 live 文件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LivePreviewFile {
+    pub path: PathBuf,
+    /// 将写入的完整内容；None 表示该文件会被移除
+    pub content: Option<String>,
+}
+
+impl LivePreviewFile {
+    fn text(path: PathBuf, content: impl Into<String>) -> Self {
+        Self {
+            path,
+            content: Some(content.into()),
+        }
+    }
+
+    fn json(path: PathBuf, value: &Value) -> Result<Self, AppError> {
+        let content = serde_json::to_string_pretty(value)
+            .map_err(|source| AppError::JsonSerialize { source })?;
+        Ok(Self::text(path, content))
+    }
+}
+
+impl ProviderService {
+    /// 计算切换到指定供应商时各 live 文件的内容，不写入任何文件
+    pub fn preview_live(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+    ) -> Result<Vec<LivePreviewFile>, AppError> {
+        let (provider, snippet) = {
+            let config = state.config.read().map_err(AppError::from)?;
+            let provider = config
+                .get_manager(&app_type)
+                .ok_or_else(|| Self::app_not_found(&app_type))?
+                .providers
+                .get(provider_id)
+                .cloned()
+                .ok_or_else(|| AppError::provider_not_found(provider_id))?;
+            let snippet = config.common_config_snippets.get(&app_type).cloned();
+            (provider, snippet)
+        };
+        let apply_common_config = provider
+            .meta
+            .as_ref()
+            .and_then(|meta| meta.apply_common_config)
+            .unwrap_or(true);
+
+        let snippet = snippet.as_deref().filter(|_| apply_common_config);
+        let files = match app_type {
+            AppType::Claude => vec![LivePreviewFile::json(
+                crate::config::get_claude_settings_path(),
+                &Self::render_claude_live(&provider, snippet)?,
+            )?],
+            AppType::Codex => {
+                let rendered = Self::render_codex_live(&provider, snippet, true)?;
+                let mut files = vec![LivePreviewFile::text(
+                    crate::codex_config::get_codex_config_path(),
+                    rendered.config,
+                )];
+                if let Some(env) = rendered.env {
+                    files.push(LivePreviewFile::text(
+                        crate::codex_config::get_codex_env_path(),
+                        env,
+                    ));
+                }
+                let auth_path = crate::codex_config::get_codex_auth_path();
+                files.push(match &rendered.auth {
+                    Some(auth) => LivePreviewFile::json(auth_path, auth)?,
+                    None => LivePreviewFile {
+                        path: auth_path,
+                        content: None,
+                    },
+                });
+                files
+            }
+            AppType::Gemini => {
+                let rendered = Self::render_gemini_live(&provider, snippet)?;
+                vec![
+                    LivePreviewFile::text(
+                        crate::gemini_config::get_gemini_env_path(),
+                        crate::gemini_config::serialize_env_file(&rendered.env),
+                    ),
+                    LivePreviewFile::json(
+                        crate::gemini_config::get_gemini_settings_path(),
+                        &rendered.settings,
+                    )?,
+                ]
+            }
+            AppType::OpenCode => {
+                let mut providers = serde_json::Map::new();
+                providers.insert(provider.id.clone(), Self::opencode_live_entry(&provider));
+                // 只展示本供应商条目，opencode.json 中的其它键保持不变
+                vec![LivePreviewFile::json(
+                    crate::opencode_config::get_opencode_config_path(),
+                    &json!({ "provider": providers }),
+                )?]
+            }
+        };
+        Ok(files)
+    }
+}
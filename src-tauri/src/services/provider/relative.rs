@@ -0,0 +1,61 @@
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+/// 按 `sort_index`、`created_at` 排序（跳过已归档），返回距 `current` `delta` 个位置的供应商 ID
+///
+/// 首尾循环；`current` 不在列表中（如已归档）时，正向从第一个开始计，反向从最后一个开始计。
+/// CLI `--next/--prev` 与 TUI `n/p` 共用此排序。
+pub fn relative_provider_id<'a>(
+    providers: impl IntoIterator<Item = (&'a str, &'a Provider)>,
+    current: &str,
+    delta: i32,
+) -> Option<&'a str> {
+    let mut ordered: Vec<(&str, &Provider)> = providers
+        .into_iter()
+        .filter(|(_, provider)| !provider.is_archived())
+        .collect();
+    if ordered.is_empty() {
+        return None;
+    }
+    ordered.sort_by(|(_, a), (_, b)| match (a.sort_index, b.sort_index) {
+        (Some(idx_a), Some(idx_b)) => idx_a.cmp(&idx_b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => a.created_at.cmp(&b.created_at),
+    });
+
+    let start = match ordered.iter().position(|(id, _)| *id == current) {
+        Some(pos) => pos as i64,
+        None if delta > 0 => -1,
+        None => 0,
+    };
+    let idx = (start + i64::from(delta)).rem_euclid(ordered.len() as i64) as usize;
+    Some(ordered[idx].0)
+}
+
+impl ProviderService {
+    /// 返回排序中距当前供应商 `delta` 个位置的供应商 ID，见 [`relative_provider_id`]
+    pub fn relative_provider_id(
+        state: &AppState,
+        app_type: AppType,
+        delta: i32,
+    ) -> Result<String, AppError> {
+        let providers = Self::list(state, app_type.clone())?;
+        let current = Self::current(state, app_type.clone())?;
+
+        let entries = providers.iter().map(|(id, p)| (id.as_str(), p));
+        relative_provider_id(entries, &current, delta)
+            .map(str::to_string)
+            .ok_or_else(|| {
+                AppError::localized(
+                    "provider.switch_relative.empty",
+                    format!("{} 没有可切换的供应商", app_type.as_str()),
+                    format!("No providers to switch to for {}", app_type.as_str()),
+                )
+            })
+    }
+}
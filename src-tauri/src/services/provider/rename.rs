@@ -0,0 +1,69 @@
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+impl ProviderService {
+    /// 修改供应商 ID（与显示名称无关）
+    ///
+    /// 在原位置替换 `IndexMap` 的键，保留 `meta`、`sort_index` 等字段；
+    /// 若 `current` 指向旧 ID，则同步更新为新 ID。
+    pub fn rename_id(
+        state: &AppState,
+        app_type: AppType,
+        old_id: &str,
+        new_id: &str,
+    ) -> Result<(), AppError> {
+        let old_id = old_id.to_string();
+        let new_id = new_id.trim().to_string();
+        if new_id.is_empty() {
+            return Err(AppError::localized(
+                "provider.rename_id.empty",
+                "新的供应商 ID 不能为空",
+                "New provider ID cannot be empty",
+            ));
+        }
+
+        let app_type_clone = app_type.clone();
+        let previous_id = old_id.clone();
+        let renamed: Provider = Self::run_transaction(state, move |config| {
+            let manager = config
+                .get_manager_mut(&app_type_clone)
+                .ok_or_else(|| Self::app_not_found(&app_type_clone))?;
+
+            let index = manager
+                .providers
+                .get_index_of(&old_id)
+                .ok_or_else(|| AppError::provider_not_found(&old_id))?;
+            if manager.providers.contains_key(&new_id) {
+                return Err(AppError::localized(
+                    "provider.rename_id.exists",
+                    format!("供应商 ID 已存在: {new_id}"),
+                    format!("Provider ID already exists: {new_id}"),
+                ));
+            }
+
+            // 上面已确认新 ID 不存在，替换不会失败
+            let _ = manager.providers.replace_index(index, new_id.clone());
+            let provider = &mut manager.providers[index];
+            provider.id = new_id.clone();
+            let renamed = provider.clone();
+
+            if manager.current == old_id {
+                manager.current = new_id;
+            }
+
+            Ok((renamed, None))
+        })?;
+
+        // 叠加模式（OpenCode）的 live 配置按 ID 存储，需要迁移条目
+        if app_type.is_additive_mode() && crate::sync_policy::should_sync_live(&app_type) {
+            crate::opencode_config::remove_provider(&previous_id)?;
+            Self::write_live_snapshot(&app_type, &renamed, None, false)?;
+        }
+
+        Ok(())
+    }
+}
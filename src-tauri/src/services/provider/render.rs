@@ -0,0 +1,275 @@
+//! 渲染切换时写入的 live 文件内容
+//!
+//! 写入路径（`write_*_live`）与 `provider preview-live` 共用这里的渲染结果：
+//! 渲染只读取现有 live 文件用于合并，不做任何写入。
+
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::config::read_json_file;
+use crate::error::AppError;
+use crate::provider::Provider;
+
+use super::gemini_auth::GeminiAuthType;
+use super::{
+    claude_headers, codex_auth, is_codex_official_provider, merge_json_values, ProviderService,
+};
+
+/// Codex 切换要写入的内容
+pub(super) struct CodexLiveFiles {
+    pub config: String,
+    /// None 表示移除 auth.json
+    pub auth: Option<Value>,
+    /// `~/.codex/.env` 的完整新内容；None 表示不改动
+    pub env: Option<String>,
+    /// 写入后 `.env` 中由 cc-switch 管理的变量名
+    pub env_key: Option<String>,
+    /// auth 只有一个空的 API Key，不写入 auth.json
+    pub empty_api_key: bool,
+}
+
+/// Gemini 切换要写入的内容
+pub(super) struct GeminiLiveFiles {
+    pub auth_type: GeminiAuthType,
+    pub env: HashMap<String, String>,
+    /// 合并到现有 settings.json 之后的完整内容（保留 mcpServers 等现有 key）
+    pub settings: Value,
+}
+
+impl ProviderService {
+    pub(super) fn render_claude_live(
+        provider: &Provider,
+        common_config_snippet: Option<&str>,
+    ) -> Result<Value, AppError> {
+        let mut provider_content = provider.settings_config.clone();
+        let _ = Self::normalize_claude_models_in_value(&mut provider_content);
+        claude_headers::apply_custom_headers(&mut provider_content);
+
+        let Some(snippet) = common_config_snippet.map(str::trim) else {
+            return Ok(provider_content);
+        };
+        if snippet.is_empty() {
+            return Ok(provider_content);
+        }
+        let common = Self::parse_common_claude_config_snippet(snippet)?;
+        let mut merged = common;
+        merge_json_values(&mut merged, &provider_content);
+        let _ = Self::normalize_claude_models_in_value(&mut merged);
+        Ok(merged)
+    }
+
+    /// Aligned with upstream: the stored `settings_config.config` is the full config.toml text,
+    /// optionally merged with the common config snippet. Auth is rendered separately.
+    pub(super) fn render_codex_live(
+        provider: &Provider,
+        common_config_snippet: Option<&str>,
+        apply_common_config: bool,
+    ) -> Result<CodexLiveFiles, AppError> {
+        let settings = provider
+            .settings_config
+            .as_object()
+            .ok_or_else(|| AppError::Config("Codex 配置必须是 JSON 对象".into()))?;
+
+        // auth 字段现在是可选的（Codex 0.64+ 使用环境变量）；只含一个空 Key 时同样视为空
+        let auth = settings.get("auth");
+        let empty_api_key = codex_auth::has_only_empty_api_key(provider);
+        let auth_is_empty = empty_api_key
+            || auth
+                .map(|a| a.as_object().map(|o| o.is_empty()).unwrap_or(true))
+                .unwrap_or(true);
+
+        // 获取存储的 config TOML 文本
+        let cfg_text = settings.get("config").and_then(Value::as_str).unwrap_or("");
+
+        // For official OpenAI providers, ensure wire_api and requires_openai_auth
+        // have sensible defaults in the model_providers section.
+        let cfg_text_owned;
+        let cfg_text = if is_codex_official_provider(provider) && !cfg_text.trim().is_empty() {
+            if let Ok(mut doc) = cfg_text.parse::<toml_edit::DocumentMut>() {
+                let mp_key = doc
+                    .get("model_provider")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string());
+                if let Some(key) = mp_key {
+                    if let Some(section) = doc
+                        .get_mut("model_providers")
+                        .and_then(|v| v.as_table_like_mut())
+                        .and_then(|t| t.get_mut(&key))
+                        .and_then(|v| v.as_table_like_mut())
+                    {
+                        if section.get("wire_api").is_none() {
+                            section.insert("wire_api", toml_edit::value("responses"));
+                        }
+                        if section.get("requires_openai_auth").is_none() {
+                            section.insert("requires_openai_auth", toml_edit::value(true));
+                        }
+                    }
+                }
+                cfg_text_owned = doc.to_string();
+                &cfg_text_owned
+            } else {
+                cfg_text
+            }
+        } else {
+            cfg_text
+        };
+
+        // Validate TOML before writing
+        if !cfg_text.trim().is_empty() {
+            crate::codex_config::validate_config_toml(cfg_text)?;
+        }
+
+        // Merge common config snippet if applicable
+        let snippet = common_config_snippet
+            .filter(|_| apply_common_config)
+            .map(str::trim)
+            .filter(|snippet| !snippet.is_empty());
+        let config = match snippet {
+            Some(snippet) if !cfg_text.trim().is_empty() => {
+                // Parse both as TOML documents and merge
+                let mut doc = cfg_text
+                    .parse::<toml_edit::DocumentMut>()
+                    .map_err(|e| AppError::Config(format!("TOML parse error: {e}")))?;
+                let common_doc = snippet.parse::<toml_edit::DocumentMut>().map_err(|e| {
+                    AppError::Config(format!("Common config TOML parse error: {e}"))
+                })?;
+                Self::merge_toml_tables(doc.as_table_mut(), common_doc.as_table());
+                doc.to_string()
+            }
+            _ => cfg_text.to_string(),
+        };
+
+        // auth.json handling:
+        //
+        // Codex has two auth modes:
+        // - API Key mode (auth.json): third-party/custom providers that explicitly carry auth.
+        // - Credential store / OpenAI official mode: auth.json must be absent, otherwise it
+        //   overrides the credential store.
+        //
+        // Align with upstream UI behavior:
+        // - If provider has no auth (or is explicitly marked as official), remove existing auth.json.
+        // - Otherwise, write auth.json from provider.auth.
+        //
+        // env-file 模式：API Key 写入 `~/.codex/.env`，auth.json 同样移除。
+        //
+        // 切到非 env-file 模式（或换了变量名）时，移除此前写入 `.env` 的变量。
+        let env_file_entry = codex_auth::codex_env_file_entry(provider);
+        let env_key = env_file_entry.as_ref().map(|(name, _)| name.clone());
+        let stale_key =
+            crate::settings::get_codex_env_key().filter(|old| Some(old) != env_key.as_ref());
+        let env_path = crate::codex_config::get_codex_env_path();
+        let existing_env = if env_path.exists() {
+            Some(std::fs::read_to_string(&env_path).map_err(|e| AppError::io(&env_path, e))?)
+        } else {
+            None
+        };
+        let mut env = match &stale_key {
+            Some(old) => existing_env
+                .as_deref()
+                .map(|existing| crate::codex_config::remove_env_assignment(existing, old))
+                .filter(|cleaned| Some(cleaned.as_str()) != existing_env.as_deref()),
+            None => None,
+        };
+        if let Some((name, key)) = &env_file_entry {
+            let base = env
+                .as_deref()
+                .or(existing_env.as_deref())
+                .unwrap_or_default();
+            env = Some(crate::codex_config::upsert_env_assignment(base, name, key));
+        }
+        let remove_auth =
+            auth_is_empty || is_codex_official_provider(provider) || env_file_entry.is_some();
+
+        Ok(CodexLiveFiles {
+            config,
+            auth: auth.filter(|_| !remove_auth).cloned(),
+            env,
+            env_key,
+            empty_api_key,
+        })
+    }
+
+    pub(super) fn render_gemini_live(
+        provider: &Provider,
+        common_config_snippet: Option<&str>,
+    ) -> Result<GeminiLiveFiles, AppError> {
+        use crate::gemini_config::{
+            get_gemini_settings_path, json_to_env, validate_gemini_settings_strict,
+        };
+
+        // 一次性检测认证类型，避免重复检测
+        let auth_type = Self::detect_gemini_auth_type(provider);
+
+        let provider_content = provider.settings_config.clone();
+        let content_to_write = match common_config_snippet.map(str::trim) {
+            Some(snippet) if !snippet.is_empty() => {
+                let common = Self::parse_common_gemini_config_snippet(snippet)?;
+                let mut merged = common;
+                merge_json_values(&mut merged, &provider_content);
+                merged
+            }
+            _ => provider_content,
+        };
+
+        let env = match auth_type {
+            // Google 官方使用 OAuth，清空 env
+            GeminiAuthType::GoogleOfficial => HashMap::new(),
+            // API Key 供应商（所有第三方服务）：验证配置后写入 .env
+            GeminiAuthType::ApiKey => {
+                validate_gemini_settings_strict(&content_to_write)?;
+                json_to_env(&content_to_write)?
+            }
+        };
+
+        // 准备要写入 ~/.gemini/settings.json 的配置（缺省时保留现有文件内容）
+        let settings_path = get_gemini_settings_path();
+        let existing = || -> Result<Value, AppError> {
+            if settings_path.exists() {
+                read_json_file(&settings_path)
+            } else {
+                Ok(json!({}))
+            }
+        };
+        let settings = match content_to_write.get("config") {
+            // null 或空对象 {} → 保留现有文件
+            None | Some(Value::Null) => existing()?,
+            Some(Value::Object(provider_config)) if provider_config.is_empty() => existing()?,
+            // 有内容 → 合并到现有 settings.json（保留现有 key，如 mcpServers），供应商优先
+            Some(Value::Object(provider_config)) => {
+                let mut merged = existing()?;
+                if !merged.is_object() {
+                    merged = json!({});
+                }
+                let merged_map = merged.as_object_mut().ok_or_else(|| {
+                    AppError::localized(
+                        "gemini.validation.invalid_settings",
+                        "Gemini 现有 settings.json 格式错误: 必须是对象",
+                        "Gemini existing settings.json invalid: must be a JSON object",
+                    )
+                })?;
+                // mcpServers 由 MCP 同步维护：供应商快照中的旧值不得覆盖 live 文件
+                for (key, value) in provider_config {
+                    if key == "mcpServers" {
+                        continue;
+                    }
+                    merged_map.insert(key.clone(), value.clone());
+                }
+                merged
+            }
+            Some(_) => {
+                return Err(AppError::localized(
+                    "gemini.validation.invalid_config",
+                    "Gemini 配置格式错误: config 必须是对象或 null",
+                    "Gemini config invalid: config must be an object or null",
+                ));
+            }
+        };
+
+        Ok(GeminiLiveFiles {
+            auth_type,
+            env,
+            settings,
+        })
+    }
+}
@@ -0,0 +1,106 @@
+use indexmap::IndexMap;
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+impl ProviderService {
+    /// 在每个应用中按 ID 或名称（忽略大小写）精确解析 `target`，找不到的应用为 None（跳过）
+    ///
+    /// 不做前缀匹配，避免 `default` 误中只有 `defaults-old` 的应用。
+    /// 只解析不切换：任一应用解析出错（如同名供应商有多个）时整体失败，不会留下部分切换。
+    pub fn resolve_each_app(
+        state: &AppState,
+        apps: &[AppType],
+        target: &str,
+    ) -> Result<Vec<(AppType, Option<String>)>, AppError> {
+        let mut results = Vec::with_capacity(apps.len());
+        for app in apps {
+            let providers = Self::list(state, app.clone())?;
+            results.push((app.clone(), exact_ref(&providers, target)?));
+        }
+        Ok(results)
+    }
+
+    /// 将用户输入解析为供应商 ID：先按 ID 精确匹配，再按名称（忽略大小写）精确匹配，
+    /// 最后按 ID 或名称的唯一前缀匹配；前缀命中多个时报错并列出候选
+    pub fn resolve_provider_ref(
+        state: &AppState,
+        app_type: AppType,
+        query: &str,
+    ) -> Result<String, AppError> {
+        let providers = Self::list(state, app_type)?;
+        if let Some(id) = exact_ref(&providers, query)? {
+            return Ok(id);
+        }
+
+        let needle = query.trim().to_lowercase();
+        if needle.is_empty() {
+            return Err(AppError::provider_not_found(query));
+        }
+
+        let mut candidates: Vec<(&String, &str)> = providers
+            .iter()
+            .filter(|(id, provider)| {
+                id.to_lowercase().starts_with(&needle)
+                    || provider.name.trim().to_lowercase().starts_with(&needle)
+            })
+            .map(|(id, provider)| (id, provider.name.as_str()))
+            .collect();
+        candidates.sort_by(|a, b| a.0.cmp(b.0));
+        match candidates.as_slice() {
+            [] => Err(AppError::provider_not_found(query)),
+            [(id, _)] => Ok((*id).clone()),
+            _ => {
+                let listed = candidates
+                    .iter()
+                    .map(|(id, name)| format!("{id} ({name})"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Err(AppError::localized(
+                    "provider.ambiguous",
+                    format!("'{query}' 匹配到多个供应商：{listed}，请使用完整 ID"),
+                    format!("'{query}' matches several providers: {listed}; use the full ID"),
+                ))
+            }
+        }
+    }
+}
+
+/// 按 ID 精确匹配，再按名称（忽略大小写）精确匹配；同名供应商有多个时报错
+fn exact_ref(
+    providers: &IndexMap<String, Provider>,
+    query: &str,
+) -> Result<Option<String>, AppError> {
+    if providers.contains_key(query) {
+        return Ok(Some(query.to_string()));
+    }
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        return Ok(None);
+    }
+    let by_name: Vec<&String> = providers
+        .iter()
+        .filter(|(_, provider)| provider.name.trim().to_lowercase() == needle)
+        .map(|(id, _)| id)
+        .collect();
+    match by_name.as_slice() {
+        [] => Ok(None),
+        [id] => Ok(Some((*id).clone())),
+        _ => {
+            let listed = by_name
+                .iter()
+                .map(|id| id.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            Err(AppError::localized(
+                "provider.ambiguous",
+                format!("名称 '{query}' 对应多个供应商：{listed}，请使用完整 ID"),
+                format!("Name '{query}' matches several providers: {listed}; use the full ID"),
+            ))
+        }
+    }
+}
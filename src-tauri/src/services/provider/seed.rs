@@ -0,0 +1,121 @@
+//! 在应用安装之前预置供应商快照（`provider set-current-from`）
+//!
+//! 只更新存储的 `settings_config`，不读写任何 live 文件；应用安装后切换到该供应商时才写入。
+
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+fn seed_file_invalid(path: &Path, app_type: &AppType) -> AppError {
+    AppError::localized(
+        "provider.seed.invalid_file",
+        format!(
+            "无法将 {} 解析为 {app_type} 配置（需要 JSON 对象{}）",
+            path.display(),
+            match app_type {
+                AppType::Codex => "或 config.toml",
+                AppType::Gemini => "或 .env",
+                _ => "",
+            }
+        ),
+        format!(
+            "Cannot read {} as a {app_type} config (expected a JSON object{})",
+            path.display(),
+            match app_type {
+                AppType::Codex => " or a config.toml",
+                AppType::Gemini => " or a .env file",
+                _ => "",
+            }
+        ),
+    )
+}
+
+/// 将文件内容转为 `settings_config`
+///
+/// JSON 对象按原样使用；Codex 也接受 config.toml、Gemini 也接受 .env，
+/// 此时快照中另一半（auth / settings.json）沿用现有值。
+fn seed_settings(
+    app_type: &AppType,
+    raw: &str,
+    existing: &Value,
+    path: &Path,
+) -> Result<Value, AppError> {
+    if let Ok(value @ Value::Object(_)) = serde_json::from_str::<Value>(raw) {
+        return Ok(value);
+    }
+    match app_type {
+        AppType::Codex => {
+            crate::codex_config::validate_config_toml(raw)
+                .map_err(|_| seed_file_invalid(path, app_type))?;
+            let mut settings = json!({ "config": raw });
+            if let Some(auth) = existing.get("auth") {
+                settings["auth"] = auth.clone();
+            }
+            Ok(settings)
+        }
+        AppType::Gemini => {
+            let env = crate::gemini_config::parse_env_file_strict(raw)
+                .map_err(|_| seed_file_invalid(path, app_type))?;
+            let mut settings = crate::gemini_config::env_to_json(&env);
+            settings["config"] = existing.get("config").cloned().unwrap_or(json!({}));
+            Ok(settings)
+        }
+        _ => Err(seed_file_invalid(path, app_type)),
+    }
+}
+
+impl ProviderService {
+    /// 从文件预置供应商的存储快照，不触碰 live 文件。
+    ///
+    /// 未指定 ID 时使用当前供应商。内容按添加供应商时的规则校验。返回被更新的供应商 ID。
+    pub fn seed_snapshot_from_file(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: Option<&str>,
+        path: &Path,
+    ) -> Result<String, AppError> {
+        let provider_id = match provider_id {
+            Some(id) => id.to_string(),
+            None => {
+                let current = Self::current(state, app_type.clone())?;
+                if current.is_empty() {
+                    return Err(AppError::localized(
+                        "provider.seed.id_required",
+                        format!("{app_type} 没有当前供应商，请用 --id 指定供应商"),
+                        format!("{app_type} has no current provider; pass --id"),
+                    ));
+                }
+                current
+            }
+        };
+        let mut provider = Self::list(state, app_type.clone())?
+            .shift_remove(&provider_id)
+            .ok_or_else(|| AppError::provider_not_found(&provider_id))?;
+
+        let raw = std::fs::read_to_string(path).map_err(|e| AppError::io(path, e))?;
+        provider.settings_config = seed_settings(&app_type, &raw, &provider.settings_config, path)?;
+        Self::normalize_provider_if_claude(&app_type, &mut provider);
+        Self::validate_provider_settings(&app_type, &provider)?;
+
+        let app_type_clone = app_type.clone();
+        Self::run_transaction(state, move |config| {
+            let manager = config
+                .get_manager_mut(&app_type_clone)
+                .ok_or_else(|| Self::app_not_found(&app_type_clone))?;
+            let target = manager
+                .providers
+                .get_mut(&provider.id)
+                .ok_or_else(|| AppError::provider_not_found(&provider.id))?;
+            target.settings_config = provider.settings_config;
+            Ok(((), None))
+        })?;
+
+        Ok(provider_id)
+    }
+}
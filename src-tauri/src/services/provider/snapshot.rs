@@ -0,0 +1,54 @@
+//! 手动刷新供应商快照（`provider refresh-snapshot`）
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+impl ProviderService {
+    /// 按 live 配置手动刷新供应商的存储快照（如手动编辑 live 文件之后）。
+    ///
+    /// live 只反映当前供应商，因此仅允许刷新当前供应商；累加模式应用须显式指定 ID。
+    /// 通用配置片段照常剥离。返回被刷新的供应商 ID。
+    pub fn refresh_snapshot(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: Option<&str>,
+    ) -> Result<String, AppError> {
+        let current = Self::current(state, app_type.clone())?;
+        let provider_id = match provider_id {
+            Some(id) => id.to_string(),
+            None if app_type.is_additive_mode() => {
+                return Err(AppError::localized(
+                    "provider.snapshot.id_required",
+                    format!("{app_type} 没有单一的当前供应商，请指定供应商 ID"),
+                    format!("{app_type} has no single current provider; specify a provider ID"),
+                ));
+            }
+            None if current.is_empty() => {
+                return Err(AppError::localized(
+                    "provider.snapshot.no_current",
+                    format!("{app_type} 没有当前供应商"),
+                    format!("{app_type} has no current provider"),
+                ));
+            }
+            None => current.clone(),
+        };
+        if !Self::list(state, app_type.clone())?.contains_key(&provider_id) {
+            return Err(AppError::provider_not_found(provider_id));
+        }
+        if !app_type.is_additive_mode() && provider_id != current {
+            return Err(AppError::localized(
+                "provider.snapshot.not_current",
+                format!("live 配置只反映当前供应商 '{current}'，无法刷新 '{provider_id}' 的快照"),
+                format!(
+                    "Live config reflects the current provider '{current}'; cannot refresh '{provider_id}'"
+                ),
+            ));
+        }
+
+        Self::refresh_provider_snapshot(state, &app_type, &provider_id)?;
+        Ok(provider_id)
+    }
+}
@@ -0,0 +1,245 @@
+use serde_json::{Map, Value};
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::{Provider, ProviderMeta};
+use crate::store::AppState;
+
+use super::{PostCommitAction, ProviderService};
+
+/// 供应商配置补丁：`env.ANTHROPIC_BASE_URL=https://proxy` 或 `/env/ANTHROPIC_BASE_URL=https://proxy`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingsPatch {
+    pub path: Vec<String>,
+    pub value: Value,
+}
+
+impl SettingsPatch {
+    /// 解析 `path=value`；path 见 [`parse_settings_path`]。
+    /// value 能解析为 JSON 时按 JSON 处理，否则视为字符串；
+    /// `env` 下的变量值始终按字符串写入（`env.API_TIMEOUT_MS=3000` 得到 `"3000"`）
+    pub fn parse(raw: &str) -> Result<Self, AppError> {
+        let invalid = || {
+            AppError::localized(
+                "provider.patch.invalid",
+                format!("无效的配置补丁（应为 path=value 或 /json/pointer=value）: {raw}"),
+                format!(
+                    "Invalid settings patch (expected path=value or /json/pointer=value): {raw}"
+                ),
+            )
+        };
+        let (path, value) = raw.split_once('=').ok_or_else(invalid)?;
+        let path = parse_settings_path(path.trim()).ok_or_else(invalid)?;
+        let is_env_var = path.len() > 1 && path[0] == "env";
+        let value = match serde_json::from_str(value) {
+            Ok(value) if !is_env_var => value,
+            _ => Value::String(value.into()),
+        };
+        Ok(Self { path, value })
+    }
+
+    /// 以 RFC 6901 JSON Pointer 形式表示路径（键名可含 `.`，可被无歧义地解析回来）
+    pub fn pointer(&self) -> String {
+        self.path
+            .iter()
+            .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+            .collect()
+    }
+
+    pub(super) fn apply(&self, settings: &mut Value) -> Result<(), AppError> {
+        let mut cursor = settings;
+        for (index, segment) in self.path.iter().enumerate() {
+            if cursor.is_null() {
+                *cursor = Value::Object(Map::new());
+            }
+            let obj = cursor.as_object_mut().ok_or_else(|| {
+                let prefix = self.path[..index].join(".");
+                AppError::localized(
+                    "provider.patch.not_object",
+                    format!("配置路径 {prefix} 不是 JSON 对象"),
+                    format!("Settings path {prefix} is not a JSON object"),
+                )
+            })?;
+            if index + 1 == self.path.len() {
+                obj.insert(segment.clone(), self.value.clone());
+                return Ok(());
+            }
+            cursor = obj.entry(segment.clone()).or_insert(Value::Null);
+        }
+        Ok(())
+    }
+}
+
+/// 解析配置路径：以 `/` 开头时按 RFC 6901 JSON Pointer（`/env/KEY`，`~1` 表示 `/`，
+/// `~0` 表示 `~`），否则按 `.` 分隔（`env.KEY`）。空路径、空段或非法转义返回 None
+fn parse_settings_path(path: &str) -> Option<Vec<String>> {
+    if path.starts_with('/') {
+        return parse_pointer(path);
+    }
+    let segments: Vec<String> = path.split('.').map(str::to_string).collect();
+    if segments.iter().any(|segment| segment.trim().is_empty()) {
+        return None;
+    }
+    Some(segments)
+}
+
+/// 解析 RFC 6901 JSON Pointer（必须以 `/` 开头）
+pub(super) fn parse_pointer(pointer: &str) -> Option<Vec<String>> {
+    let segments: Vec<String> = pointer
+        .strip_prefix('/')?
+        .split('/')
+        .map(unescape_pointer_segment)
+        .collect::<Option<_>>()?;
+    if segments.iter().any(|segment| segment.trim().is_empty()) {
+        return None;
+    }
+    Some(segments)
+}
+
+fn unescape_pointer_segment(segment: &str) -> Option<String> {
+    let mut result = String::with_capacity(segment.len());
+    let mut chars = segment.chars();
+    while let Some(c) = chars.next() {
+        if c != '~' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('0') => result.push('~'),
+            Some('1') => result.push('/'),
+            _ => return None,
+        }
+    }
+    Some(result)
+}
+
+fn normalize_tags(tags: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut result: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.trim();
+        if !tag.is_empty() && !result.iter().any(|t| t == tag) {
+            result.push(tag.to_string());
+        }
+    }
+    result
+}
+
+pub(crate) fn provider_tags(provider: &Provider) -> &[String] {
+    provider
+        .meta
+        .as_ref()
+        .map(|meta| meta.tags.as_slice())
+        .unwrap_or_default()
+}
+
+impl ProviderService {
+    /// 修改供应商标签，返回修改后的标签列表
+    pub fn update_tags(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        add: &[String],
+        remove: &[String],
+    ) -> Result<Vec<String>, AppError> {
+        let provider_id = provider_id.to_string();
+        let add = add.to_vec();
+        let remove = remove.to_vec();
+
+        Self::run_transaction(state, move |config| {
+            let provider = config
+                .get_manager_mut(&app_type)
+                .ok_or_else(|| Self::app_not_found(&app_type))?
+                .providers
+                .get_mut(&provider_id)
+                .ok_or_else(|| AppError::provider_not_found(provider_id))?;
+
+            let meta = provider.meta.get_or_insert_with(ProviderMeta::default);
+            let merged = meta.tags.iter().cloned().chain(add);
+            meta.tags = normalize_tags(merged)
+                .into_iter()
+                .filter(|tag| !remove.iter().any(|r| r.trim() == tag))
+                .collect();
+
+            Ok((meta.tags.clone(), None))
+        })
+    }
+
+    /// 对带有指定标签的全部供应商应用配置补丁（全部成功或全部回滚），返回更新数量
+    pub fn apply_tag_patch(
+        state: &AppState,
+        app_type: AppType,
+        tag: &str,
+        patches: &[SettingsPatch],
+    ) -> Result<usize, AppError> {
+        let tag = tag.trim().to_string();
+        let patches = patches.to_vec();
+        let app_type_clone = app_type.clone();
+
+        let (count, additive) = Self::run_transaction(state, move |config| {
+            // 叠加模式的 live 写入在提交后逐个进行，失败时需要回滚到这里的配置
+            let original = app_type_clone.is_additive_mode().then(|| config.clone());
+            let common_config_snippet = config.common_config_snippets.get(&app_type_clone).cloned();
+            let manager = config
+                .get_manager_mut(&app_type_clone)
+                .ok_or_else(|| Self::app_not_found(&app_type_clone))?;
+
+            let mut updated = Vec::new();
+            for provider in manager.providers.values_mut() {
+                if !provider_tags(provider).contains(&tag) {
+                    continue;
+                }
+                for patch in &patches {
+                    patch.apply(&mut provider.settings_config)?;
+                }
+                Self::normalize_provider_if_claude(&app_type_clone, provider);
+                Self::validate_provider_settings(&app_type_clone, provider)?;
+                updated.push(provider.clone());
+            }
+
+            if updated.is_empty() {
+                return Err(AppError::localized(
+                    "provider.tag.no_match",
+                    format!("没有带标签 {tag} 的供应商"),
+                    format!("No providers tagged '{tag}'"),
+                ));
+            }
+
+            let count = updated.len();
+            if let Some(original) = original {
+                let backup = Self::capture_live_snapshot(&app_type_clone)?;
+                return Ok(((count, Some((original, backup, updated))), None));
+            }
+
+            let action = match updated.into_iter().find(|p| p.id == manager.current) {
+                Some(provider) => Some(PostCommitAction {
+                    app_type: app_type_clone.clone(),
+                    provider,
+                    backup: Self::capture_live_snapshot(&app_type_clone)?,
+                    sync_mcp: false,
+                    strict_mcp: false,
+                    refresh_snapshot: false,
+                    common_config_snippet,
+                    takeover_active: false,
+                }),
+                None => None,
+            };
+
+            Ok(((count, None), action))
+        })?;
+
+        // 叠加模式（OpenCode）下所有供应商都写入 live，任一失败则连同配置一起回滚
+        if let Some((original, backup, providers)) = additive {
+            let written = providers.iter().try_for_each(|provider| {
+                Self::write_live_snapshot(&app_type, provider, None, false).map(|_| ())
+            });
+            if let Err(err) = written {
+                if let Err(rollback_err) = Self::rollback_after_failure(state, original, backup) {
+                    return Err(Self::post_commit_rollback_failed(&err, &rollback_err));
+                }
+                return Err(err);
+            }
+        }
+
+        Ok(count)
+    }
+}
@@ -0,0 +1,123 @@
+//! 供应商服务单元测试共用的夹具：隔离的 HOME、常用供应商与本地 HTTP 服务
+
+use std::ffi::OsString;
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+use crate::app_config::{AppType, MultiAppConfig};
+use crate::provider::Provider;
+use crate::store::AppState;
+
+use super::state_from_config;
+
+/// 将 HOME / USERPROFILE 指向给定目录，析构时恢复
+pub(super) struct EnvGuard {
+    old_home: Option<OsString>,
+    old_userprofile: Option<OsString>,
+}
+
+impl EnvGuard {
+    pub(super) fn set_home(home: &Path) -> Self {
+        let old_home = std::env::var_os("HOME");
+        let old_userprofile = std::env::var_os("USERPROFILE");
+        std::env::set_var("HOME", home);
+        std::env::set_var("USERPROFILE", home);
+        Self {
+            old_home,
+            old_userprofile,
+        }
+    }
+}
+
+impl Drop for EnvGuard {
+    fn drop(&mut self) {
+        match &self.old_home {
+            Some(value) => std::env::set_var("HOME", value),
+            None => std::env::remove_var("HOME"),
+        }
+        match &self.old_userprofile {
+            Some(value) => std::env::set_var("USERPROFILE", value),
+            None => std::env::remove_var("USERPROFILE"),
+        }
+    }
+}
+
+/// 启动先返回一次 503、之后返回 200 的本地 HTTP 服务，返回其地址
+pub(super) fn serve_unavailable_then_ok() -> String {
+    use std::io::{Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+    let addr = listener.local_addr().expect("addr");
+    std::thread::spawn(move || {
+        let responses: [&[u8]; 2] = [
+            b"HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}",
+        ];
+        for response in responses {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let mut buf = [0u8; 4096];
+                let _ = stream.read(&mut buf);
+                let _ = stream.write_all(response);
+            }
+        }
+    });
+    format!("http://{addr}")
+}
+
+/// 启动只响应一次 200 的本地 HTTP 服务，返回其地址
+pub(super) fn serve_ok_once() -> String {
+    use std::io::{Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+    let addr = listener.local_addr().expect("addr");
+    std::thread::spawn(move || {
+        if let Ok((mut stream, _)) = listener.accept() {
+            let mut buf = [0u8; 4096];
+            let _ = stream.read(&mut buf);
+            let _ = stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}");
+        }
+    });
+    format!("http://{addr}")
+}
+
+/// 绑定后立即释放端口，确保连接被拒绝
+pub(super) fn dead_url() -> String {
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .expect("bind")
+        .local_addr()
+        .expect("addr")
+        .port();
+    format!("http://127.0.0.1:{port}")
+}
+
+pub(super) fn failover_state(urls: &[(&str, Option<String>)]) -> AppState {
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    {
+        let manager = config.get_manager_mut(&AppType::Claude).expect("claude");
+        for (index, (id, url)) in urls.iter().enumerate() {
+            let env = match url {
+                Some(url) => json!({ "ANTHROPIC_BASE_URL": url, "ANTHROPIC_AUTH_TOKEN": "sk" }),
+                None => json!({}),
+            };
+            let mut provider = tagged_claude_provider(id, &[], json!({ "env": env }));
+            provider.sort_index = Some(index);
+            manager.providers.insert(id.to_string(), provider);
+        }
+        manager.current = "main".to_string();
+    }
+    let state = state_from_config(config);
+    state.save().expect("persist providers");
+    state
+}
+
+pub(super) fn tagged_claude_provider(id: &str, tags: &[&str], settings: Value) -> Provider {
+    let mut provider = Provider::with_id(id.to_string(), id.to_string(), settings, None);
+    provider.meta = Some(crate::provider::ProviderMeta {
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        ..Default::default()
+    });
+    provider
+}
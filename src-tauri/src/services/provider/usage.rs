@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use regex::Regex;
 
 use crate::app_config::AppType;
@@ -9,13 +11,91 @@ use crate::usage_script;
 
 use super::ProviderService;
 
+/// 用量脚本请求未配置超时时的默认值（全局网络超时优先）
+const DEFAULT_USAGE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 脚本自身配置的超时（秒），约束在 2–30 秒，防止异常配置导致长时间阻塞
+fn script_timeout(secs: u64) -> Duration {
+    Duration::from_secs(secs.clamp(2, 30))
+}
+
+/// 用量脚本的临时凭证覆盖：`API_KEY=sk-...`（仅作用于本次执行，不写回供应商）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageEnvOverride {
+    pub key: UsageEnvKey,
+    pub value: String,
+}
+
+/// 可覆盖的用量脚本变量，对应脚本中的 `{{apiKey}}` 等占位符
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageEnvKey {
+    ApiKey,
+    BaseUrl,
+    AccessToken,
+    UserId,
+}
+
+impl UsageEnvKey {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ApiKey => "API_KEY",
+            Self::BaseUrl => "BASE_URL",
+            Self::AccessToken => "ACCESS_TOKEN",
+            Self::UserId => "USER_ID",
+        }
+    }
+}
+
+impl UsageEnvOverride {
+    /// 解析 `KEY=VALUE`；KEY 不区分大小写，可为 API_KEY / BASE_URL / ACCESS_TOKEN / USER_ID
+    pub fn parse(raw: &str) -> Result<Self, AppError> {
+        let (key, value) = raw.split_once('=').ok_or_else(|| {
+            AppError::localized(
+                "usage_script.env.invalid",
+                format!("无效的覆盖项（应为 KEY=VALUE）: {raw}"),
+                format!("Invalid override (expected KEY=VALUE): {raw}"),
+            )
+        })?;
+        let key = match key.trim().to_ascii_uppercase().as_str() {
+            "API_KEY" => UsageEnvKey::ApiKey,
+            "BASE_URL" => UsageEnvKey::BaseUrl,
+            "ACCESS_TOKEN" => UsageEnvKey::AccessToken,
+            "USER_ID" => UsageEnvKey::UserId,
+            other => {
+                return Err(AppError::localized(
+                    "usage_script.env.unknown_key",
+                    format!(
+                        "不支持的覆盖项 {other}（可用: API_KEY, BASE_URL, ACCESS_TOKEN, USER_ID）"
+                    ),
+                    format!(
+                        "Unsupported override {other} (expected API_KEY, BASE_URL, ACCESS_TOKEN or USER_ID)"
+                    ),
+                ))
+            }
+        };
+        Ok(Self {
+            key,
+            value: value.to_string(),
+        })
+    }
+}
+
+/// 用量脚本最终使用的凭证
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct UsageScriptCredentials {
+    pub api_key: String,
+    pub base_url: String,
+    pub access_token: Option<String>,
+    pub user_id: Option<String>,
+}
+
 impl ProviderService {
     /// 执行用量脚本并格式化结果（私有辅助方法）
     async fn execute_and_format_usage_result(
         script_code: &str,
         api_key: &str,
         base_url: &str,
-        timeout: u64,
+        timeout: Duration,
         access_token: Option<&str>,
         user_id: Option<&str>,
     ) -> Result<UsageResult, AppError> {
@@ -86,18 +166,26 @@ impl ProviderService {
         app_type: AppType,
         provider_id: &str,
     ) -> Result<UsageResult, AppError> {
-        let (script_code, timeout, api_key, base_url, access_token, user_id) = {
+        Self::query_usage_with_env(state, app_type, provider_id, &[]).await
+    }
+
+    /// 查询供应商用量，`overrides` 在本次执行中覆盖解析出的凭证
+    pub async fn query_usage_with_env(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        overrides: &[UsageEnvOverride],
+    ) -> Result<UsageResult, AppError> {
+        let (script_code, timeout, credentials) = {
             let config = state.config.read().map_err(AppError::from)?;
             let manager = config
                 .get_manager(&app_type)
                 .ok_or_else(|| Self::app_not_found(&app_type))?;
-            let provider = manager.providers.get(provider_id).cloned().ok_or_else(|| {
-                AppError::localized(
-                    "provider.not_found",
-                    format!("供应商不存在: {provider_id}"),
-                    format!("Provider not found: {provider_id}"),
-                )
-            })?;
+            let provider = manager
+                .providers
+                .get(provider_id)
+                .cloned()
+                .ok_or_else(|| AppError::provider_not_found(provider_id))?;
 
             let usage_script = provider
                 .meta
@@ -118,26 +206,26 @@ impl ProviderService {
                 ));
             }
 
-            let (api_key, base_url) =
-                Self::resolve_usage_script_credentials(&provider, &app_type, usage_script)?;
+            let credentials =
+                Self::resolve_usage_credentials(&provider, &app_type, usage_script, overrides)?;
 
             (
                 usage_script.code.clone(),
-                usage_script.timeout.unwrap_or(10),
-                api_key,
-                base_url,
-                usage_script.access_token.clone(),
-                usage_script.user_id.clone(),
+                crate::proxy::resolve_timeout(
+                    usage_script.timeout.map(script_timeout),
+                    DEFAULT_USAGE_TIMEOUT,
+                ),
+                credentials,
             )
         };
 
         Self::execute_and_format_usage_result(
             &script_code,
-            &api_key,
-            &base_url,
+            &credentials.api_key,
+            &credentials.base_url,
             timeout,
-            access_token.as_deref(),
-            user_id.as_deref(),
+            credentials.access_token.as_deref(),
+            credentials.user_id.as_deref(),
         )
         .await
     }
@@ -160,7 +248,7 @@ impl ProviderService {
             script_code,
             api_key.unwrap_or(""),
             base_url.unwrap_or(""),
-            timeout,
+            script_timeout(timeout),
             access_token,
             user_id,
         )
@@ -354,6 +442,23 @@ impl ProviderService {
         ))
     }
 
+    /// 切换前的凭证预检：非官方供应商取不到 API Key（缺失或为空）时返回 true
+    pub fn credentials_missing(provider: &Provider, app_type: &AppType) -> bool {
+        let official = match app_type {
+            AppType::Codex => super::is_codex_official_provider(provider),
+            _ => provider
+                .category
+                .as_deref()
+                .is_some_and(|category| category.eq_ignore_ascii_case("official")),
+        };
+        if official {
+            return false;
+        }
+        Self::extract_credentials(provider, app_type)
+            .map(|(api_key, _)| api_key.trim().is_empty())
+            .unwrap_or(true)
+    }
+
     pub(super) fn resolve_usage_script_credentials(
         provider: &Provider,
         app_type: &AppType,
@@ -371,4 +476,88 @@ impl ProviderService {
 
         Ok((api_key, base_url))
     }
+
+    /// 解析用量脚本凭证：覆盖项 > 脚本配置 > 供应商配置；同一 KEY 以最后一次为准
+    pub(super) fn resolve_usage_credentials(
+        provider: &Provider,
+        app_type: &AppType,
+        usage_script: &UsageScript,
+        overrides: &[UsageEnvOverride],
+    ) -> Result<UsageScriptCredentials, AppError> {
+        let mut script = usage_script.clone();
+        for item in overrides {
+            let slot = match item.key {
+                UsageEnvKey::ApiKey => &mut script.api_key,
+                UsageEnvKey::BaseUrl => &mut script.base_url,
+                UsageEnvKey::AccessToken => &mut script.access_token,
+                UsageEnvKey::UserId => &mut script.user_id,
+            };
+            *slot = Some(item.value.clone());
+        }
+
+        let (api_key, base_url) =
+            Self::resolve_usage_script_credentials(provider, app_type, &script)?;
+        Ok(UsageScriptCredentials {
+            api_key,
+            base_url,
+            access_token: script.access_token,
+            user_id: script.user_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::Provider;
+    use serde_json::json;
+
+    #[test]
+    fn usage_env_override_replaces_stored_api_key_for_the_run() {
+        let provider = Provider::with_id(
+            "claude".into(),
+            "Claude".into(),
+            json!({
+                "env": {
+                    "ANTHROPIC_AUTH_TOKEN": "stored-token",
+                    "ANTHROPIC_BASE_URL": "https://claude.example"
+                }
+            }),
+            None,
+        );
+        let usage_script = crate::provider::UsageScript {
+            enabled: true,
+            language: "javascript".to_string(),
+            code: String::new(),
+            timeout: None,
+            api_key: None,
+            base_url: None,
+            access_token: None,
+            user_id: None,
+            template_type: None,
+            auto_query_interval: None,
+        };
+        let overrides = [
+            UsageEnvOverride::parse("API_KEY=injected-key").expect("parse override"),
+            UsageEnvOverride::parse("user_id=42").expect("parse lowercase key"),
+        ];
+
+        let credentials = ProviderService::resolve_usage_credentials(
+            &provider,
+            &AppType::Claude,
+            &usage_script,
+            &overrides,
+        )
+        .expect("resolve with overrides");
+        assert_eq!(credentials.api_key, "injected-key");
+        assert_eq!(credentials.base_url, "https://claude.example");
+        assert_eq!(credentials.user_id.as_deref(), Some("42"));
+        assert_eq!(
+            provider.settings_config["env"]["ANTHROPIC_AUTH_TOKEN"],
+            "stored-token"
+        );
+
+        assert!(UsageEnvOverride::parse("TOKEN=x").is_err());
+        assert!(UsageEnvOverride::parse("API_KEY").is_err());
+    }
 }
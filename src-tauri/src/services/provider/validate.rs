@@ -0,0 +1,79 @@
+//! 单个应用的配置校验（`config validate`）
+//!
+//! 逐个校验供应商配置、当前供应商 ID 是否存在以及通用配置片段能否解析，
+//! 问题以本地化文本收集，不在首个错误处中断。
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+/// 单个应用的校验结果
+#[derive(Debug, Clone)]
+pub struct AppValidationReport {
+    pub app_type: AppType,
+    pub provider_count: usize,
+    pub current: String,
+    pub issues: Vec<String>,
+}
+
+impl ProviderService {
+    pub(crate) fn validate_app(
+        state: &AppState,
+        app_type: &AppType,
+    ) -> Result<AppValidationReport, AppError> {
+        let config = state.config.read().map_err(AppError::from)?;
+        let mut issues = Vec::new();
+        let Some(manager) = config.get_manager(app_type) else {
+            return Ok(AppValidationReport {
+                app_type: app_type.clone(),
+                provider_count: 0,
+                current: String::new(),
+                issues,
+            });
+        };
+
+        for (id, provider) in &manager.providers {
+            if let Err(err) = Self::validate_provider_settings(app_type, provider) {
+                issues.push(format!("provider '{id}': {err}"));
+            }
+        }
+
+        if !app_type.is_additive_mode()
+            && !manager.current.is_empty()
+            && !manager.providers.contains_key(&manager.current)
+        {
+            issues.push(format!(
+                "current provider '{}' does not exist",
+                manager.current
+            ));
+        }
+
+        if let Some(snippet) = config
+            .common_config_snippets
+            .get(app_type)
+            .map(|snippet| snippet.trim())
+            .filter(|snippet| !snippet.is_empty())
+        {
+            let parsed = match app_type {
+                AppType::Claude => Self::parse_common_claude_config_snippet(snippet).map(|_| ()),
+                AppType::Gemini => Self::parse_common_gemini_config_snippet(snippet).map(|_| ()),
+                AppType::Codex => crate::codex_config::validate_config_toml(snippet),
+                AppType::OpenCode => serde_json::from_str::<serde_json::Value>(snippet)
+                    .map(|_| ())
+                    .map_err(|e| AppError::Message(e.to_string())),
+            };
+            if let Err(err) = parsed {
+                issues.push(format!("common config snippet: {err}"));
+            }
+        }
+
+        Ok(AppValidationReport {
+            app_type: app_type.clone(),
+            provider_count: manager.providers.len(),
+            current: manager.current.clone(),
+            issues,
+        })
+    }
+}
@@ -0,0 +1,173 @@
+//! 从 HTTPS 地址导入配置或团队配置包（`config import <url>` / `config import-bundle <url>`）
+//!
+//! 下载到临时目录后复用本地文件的导入流程；出站请求使用全局代理设置（未配置时遵循
+//! `HTTPS_PROXY` 等环境变量）。
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use tempfile::TempDir;
+
+use crate::error::AppError;
+use crate::store::AppState;
+
+use super::ConfigService;
+
+/// 远程导入文件的大小上限
+const MAX_REMOTE_IMPORT_BYTES: u64 = 20 * 1024 * 1024;
+const REMOTE_IMPORT_TIMEOUT_SECS: u64 = 60;
+
+/// 已下载的远程导入文件；临时目录随结构体释放
+#[derive(Debug)]
+pub struct RemoteImportFile {
+    _temp_dir: TempDir,
+    pub path: PathBuf,
+    pub bytes: u64,
+}
+
+impl ConfigService {
+    /// 导入源是否为 URL（否则按本地路径处理）
+    pub fn is_remote_source(source: &str) -> bool {
+        let lower = source.trim().to_ascii_lowercase();
+        lower.starts_with("https://") || lower.starts_with("http://")
+    }
+
+    /// 校验远程导入地址：默认只允许 https，`allow_http` 时放行 http
+    pub fn parse_remote_source(source: &str, allow_http: bool) -> Result<url::Url, AppError> {
+        let url = url::Url::parse(source.trim()).map_err(|e| {
+            AppError::localized(
+                "config.remote.invalid_url",
+                format!("无效的导入地址 '{source}': {e}"),
+                format!("Invalid import URL '{source}': {e}"),
+            )
+        })?;
+        match url.scheme() {
+            "https" => Ok(url),
+            "http" if allow_http => Ok(url),
+            "http" => Err(AppError::localized(
+                "config.remote.insecure_url",
+                "拒绝从非 HTTPS 地址导入；确认可信后可添加 --allow-http",
+                "Refusing to import from a non-HTTPS URL; pass --allow-http if you trust it",
+            )),
+            scheme => Err(AppError::localized(
+                "config.remote.unsupported_scheme",
+                format!("不支持的导入地址协议: {scheme}"),
+                format!("Unsupported import URL scheme: {scheme}"),
+            )),
+        }
+    }
+
+    /// 下载远程导入文件，`on_progress` 接收（已下载字节数, 总字节数）
+    pub fn download_remote_source(
+        state: &AppState,
+        source: &str,
+        allow_http: bool,
+        on_progress: &dyn Fn(u64, Option<u64>),
+    ) -> Result<RemoteImportFile, AppError> {
+        let url = Self::parse_remote_source(source, allow_http)?;
+        let proxy_url = state.db.get_global_proxy_url()?;
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| {
+                AppError::localized(
+                    "config.remote.runtime_create_failed",
+                    format!("创建异步运行时失败: {e}"),
+                    format!("Failed to create async runtime: {e}"),
+                )
+            })?
+            .block_on(download(url, proxy_url.as_deref(), on_progress))
+    }
+
+    /// 导入前校验 SQL 备份文件格式（`config import` 只接受 CC Switch 导出的 SQL）
+    pub fn check_import_file(path: &Path) -> Result<(), AppError> {
+        let sql = std::fs::read_to_string(path).map_err(|e| AppError::io(path, e))?;
+        crate::database::Database::validate_cc_switch_sql_export(sql.trim_start_matches('\u{feff}'))
+    }
+}
+
+fn download_failed(url: &url::Url, reason: impl std::fmt::Display) -> AppError {
+    AppError::localized(
+        "config.remote.download_failed",
+        format!("下载导入文件失败 ({url}): {reason}"),
+        format!("Failed to download import file ({url}): {reason}"),
+    )
+}
+
+fn too_large(url: &url::Url) -> AppError {
+    download_failed(
+        url,
+        format!("file exceeds {} MB", MAX_REMOTE_IMPORT_BYTES / 1024 / 1024),
+    )
+}
+
+async fn download(
+    url: url::Url,
+    proxy_url: Option<&str>,
+    on_progress: &dyn Fn(u64, Option<u64>),
+) -> Result<RemoteImportFile, AppError> {
+    let mut builder =
+        crate::proxy::client_builder(None, Duration::from_secs(REMOTE_IMPORT_TIMEOUT_SECS))
+            .redirect(reqwest::redirect::Policy::limited(5));
+    if let Some(proxy_url) = proxy_url.map(str::trim).filter(|p| !p.is_empty()) {
+        let proxy = reqwest::Proxy::all(proxy_url).map_err(|e| {
+            AppError::localized(
+                "config.remote.invalid_proxy",
+                format!("全局代理配置无效 ({proxy_url}): {e}"),
+                format!("Invalid global proxy setting ({proxy_url}): {e}"),
+            )
+        })?;
+        builder = builder.proxy(proxy);
+    }
+    let client = builder
+        .build()
+        .map_err(|e| download_failed(&url, format!("HTTP client: {e}")))?;
+
+    let mut response = client
+        .get(url.clone())
+        .send()
+        .await
+        .map_err(|e| download_failed(&url, e))?
+        .error_for_status()
+        .map_err(|e| download_failed(&url, e))?;
+    let total = response.content_length();
+    if total.is_some_and(|total| total > MAX_REMOTE_IMPORT_BYTES) {
+        return Err(too_large(&url));
+    }
+
+    let file_name = url
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .filter(|name| !name.is_empty())
+        .unwrap_or("import")
+        .to_string();
+    let temp_dir = tempfile::tempdir().map_err(|e| AppError::IoContext {
+        context: "创建临时目录失败".to_string(),
+        source: e,
+    })?;
+    let path = temp_dir.path().join(file_name);
+    let mut output = std::fs::File::create(&path).map_err(|e| AppError::io(&path, e))?;
+
+    let mut bytes = 0_u64;
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| download_failed(&url, e))?
+    {
+        bytes = bytes.saturating_add(chunk.len() as u64);
+        if bytes > MAX_REMOTE_IMPORT_BYTES {
+            return Err(too_large(&url));
+        }
+        output
+            .write_all(&chunk)
+            .map_err(|e| AppError::io(&path, e))?;
+        on_progress(bytes, total);
+    }
+
+    Ok(RemoteImportFile {
+        _temp_dir: temp_dir,
+        path,
+        bytes,
+    })
+}
@@ -20,6 +20,7 @@ use crate::database::Database;
 use crate::error::{format_skill_error, AppError};
 
 const SKILLS_INDEX_VERSION: u32 = 1;
+const GITHUB_API_BASE: &str = "https://api.github.com";
 
 fn default_skills_index_version() -> u32 {
     SKILLS_INDEX_VERSION
@@ -186,6 +187,17 @@ pub struct Skill {
     pub repo_branch: Option<String>,
 }
 
+/// `skills update` 对单个 Skill 的处理方式
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkillUpdateAction {
+    /// 按仓库分支重新下载
+    Update,
+    /// 固定在该提交或标签，未指定 `--force` 时跳过
+    Pinned(String),
+    /// 本地导入的 Skill，没有来源仓库
+    NoSource,
+}
+
 /// Skill metadata extracted from SKILL.md YAML front matter.
 #[derive(Debug, Clone, Deserialize)]
 pub struct SkillMetadata {
@@ -378,11 +390,31 @@ pub struct SkillService {
     http_client: Client,
 }
 
+/// 技能仓库可达性（`skills repos verify`）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepoReachability {
+    Reachable,
+    /// 仓库或分支不存在；未授权访问私有仓库时 GitHub 同样返回 404
+    NotFound,
+    /// 401 / 403：需要认证或触发了访问频率限制
+    AuthRequired,
+    /// 网络错误或其他响应
+    Unreachable,
+}
+
+/// 单个仓库的检查结果
+#[derive(Debug, Clone)]
+pub struct RepoVerification {
+    pub repo: SkillRepo,
+    pub status: RepoReachability,
+    /// HTTP 状态码或网络错误
+    pub detail: Option<String>,
+}
+
 impl SkillService {
     pub fn new() -> Result<Self, AppError> {
-        let http_client = Client::builder()
+        let http_client = crate::proxy::client_builder(None, std::time::Duration::from_secs(10))
             .user_agent("cc-switch")
-            .timeout(std::time::Duration::from_secs(10))
             .build()
             .map_err(|e| {
                 AppError::localized(
@@ -664,6 +696,7 @@ impl SkillService {
                             repo_owner: None,
                             repo_name: None,
                             repo_branch: None,
+                            pinned_ref: None,
                             apps,
                             installed_at: Utc::now().timestamp(),
                         },
@@ -825,6 +858,33 @@ impl SkillService {
         Ok(skills)
     }
 
+    /// 按应用启用状态与名称过滤已安装的 Skills
+    ///
+    /// `enabled_only` 为 true 时：指定 `app` 则仅保留对该应用启用的 Skill，否则保留至少对一个应用启用的 Skill。
+    /// `query` 匹配名称或目录（不区分大小写）。
+    pub fn list_installed_filtered(
+        app: Option<&AppType>,
+        enabled_only: bool,
+        query: Option<&str>,
+    ) -> Result<Vec<InstalledSkill>, AppError> {
+        let query = query
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+            .map(str::to_lowercase);
+        let mut skills = Self::list_installed()?;
+        skills.retain(|skill| {
+            let enabled = match app {
+                Some(app) => skill.apps.is_enabled_for(app),
+                None => !skill.apps.is_empty(),
+            };
+            let matches_query = query.as_ref().is_none_or(|q| {
+                skill.name.to_lowercase().contains(q) || skill.directory.to_lowercase().contains(q)
+            });
+            (!enabled_only || enabled) && matches_query
+        });
+        Ok(skills)
+    }
+
     pub fn list_repos() -> Result<Vec<SkillRepo>, AppError> {
         Ok(Self::load_index()?.repos)
     }
@@ -913,6 +973,43 @@ impl SkillService {
         Ok(())
     }
 
+    /// 一次性修改 Skill 在多个应用上的启用状态，只保存一次索引；返回状态实际发生变化的应用
+    pub fn set_apps_enabled(
+        directory_or_id: &str,
+        apps: &[AppType],
+        enabled: bool,
+    ) -> Result<Vec<AppType>, AppError> {
+        let mut index = Self::load_index()?;
+        let Some(dir) = Self::resolve_directory_from_input(&index, directory_or_id) else {
+            return Err(AppError::Message(format!(
+                "未找到已安装的 Skill: {directory_or_id}"
+            )));
+        };
+        let sync_method = index.sync_method;
+        let Some(record) = index.skills.get_mut(&dir) else {
+            return Err(AppError::Message(format!("未找到已安装的 Skill: {dir}")));
+        };
+
+        let mut changed = Vec::new();
+        for app in apps {
+            if changed.contains(app) || record.apps.is_enabled_for(app) == enabled {
+                continue;
+            }
+            record.apps.set_enabled_for(app, enabled);
+            if enabled {
+                Self::sync_to_app_dir(&record.directory, app, sync_method)?;
+            } else {
+                Self::remove_from_app(&record.directory, app)?;
+            }
+            changed.push(app.clone());
+        }
+
+        if !changed.is_empty() {
+            Self::save_index(&index)?;
+        }
+        Ok(changed)
+    }
+
     pub fn uninstall(directory_or_id: &str) -> Result<(), AppError> {
         let index = Self::load_index()?;
         let Some(dir) = Self::resolve_directory_from_input(&index, directory_or_id) else {
@@ -950,11 +1047,21 @@ impl SkillService {
         Ok(())
     }
 
-    pub async fn install(&self, spec: &str, app: &AppType) -> Result<InstalledSkill, AppError> {
+    /// 安装 Skill；`pin` 为提交或标签时按其下载并记录到 `pinned_ref`，否则跟随仓库分支
+    pub async fn install(
+        &self,
+        spec: &str,
+        app: &AppType,
+        pin: Option<&str>,
+    ) -> Result<InstalledSkill, AppError> {
         let spec = spec.trim();
         if spec.is_empty() {
             return Err(AppError::InvalidInput("Skill 不能为空".to_string()));
         }
+        let pin = match pin.map(str::trim) {
+            Some("") => return Err(AppError::InvalidInput("固定版本不能为空".to_string())),
+            other => other,
+        };
 
         let mut index = Self::load_index()?;
         let _ = Self::migrate_ssot_if_pending(&mut index)?;
@@ -998,6 +1105,17 @@ impl SkillService {
 
             // Already installed: just enable current app and sync.
             let mut updated = existing.clone();
+            if let Some(pin) = pin.filter(|pin| existing.pinned_ref.as_deref() != Some(*pin)) {
+                let dest = Self::get_ssot_dir()?.join(&install_name);
+                self.fetch_skill_files(
+                    &Self::skill_repo(&discoverable),
+                    Some(pin),
+                    &install_name,
+                    &dest,
+                )
+                .await?;
+                updated.pinned_ref = Some(pin.to_string());
+            }
             updated.apps.set_enabled_for(app, true);
             index.skills.insert(install_name.clone(), updated.clone());
             Self::save_index(&index)?;
@@ -1008,53 +1126,9 @@ impl SkillService {
         // Ensure SSOT dir and install files.
         let ssot_dir = Self::get_ssot_dir()?;
         let dest = ssot_dir.join(&install_name);
-        if !dest.exists() {
-            let repo = SkillRepo {
-                owner: discoverable.repo_owner.clone(),
-                name: discoverable.repo_name.clone(),
-                branch: discoverable.repo_branch.clone(),
-                enabled: true,
-            };
-
-            let temp_dir = timeout(
-                std::time::Duration::from_secs(60),
-                self.download_repo(&repo),
-            )
-            .await
-            .map_err(|_| {
-                AppError::Message(format_skill_error(
-                    "DOWNLOAD_TIMEOUT",
-                    &[
-                        ("owner", repo.owner.as_str()),
-                        ("name", repo.name.as_str()),
-                        ("timeout", "60"),
-                    ],
-                    Some("checkNetwork"),
-                ))
-            })??;
-
-            let source =
-                Self::find_skill_dir_in_repo(&temp_dir, &install_name)?.ok_or_else(|| {
-                    let _ = fs::remove_dir_all(&temp_dir);
-                    AppError::Message(format_skill_error(
-                        "SKILL_DIR_NOT_FOUND",
-                        &[("directory", install_name.as_str())],
-                        Some("checkRepoUrl"),
-                    ))
-                })?;
-
-            if !source.exists() {
-                let _ = fs::remove_dir_all(&temp_dir);
-                let source_path_string = source.display().to_string();
-                return Err(AppError::Message(format_skill_error(
-                    "SKILL_DIR_NOT_FOUND",
-                    &[("path", source_path_string.as_str())],
-                    Some("checkRepoUrl"),
-                )));
-            }
-
-            Self::copy_dir_recursive(&source, &dest)?;
-            let _ = fs::remove_dir_all(&temp_dir);
+        if pin.is_some() || !dest.exists() {
+            self.fetch_skill_files(&Self::skill_repo(&discoverable), pin, &install_name, &dest)
+                .await?;
         }
 
         let installed = InstalledSkill {
@@ -1070,6 +1144,7 @@ impl SkillService {
             repo_owner: Some(discoverable.repo_owner.clone()),
             repo_name: Some(discoverable.repo_name.clone()),
             repo_branch: Some(discoverable.repo_branch.clone()),
+            pinned_ref: pin.map(str::to_string),
             apps: SkillApps::only(app),
             installed_at: Utc::now().timestamp(),
         };
@@ -1081,6 +1156,148 @@ impl SkillService {
         Ok(installed)
     }
 
+    fn skill_repo(discoverable: &DiscoverableSkill) -> SkillRepo {
+        SkillRepo {
+            owner: discoverable.repo_owner.clone(),
+            name: discoverable.repo_name.clone(),
+            branch: discoverable.repo_branch.clone(),
+            enabled: true,
+        }
+    }
+
+    /// 下载仓库（`pin` 存在时按提交或标签，否则按分支），用其中的 Skill 目录替换 SSOT 副本
+    async fn fetch_skill_files(
+        &self,
+        repo: &SkillRepo,
+        pin: Option<&str>,
+        directory: &str,
+        dest: &Path,
+    ) -> Result<(), AppError> {
+        let download = async {
+            match pin {
+                Some(pin) => self.download_repo_at_ref(repo, pin).await,
+                None => self.download_repo(repo).await,
+            }
+        };
+        let temp_dir = timeout(std::time::Duration::from_secs(60), download)
+            .await
+            .map_err(|_| {
+                AppError::Message(format_skill_error(
+                    "DOWNLOAD_TIMEOUT",
+                    &[
+                        ("owner", repo.owner.as_str()),
+                        ("name", repo.name.as_str()),
+                        ("timeout", "60"),
+                    ],
+                    Some("checkNetwork"),
+                ))
+            })??;
+
+        let source = Self::find_skill_dir_in_repo(&temp_dir, directory)?.ok_or_else(|| {
+            let _ = fs::remove_dir_all(&temp_dir);
+            AppError::Message(format_skill_error(
+                "SKILL_DIR_NOT_FOUND",
+                &[("directory", directory)],
+                Some("checkRepoUrl"),
+            ))
+        })?;
+
+        if !source.exists() {
+            let _ = fs::remove_dir_all(&temp_dir);
+            let source_path_string = source.display().to_string();
+            return Err(AppError::Message(format_skill_error(
+                "SKILL_DIR_NOT_FOUND",
+                &[("path", source_path_string.as_str())],
+                Some("checkRepoUrl"),
+            )));
+        }
+
+        if dest.exists() {
+            fs::remove_dir_all(dest).map_err(|e| AppError::io(dest, e))?;
+        }
+        Self::copy_dir_recursive(&source, dest)?;
+        let _ = fs::remove_dir_all(&temp_dir);
+        Ok(())
+    }
+
+    /// 规划 `skills update`：固定版本的 Skill 仅在 `force` 时更新，本地 Skill 没有来源仓库
+    pub fn plan_update(
+        spec: Option<&str>,
+        force: bool,
+    ) -> Result<Vec<(String, SkillUpdateAction)>, AppError> {
+        let mut index = Self::load_index()?;
+        let _ = Self::migrate_ssot_if_pending(&mut index)?;
+
+        let mut directories: Vec<String> = match spec {
+            Some(spec) => {
+                let Some(dir) = Self::resolve_directory_from_input(&index, spec) else {
+                    return Err(AppError::Message(format!("未找到已安装的 Skill: {spec}")));
+                };
+                vec![dir]
+            }
+            None => index.skills.keys().cloned().collect(),
+        };
+        directories.sort();
+
+        Ok(directories
+            .into_iter()
+            .filter_map(|dir| {
+                let skill = index.skills.get(&dir)?;
+                let action = if skill.repo_owner.is_none() || skill.repo_name.is_none() {
+                    SkillUpdateAction::NoSource
+                } else if let (Some(pin), false) = (skill.pinned_ref.as_ref(), force) {
+                    SkillUpdateAction::Pinned(pin.clone())
+                } else {
+                    SkillUpdateAction::Update
+                };
+                Some((dir, action))
+            })
+            .collect())
+    }
+
+    /// 按分支重新下载 Skill 并同步到已启用的应用；`force` 时固定版本的 Skill 也会更新并解除固定
+    pub async fn update(
+        &self,
+        spec: Option<&str>,
+        force: bool,
+    ) -> Result<Vec<(String, SkillUpdateAction)>, AppError> {
+        let plan = Self::plan_update(spec, force)?;
+        let mut index = Self::load_index()?;
+        let ssot_dir = Self::get_ssot_dir()?;
+
+        for (dir, action) in &plan {
+            if *action != SkillUpdateAction::Update {
+                continue;
+            }
+            let Some(record) = index.skills.get_mut(dir) else {
+                continue;
+            };
+            let repo = SkillRepo {
+                owner: record.repo_owner.clone().unwrap_or_default(),
+                name: record.repo_name.clone().unwrap_or_default(),
+                branch: record.repo_branch.clone().unwrap_or_default(),
+                enabled: true,
+            };
+            self.fetch_skill_files(&repo, None, dir, &ssot_dir.join(dir))
+                .await?;
+            record.pinned_ref = None;
+
+            for app in [
+                AppType::Claude,
+                AppType::Codex,
+                AppType::Gemini,
+                AppType::OpenCode,
+            ] {
+                if record.apps.is_enabled_for(&app) {
+                    Self::sync_to_app_dir(dir, &app, index.sync_method)?;
+                }
+            }
+        }
+
+        Self::save_index(&index)?;
+        Ok(plan)
+    }
+
     async fn resolve_install_spec(
         &self,
         index: &SkillsIndex,
@@ -1232,6 +1449,7 @@ impl SkillService {
                 repo_owner,
                 repo_name,
                 repo_branch,
+                pinned_ref: None,
                 readme_url,
                 apps,
                 installed_at: Utc::now().timestamp(),
@@ -1501,6 +1719,52 @@ impl SkillService {
         Ok(meta)
     }
 
+    /// 检查所有已启用仓库的仓库与分支是否存在（GitHub API，遵循系统代理设置）
+    pub async fn verify_enabled_repos(&self) -> Result<Vec<RepoVerification>, AppError> {
+        let repos = Self::list_repos()?;
+        let checks = repos
+            .into_iter()
+            .filter(|repo| repo.enabled)
+            .map(|repo| self.verify_repo_at(GITHUB_API_BASE, repo));
+        Ok(join_all(checks).await)
+    }
+
+    async fn verify_repo_at(&self, api_base: &str, repo: SkillRepo) -> RepoVerification {
+        // 未指定分支时下载会依次尝试 main / master，这里只检查仓库本身
+        let url = if repo.branch.trim().is_empty() {
+            format!("{api_base}/repos/{}/{}", repo.owner, repo.name)
+        } else {
+            format!(
+                "{api_base}/repos/{}/{}/branches/{}",
+                repo.owner, repo.name, repo.branch
+            )
+        };
+        let (status, detail) = match self
+            .http_client
+            .get(&url)
+            .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+            .send()
+            .await
+        {
+            Ok(response) => {
+                let code = response.status();
+                let status = match code.as_u16() {
+                    _ if code.is_success() => RepoReachability::Reachable,
+                    404 => RepoReachability::NotFound,
+                    401 | 403 => RepoReachability::AuthRequired,
+                    _ => RepoReachability::Unreachable,
+                };
+                (status, (!code.is_success()).then(|| code.to_string()))
+            }
+            Err(e) => (RepoReachability::Unreachable, Some(e.to_string())),
+        };
+        RepoVerification {
+            repo,
+            status,
+            detail,
+        }
+    }
+
     async fn download_repo(&self, repo: &SkillRepo) -> Result<PathBuf, AppError> {
         let temp_dir = tempfile::tempdir().map_err(|e| {
             AppError::localized(
@@ -1543,6 +1807,26 @@ impl SkillService {
         }))
     }
 
+    /// 按提交或标签下载仓库（GitHub 的 archive 地址同时接受两者）
+    async fn download_repo_at_ref(&self, repo: &SkillRepo, pin: &str) -> Result<PathBuf, AppError> {
+        let temp_dir = tempfile::tempdir().map_err(|e| {
+            AppError::localized(
+                "skills.tempdir_failed",
+                format!("创建临时目录失败: {e}"),
+                format!("Failed to create temp dir: {e}"),
+            )
+        })?;
+        let temp_path = temp_dir.path().to_path_buf();
+        let _ = temp_dir.keep();
+
+        let url = format!(
+            "https://github.com/{}/{}/archive/{}.zip",
+            repo.owner, repo.name, pin
+        );
+        self.download_and_extract(&url, &temp_path).await?;
+        Ok(temp_path)
+    }
+
     async fn download_and_extract(&self, url: &str, dest: &Path) -> Result<(), AppError> {
         let response = self.http_client.get(url).send().await.map_err(|e| {
             AppError::localized(
@@ -1715,3 +1999,55 @@ impl SkillService {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+
+    /// 本地模拟 GitHub API：`good` 仓库的请求返回 200，其余返回 404
+    fn serve_github_api(requests: usize) -> String {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        std::thread::spawn(move || {
+            for _ in 0..requests {
+                let Ok((mut stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut buf = [0u8; 4096];
+                let n = stream.read(&mut buf).unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let response: &[u8] = if request.starts_with("GET /repos/acme/good/") {
+                    b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}"
+                } else {
+                    b"HTTP/1.1 404 Not Found\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}"
+                };
+                let _ = stream.write_all(response);
+            }
+        });
+        format!("http://{addr}")
+    }
+
+    fn repo(name: &str) -> SkillRepo {
+        SkillRepo {
+            owner: "acme".to_string(),
+            name: name.to_string(),
+            branch: "main".to_string(),
+            enabled: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn verify_repo_reports_not_found_for_missing_repo() {
+        let base = serve_github_api(2);
+        let service = SkillService::new().expect("service");
+
+        let good = service.verify_repo_at(&base, repo("good")).await;
+        assert_eq!(good.status, RepoReachability::Reachable);
+        assert!(good.detail.is_none());
+
+        let bad = service.verify_repo_at(&base, repo("typo")).await;
+        assert_eq!(bad.status, RepoReachability::NotFound);
+        assert!(bad.detail.as_deref().is_some_and(|d| d.contains("404")));
+    }
+}
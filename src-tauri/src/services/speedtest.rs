@@ -31,7 +31,8 @@ impl SpeedtestService {
             return Ok(vec![]);
         }
 
-        let timeout = Self::sanitize_timeout(timeout_secs);
+        let timeout =
+            timeout_secs.map(|secs| Duration::from_secs(Self::sanitize_timeout(Some(secs))));
         let client = Self::build_client(timeout)?;
 
         let tasks = urls.into_iter().map(|raw_url| {
@@ -95,9 +96,8 @@ impl SpeedtestService {
         Ok(join_all(tasks).await)
     }
 
-    fn build_client(timeout_secs: u64) -> Result<Client, AppError> {
-        Client::builder()
-            .timeout(Duration::from_secs(timeout_secs))
+    fn build_client(timeout: Option<Duration>) -> Result<Client, AppError> {
+        crate::proxy::client_builder(timeout, Duration::from_secs(DEFAULT_TIMEOUT_SECS))
             .redirect(reqwest::redirect::Policy::limited(5))
             .user_agent("cc-switch-speedtest/1.0")
             .build()
@@ -329,6 +329,8 @@ impl StreamCheckService {
         Ok(AuthInfo::new(key, AuthStrategy::Google))
     }
 
+    /// 不设客户端级超时：每个检查请求按流式检查配置的 `timeout_secs` 设置超时，
+    /// 不受全局网络超时影响
     pub(crate) fn build_client_for_provider(provider: &Provider) -> Result<Client, AppError> {
         let mut builder = Client::builder().redirect(reqwest::redirect::Policy::limited(5));
 
@@ -106,8 +106,21 @@ fn redact_url(url: &str) -> String {
 // ---------------------------------------------------------------------------
 
 fn build_client(timeout_secs: u64) -> Result<Client, AppError> {
-    Client::builder()
-        .timeout(Duration::from_secs(timeout_secs.max(1)))
+    finish_client(crate::proxy::client_builder(
+        None,
+        Duration::from_secs(timeout_secs.max(1)),
+    ))
+}
+
+/// 上传下载备份用：总时长固定，全局网络超时只约束建立连接
+fn build_transfer_client() -> Result<Client, AppError> {
+    finish_client(crate::proxy::transfer_client_builder(Duration::from_secs(
+        TRANSFER_TIMEOUT_SECS,
+    )))
+}
+
+fn finish_client(builder: reqwest::ClientBuilder) -> Result<Client, AppError> {
+    builder
         .build()
         .map_err(|e| AppError::Message(format!("创建 WebDAV HTTP 客户端失败: {e}")))
 }
@@ -200,7 +213,7 @@ pub async fn put_bytes(
     content_type: &str,
 ) -> Result<(), AppError> {
     let base_url = url;
-    let client = build_client(TRANSFER_TIMEOUT_SECS)?;
+    let client = build_transfer_client()?;
     let mut req = client
         .put(url)
         .header("Content-Type", content_type)
@@ -228,7 +241,7 @@ pub async fn get_bytes(
     max_bytes: Option<u64>,
 ) -> Result<Option<(Vec<u8>, Option<String>)>, AppError> {
     let base_url = url;
-    let client = build_client(TRANSFER_TIMEOUT_SECS)?;
+    let client = build_transfer_client()?;
     let mut req = client.get(url);
     req = apply_auth(req, auth);
     let resp = req.send().await.map_err(|e| {
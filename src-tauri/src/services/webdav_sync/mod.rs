@@ -137,11 +137,21 @@ impl WebDavSyncService {
     }
 
     pub fn upload() -> Result<WebDavSyncSummary, AppError> {
-        run_http(upload())
+        run_http(upload(None))
     }
 
     pub fn download() -> Result<WebDavSyncSummary, AppError> {
-        run_http(download())
+        run_http(download(None))
+    }
+
+    /// 上传到指定的远端 profile（仅本次生效，不修改已保存的设置与同步状态）
+    pub fn upload_to_profile(profile: &str) -> Result<WebDavSyncSummary, AppError> {
+        run_http(upload(Some(profile)))
+    }
+
+    /// 从指定的远端 profile 下载（仅本次生效，不修改已保存的设置与同步状态）
+    pub fn download_from_profile(profile: &str) -> Result<WebDavSyncSummary, AppError> {
+        run_http(download(Some(profile)))
     }
 
     /// 用户确认后调用：下载 V1 数据 → 应用 → 上传 V2 → 删除 V1
@@ -163,8 +173,8 @@ async fn check_connection() -> Result<(), AppError> {
     Ok(())
 }
 
-async fn upload() -> Result<WebDavSyncSummary, AppError> {
-    let mut settings = load_webdav_settings()?;
+async fn upload(profile: Option<&str>) -> Result<WebDavSyncSummary, AppError> {
+    let mut settings = with_profile_override(load_webdav_settings()?, profile)?;
     let auth = webdav::auth_from_credentials(&settings.username, &settings.password);
 
     let dir_segments = remote_dir_segments(&settings, RemoteLayout::Current);
@@ -198,7 +208,10 @@ async fn upload() -> Result<WebDavSyncSummary, AppError> {
         }
     };
 
-    persist_sync_success_best_effort(&mut settings, &snapshot.manifest_hash, etag);
+    // 同步状态只记录已配置的 profile，避免干扰其自动同步的变更检测
+    if profile.is_none() {
+        persist_sync_success_best_effort(&mut settings, &snapshot.manifest_hash, etag);
+    }
 
     // 上传成功后，静默清理 V1 远端数据
     cleanup_v1_remote(&settings, &auth).await;
@@ -209,8 +222,8 @@ async fn upload() -> Result<WebDavSyncSummary, AppError> {
     })
 }
 
-async fn download() -> Result<WebDavSyncSummary, AppError> {
-    let mut settings = load_webdav_settings()?;
+async fn download(profile: Option<&str>) -> Result<WebDavSyncSummary, AppError> {
+    let mut settings = with_profile_override(load_webdav_settings()?, profile)?;
     let auth = webdav::auth_from_credentials(&settings.username, &settings.password);
 
     if let Some(snapshot) = find_remote_snapshot(&settings, &auth).await? {
@@ -234,8 +247,10 @@ async fn download() -> Result<WebDavSyncSummary, AppError> {
         )
         .await?;
 
-        apply_snapshot(&db_sql, &skills_zip)?;
-        persist_sync_success_best_effort(&mut settings, &manifest_hash, snapshot.manifest_etag);
+        apply_snapshot(&db_sql, &skills_zip, settings.exclude_history)?;
+        if profile.is_none() {
+            persist_sync_success_best_effort(&mut settings, &manifest_hash, snapshot.manifest_etag);
+        }
         cleanup_v1_remote(&settings, &auth).await;
 
         Ok(WebDavSyncSummary {
@@ -279,6 +294,25 @@ fn load_webdav_settings() -> Result<WebDavSyncSettings, AppError> {
     Ok(settings)
 }
 
+/// 用本次指定的远端 profile 替换已配置的 profile
+fn with_profile_override(
+    mut settings: WebDavSyncSettings,
+    profile: Option<&str>,
+) -> Result<WebDavSyncSettings, AppError> {
+    if let Some(profile) = profile {
+        settings.profile = profile.to_string();
+        settings.normalize();
+        if settings.profile.is_empty() {
+            return Err(localized(
+                "webdav.sync.profile_empty",
+                "profile 名称不能为空",
+                "Profile name must not be empty",
+            ));
+        }
+    }
+    Ok(settings)
+}
+
 // ---------------------------------------------------------------------------
 // 远端路径
 // ---------------------------------------------------------------------------
@@ -308,7 +342,7 @@ fn build_artifact_url(
 // 本地快照构建
 // ---------------------------------------------------------------------------
 
-fn build_local_snapshot(_settings: &WebDavSyncSettings) -> Result<LocalSnapshot, AppError> {
+fn build_local_snapshot(settings: &WebDavSyncSettings) -> Result<LocalSnapshot, AppError> {
     let tmp = tempdir().map_err(|e| {
         io_context_localized(
             "webdav.sync.snapshot_tmpdir_failed",
@@ -319,7 +353,9 @@ fn build_local_snapshot(_settings: &WebDavSyncSettings) -> Result<LocalSnapshot,
     })?;
 
     // 导出 DB
-    let db_sql = Database::init()?.export_sql_string_for_sync()?.into_bytes();
+    let db_sql = Database::init()?
+        .export_sql_string_for_sync_with(settings.exclude_history)?
+        .into_bytes();
 
     // 打包 skills
     let skills_zip_path = tmp.path().join(REMOTE_SKILLS_ZIP);
@@ -565,7 +601,11 @@ fn validate_artifact_size_limit(name: &str, size: u64) -> Result<(), AppError> {
 // 快照应用（带 skills 备份回滚）
 // ---------------------------------------------------------------------------
 
-fn apply_snapshot(db_sql: &[u8], skills_zip: &[u8]) -> Result<(), AppError> {
+fn apply_snapshot(
+    db_sql: &[u8],
+    skills_zip: &[u8],
+    keep_local_history: bool,
+) -> Result<(), AppError> {
     let sql_str = std::str::from_utf8(db_sql).map_err(|e| {
         localized(
             "webdav.sync.sql_not_utf8",
@@ -579,7 +619,7 @@ fn apply_snapshot(db_sql: &[u8], skills_zip: &[u8]) -> Result<(), AppError> {
     // 先替换 skills，再导入数据库；若导入失败则回滚 skills，避免"半恢复"。
     restore_skills_zip(skills_zip)?;
 
-    if let Err(db_err) = Database::init()?.import_sql_string_for_sync(sql_str) {
+    if let Err(db_err) = Database::init()?.import_sql_string_for_sync(sql_str, keep_local_history) {
         if let Err(rollback_err) = skills_backup.restore() {
             return Err(localized(
                 "webdav.sync.db_import_and_rollback_failed",
@@ -894,10 +934,10 @@ async fn migrate_v1_to_v2() -> Result<WebDavSyncSummary, AppError> {
     .await?;
 
     // 3. 应用到本地
-    apply_snapshot(&db_sql, &skills_zip)?;
+    apply_snapshot(&db_sql, &skills_zip, settings.exclude_history)?;
 
     // 4. 重新上传为 V2 格式（upload 内部会 best-effort 清理 V1 远端数据）
-    upload().await?;
+    upload(None).await?;
 
     Ok(WebDavSyncSummary {
         decision: SyncDecision::Download,
@@ -922,6 +962,7 @@ mod tests {
             username: "demo".to_string(),
             password: "secret".to_string(),
             auto_sync: false,
+            exclude_history: false,
             status: WebDavSyncStatus::default(),
         }
     }
@@ -959,6 +1000,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn profile_override_targets_named_profile_directory() {
+        let mut settings = sample_settings();
+        settings.normalize();
+
+        let work = with_profile_override(settings.clone(), Some(" work ")).expect("override");
+        let segments = remote_dir_segments(&work, RemoteLayout::Current);
+        assert_eq!(segments.last().map(String::as_str), Some("work"));
+        let url = build_artifact_url(&work, RemoteLayout::Current, REMOTE_DB_SQL)
+            .expect("build artifact url");
+        assert!(url.ends_with("/work/db.sql"), "unexpected url: {url}");
+
+        let default = with_profile_override(settings, None).expect("no override");
+        assert_eq!(default.profile, "default profile");
+        assert!(with_profile_override(default, Some("  ")).is_err());
+    }
+
     #[test]
     fn snapshot_id_is_stable() {
         let mut artifacts = BTreeMap::new();
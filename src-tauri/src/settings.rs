@@ -65,6 +65,9 @@ pub struct WebDavSyncSettings {
     pub password: String,
     #[serde(default)]
     pub auto_sync: bool,
+    /// 不同步切换历史（供应商 `lastUsedAt`）：上传时剔除，下载时保留本机记录
+    #[serde(default)]
+    pub exclude_history: bool,
     #[serde(default)]
     pub status: WebDavSyncStatus,
 }
@@ -89,6 +92,7 @@ impl Default for WebDavSyncSettings {
             username: String::new(),
             password: String::new(),
             auto_sync: false,
+            exclude_history: false,
             status: WebDavSyncStatus::default(),
         }
     }
@@ -157,6 +161,106 @@ fn sanitize_path_segment(raw: &str) -> String {
         .join("/")
 }
 
+/// 切换供应商前后执行的 shell 命令
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchHookCommands {
+    /// 切换前执行；失败（非零退出）时中止切换
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pre_switch: Option<String>,
+    /// 切换成功后执行；失败只告警，不回滚
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_switch: Option<String>,
+}
+
+/// 切换钩子：全局命令 + 按应用覆盖（键为 claude/codex/gemini/opencode）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchHookSettings {
+    #[serde(flatten)]
+    pub global: SwitchHookCommands,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub apps: HashMap<String, SwitchHookCommands>,
+}
+
+impl SwitchHookSettings {
+    /// 指定应用实际生效的钩子：应用级配置优先，未配置的钩子回退到全局
+    pub fn resolve(&self, app: &crate::app_config::AppType) -> SwitchHookCommands {
+        let app_hooks = self.apps.get(app.as_str());
+        let pick = |select: fn(&SwitchHookCommands) -> &Option<String>| {
+            app_hooks
+                .and_then(|hooks| select(hooks).clone())
+                .or_else(|| select(&self.global).clone())
+                .filter(|command| !command.trim().is_empty())
+        };
+        SwitchHookCommands {
+            pre_switch: pick(|hooks| &hooks.pre_switch),
+            post_switch: pick(|hooks| &hooks.post_switch),
+        }
+    }
+}
+
+/// `provider switch --set` 写入 live 的临时覆盖：下次切走时按存储值还原这些路径，
+/// 避免回填把覆盖值写进供应商
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveOverlay {
+    pub provider_id: String,
+    /// 被覆盖的配置路径（JSON Pointer，如 `/env/ANTHROPIC_MODEL`）
+    pub paths: Vec<String>,
+}
+
+/// 自动备份：任意命令运行时若距上次自动备份已超过间隔，则创建一次备份
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoBackupSettings {
+    /// 备份间隔（小时）
+    pub interval_hours: u64,
+    /// 上次自动备份的时间（Unix 秒）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_backup_at: Option<i64>,
+}
+
+impl AutoBackupSettings {
+    /// 在 `now`（Unix 秒）时是否应执行自动备份
+    pub fn is_due(&self, now: i64) -> bool {
+        match self.last_backup_at {
+            Some(last) => now.saturating_sub(last) >= (self.interval_hours as i64) * 3600,
+            None => true,
+        }
+    }
+}
+
+/// MCP 同步策略：目标应用顺序与失败处理
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpSyncSettings {
+    /// 同步顺序；未列出的应用按默认顺序追加在后面
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub order: Vec<crate::app_config::AppType>,
+    /// 某个应用同步失败后是否继续同步其余应用（默认遇错即停）
+    #[serde(default)]
+    pub continue_on_error: bool,
+}
+
+impl McpSyncSettings {
+    /// 解析后的完整同步顺序（去重，并补齐未列出的应用）
+    pub fn resolved_order(&self) -> Vec<crate::app_config::AppType> {
+        let mut order: Vec<crate::app_config::AppType> = Vec::new();
+        for app in self
+            .order
+            .iter()
+            .cloned()
+            .chain(crate::app_config::AppType::all())
+        {
+            if !order.contains(&app) {
+                order.push(app);
+            }
+        }
+        order
+    }
+}
+
 /// 应用设置结构，允许覆盖默认配置目录
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -171,6 +275,12 @@ pub struct AppSettings {
     /// 是否跳过 Claude Code 初次安装确认
     #[serde(default)]
     pub skip_claude_onboarding: bool,
+    /// TUI 卸载 Skill 时是否跳过确认
+    #[serde(default)]
+    pub skip_skill_uninstall_confirm: bool,
+    /// 切换 Codex 时不再从 live config.toml 自动提取通用配置片段
+    #[serde(default)]
+    pub no_backfill_extract: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub claude_config_dir: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -191,6 +301,42 @@ pub struct AppSettings {
     pub security: Option<SecuritySettings>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub webdav_sync: Option<WebDavSyncSettings>,
+    /// MCP 同步策略
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mcp_sync: Option<McpSyncSettings>,
+    /// 供应商切换前后执行的命令
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub switch_hooks: Option<SwitchHookSettings>,
+    /// 定时自动备份（默认关闭）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_backup: Option<AutoBackupSettings>,
+    /// 各应用当前生效的 live 临时覆盖（键为应用名）
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub live_overlays: HashMap<String, LiveOverlay>,
+    /// env-file 模式下写入 `~/.codex/.env` 的变量名；切到其它模式时据此移除该变量
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub codex_env_key: Option<String>,
+    /// 备份目录总大小上限（字节）；超出时创建备份会删除最旧的备份（默认不限制）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_max_bytes: Option<u64>,
+    /// 网络操作（测速、更新检查、技能、用量脚本、WebDAV 等）的默认超时（毫秒）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_timeout_ms: Option<u64>,
+    /// 终端中显示密钥的遮罩样式（hidden|last4|first2last2）
+    #[serde(default)]
+    pub mask_style: crate::redact::MaskStyle,
+    /// 额外视为密钥的键名模式（glob，或 `re:` 前缀的正则），补充内置脱敏规则
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redaction_patterns: Vec<String>,
+    /// TUI 显示明文密钥后自动重新遮罩的秒数（0 表示直到手动关闭）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_reveal_timeout_secs: Option<u64>,
+    /// 新增供应商时预填的备注模板（如 `owner: , tier: `），为空时不预填
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider_notes_template: Option<String>,
+    /// 新增供应商时预填的默认模型（按应用，键为 claude/codex/gemini），为空时不预填
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub default_models: HashMap<String, String>,
     /// Claude 自定义端点列表
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub custom_endpoints_claude: HashMap<String, CustomEndpoint>,
@@ -214,6 +360,8 @@ impl Default for AppSettings {
             minimize_to_tray_on_close: true,
             enable_claude_plugin_integration: false,
             skip_claude_onboarding: false,
+            skip_skill_uninstall_confirm: false,
+            no_backfill_extract: false,
             claude_config_dir: None,
             codex_config_dir: None,
             gemini_config_dir: None,
@@ -223,6 +371,18 @@ impl Default for AppSettings {
             skill_sync_method: crate::services::skill::SyncMethod::default(),
             security: None,
             webdav_sync: None,
+            mcp_sync: None,
+            switch_hooks: None,
+            auto_backup: None,
+            live_overlays: HashMap::new(),
+            codex_env_key: None,
+            backup_max_bytes: None,
+            network_timeout_ms: None,
+            mask_style: crate::redact::MaskStyle::default(),
+            redaction_patterns: Vec::new(),
+            secret_reveal_timeout_secs: None,
+            provider_notes_template: None,
+            default_models: HashMap::new(),
             custom_endpoints_claude: HashMap::new(),
             custom_endpoints_codex: HashMap::new(),
         }
@@ -440,6 +600,213 @@ pub fn set_webdav_sync_settings(webdav_sync: Option<WebDavSyncSettings>) -> Resu
     update_settings(settings)
 }
 
+pub fn get_mcp_sync_settings() -> McpSyncSettings {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.mcp_sync.clone())
+        .unwrap_or_default()
+}
+
+pub fn set_mcp_sync_settings(mcp_sync: McpSyncSettings) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.mcp_sync = (mcp_sync != McpSyncSettings::default()).then_some(mcp_sync);
+    update_settings(settings)
+}
+
+pub fn get_auto_backup_settings() -> Option<AutoBackupSettings> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.auto_backup.clone())
+}
+
+/// 设置自动备份间隔（小时）；`0` 表示关闭。修改间隔时保留上次备份时间
+pub fn set_auto_backup_interval_hours(hours: u64) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.auto_backup = (hours > 0).then(|| AutoBackupSettings {
+        interval_hours: hours,
+        last_backup_at: settings
+            .auto_backup
+            .as_ref()
+            .and_then(|auto| auto.last_backup_at),
+    });
+    update_settings(settings)
+}
+
+pub fn get_backup_max_bytes() -> Option<u64> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.backup_max_bytes)
+}
+
+/// 设置备份目录总大小上限（字节）；`0` 表示不限制
+pub fn set_backup_max_bytes(bytes: u64) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.backup_max_bytes = (bytes > 0).then_some(bytes);
+    update_settings(settings)
+}
+
+pub fn get_network_timeout_ms() -> Option<u64> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.network_timeout_ms)
+}
+
+/// 设置网络操作的默认超时（毫秒）；`None` 恢复各操作的内置默认值
+pub fn set_network_timeout_ms(ms: Option<u64>) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.network_timeout_ms = ms.filter(|ms| *ms > 0);
+    update_settings(settings)
+}
+
+pub fn get_live_overlay(app: &crate::app_config::AppType) -> Option<LiveOverlay> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.live_overlays.get(app.as_str()).cloned())
+}
+
+/// 记录或清除应用的 live 临时覆盖；未变化时不写入设置文件
+pub fn set_live_overlay(
+    app: &crate::app_config::AppType,
+    overlay: Option<LiveOverlay>,
+) -> Result<(), AppError> {
+    if get_live_overlay(app) == overlay {
+        return Ok(());
+    }
+    let mut settings = get_settings();
+    match overlay {
+        Some(overlay) => {
+            settings
+                .live_overlays
+                .insert(app.as_str().to_string(), overlay);
+        }
+        None => {
+            settings.live_overlays.remove(app.as_str());
+        }
+    }
+    update_settings(settings)
+}
+
+pub fn get_codex_env_key() -> Option<String> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.codex_env_key.clone())
+}
+
+/// 记录（或清除）cc-switch 写入 `~/.codex/.env` 的变量名；未变化时不写入设置文件
+pub fn set_codex_env_key(name: Option<String>) -> Result<(), AppError> {
+    if get_codex_env_key() == name {
+        return Ok(());
+    }
+    let mut settings = get_settings();
+    settings.codex_env_key = name;
+    update_settings(settings)
+}
+
+/// 记录一次自动备份的时间（Unix 秒）；未开启自动备份时忽略
+pub fn set_last_auto_backup_at(timestamp: i64) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    let Some(auto) = settings.auto_backup.as_mut() else {
+        return Ok(());
+    };
+    auto.last_backup_at = Some(timestamp);
+    update_settings(settings)
+}
+
+pub fn get_switch_hooks(app: &crate::app_config::AppType) -> SwitchHookCommands {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.switch_hooks.as_ref().map(|hooks| hooks.resolve(app)))
+        .unwrap_or_default()
+}
+
+/// 完整的切换钩子配置（全局 + 按应用覆盖）；未配置时为 None
+pub fn get_switch_hook_settings() -> Option<SwitchHookSettings> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.switch_hooks.clone())
+}
+
+/// 替换全部切换钩子配置；空配置视为清除
+pub fn set_switch_hook_settings(hooks: Option<SwitchHookSettings>) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.switch_hooks = hooks.filter(|hooks| *hooks != SwitchHookSettings::default());
+    update_settings(settings)
+}
+
+/// 设置全局（`app` 为 None）或某个应用的切换钩子；传入空字符串表示移除该钩子
+pub fn set_switch_hook(
+    app: Option<&crate::app_config::AppType>,
+    pre_switch: Option<String>,
+    post_switch: Option<String>,
+) -> Result<(), AppError> {
+    let mut hooks = get_switch_hook_settings().unwrap_or_default();
+    let target = match app {
+        Some(app) => hooks.apps.entry(app.as_str().to_string()).or_default(),
+        None => &mut hooks.global,
+    };
+    let normalize = |command: String| Some(command.trim().to_string()).filter(|c| !c.is_empty());
+    if let Some(command) = pre_switch {
+        target.pre_switch = normalize(command);
+    }
+    if let Some(command) = post_switch {
+        target.post_switch = normalize(command);
+    }
+    hooks
+        .apps
+        .retain(|_, commands| *commands != SwitchHookCommands::default());
+    set_switch_hook_settings(Some(hooks))
+}
+
+pub fn get_mask_style() -> crate::redact::MaskStyle {
+    settings_store()
+        .read()
+        .map(|s| s.mask_style)
+        .unwrap_or_default()
+}
+
+pub fn set_mask_style(style: crate::redact::MaskStyle) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.mask_style = style;
+    update_settings(settings)
+}
+
+pub fn get_redaction_patterns() -> Vec<String> {
+    settings_store()
+        .read()
+        .map(|s| s.redaction_patterns.clone())
+        .unwrap_or_default()
+}
+
+/// 保存自定义脱敏模式；任一模式无效时报错且不写入
+pub fn set_redaction_patterns(patterns: Vec<String>) -> Result<(), AppError> {
+    crate::redact::compile_redaction_patterns(&patterns)?;
+    let mut settings = get_settings();
+    settings.redaction_patterns = patterns;
+    update_settings(settings)
+}
+
+pub fn get_provider_notes_template() -> Option<String> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.provider_notes_template.clone())
+}
+
+/// 保存备注模板；传入 `None` 或空白文本时清除
+pub fn set_provider_notes_template(template: Option<String>) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.provider_notes_template = template.filter(|text| !text.trim().is_empty());
+    update_settings(settings)
+}
+
 pub fn update_webdav_sync_status(status: WebDavSyncStatus) -> Result<(), AppError> {
     let mut settings = get_settings();
     if let Some(ref mut webdav) = settings.webdav_sync {
@@ -452,6 +819,53 @@ pub fn webdav_jianguoyun_preset(username: &str, password: &str) -> WebDavSyncSet
     WebDavSyncSettings::jianguoyun_preset(username, password)
 }
 
+/// TUI 显示明文密钥的默认时长（秒）
+pub const DEFAULT_SECRET_REVEAL_TIMEOUT_SECS: u64 = 10;
+
+pub fn get_default_model(app: &crate::app_config::AppType) -> Option<String> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.default_models.get(app.as_str()).cloned())
+}
+
+/// 设置某个应用的默认模型；传入空白或 `None` 即清除
+pub fn set_default_model(
+    app: &crate::app_config::AppType,
+    model: Option<String>,
+) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    match model
+        .map(|model| model.trim().to_string())
+        .filter(|model| !model.is_empty())
+    {
+        Some(model) => {
+            settings
+                .default_models
+                .insert(app.as_str().to_string(), model);
+        }
+        None => {
+            settings.default_models.remove(app.as_str());
+        }
+    }
+    update_settings(settings)
+}
+
+pub fn get_secret_reveal_timeout_secs() -> u64 {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.secret_reveal_timeout_secs)
+        .unwrap_or(DEFAULT_SECRET_REVEAL_TIMEOUT_SECS)
+}
+
+/// None 恢复默认时长；0 表示直到手动隐藏
+pub fn set_secret_reveal_timeout_secs(secs: Option<u64>) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.secret_reveal_timeout_secs = secs;
+    update_settings(settings)
+}
+
 pub fn get_skip_claude_onboarding() -> bool {
     settings_store()
         .read()
@@ -459,6 +873,32 @@ pub fn get_skip_claude_onboarding() -> bool {
         .unwrap_or(false)
 }
 
+pub fn get_skip_skill_uninstall_confirm() -> bool {
+    settings_store()
+        .read()
+        .map(|s| s.skip_skill_uninstall_confirm)
+        .unwrap_or(false)
+}
+
+pub fn set_skip_skill_uninstall_confirm(enabled: bool) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.skip_skill_uninstall_confirm = enabled;
+    update_settings(settings)
+}
+
+pub fn get_no_backfill_extract() -> bool {
+    settings_store()
+        .read()
+        .map(|s| s.no_backfill_extract)
+        .unwrap_or(false)
+}
+
+pub fn set_no_backfill_extract(enabled: bool) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.no_backfill_extract = enabled;
+    update_settings(settings)
+}
+
 pub fn get_enable_claude_plugin_integration() -> bool {
     settings_store()
         .read()
@@ -274,6 +274,7 @@ fn load_skills_index_for_migration(
             repo_owner: None,
             repo_name: None,
             repo_branch: None,
+            pinned_ref: None,
             apps: SkillApps::only(&crate::app_config::AppType::Claude),
             installed_at,
         };
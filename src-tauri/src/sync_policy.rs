@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use crate::app_config::AppType;
 
 /// Whether we should write/delete "live" config files for a given app.
@@ -11,14 +13,21 @@ pub(crate) fn should_sync_live(app_type: &AppType) -> bool {
         // - ~/.claude (settings dir) exists, or
         // - ~/.claude.json (MCP file) exists
         AppType::Claude => {
-            crate::config::get_claude_config_dir().exists()
-                || crate::config::get_claude_mcp_path().exists()
+            live_config_dir(app_type).exists() || crate::config::get_claude_mcp_path().exists()
         }
         // Codex is considered initialized if ~/.codex (or override dir) exists.
-        AppType::Codex => crate::codex_config::get_codex_config_dir().exists(),
         // Gemini is considered initialized if ~/.gemini (or override dir) exists.
-        AppType::Gemini => crate::gemini_config::get_gemini_dir().exists(),
         // OpenCode is considered initialized if ~/.config/opencode (or override dir) exists.
-        AppType::OpenCode => crate::opencode_config::get_opencode_dir().exists(),
+        AppType::Codex | AppType::Gemini | AppType::OpenCode => live_config_dir(app_type).exists(),
+    }
+}
+
+/// The directory whose presence marks an app as initialized.
+pub(crate) fn live_config_dir(app_type: &AppType) -> PathBuf {
+    match app_type {
+        AppType::Claude => crate::config::get_claude_config_dir(),
+        AppType::Codex => crate::codex_config::get_codex_config_dir(),
+        AppType::Gemini => crate::gemini_config::get_gemini_dir(),
+        AppType::OpenCode => crate::opencode_config::get_opencode_dir(),
     }
 }
@@ -1,4 +1,3 @@
-use reqwest::Client;
 use rquickjs::{Context, Function, Runtime};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -11,7 +10,7 @@ pub async fn execute_usage_script(
     script_code: &str,
     api_key: &str,
     base_url: &str,
-    timeout_secs: u64,
+    timeout: Duration,
     access_token: Option<&str>,
     user_id: Option<&str>,
 ) -> Result<Value, AppError> {
@@ -104,7 +103,7 @@ pub async fn execute_usage_script(
     })?;
 
     // 4. 发送 HTTP 请求
-    let response_data = send_http_request(&request, timeout_secs).await?;
+    let response_data = send_http_request(&request, timeout).await?;
 
     // 5. 在独立作用域中执行 extractor（确保 Runtime/Context 在函数结束前释放）
     let result: Value = {
@@ -216,19 +215,9 @@ struct RequestConfig {
 }
 
 /// 发送 HTTP 请求
-async fn send_http_request(config: &RequestConfig, timeout_secs: u64) -> Result<String, AppError> {
-    // 约束超时范围，防止异常配置导致长时间阻塞
-    let timeout = timeout_secs.clamp(2, 30);
-    let client = Client::builder()
-        .timeout(Duration::from_secs(timeout))
-        .build()
-        .map_err(|e| {
-            AppError::localized(
-                "usage_script.client_create_failed",
-                format!("创建客户端失败: {e}"),
-                format!("Failed to create client: {e}"),
-            )
-        })?;
+async fn send_http_request(config: &RequestConfig, timeout: Duration) -> Result<String, AppError> {
+    // 超时已由调用方解析（脚本配置 > 全局网络超时 > 默认值）
+    let client = crate::proxy::build_client(Some(timeout), timeout)?;
 
     // 严格校验 HTTP 方法，非法值不回退为 GET
     let method: reqwest::Method = config.method.parse().map_err(|_| {
@@ -1,5 +1,8 @@
 use base64::prelude::*;
-use cc_switch_lib::{import_provider_from_deeplink, parse_deeplink_url, AppType, MultiAppConfig};
+use cc_switch_lib::{
+    import_provider_from_deeplink, parse_deeplink_url, prepare_provider_from_deeplink, AppType,
+    MultiAppConfig,
+};
 
 #[path = "support.rs"]
 mod support;
@@ -153,3 +156,21 @@ fn deeplink_import_rejects_non_http_endpoints_from_config() {
         "expected scheme validation error, got {err:?}"
     );
 }
+
+#[test]
+fn deeplink_prepare_provider_builds_without_saving() {
+    let url = "ccswitch://v1/import?resource=provider&app=codex&name=Shared&endpoint=https%3A%2F%2Fapi.example.com%2Fv1&apiKey=sk-shared&enabled=true";
+    let request = parse_deeplink_url(url).expect("parse deeplink url");
+
+    let prepared = prepare_provider_from_deeplink(&request).expect("prepare provider");
+    assert_eq!(prepared.app_type, AppType::Codex);
+    assert_eq!(prepared.provider.name, "Shared");
+    assert!(prepared.provider.id.starts_with("shared-"));
+    assert!(prepared.switch_after_import);
+
+    let missing_key = parse_deeplink_url(
+        "ccswitch://v1/import?resource=provider&app=claude&name=X&endpoint=https%3A%2F%2Fapi.example.com",
+    )
+    .expect("parse deeplink url");
+    assert!(prepare_provider_from_deeplink(&missing_key).is_err());
+}
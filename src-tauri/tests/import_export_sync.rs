@@ -3,8 +3,10 @@ use serde_json::json;
 use std::{fs, path::Path};
 
 use cc_switch_lib::{
-    get_claude_settings_path, read_json_file, AppError, AppType, ConfigService, Database,
-    MultiAppConfig, Provider, ProviderMeta,
+    get_auto_backup_settings, get_claude_settings_path, read_json_file,
+    set_auto_backup_interval_hours, set_backup_max_bytes, AppError, AppState, AppType,
+    BundleProviderStatus, BundleSelection, ChangeCounts, ConfigService, Database, McpApps,
+    McpServer, MultiAppConfig, PromptService, Provider, ProviderMeta, ProviderService,
 };
 
 #[path = "support.rs"]
@@ -797,6 +799,89 @@ fn create_backup_generates_snapshot_file() {
     );
 }
 
+#[test]
+fn auto_backup_runs_only_when_enabled_and_interval_elapsed() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    let state = state_from_config(MultiAppConfig::default());
+    state.save().expect("persist db");
+
+    let now = 1_700_000_000;
+    assert!(
+        ConfigService::auto_backup_if_due(now)
+            .expect("auto backup off")
+            .is_none(),
+        "auto backup should be off by default"
+    );
+
+    set_auto_backup_interval_hours(6).expect("enable auto backup");
+    let backup_id = ConfigService::auto_backup_if_due(now)
+        .expect("first auto backup")
+        .expect("first run should back up");
+    assert!(backup_id.starts_with("auto_"));
+    assert!(home
+        .join(".cc-switch")
+        .join("backups")
+        .join(format!("{backup_id}.sql"))
+        .exists());
+    assert_eq!(
+        get_auto_backup_settings().and_then(|auto| auto.last_backup_at),
+        Some(now)
+    );
+
+    assert!(ConfigService::auto_backup_if_due(now + 3600)
+        .expect("check within interval")
+        .is_none());
+    assert!(ConfigService::auto_backup_if_due(now + 6 * 3600)
+        .expect("check after interval")
+        .is_some());
+
+    set_auto_backup_interval_hours(0).expect("disable auto backup");
+    assert!(get_auto_backup_settings().is_none());
+}
+
+#[test]
+fn backup_notes_are_listed_and_searchable() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    let db_path = home.join(".cc-switch").join("cc-switch.db");
+    let state = state_from_config(MultiAppConfig::default());
+    state.save().expect("persist db");
+
+    let codex_id = ConfigService::create_backup_with_note(
+        &db_path,
+        Some("first".to_string()),
+        Some("Before trying new Codex base URL"),
+    )
+    .expect("backup with note");
+    let plain_id =
+        ConfigService::create_backup_with_note(&db_path, Some("second".to_string()), None)
+            .expect("backup without note");
+
+    let backups = ConfigService::list_backups(&db_path).expect("list backups");
+    assert_eq!(backups.len(), 2);
+    let noted = backups
+        .iter()
+        .find(|backup| backup.id == codex_id)
+        .expect("noted backup listed");
+    assert_eq!(
+        noted.note.as_deref(),
+        Some("Before trying new Codex base URL")
+    );
+
+    let matches: Vec<&str> = backups
+        .iter()
+        .filter(|backup| backup.matches("codex base"))
+        .map(|backup| backup.id.as_str())
+        .collect();
+    assert_eq!(matches, vec![codex_id.as_str()]);
+    assert!(backups
+        .iter()
+        .any(|backup| backup.id == plain_id && backup.matches("SECOND")));
+}
+
 #[test]
 fn create_backup_retains_only_latest_entries() {
     let _guard = lock_test_mutex();
@@ -853,6 +938,57 @@ fn create_backup_retains_only_latest_entries() {
     );
 }
 
+#[test]
+fn create_backup_prunes_oldest_backups_over_size_limit() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    let db_path = home.join(".cc-switch").join("cc-switch.db");
+
+    let state = state_from_config(MultiAppConfig::default());
+    state.save().expect("persist db");
+
+    // 先测出一次备份的大小，再据此设置刚好容纳两个旧备份的上限
+    let probe_id =
+        ConfigService::create_backup(&db_path, Some("probe".to_string())).expect("probe backup");
+    let backups_dir = home.join(".cc-switch").join("backups");
+    let probe_path = backups_dir.join(format!("{probe_id}.sql"));
+    let backup_size = fs::metadata(&probe_path).expect("probe metadata").len();
+    fs::remove_file(&probe_path).expect("remove probe");
+
+    for idx in 0..4 {
+        let manual = backups_dir.join(format!("manual_{idx:02}.sql"));
+        fs::write(&manual, "x".repeat(1000)).expect("seed manual backup");
+    }
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    set_backup_max_bytes(backup_size + 2500).expect("set size limit");
+    let (backup_id, prune) =
+        ConfigService::create_backup_with_report(&db_path, Some("limited".to_string()), None)
+            .expect("create backup with size limit");
+
+    assert_eq!(prune.pruned_files, 2);
+    assert_eq!(prune.pruned_bytes, 2000);
+    assert!(!prune.oversized);
+    assert!(backups_dir.join(format!("{backup_id}.sql")).exists());
+    let remaining_manual = fs::read_dir(&backups_dir)
+        .expect("read backups dir")
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("manual_"))
+        .count();
+    assert_eq!(remaining_manual, 2);
+
+    // 单个备份已超过上限时只警告，不删除其他备份
+    set_backup_max_bytes(1).expect("set tiny limit");
+    let (big_id, prune) =
+        ConfigService::create_backup_with_report(&db_path, Some("big".to_string()), None)
+            .expect("create oversized backup");
+    assert!(prune.oversized);
+    assert_eq!(prune.pruned_files, 0);
+    assert!(backups_dir.join(format!("{big_id}.sql")).exists());
+    assert!(backups_dir.join(format!("{backup_id}.sql")).exists());
+}
+
 #[test]
 fn import_config_from_path_overwrites_state_and_creates_backup() {
     let _guard = lock_test_mutex();
@@ -930,6 +1066,171 @@ fn import_config_from_path_overwrites_state_and_creates_backup() {
     );
 }
 
+#[test]
+fn import_change_report_counts_added_updated_and_unchanged_providers() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let claude = |id: &str, key: &str| {
+        Provider::with_id(
+            id.to_string(),
+            format!("Provider {id}"),
+            json!({ "env": { "ANTHROPIC_AUTH_TOKEN": key } }),
+            None,
+        )
+    };
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "keep".to_string();
+        for provider in [claude("keep", "k1"), claude("change", "old-key")] {
+            manager.providers.insert(provider.id.clone(), provider);
+        }
+    }
+    state_from_config(config)
+        .save()
+        .expect("persist initial db");
+    let state = AppState::try_new().expect("load state");
+    let before = state.config.read().expect("read config").clone();
+
+    let import_path = home.join(".cc-switch").join("import-report.sql");
+    let import_db = Database::memory().expect("create import db");
+    for provider in [
+        claude("keep", "k1"),
+        claude("change", "new-key"),
+        claude("added", "k3"),
+    ] {
+        import_db
+            .save_provider(AppType::Claude.as_str(), &provider)
+            .expect("seed provider");
+    }
+    import_db
+        .set_current_provider(AppType::Claude.as_str(), "keep")
+        .expect("seed current");
+    import_db
+        .export_sql(&import_path)
+        .expect("export import sql");
+
+    ConfigService::import_config_from_path(&import_path, &state).expect("import");
+    let imported = AppState::try_new().expect("reload state");
+    let after = imported.config.read().expect("read config");
+    let report = ConfigService::import_change_report(&before, &after);
+
+    let claude_counts = report
+        .providers
+        .iter()
+        .find(|(app, _)| *app == AppType::Claude)
+        .map(|(_, counts)| *counts)
+        .expect("claude counts");
+    assert_eq!(
+        claude_counts,
+        ChangeCounts {
+            added: 1,
+            updated: 1,
+            unchanged: 1,
+            removed: 0,
+        }
+    );
+    assert!(report
+        .providers
+        .iter()
+        .filter(|(app, _)| *app != AppType::Claude)
+        .all(|(_, counts)| counts.is_empty()));
+    assert!(report.mcp_servers.is_empty());
+}
+
+#[test]
+fn codex_providers_round_trip_through_toml_export_and_import() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let relay_config = r#"model_provider = "relay"
+model = "gpt-5.2-codex"
+
+[model_providers.relay]
+name = "relay"
+base_url = "https://relay.example.com/v1"
+wire_api = "responses"
+requires_openai_auth = true
+"#;
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        let mut relay = Provider::with_id(
+            "relay".to_string(),
+            "Relay".to_string(),
+            json!({
+                "auth": { "OPENAI_API_KEY": "sk-relay" },
+                "config": relay_config,
+            }),
+            Some("https://relay.example.com".to_string()),
+        );
+        relay.notes = Some("team account".to_string());
+        manager.providers.insert("relay".to_string(), relay);
+
+        let mut official = Provider::with_id(
+            "openai-official".to_string(),
+            "OpenAI Official".to_string(),
+            json!({ "config": "model = \"gpt-5.2-codex\"\n" }),
+            None,
+        );
+        official.category = Some("official".to_string());
+        manager
+            .providers
+            .insert("openai-official".to_string(), official);
+    }
+    let source = state_from_config(config);
+    let toml_path = home.join("codex-providers.toml");
+    ConfigService::export_codex_toml_to_path(&source, &toml_path, false).expect("export toml");
+
+    let exported = fs::read_to_string(&toml_path).expect("read exported toml");
+    let document: toml::Table = toml::from_str(&exported).expect("valid toml document");
+    assert_eq!(
+        document["providers"]["relay"]["config"]["model_providers"]["relay"]["base_url"].as_str(),
+        Some("https://relay.example.com/v1")
+    );
+
+    let target = state_from_config(MultiAppConfig::default());
+    let summary =
+        ConfigService::import_codex_toml_from_path(&target, &toml_path).expect("import toml");
+    assert_eq!((summary.added, summary.updated), (2, 0));
+
+    let original = ProviderService::list(&source, AppType::Codex).expect("source providers");
+    let imported = ProviderService::list(&target, AppType::Codex).expect("imported providers");
+    assert_eq!(imported.len(), original.len());
+    for (id, before) in &original {
+        let after = imported.get(id).expect("provider survives round trip");
+        assert_eq!(after.name, before.name);
+        assert_eq!(after.notes, before.notes);
+        assert_eq!(after.website_url, before.website_url);
+        assert_eq!(after.category, before.category);
+        assert_eq!(
+            after.settings_config.get("auth"),
+            before.settings_config.get("auth")
+        );
+        let parse = |provider: &Provider| -> toml::Table {
+            toml::from_str(
+                provider.settings_config["config"]
+                    .as_str()
+                    .unwrap_or_default(),
+            )
+            .expect("config.toml parses")
+        };
+        assert_eq!(parse(after), parse(before));
+    }
+
+    let summary =
+        ConfigService::import_codex_toml_from_path(&target, &toml_path).expect("re-import toml");
+    assert_eq!((summary.added, summary.updated), (0, 2));
+}
+
 #[test]
 fn import_config_from_path_invalid_json_returns_error() {
     let _guard = lock_test_mutex();
@@ -1146,3 +1447,277 @@ fn export_config_to_file_returns_error_when_path_invalid() {
         "expected invalid export path message, got {err}"
     );
 }
+
+#[test]
+fn config_validate_can_be_restricted_to_one_app() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    for app_type in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+        config.ensure_app(&app_type);
+    }
+    config
+        .get_manager_mut(&AppType::Claude)
+        .expect("claude manager")
+        .current = "missing".to_string();
+    let state = state_from_config(config);
+
+    let reports = ConfigService::validate(&state, Some(&AppType::Codex)).expect("validate codex");
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].app_type, AppType::Codex);
+    assert!(reports[0].issues.is_empty());
+
+    let reports = ConfigService::validate(&state, None).expect("validate all");
+    let claude = reports
+        .iter()
+        .find(|report| report.app_type == AppType::Claude)
+        .expect("claude section");
+    assert_eq!(
+        claude.issues.len(),
+        1,
+        "dangling current id should be reported"
+    );
+    assert!(reports
+        .iter()
+        .any(|report| report.app_type == AppType::Gemini));
+}
+
+#[test]
+fn team_bundle_exports_tagged_providers_redacted_and_merges_on_import() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        for (id, tag) in [("team-a", Some("team")), ("solo", None)] {
+            let mut provider = Provider::with_id(
+                id.to_string(),
+                id.to_string(),
+                json!({
+                    "env": {
+                        "ANTHROPIC_AUTH_TOKEN": format!("sk-{id}"),
+                        "ANTHROPIC_BASE_URL": "https://api.team.example"
+                    }
+                }),
+                None,
+            );
+            provider.meta = Some(ProviderMeta {
+                tags: tag.map(|tag| vec![tag.to_string()]).unwrap_or_default(),
+                ..ProviderMeta::default()
+            });
+            manager.providers.insert(id.to_string(), provider);
+        }
+    }
+    config.mcp.servers = Some(
+        [(
+            "fetch".to_string(),
+            McpServer {
+                id: "fetch".to_string(),
+                name: "fetch".to_string(),
+                server: json!({ "command": "uvx", "args": ["mcp-server-fetch"] }),
+                apps: McpApps::default(),
+                description: None,
+                homepage: None,
+                docs: None,
+                tags: Vec::new(),
+            },
+        )]
+        .into_iter()
+        .collect(),
+    );
+    config.prompts.claude.prompts.insert(
+        "style".to_string(),
+        serde_json::from_value(json!({
+            "id": "style",
+            "name": "Team style",
+            "content": "Be concise.",
+            "enabled": true
+        }))
+        .expect("prompt"),
+    );
+    let state = state_from_config(config);
+
+    let bundle_path = home.join("team-bundle.json");
+    let selection = BundleSelection {
+        tags: vec!["team".to_string()],
+        ids: Vec::new(),
+    };
+    ConfigService::export_bundle_to_path(&state, &selection, true, &bundle_path)
+        .expect("export bundle");
+
+    let written: serde_json::Value = read_json_file(&bundle_path).expect("read bundle");
+    let claude = written["providers"]["claude"]
+        .as_array()
+        .expect("claude providers");
+    assert_eq!(claude.len(), 1, "only tagged providers are bundled");
+    assert_eq!(claude[0]["id"], "team-a");
+    assert_eq!(
+        claude[0]["settingsConfig"]["env"]["ANTHROPIC_AUTH_TOKEN"],
+        "<redacted>"
+    );
+    assert_eq!(written["mcpServers"][0]["id"], "fetch");
+
+    reset_test_fs();
+    let _home = ensure_test_home();
+    let fresh = state_from_config(MultiAppConfig::default());
+    let summary =
+        ConfigService::import_bundle_from_path(&fresh, &bundle_path).expect("import bundle");
+    assert_eq!(summary.providers_added, 1);
+    assert_eq!(summary.mcp_added, 1);
+    assert_eq!(summary.prompts_added, 1);
+
+    let prompt =
+        PromptService::get_prompts(&fresh, AppType::Claude).expect("prompts")["style"].clone();
+    assert!(
+        !prompt.enabled,
+        "imported prompts must not take over the live prompt"
+    );
+
+    let again =
+        ConfigService::import_bundle_from_path(&fresh, &bundle_path).expect("re-import bundle");
+    assert_eq!(
+        again.providers_added + again.mcp_added + again.prompts_added,
+        0
+    );
+    assert_eq!(again.providers_skipped, 1);
+
+    let _ = fs::remove_file(&bundle_path);
+}
+
+#[test]
+fn bundle_preview_reports_new_identical_and_conflicting_providers() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let claude_provider = |id: &str, url: &str| {
+        Provider::with_id(
+            id.to_string(),
+            id.to_string(),
+            json!({
+                "env": {
+                    "ANTHROPIC_AUTH_TOKEN": format!("sk-{id}"),
+                    "ANTHROPIC_BASE_URL": url
+                }
+            }),
+            None,
+        )
+    };
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        for id in ["same", "changed", "fresh"] {
+            manager.providers.insert(
+                id.to_string(),
+                claude_provider(id, "https://api.team.example"),
+            );
+        }
+    }
+    let state = state_from_config(config);
+    let bundle_path = home.join("preview-bundle.json");
+    ConfigService::export_bundle_to_path(&state, &BundleSelection::default(), true, &bundle_path)
+        .expect("export bundle");
+
+    reset_test_fs();
+    let _home = ensure_test_home();
+    let mut local = MultiAppConfig::default();
+    {
+        let manager = local
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        // 配置包已脱敏，本地密钥不同也应视为一致
+        let mut same = claude_provider("same", "https://api.team.example");
+        same.settings_config["env"]["ANTHROPIC_AUTH_TOKEN"] = json!("sk-local");
+        manager.providers.insert("same".to_string(), same);
+        manager.providers.insert(
+            "changed".to_string(),
+            claude_provider("changed", "https://other.example"),
+        );
+    }
+    let local_state = state_from_config(local);
+
+    let previews = ConfigService::preview_bundle_from_path(&local_state, &bundle_path)
+        .expect("preview bundle");
+    let status = |id: &str| {
+        previews
+            .iter()
+            .find(|preview| preview.id == id)
+            .map(|preview| preview.status)
+            .expect("provider in preview")
+    };
+    assert_eq!(status("same"), BundleProviderStatus::Identical);
+    assert_eq!(status("changed"), BundleProviderStatus::Conflict);
+    assert_eq!(status("fresh"), BundleProviderStatus::New);
+
+    let providers = ProviderService::list(&local_state, AppType::Claude).expect("list");
+    assert_eq!(providers.len(), 2, "preview must not import anything");
+
+    let _ = fs::remove_file(&bundle_path);
+}
+
+/// 启动只响应一次的本地 HTTP 服务，返回给定内容
+fn serve_body_once(body: String) -> String {
+    use std::io::{Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+    let addr = listener.local_addr().expect("addr");
+    std::thread::spawn(move || {
+        if let Ok((mut stream, _)) = listener.accept() {
+            let mut buf = [0u8; 4096];
+            let _ = stream.read(&mut buf);
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+    format!("http://{addr}/team-bundle.json")
+}
+
+#[test]
+fn remote_import_rejects_plain_http_unless_allowed_and_downloads_bundle() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let err = ConfigService::parse_remote_source("http://example.com/c.sql", false)
+        .expect_err("plain http is rejected by default");
+    assert_eq!(err.code(), "config.remote.insecure_url");
+    let err = ConfigService::parse_remote_source("ftp://example.com/c.sql", true)
+        .expect_err("other schemes are rejected");
+    assert_eq!(err.code(), "config.remote.unsupported_scheme");
+    assert!(ConfigService::is_remote_source("HTTPS://example.com/c.sql"));
+    assert!(!ConfigService::is_remote_source("./backup.sql"));
+
+    let bundle = json!({
+        "version": 1,
+        "providers": {
+            "claude": [{
+                "id": "team",
+                "name": "Team",
+                "settingsConfig": { "env": { "ANTHROPIC_BASE_URL": "https://team.example" } }
+            }]
+        }
+    });
+    let url = serve_body_once(bundle.to_string());
+    let state = state_from_config(MultiAppConfig::default());
+    let progress = std::cell::Cell::new(0);
+    let remote =
+        ConfigService::download_remote_source(&state, &url, true, &|done, _| progress.set(done))
+            .expect("download bundle");
+    assert_eq!(remote.bytes, bundle.to_string().len() as u64);
+    assert_eq!(progress.get(), remote.bytes);
+
+    let summary =
+        ConfigService::import_bundle_from_path(&state, &remote.path).expect("import bundle");
+    assert_eq!(summary.providers_added, 1);
+}
@@ -1,10 +1,11 @@
-use std::{collections::HashMap, fs};
+use std::{collections::HashMap, fs, time::Duration};
 
 use serde_json::json;
 
 use cc_switch_lib::{
-    get_claude_mcp_path, get_claude_settings_path, AppError, AppState, AppType, McpApps, McpServer,
-    McpService, MultiAppConfig, ProviderService,
+    get_claude_mcp_path, get_claude_settings_path, get_codex_config_path, AppError, AppState,
+    AppType, McpApps, McpCheckLevel, McpServer, McpServerCheck, McpService, McpSyncOutcome,
+    McpSyncSettings, MultiAppConfig, ProviderService,
 };
 
 #[path = "support.rs"]
@@ -151,6 +152,118 @@ fn import_mcp_from_claude_creates_config_and_enables_servers() {
     );
 }
 
+#[test]
+fn preview_import_mcp_from_claude_reports_servers_without_persisting() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let claude_json = json!({
+        "mcpServers": {
+            "echo": { "type": "stdio", "command": "echo" },
+            "remote": { "type": "http", "url": "https://example.com/mcp" },
+            "plain": { "command": "plain" }
+        }
+    });
+    fs::write(
+        get_claude_mcp_path(),
+        serde_json::to_string_pretty(&claude_json).expect("serialize claude mcp"),
+    )
+    .expect("seed ~/.claude.json");
+
+    let mut config = MultiAppConfig::default();
+    config.mcp.servers = Some(HashMap::from([(
+        "echo".to_string(),
+        McpServer {
+            id: "echo".to_string(),
+            name: "echo".to_string(),
+            server: json!({ "type": "stdio", "command": "echo" }),
+            apps: McpApps {
+                claude: false,
+                codex: true,
+                gemini: false,
+                opencode: false,
+            },
+            description: None,
+            homepage: None,
+            docs: None,
+            tags: Vec::new(),
+        },
+    )]));
+    let state = state_from_config(config);
+    state.save().expect("persist seeded server");
+
+    let previews =
+        McpService::preview_import(&state, &AppType::Claude).expect("preview import succeeds");
+    let summary: Vec<(&str, bool, &str)> = previews
+        .iter()
+        .map(|p| (p.id.as_str(), p.is_new, p.transport.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("echo", false, "stdio"),
+            ("plain", true, "stdio"),
+            ("remote", true, "http"),
+        ]
+    );
+
+    let guard = state.config.read().expect("lock config");
+    let servers = guard.mcp.servers.as_ref().expect("servers");
+    assert_eq!(servers.len(), 1, "dry run must not touch in-memory state");
+    assert!(!servers["echo"].apps.claude);
+    drop(guard);
+
+    let servers_db = state.db.get_all_mcp_servers().expect("load mcp servers");
+    assert_eq!(servers_db.len(), 1, "dry run must not touch the db");
+    assert!(!servers_db["echo"].apps.claude);
+}
+
+#[test]
+fn unmanaged_codex_live_servers_lists_servers_missing_from_store() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let codex_dir = home.join(".codex");
+    fs::create_dir_all(&codex_dir).expect("create codex dir");
+    fs::write(
+        get_codex_config_path(),
+        "[mcp_servers.hand_added]\ncommand = \"hand\"\n\n[mcp_servers.echo]\ncommand = \"echo\"\n",
+    )
+    .expect("seed codex config.toml");
+
+    let mut config = MultiAppConfig::default();
+    config.mcp.servers = Some(HashMap::from([(
+        "echo".to_string(),
+        McpServer {
+            id: "echo".to_string(),
+            name: "echo".to_string(),
+            server: json!({ "type": "stdio", "command": "echo" }),
+            apps: McpApps {
+                claude: false,
+                codex: true,
+                gemini: false,
+                opencode: false,
+            },
+            description: None,
+            homepage: None,
+            docs: None,
+            tags: Vec::new(),
+        },
+    )]));
+    let state = state_from_config(config);
+
+    let at_risk = McpService::unmanaged_live_servers(&state, &AppType::Codex)
+        .expect("detect unmanaged servers");
+    assert_eq!(at_risk, vec!["hand_added".to_string()]);
+
+    McpService::import_from_codex(&state).expect("import codex servers");
+    let at_risk = McpService::unmanaged_live_servers(&state, &AppType::Codex)
+        .expect("detect unmanaged servers after import");
+    assert!(at_risk.is_empty(), "imported servers are no longer at risk");
+}
+
 #[test]
 fn import_mcp_from_claude_invalid_json_preserves_state() {
     let _guard = lock_test_mutex();
@@ -507,3 +620,321 @@ fn upsert_server_disables_app_removes_from_gemini_live() {
         "upsert with Gemini disabled should remove it from ~/.gemini/settings.json, got: {settings_text}"
     );
 }
+
+fn state_with_claude_and_codex_server() -> AppState {
+    let mut config = MultiAppConfig::default();
+    config.mcp.servers = Some(HashMap::from([(
+        "echo".to_string(),
+        McpServer {
+            id: "echo".to_string(),
+            name: "echo".to_string(),
+            server: json!({ "type": "stdio", "command": "echo" }),
+            apps: McpApps {
+                claude: true,
+                codex: true,
+                gemini: false,
+                opencode: false,
+            },
+            description: None,
+            homepage: None,
+            docs: None,
+            tags: Vec::new(),
+        },
+    )]));
+    state_from_config(config)
+}
+
+#[test]
+fn sync_all_enabled_with_continue_on_error_updates_remaining_apps() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    // ~/.claude.json 损坏：Claude 同步失败
+    fs::write(get_claude_mcp_path(), "{ not json").expect("seed broken ~/.claude.json");
+    let codex_path = get_codex_config_path();
+    fs::create_dir_all(codex_path.parent().expect("codex dir")).expect("create ~/.codex");
+
+    let state = state_with_claude_and_codex_server();
+    let settings = McpSyncSettings {
+        order: vec![AppType::Claude, AppType::Codex],
+        continue_on_error: true,
+    };
+    let results = McpService::sync_all_enabled_with(&state, &settings).expect("sync runs");
+
+    let apps: Vec<AppType> = results.iter().map(|r| r.app.clone()).collect();
+    assert_eq!(
+        apps,
+        vec![
+            AppType::Claude,
+            AppType::Codex,
+            AppType::Gemini,
+            AppType::OpenCode
+        ]
+    );
+    assert!(matches!(results[0].outcome, McpSyncOutcome::Failed(_)));
+    assert!(matches!(results[1].outcome, McpSyncOutcome::Synced(1)));
+    assert!(matches!(results[2].outcome, McpSyncOutcome::Synced(0)));
+
+    let codex_text = fs::read_to_string(&codex_path).expect("codex config written");
+    assert!(codex_text.contains("echo"), "codex should still be synced");
+}
+
+#[test]
+fn sync_all_enabled_stops_on_first_failure_by_default() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    fs::write(get_claude_mcp_path(), "{ not json").expect("seed broken ~/.claude.json");
+    let codex_path = get_codex_config_path();
+    fs::create_dir_all(codex_path.parent().expect("codex dir")).expect("create ~/.codex");
+
+    let state = state_with_claude_and_codex_server();
+    let results =
+        McpService::sync_all_enabled_with(&state, &McpSyncSettings::default()).expect("sync runs");
+
+    assert!(matches!(results[0].outcome, McpSyncOutcome::Failed(_)));
+    assert!(results[1..]
+        .iter()
+        .all(|r| matches!(r.outcome, McpSyncOutcome::Skipped)));
+    assert!(!codex_path.exists(), "codex must not be touched after stop");
+    assert!(McpService::sync_all_enabled(&state).is_err());
+}
+
+#[test]
+fn list_unsynced_reports_enabled_servers_missing_from_live_config() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let claude_json = json!({
+        "mcpServers": { "synced": { "type": "stdio", "command": "echo" } }
+    });
+    fs::write(
+        get_claude_mcp_path(),
+        serde_json::to_string_pretty(&claude_json).expect("serialize claude mcp"),
+    )
+    .expect("seed ~/.claude.json");
+
+    let server = |id: &str, claude: bool| McpServer {
+        id: id.to_string(),
+        name: id.to_string(),
+        server: json!({ "type": "stdio", "command": "echo" }),
+        apps: McpApps {
+            claude,
+            codex: false,
+            gemini: false,
+            opencode: false,
+        },
+        description: None,
+        homepage: None,
+        docs: None,
+        tags: Vec::new(),
+    };
+    let mut config = MultiAppConfig::default();
+    config.mcp.servers = Some(HashMap::from([
+        ("synced".to_string(), server("synced", true)),
+        ("drifted".to_string(), server("drifted", true)),
+        ("disabled".to_string(), server("disabled", false)),
+    ]));
+    let state = state_from_config(config);
+    state.save().expect("persist seeded servers");
+
+    let missing: Vec<String> = McpService::list_unsynced(&state, &AppType::Claude)
+        .expect("list unsynced")
+        .into_iter()
+        .map(|server| server.id)
+        .collect();
+    assert_eq!(missing, vec!["drifted".to_string()]);
+}
+
+#[test]
+fn validate_all_reports_present_and_missing_commands() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let stdio_server = |id: &str, command: &str, claude: bool| McpServer {
+        id: id.to_string(),
+        name: id.to_string(),
+        server: json!({ "type": "stdio", "command": command }),
+        apps: McpApps {
+            claude,
+            codex: false,
+            gemini: false,
+            opencode: false,
+        },
+        description: None,
+        homepage: None,
+        docs: None,
+        tags: Vec::new(),
+    };
+    let present = if cfg!(windows) { "cmd" } else { "sh" };
+
+    let mut config = MultiAppConfig::default();
+    config.mcp.servers = Some(HashMap::from([
+        (
+            "present".to_string(),
+            stdio_server("present", present, true),
+        ),
+        (
+            "missing".to_string(),
+            stdio_server("missing", "cc-switch-no-such-binary", true),
+        ),
+        (
+            "disabled".to_string(),
+            stdio_server("disabled", "cc-switch-no-such-binary", false),
+        ),
+    ]));
+    let state = state_from_config(config);
+
+    let results = McpService::validate_all(&state, &AppType::Claude, Duration::from_secs(1))
+        .expect("validate servers");
+
+    let summary: Vec<(&str, McpCheckLevel)> = results
+        .iter()
+        .map(|result| (result.id.as_str(), result.check.level()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("missing", McpCheckLevel::Fail),
+            ("present", McpCheckLevel::Pass),
+        ],
+        "only servers enabled for the app are checked, sorted by id"
+    );
+    assert_eq!(
+        results[0].check,
+        McpServerCheck::CommandMissing("cc-switch-no-such-binary".to_string())
+    );
+    assert!(matches!(results[1].check, McpServerCheck::CommandFound(_)));
+}
+
+#[test]
+fn disable_missing_commands_turns_off_only_missing_servers_for_app() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let stdio_server = |id: &str, command: &str| McpServer {
+        id: id.to_string(),
+        name: id.to_string(),
+        server: json!({ "type": "stdio", "command": command }),
+        apps: McpApps {
+            claude: true,
+            codex: true,
+            gemini: false,
+            opencode: false,
+        },
+        description: None,
+        homepage: None,
+        docs: None,
+        tags: Vec::new(),
+    };
+    let present = if cfg!(windows) { "cmd" } else { "sh" };
+
+    let mut config = MultiAppConfig::default();
+    config.mcp.servers = Some(HashMap::from([
+        ("present".to_string(), stdio_server("present", present)),
+        (
+            "missing".to_string(),
+            stdio_server("missing", "cc-switch-no-such-binary"),
+        ),
+    ]));
+    let state = state_from_config(config);
+
+    let results = McpService::validate_all(&state, &AppType::Claude, Duration::from_secs(1))
+        .expect("validate servers");
+    let disabled = McpService::disable_missing_commands(&state, &AppType::Claude, &results)
+        .expect("disable missing servers");
+    assert_eq!(disabled, vec!["missing".to_string()]);
+
+    let servers = McpService::get_all_servers(&state).expect("servers");
+    assert!(
+        !servers["missing"].apps.claude,
+        "missing server is disabled"
+    );
+    assert!(
+        servers["missing"].apps.codex,
+        "other apps keep their enabled state"
+    );
+    assert!(servers["present"].apps.claude);
+
+    let rerun = McpService::validate_all(&state, &AppType::Claude, Duration::from_secs(1))
+        .expect("validate again");
+    assert!(rerun
+        .iter()
+        .all(|result| result.check.level() == McpCheckLevel::Pass));
+}
+
+#[test]
+fn export_for_codex_keeps_only_codex_servers_and_import_enables_codex() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let server = |id: &str, claude: bool, codex: bool| McpServer {
+        id: id.to_string(),
+        name: id.to_string(),
+        server: json!({ "type": "stdio", "command": "echo" }),
+        apps: McpApps {
+            claude,
+            codex,
+            gemini: false,
+            opencode: false,
+        },
+        description: None,
+        homepage: None,
+        docs: None,
+        tags: Vec::new(),
+    };
+    let mut config = MultiAppConfig::default();
+    config.mcp.servers = Some(HashMap::from([
+        ("shared".to_string(), server("shared", true, true)),
+        (
+            "claude-only".to_string(),
+            server("claude-only", true, false),
+        ),
+        ("codex-only".to_string(), server("codex-only", false, true)),
+    ]));
+    let state = state_from_config(config);
+
+    let export_path = home.join("codex-mcp.json");
+    let (json, count) =
+        McpService::export_json(&state, Some(&AppType::Codex)).expect("export codex servers");
+    assert_eq!(count, 2);
+    fs::write(&export_path, json).expect("write export");
+    let exported: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&export_path).expect("read export"))
+            .expect("parse export");
+    assert_eq!(exported["app"], json!("codex"));
+    let ids: Vec<_> = exported["servers"]
+        .as_array()
+        .expect("servers")
+        .iter()
+        .map(|server| {
+            assert_eq!(server["apps"]["codex"], json!(true));
+            assert_eq!(server["apps"]["claude"], json!(false));
+            server["id"].as_str().expect("id").to_string()
+        })
+        .collect();
+    assert_eq!(ids, vec!["codex-only", "shared"]);
+
+    let mut target = MultiAppConfig::default();
+    target.mcp.servers = Some(HashMap::from([(
+        "shared".to_string(),
+        server("shared", true, false),
+    )]));
+    let target = state_from_config(target);
+    let summary =
+        McpService::import_from_path(&target, &export_path, None).expect("import codex servers");
+    assert_eq!(summary.added, 1);
+    assert_eq!(summary.enabled, 1);
+
+    let servers = McpService::get_all_servers(&target).expect("servers");
+    assert!(servers["codex-only"].apps.codex);
+    assert!(servers["shared"].apps.codex);
+    assert!(servers["shared"].apps.claude, "local flags are preserved");
+    assert!(!servers.contains_key("claude-only"));
+}
@@ -0,0 +1,100 @@
+use std::net::TcpListener;
+use std::time::{Duration, Instant};
+
+use std::io::{Read, Write};
+
+use cc_switch_lib::{
+    resolve_timeout, set_network_timeout_ms, transfer_client_builder, SpeedtestService,
+};
+
+#[path = "support.rs"]
+mod support;
+use support::{ensure_test_home, lock_test_mutex, reset_test_fs};
+
+#[test]
+fn resolve_timeout_prefers_explicit_then_setting_then_fallback() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let fallback = Duration::from_secs(5);
+    assert_eq!(resolve_timeout(None, fallback), fallback);
+
+    set_network_timeout_ms(Some(1500)).expect("set network timeout");
+    assert_eq!(resolve_timeout(None, fallback), Duration::from_millis(1500));
+    assert_eq!(
+        resolve_timeout(Some(Duration::from_millis(300)), fallback),
+        Duration::from_millis(300),
+        "per-command timeout should win over the setting"
+    );
+
+    set_network_timeout_ms(None).expect("clear network timeout");
+    assert_eq!(resolve_timeout(None, fallback), fallback);
+}
+
+#[tokio::test]
+async fn shared_client_uses_configured_network_timeout() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    // 接受连接但从不响应，只有客户端超时才能结束请求
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind listener");
+    let addr = listener.local_addr().expect("listener addr");
+    std::thread::spawn(move || {
+        let mut held = Vec::new();
+        for stream in listener.incoming().flatten() {
+            held.push(stream);
+        }
+    });
+
+    set_network_timeout_ms(Some(200)).expect("set network timeout");
+    let start = Instant::now();
+    let results = SpeedtestService::test_endpoints(vec![format!("http://{addr}/")], None)
+        .await
+        .expect("speedtest runs");
+    let elapsed = start.elapsed();
+
+    assert_eq!(results.len(), 1);
+    assert!(
+        results[0].latency.is_none(),
+        "silent endpoint should time out"
+    );
+    assert!(
+        elapsed < Duration::from_secs(4),
+        "speedtest default is 8s, but the 200ms setting should apply (took {elapsed:?})"
+    );
+}
+
+#[tokio::test]
+async fn transfer_client_is_not_capped_by_network_timeout() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    // 连接立即建立，响应在 600ms 后才返回（模拟较慢的大文件传输）
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind listener");
+    let addr = listener.local_addr().expect("listener addr");
+    std::thread::spawn(move || {
+        if let Some(mut stream) = listener.incoming().flatten().next() {
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf);
+            std::thread::sleep(Duration::from_millis(600));
+            let _ = stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok");
+        }
+    });
+
+    set_network_timeout_ms(Some(200)).expect("set network timeout");
+    let client = transfer_client_builder(Duration::from_secs(5))
+        .build()
+        .expect("client");
+    let body = client
+        .get(format!("http://{addr}/"))
+        .send()
+        .await
+        .expect("slow transfer completes")
+        .text()
+        .await
+        .expect("body");
+    assert_eq!(body, "ok");
+}
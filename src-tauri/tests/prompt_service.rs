@@ -0,0 +1,100 @@
+use cc_switch_lib::{AppError, AppState, AppType, MultiAppConfig, Prompt, PromptService};
+
+#[path = "support.rs"]
+mod support;
+use support::{lock_test_mutex, reset_test_fs, state_from_config};
+
+fn prompt(id: &str, updated_at: i64) -> Prompt {
+    Prompt {
+        id: id.to_string(),
+        name: format!("Prompt {id}"),
+        content: format!("content of {id}"),
+        description: None,
+        enabled: false,
+        created_at: Some(updated_at),
+        updated_at: Some(updated_at),
+        sort_index: None,
+    }
+}
+
+fn listed_ids(state: &AppState) -> Vec<String> {
+    let prompts = PromptService::get_prompts(state, AppType::Claude).expect("get prompts");
+    PromptService::sorted_prompts(prompts)
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect()
+}
+
+#[test]
+fn reorder_prompts_changes_list_order_deterministically_and_persists() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+
+    let mut config = MultiAppConfig::default();
+    for (id, updated_at) in [("a", 1), ("b", 2), ("c", 3), ("d", 4)] {
+        config
+            .prompts
+            .claude
+            .prompts
+            .insert(id.to_string(), prompt(id, updated_at));
+    }
+    let state = state_from_config(config);
+    state.save().expect("persist prompts");
+
+    // 未排序时按更新时间倒序
+    assert_eq!(listed_ids(&state), ["d", "c", "b", "a"]);
+
+    let order = PromptService::reorder_prompts(
+        &state,
+        AppType::Claude,
+        &["b".to_string(), "a".to_string()],
+    )
+    .expect("reorder");
+    assert_eq!(order, ["b", "a", "d", "c"]);
+    assert_eq!(listed_ids(&state), order);
+
+    // 重复执行结果一致
+    let again = PromptService::reorder_prompts(
+        &state,
+        AppType::Claude,
+        &["b".to_string(), "a".to_string()],
+    )
+    .expect("reorder again");
+    assert_eq!(again, order);
+
+    let reloaded = AppState::try_new().expect("reload app state");
+    assert_eq!(listed_ids(&reloaded), order, "order survives a reload");
+    let prompts = PromptService::get_prompts(&reloaded, AppType::Claude).expect("get prompts");
+    assert_eq!(prompts["c"].sort_index, Some(3));
+}
+
+#[test]
+fn reorder_prompts_rejects_unknown_or_duplicate_ids() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+
+    let mut config = MultiAppConfig::default();
+    config
+        .prompts
+        .claude
+        .prompts
+        .insert("a".to_string(), prompt("a", 1));
+    let state = state_from_config(config);
+
+    let err = PromptService::reorder_prompts(&state, AppType::Claude, &["missing".to_string()])
+        .expect_err("unknown id");
+    assert!(matches!(err, AppError::InvalidInput(_)));
+
+    let err = PromptService::reorder_prompts(
+        &state,
+        AppType::Claude,
+        &["a".to_string(), "a".to_string()],
+    )
+    .expect_err("duplicate id");
+    assert!(matches!(err, AppError::InvalidInput(_)));
+    assert_eq!(
+        PromptService::get_prompts(&state, AppType::Claude).expect("get")["a"].sort_index,
+        None,
+        "rejected reorder leaves prompts untouched"
+    );
+}
@@ -570,3 +570,47 @@ async fn switch_provider_under_takeover_keeps_claude_live_pointing_to_proxy_and_
         "restore after a takeover-time switch should keep the normal Claude common snippet semantics"
     );
 }
+
+#[test]
+fn switch_verbose_lists_codex_config_and_auth_files() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    write_codex_live_atomic(&json!({"OPENAI_API_KEY": "old-key"}), Some(""))
+        .expect("seed codex live dir");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        manager.providers.insert(
+            "with-auth".to_string(),
+            Provider::with_id(
+                "with-auth".to_string(),
+                "With Auth".to_string(),
+                json!({
+                    "auth": {"OPENAI_API_KEY": "fresh-key"},
+                    "config": "model_provider = \"custom\"\n\n[model_providers.custom]\nbase_url = \"https://api.example.com/v1\"\n"
+                }),
+                None,
+            ),
+        );
+    }
+    let state = state_from_config(config);
+
+    let outcome = ProviderService::switch_reporting_hooks(&state, AppType::Codex, "with-auth")
+        .expect("switch codex provider");
+    let lines = cc_switch_lib::cli::commands::provider::live_write_lines(&outcome.written);
+
+    let config_path = get_codex_config_path();
+    let auth_path = get_codex_auth_path();
+    let config_size = std::fs::metadata(&config_path).expect("config.toml").len();
+    let auth_size = std::fs::metadata(&auth_path).expect("auth.json").len();
+    assert_eq!(lines.len(), 3, "title plus two files: {lines:?}");
+    assert!(lines[1].contains(&config_path.display().to_string()));
+    assert!(lines[1].contains(&config_size.to_string()));
+    assert!(lines[2].contains(&auth_path.display().to_string()));
+    assert!(lines[2].contains(&auth_size.to_string()));
+}
@@ -0,0 +1,380 @@
+use serde_json::json;
+
+use cc_switch_lib::{
+    AppType, MultiAppConfig, Provider, ProviderMeta, ProviderService, SettingsPatch,
+};
+
+#[path = "support.rs"]
+mod support;
+use support::{ensure_test_home, lock_test_mutex, reset_test_fs, state_from_config};
+
+#[test]
+fn add_normalizes_provider_id_with_whitespace() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    let state = state_from_config(config);
+
+    let settings = json!({ "env": { "ANTHROPIC_BASE_URL": "https://claude.example" } });
+    let spaced = Provider::with_id(
+        "my relay".to_string(),
+        "my relay".to_string(),
+        settings.clone(),
+        None,
+    );
+    ProviderService::add(&state, AppType::Claude, spaced).expect("add normalizes id");
+    let empty = Provider::with_id("  ".to_string(), "  ".to_string(), settings, None);
+    let err = ProviderService::add(&state, AppType::Claude, empty).expect_err("empty id");
+    assert_eq!(err.code(), "provider.id.empty");
+
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list");
+    assert_eq!(providers.keys().collect::<Vec<_>>(), vec!["my-relay"]);
+}
+
+#[test]
+fn add_rejects_duplicate_id_unless_overwrite() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    let state = state_from_config(config);
+
+    let provider = |name: &str| {
+        Provider::with_id(
+            "dup".to_string(),
+            name.to_string(),
+            json!({ "env": {} }),
+            None,
+        )
+    };
+    ProviderService::add(&state, AppType::Claude, provider("First")).expect("first add");
+
+    let err = ProviderService::add(&state, AppType::Claude, provider("Second"))
+        .expect_err("duplicate id must be rejected");
+    assert_eq!(err.code(), "provider.already_exists");
+    assert!(
+        err.to_string().contains("dup-2"),
+        "should suggest a free id: {err}"
+    );
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list");
+    assert_eq!(providers["dup"].name, "First");
+
+    ProviderService::add_with(&state, AppType::Claude, provider("Second"), true)
+        .expect("overwrite updates");
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list");
+    assert_eq!(providers.len(), 1);
+    assert_eq!(providers["dup"].name, "Second");
+}
+
+#[test]
+fn update_preserves_created_at_and_sort_index_when_payload_omits_them() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    {
+        let manager = config.get_manager_mut(&AppType::Claude).expect("claude");
+        let mut original = Provider::with_id(
+            "p1".to_string(),
+            "p1".to_string(),
+            json!({ "env": {} }),
+            None,
+        );
+        original.created_at = Some(1_700_000_000_000);
+        original.sort_index = Some(3);
+        manager.providers.insert("p1".to_string(), original);
+    }
+    let state = state_from_config(config);
+
+    let mut payload = Provider::with_id(
+        "p1".to_string(),
+        "Renamed".to_string(),
+        json!({ "env": {} }),
+        None,
+    );
+    payload.created_at = None;
+    ProviderService::update(&state, AppType::Claude, payload.clone()).expect("update");
+    payload.created_at = Some(0);
+    ProviderService::update(&state, AppType::Claude, payload).expect("update zeroed");
+
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list");
+    assert_eq!(providers["p1"].name, "Renamed");
+    assert_eq!(providers["p1"].created_at, Some(1_700_000_000_000));
+    assert_eq!(providers["p1"].sort_index, Some(3));
+}
+
+#[test]
+fn codex_add_rejects_colliding_model_provider_key() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let codex_settings = |base_url: &str| json!({ "auth": { "OPENAI_API_KEY": "sk-duck" }, "config": format!("model_provider = \"duck_coding\"\nmodel = \"gpt-5.2-codex\"\n\n[model_providers.duck_coding]\nbase_url = \"{base_url}\"\n") });
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Codex);
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        manager.current = "Duck-Coding".to_string();
+        manager.providers.insert(
+            "Duck-Coding".to_string(),
+            Provider::with_id(
+                "Duck-Coding".to_string(),
+                "Duck Coding".to_string(),
+                codex_settings("https://api.duck.example/v1"),
+                None,
+            ),
+        );
+    }
+    let state = state_from_config(config);
+
+    let err = ProviderService::add(
+        &state,
+        AppType::Codex,
+        Provider::with_id(
+            "duck-coding".to_string(),
+            "duck-coding".to_string(),
+            codex_settings("https://relay.duck.example/v1"),
+            None,
+        ),
+    )
+    .expect_err("colliding model_provider should be rejected");
+    let message = err.to_string();
+    assert!(
+        message.contains("Duck-Coding") && message.contains("duck_coding_2"),
+        "error should name the clash and suggest a key: {message}"
+    );
+
+    // 同一地址不算冲突
+    ProviderService::add(
+        &state,
+        AppType::Codex,
+        Provider::with_id(
+            "duck-mirror".to_string(),
+            "Duck Mirror".to_string(),
+            codex_settings("https://api.duck.example/v1/"),
+            None,
+        ),
+    )
+    .expect("same base_url should be allowed");
+}
+
+#[test]
+fn archived_provider_cannot_be_switched_to_until_restored() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    {
+        let manager = config.get_manager_mut(&AppType::Claude).expect("claude");
+        for id in ["current", "old"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(id.to_string(), id.to_string(), json!({ "env": {} }), None),
+            );
+        }
+        manager.current = "current".to_string();
+    }
+    let state = state_from_config(config);
+
+    let err = ProviderService::set_archived(&state, AppType::Claude, "current", true)
+        .expect_err("current provider cannot be archived");
+    assert_eq!(err.code(), "provider.archive.current");
+
+    ProviderService::set_archived(&state, AppType::Claude, "old", true).expect("archive old");
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list");
+    assert!(providers["old"].is_archived());
+    let err = ProviderService::switch(&state, AppType::Claude, "old")
+        .expect_err("archived provider cannot be switched to");
+    assert_eq!(err.code(), "provider.archived");
+
+    ProviderService::set_archived(&state, AppType::Claude, "old", false).expect("unarchive old");
+    ProviderService::switch(&state, AppType::Claude, "old").expect("switch after restore");
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list");
+    assert!(!providers["old"].is_archived());
+    assert_eq!(
+        ProviderService::current(&state, AppType::Claude).expect("current"),
+        "old"
+    );
+}
+
+#[test]
+fn rename_id_moves_provider_and_current_follows() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    {
+        let manager = config.get_manager_mut(&AppType::Claude).expect("claude");
+        for (id, sort_index) in [("a", 2), ("b", 0), ("c", 1)] {
+            let mut provider =
+                Provider::with_id(id.to_string(), id.to_string(), json!({ "env": {} }), None);
+            provider.sort_index = Some(sort_index);
+            provider.meta = Some(ProviderMeta {
+                tags: vec!["team".to_string()],
+                ..ProviderMeta::default()
+            });
+            manager.providers.insert(id.to_string(), provider);
+        }
+        manager.current = "b".to_string();
+    }
+    let state = state_from_config(config);
+
+    ProviderService::rename_id(&state, AppType::Claude, "b", "beta").expect("rename id");
+
+    let cfg = state.config.read().expect("read config");
+    let manager = cfg.get_manager(&AppType::Claude).expect("claude");
+    assert_eq!(manager.current, "beta");
+    let ids: Vec<&str> = manager.providers.keys().map(String::as_str).collect();
+    assert_eq!(ids, vec!["a", "beta", "c"]);
+    let renamed = &manager.providers["beta"];
+    assert_eq!(renamed.id, "beta");
+    assert_eq!(renamed.sort_index, Some(0));
+    assert_eq!(renamed.meta.as_ref().expect("meta").tags, ["team"]);
+    drop(cfg);
+
+    assert_eq!(
+        state.db.get_current_provider("claude").expect("db current"),
+        Some("beta".to_string())
+    );
+    assert!(
+        ProviderService::rename_id(&state, AppType::Claude, "a", "c").is_err(),
+        "existing id must be rejected"
+    );
+}
+
+#[test]
+fn apply_tag_patch_updates_all_tagged_providers() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    {
+        let manager = config.get_manager_mut(&AppType::Claude).expect("claude");
+        for (id, tags) in [
+            ("a", &["work"][..]),
+            ("b", &["work", "eu"]),
+            ("c", &["home"]),
+        ] {
+            let mut provider =
+                Provider::with_id(id.to_string(), id.to_string(), json!({ "env": {} }), None);
+            provider.meta = Some(ProviderMeta {
+                tags: tags.iter().map(|t| t.to_string()).collect(),
+                ..ProviderMeta::default()
+            });
+            manager.providers.insert(id.to_string(), provider);
+        }
+    }
+    let state = state_from_config(config);
+
+    let patch = SettingsPatch::parse("env.ANTHROPIC_BASE_URL=https://proxy").expect("patch");
+    let count = ProviderService::apply_tag_patch(&state, AppType::Claude, "work", &[patch])
+        .expect("group patch should succeed");
+    assert_eq!(count, 2);
+
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list");
+    for id in ["a", "b"] {
+        assert_eq!(
+            providers[id].settings_config["env"]["ANTHROPIC_BASE_URL"],
+            json!("https://proxy")
+        );
+    }
+    assert!(providers["c"].settings_config["env"]
+        .get("ANTHROPIC_BASE_URL")
+        .is_none());
+}
+
+#[test]
+fn apply_tag_patch_rolls_back_when_one_provider_fails_validation() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    {
+        let manager = config.get_manager_mut(&AppType::Claude).expect("claude");
+        for (id, settings) in [
+            ("a", json!({ "env": {} })),
+            (
+                "b",
+                json!({ "env": {}, "customHeaders": { "Bad Header": "x" } }),
+            ),
+        ] {
+            let mut provider = Provider::with_id(id.to_string(), id.to_string(), settings, None);
+            provider.meta = Some(ProviderMeta {
+                tags: vec!["work".to_string()],
+                ..ProviderMeta::default()
+            });
+            manager.providers.insert(id.to_string(), provider);
+        }
+    }
+    let state = state_from_config(config);
+
+    let patch = SettingsPatch::parse("env.ANTHROPIC_BASE_URL=https://proxy").expect("patch");
+    ProviderService::apply_tag_patch(&state, AppType::Claude, "work", &[patch])
+        .expect_err("invalid provider should abort the whole group update");
+
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list");
+    for id in ["a", "b"] {
+        assert!(
+            providers[id].settings_config["env"]
+                .get("ANTHROPIC_BASE_URL")
+                .is_none(),
+            "provider {id} should be rolled back"
+        );
+    }
+}
+
+#[test]
+fn settings_patch_parses_json_values_and_rejects_empty_segments() {
+    let patch = SettingsPatch::parse("alwaysThinkingEnabled=true").expect("parse");
+    assert_eq!(patch.value, json!(true));
+
+    // env 变量值必须是字符串，即便看起来像 JSON 数字/布尔
+    let patch = SettingsPatch::parse("env.API_TIMEOUT_MS=3000").expect("parse");
+    assert_eq!(patch.path, vec!["env", "API_TIMEOUT_MS"]);
+    assert_eq!(patch.value, json!("3000"));
+    let patch = SettingsPatch::parse("/env/DISABLE_TELEMETRY=true").expect("parse");
+    assert_eq!(patch.value, json!("true"));
+    let patch = SettingsPatch::parse(r#"env={"A":"1"}"#).expect("parse");
+    assert_eq!(patch.value, json!({ "A": "1" }));
+
+    let patch = SettingsPatch::parse("env.URL=https://a=b").expect("parse");
+    assert_eq!(patch.value, json!("https://a=b"));
+
+    assert!(SettingsPatch::parse("env..URL=x").is_err());
+    assert!(SettingsPatch::parse("env.URL").is_err());
+}
+
+#[test]
+fn settings_patch_accepts_json_pointer_paths() {
+    let patch = SettingsPatch::parse("/env/ANTHROPIC_BASE_URL=https://proxy").expect("parse");
+    assert_eq!(patch.path, vec!["env", "ANTHROPIC_BASE_URL"]);
+    assert_eq!(patch.value, json!("https://proxy"));
+    assert_eq!(patch.pointer(), "/env/ANTHROPIC_BASE_URL");
+
+    // 指针形式下 `.` 是键名的一部分，`~1`/`~0` 还原为 `/` 与 `~`
+    let patch = SettingsPatch::parse("/permissions/a.b~1c~0d=true").expect("parse");
+    assert_eq!(patch.path, vec!["permissions", "a.b/c~d"]);
+    assert_eq!(patch.value, json!(true));
+    assert_eq!(patch.pointer(), "/permissions/a.b~1c~0d");
+
+    assert!(SettingsPatch::parse("/=x").is_err());
+    assert!(SettingsPatch::parse("/env//URL=x").is_err());
+    assert!(SettingsPatch::parse("/env/~2=x").is_err());
+}
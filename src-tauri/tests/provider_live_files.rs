@@ -0,0 +1,270 @@
+use serde_json::{json, Value};
+
+use cc_switch_lib::{
+    get_claude_settings_path, get_codex_auth_path, get_codex_config_path, AppType, MultiAppConfig,
+    Provider, ProviderMeta, ProviderService,
+};
+
+#[path = "support.rs"]
+mod support;
+use support::{ensure_test_home, lock_test_mutex, reset_test_fs, state_from_config};
+
+/// 预览给出的每个文件都与随后切换实际写入（或移除）的结果一致
+fn assert_preview_matches_switch(state: &cc_switch_lib::AppState, app: AppType, id: &str) {
+    let preview = ProviderService::preview_live(state, app.clone(), id).expect("preview");
+    ProviderService::switch(state, app, id).expect("switch");
+    for file in preview {
+        let on_disk = std::fs::read_to_string(&file.path).ok();
+        assert_eq!(on_disk, file.content, "{}", file.path.display());
+    }
+}
+
+#[test]
+fn preview_live_matches_written_codex_files() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let codex_dir = get_codex_config_path()
+        .parent()
+        .expect("codex dir")
+        .to_path_buf();
+    std::fs::create_dir_all(&codex_dir).expect("create codex dir");
+    std::fs::write(codex_dir.join(".env"), "OTHER=\"1\"\n").expect("seed .env");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config.get_manager_mut(&AppType::Codex).expect("codex");
+        let mut env_file = Provider::with_id(
+            "env".to_string(),
+            "Env".to_string(),
+            json!({
+                "auth": { "OPENAI_API_KEY": "sk-env" },
+                "config": "model = \"gpt\"\n"
+            }),
+            None,
+        );
+        env_file.meta = Some(ProviderMeta {
+            codex_auth_target: Some(cc_switch_lib::CodexAuthTarget::EnvFile),
+            ..ProviderMeta::default()
+        });
+        manager.providers.insert("env".to_string(), env_file);
+        manager.providers.insert(
+            "empty".to_string(),
+            Provider::with_id(
+                "empty".to_string(),
+                "Empty".to_string(),
+                json!({
+                    "auth": { "OPENAI_API_KEY": "" },
+                    "config": "model = \"gpt\"\n"
+                }),
+                None,
+            ),
+        );
+    }
+    let state = state_from_config(config);
+
+    assert_preview_matches_switch(&state, AppType::Codex, "env");
+    assert_eq!(
+        std::fs::read_to_string(codex_dir.join(".env")).expect("read .env"),
+        "OTHER=\"1\"\nOPENAI_API_KEY=\"sk-env\"\n"
+    );
+
+    // 只有空 API Key 时不预览 auth.json（切换同样不会写入）
+    let preview = ProviderService::preview_live(&state, AppType::Codex, "empty").expect("preview");
+    let auth = preview
+        .iter()
+        .find(|file| file.path == get_codex_auth_path())
+        .expect("auth.json entry");
+    assert_eq!(auth.content, None);
+    assert_preview_matches_switch(&state, AppType::Codex, "empty");
+}
+
+#[test]
+fn preview_live_merges_gemini_settings_like_the_writer() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let gemini_dir = home.join(".gemini");
+    std::fs::create_dir_all(&gemini_dir).expect("create gemini dir");
+    std::fs::write(
+        gemini_dir.join("settings.json"),
+        json!({ "mcpServers": { "fetch": { "command": "uvx" } }, "theme": "dark" }).to_string(),
+    )
+    .expect("seed settings.json");
+
+    let mut config = MultiAppConfig::default();
+    config
+        .get_manager_mut(&AppType::Gemini)
+        .expect("gemini")
+        .providers
+        .insert(
+            "g".to_string(),
+            Provider::with_id(
+                "g".to_string(),
+                "G".to_string(),
+                json!({
+                    "env": { "GEMINI_API_KEY": "key", "GOOGLE_GEMINI_BASE_URL": "https://x" },
+                    "config": { "theme": "light", "mcpServers": { "stale": {} } }
+                }),
+                None,
+            ),
+        );
+    let state = state_from_config(config);
+
+    let preview = ProviderService::preview_live(&state, AppType::Gemini, "g").expect("preview");
+    let settings: serde_json::Value = serde_json::from_str(
+        preview[1]
+            .content
+            .as_deref()
+            .expect("settings.json content"),
+    )
+    .expect("json");
+    assert_eq!(settings["theme"], json!("light"));
+    assert_eq!(
+        settings["mcpServers"],
+        json!({ "fetch": { "command": "uvx" } })
+    );
+
+    let settings_path = gemini_dir.join("settings.json");
+    ProviderService::switch(&state, AppType::Gemini, "g").expect("switch");
+    let written: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&settings_path).expect("read settings"))
+            .expect("json");
+    assert_eq!(written["theme"], settings["theme"]);
+    assert_eq!(written["mcpServers"], settings["mcpServers"]);
+    assert_eq!(
+        std::fs::read_to_string(gemini_dir.join(".env")).ok(),
+        preview[0].content
+    );
+}
+
+#[test]
+fn leaving_env_file_mode_removes_only_the_managed_codex_env_key() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let codex_dir = get_codex_config_path()
+        .parent()
+        .expect("codex dir")
+        .to_path_buf();
+    std::fs::create_dir_all(&codex_dir).expect("create codex dir");
+    let env_path = codex_dir.join(".env");
+    std::fs::write(&env_path, "OTHER=\"1\"\n").expect("seed .env");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config.get_manager_mut(&AppType::Codex).expect("codex");
+        for (id, env_file) in [("env", true), ("json", false)] {
+            let mut provider = Provider::with_id(
+                id.to_string(),
+                id.to_uppercase(),
+                json!({
+                    "auth": { "OPENAI_API_KEY": format!("sk-{id}") },
+                    "config": "model = \"gpt\"\n"
+                }),
+                None,
+            );
+            if env_file {
+                provider.meta = Some(ProviderMeta {
+                    codex_auth_target: Some(cc_switch_lib::CodexAuthTarget::EnvFile),
+                    ..ProviderMeta::default()
+                });
+            }
+            manager.providers.insert(id.to_string(), provider);
+        }
+    }
+    let state = state_from_config(config);
+
+    ProviderService::switch(&state, AppType::Codex, "env").expect("switch to env-file");
+    ProviderService::switch(&state, AppType::Codex, "json").expect("switch to auth.json");
+    assert_eq!(
+        std::fs::read_to_string(&env_path).expect("read .env"),
+        "OTHER=\"1\"\n"
+    );
+    assert!(get_codex_auth_path().exists());
+
+    // 用户自己写在 .env 中的同名变量（非 cc-switch 写入）保持不动
+    std::fs::write(&env_path, "OPENAI_API_KEY=\"mine\"\n").expect("user .env");
+    ProviderService::switch(&state, AppType::Codex, "json").expect("switch again");
+    assert_eq!(
+        std::fs::read_to_string(&env_path).expect("read .env"),
+        "OPENAI_API_KEY=\"mine\"\n"
+    );
+}
+
+#[test]
+fn preview_live_merges_common_snippet_without_writing_files() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    config.ensure_app(&AppType::Codex);
+    config.common_config_snippets.claude =
+        Some(r#"{ "includeCoAuthoredBy": false, "env": { "SHARED": "1" } }"#.to_string());
+    config.common_config_snippets.codex = Some("disable_response_storage = true\n".to_string());
+    config
+        .get_manager_mut(&AppType::Claude)
+        .expect("claude")
+        .providers
+        .insert(
+            "c".to_string(),
+            Provider::with_id(
+                "c".to_string(),
+                "c".to_string(),
+                json!({ "env": {
+                    "ANTHROPIC_MODEL": "m",
+                    "ANTHROPIC_SMALL_FAST_MODEL": "fast"
+                } }),
+                None,
+            ),
+        );
+    config
+        .get_manager_mut(&AppType::Codex)
+        .expect("codex")
+        .providers
+        .insert(
+            "x".to_string(),
+            Provider::with_id(
+                "x".to_string(),
+                "X".to_string(),
+                json!({
+                    "auth": { "OPENAI_API_KEY": "sk-x" },
+                    "config": "model = \"gpt\"\n"
+                }),
+                None,
+            ),
+        );
+    let state = state_from_config(config);
+
+    let claude = ProviderService::preview_live(&state, AppType::Claude, "c").expect("claude");
+    assert_eq!(claude.len(), 1);
+    assert_eq!(claude[0].path, get_claude_settings_path());
+    let settings: Value =
+        serde_json::from_str(claude[0].content.as_deref().expect("content")).expect("json");
+    assert_eq!(settings["includeCoAuthoredBy"], json!(false));
+    assert_eq!(settings["env"]["SHARED"], json!("1"));
+    // 旧版 SMALL_FAST 模型被规范化为 DEFAULT_HAIKU
+    assert_eq!(
+        settings["env"]["ANTHROPIC_DEFAULT_HAIKU_MODEL"],
+        json!("fast")
+    );
+    assert!(settings["env"].get("ANTHROPIC_SMALL_FAST_MODEL").is_none());
+
+    let codex = ProviderService::preview_live(&state, AppType::Codex, "x").expect("codex");
+    let config_toml = codex[0].content.as_deref().expect("config.toml");
+    assert!(config_toml.contains("model = \"gpt\""));
+    assert!(config_toml.contains("disable_response_storage = true"));
+    assert_eq!(codex[1].path, get_codex_auth_path());
+    assert!(codex[1]
+        .content
+        .as_deref()
+        .is_some_and(|auth| auth.contains("sk-x")));
+
+    assert!(!get_claude_settings_path().exists());
+    assert!(!get_codex_config_path().exists());
+}
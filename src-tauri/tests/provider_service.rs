@@ -5,6 +5,11 @@ use cc_switch_lib::{
     get_claude_settings_path, read_json_file, write_codex_live_atomic, AppError, AppType, McpApps,
     McpServer, MultiAppConfig, Provider, ProviderMeta, ProviderService,
 };
+use cc_switch_lib::{
+    get_default_model, get_provider_notes_template, set_default_model, set_no_backfill_extract,
+    set_provider_notes_template, set_redaction_patterns, LegacyImportOutcome, SettingsPatch,
+    SwitchOptions,
+};
 
 #[path = "support.rs"]
 mod support;
@@ -477,6 +482,246 @@ fn switch_gemini_merges_existing_settings_preserving_mcp_servers() {
     );
 }
 
+#[test]
+fn switch_gemini_keeps_live_mcp_servers_over_stale_provider_snapshot() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let gemini_dir = home.join(".gemini");
+    std::fs::create_dir_all(&gemini_dir).expect("create gemini dir");
+    std::fs::write(gemini_dir.join(".env"), "GEMINI_API_KEY=old-key\n").expect("seed gemini .env");
+    let gemini_settings_path = gemini_dir.join("settings.json");
+    let existing_settings = json!({
+        "mcpServers": {
+            "enabled": { "command": "enabled-cmd" },
+            "manual": { "command": "manual-cmd" }
+        }
+    });
+    std::fs::write(
+        &gemini_settings_path,
+        serde_json::to_string_pretty(&existing_settings).expect("serialize existing settings"),
+    )
+    .expect("seed existing gemini settings.json");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Gemini)
+            .expect("gemini manager");
+        manager.current = "old".to_string();
+        manager.providers.insert(
+            "old".to_string(),
+            Provider::with_id(
+                "old".to_string(),
+                "Old Gemini".to_string(),
+                json!({ "env": { "GEMINI_API_KEY": "old-key" } }),
+                None,
+            ),
+        );
+        // 快照来自启用 MCP 之前的 settings.json
+        manager.providers.insert(
+            "new".to_string(),
+            Provider::with_id(
+                "new".to_string(),
+                "New Gemini".to_string(),
+                json!({
+                    "env": { "GEMINI_API_KEY": "new-key" },
+                    "config": {
+                        "mcpServers": {},
+                        "ccSwitchTestKey": "new"
+                    }
+                }),
+                None,
+            ),
+        );
+    }
+    config.mcp.servers = Some(HashMap::from([(
+        "enabled".to_string(),
+        McpServer {
+            id: "enabled".to_string(),
+            name: "Enabled".to_string(),
+            server: json!({ "type": "stdio", "command": "enabled-cmd" }),
+            apps: McpApps {
+                claude: false,
+                codex: false,
+                gemini: true,
+                opencode: false,
+            },
+            description: None,
+            homepage: None,
+            docs: None,
+            tags: Vec::new(),
+        },
+    )]));
+
+    let state = state_from_config(config);
+
+    ProviderService::switch(&state, AppType::Gemini, "new")
+        .expect("switching to new gemini provider should succeed");
+
+    let value: serde_json::Value =
+        read_json_file(&gemini_settings_path).expect("read gemini settings.json");
+    assert_eq!(
+        value
+            .pointer("/mcpServers/enabled/command")
+            .and_then(|v| v.as_str()),
+        Some("enabled-cmd"),
+        "enabled MCP server should survive the switch, got: {value}"
+    );
+    assert_eq!(
+        value
+            .pointer("/mcpServers/manual/command")
+            .and_then(|v| v.as_str()),
+        Some("manual-cmd"),
+        "stale provider snapshot must not drop live mcpServers, got: {value}"
+    );
+    assert_eq!(
+        value.pointer("/ccSwitchTestKey").and_then(|v| v.as_str()),
+        Some("new")
+    );
+
+    let guard = state.config.read().expect("read config");
+    let old = &guard
+        .get_manager(&AppType::Gemini)
+        .expect("gemini manager")
+        .providers["old"];
+    assert!(
+        old.settings_config.pointer("/config/mcpServers").is_none(),
+        "backfilled snapshot should not capture mcpServers: {}",
+        old.settings_config
+    );
+}
+
+#[test]
+fn seed_snapshot_from_file_updates_provider_without_live_files() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        manager.current = "main".to_string();
+        for id in ["main", "relay"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_string(),
+                    json!({
+                        "auth": { "OPENAI_API_KEY": format!("sk-{id}") },
+                        "config": "model_provider = \"old\"\nmodel = \"gpt-4\"\n"
+                    }),
+                    None,
+                ),
+            );
+        }
+    }
+    let state = state_from_config(config);
+
+    let seed_path = home.join("seed-config.toml");
+    let seeded_config = "model_provider = \"relay\"\nmodel = \"gpt-5.2-codex\"\n\n[model_providers.relay]\nbase_url = \"https://relay.example/v1\"\n";
+    std::fs::write(&seed_path, seeded_config).expect("write seed file");
+
+    let id =
+        ProviderService::seed_snapshot_from_file(&state, AppType::Codex, Some("relay"), &seed_path)
+            .expect("seed snapshot");
+    assert_eq!(id, "relay");
+
+    let providers = ProviderService::list(&state, AppType::Codex).expect("list providers");
+    let relay = &providers["relay"].settings_config;
+    assert_eq!(relay["config"], json!(seeded_config));
+    assert_eq!(relay["auth"]["OPENAI_API_KEY"], json!("sk-relay"));
+    assert_eq!(
+        ProviderService::current(&state, AppType::Codex).expect("current"),
+        "main"
+    );
+    assert!(
+        !home.join(".codex").exists(),
+        "seeding must not create live files"
+    );
+
+    let bad_path = home.join("seed-bad.txt");
+    std::fs::write(&bad_path, "not = [valid").expect("write bad seed file");
+    assert!(
+        ProviderService::seed_snapshot_from_file(&state, AppType::Codex, None, &bad_path).is_err()
+    );
+}
+
+#[test]
+fn provider_from_live_creates_claude_provider_alongside_existing_ones() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let settings_path = get_claude_settings_path();
+    std::fs::create_dir_all(settings_path.parent().expect("claude dir")).expect("create dir");
+    let live = json!({
+        "env": {
+            "ANTHROPIC_BASE_URL": "https://live.example.com",
+            "ANTHROPIC_AUTH_TOKEN": "sk-live-token"
+        }
+    });
+    std::fs::write(
+        &settings_path,
+        serde_json::to_string_pretty(&live).expect("serialize live"),
+    )
+    .expect("seed claude live");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "existing".to_string();
+        manager.providers.insert(
+            "existing".to_string(),
+            Provider::with_id(
+                "existing".to_string(),
+                "Existing".to_string(),
+                json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "sk-existing" } }),
+                None,
+            ),
+        );
+    }
+    let state = state_from_config(config);
+
+    let provider = ProviderService::provider_from_live(
+        &AppType::Claude,
+        &AppType::Claude,
+        "live-copy".to_string(),
+        "Live Copy".to_string(),
+    )
+    .expect("build provider from live");
+    ProviderService::add(&state, AppType::Claude, provider).expect("add provider");
+
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list providers");
+    assert_eq!(providers.len(), 2);
+    assert_eq!(providers["live-copy"].settings_config, live);
+    assert_eq!(
+        ProviderService::current(&state, AppType::Claude).expect("current"),
+        "existing"
+    );
+
+    let codex = ProviderService::provider_from_live(
+        &AppType::Claude,
+        &AppType::Codex,
+        "live-codex".to_string(),
+        "Live Codex".to_string(),
+    )
+    .expect("convert live config to codex");
+    assert_eq!(
+        codex.settings_config["auth"]["OPENAI_API_KEY"],
+        json!("sk-live-token")
+    );
+    assert!(codex.settings_config["config"]
+        .as_str()
+        .is_some_and(|text| text.contains("https://live.example.com")));
+}
+
 #[test]
 fn provider_service_switch_claude_updates_live_and_state() {
     let _guard = lock_test_mutex();
@@ -578,8 +823,8 @@ fn provider_service_switch_missing_provider_returns_error() {
     let err = ProviderService::switch(&state, AppType::Claude, "missing")
         .expect_err("switching missing provider should fail");
     match err {
-        AppError::Localized { key, .. } => assert_eq!(key, "provider.not_found"),
-        other => panic!("expected Localized error for provider not found, got {other:?}"),
+        AppError::ProviderNotFound { id } => assert_eq!(id, "missing"),
+        other => panic!("expected ProviderNotFound error, got {other:?}"),
     }
 }
 
@@ -1077,3 +1322,1120 @@ fn provider_service_delete_current_provider_returns_error() {
         other => panic!("expected Config error, got {other:?}"),
     }
 }
+
+#[test]
+fn provider_service_export_split_writes_one_file_per_provider() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        for id in ["work", "team/a", "team?a"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    format!("Provider {id}"),
+                    json!({
+                        "env": {
+                            "ANTHROPIC_AUTH_TOKEN": "sk-secret",
+                            "ANTHROPIC_BASE_URL": "https://api.example.com"
+                        }
+                    }),
+                    None,
+                ),
+            );
+        }
+    }
+    let state = state_from_config(config);
+
+    let dir = home.join("provider-export");
+    let written = ProviderService::export_split(&state, AppType::Claude, &dir, true)
+        .expect("export providers");
+
+    let names: Vec<String> = written
+        .iter()
+        .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+        .collect();
+    assert_eq!(names, vec!["work.json", "team_a.json", "team_a-2.json"]);
+
+    let exported: Provider = read_json_file(&dir.join("team_a-2.json")).expect("read export");
+    assert_eq!(exported.id, "team?a");
+    assert_eq!(
+        exported.settings_config["env"]["ANTHROPIC_AUTH_TOKEN"],
+        json!("<redacted>")
+    );
+    assert_eq!(
+        exported.settings_config["env"]["ANTHROPIC_BASE_URL"],
+        json!("https://api.example.com")
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn provider_notes_template_defaults_empty_and_blank_clears() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    assert_eq!(get_provider_notes_template(), None);
+
+    set_provider_notes_template(Some("owner: , tier: , limit: ".to_string()))
+        .expect("save template");
+    assert_eq!(
+        get_provider_notes_template().as_deref(),
+        Some("owner: , tier: , limit: ")
+    );
+
+    set_provider_notes_template(Some("   ".to_string())).expect("blank clears");
+    assert_eq!(get_provider_notes_template(), None);
+}
+
+#[test]
+fn provider_service_export_split_applies_custom_redaction_patterns() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let err = set_redaction_patterns(vec!["re:(".to_string()]).expect_err("invalid regex");
+    assert!(err.to_string().contains("re:("), "unexpected error: {err}");
+    set_redaction_patterns(vec!["*_ORG_ID".to_string()]).expect("save patterns");
+
+    let mut config = MultiAppConfig::default();
+    config
+        .get_manager_mut(&AppType::Claude)
+        .expect("claude manager")
+        .providers
+        .insert(
+            "work".to_string(),
+            Provider::with_id(
+                "work".to_string(),
+                "Work".to_string(),
+                json!({
+                    "env": {
+                        "ANTHROPIC_AUTH_TOKEN": "sk-secret",
+                        "ANTHROPIC_ORG_ID": "org-123",
+                        "ANTHROPIC_MODEL": "claude-x"
+                    }
+                }),
+                None,
+            ),
+        );
+    let state = state_from_config(config);
+
+    let dir = home.join("provider-export-patterns");
+    ProviderService::export_split(&state, AppType::Claude, &dir, true).expect("export providers");
+    let exported: Provider = read_json_file(&dir.join("work.json")).expect("read export");
+    let env = &exported.settings_config["env"];
+    assert_eq!(env["ANTHROPIC_ORG_ID"], json!("<redacted>"));
+    assert_eq!(env["ANTHROPIC_AUTH_TOKEN"], json!("<redacted>"));
+    assert_eq!(env["ANTHROPIC_MODEL"], json!("claude-x"));
+
+    let _ = std::fs::remove_dir_all(&dir);
+    reset_test_fs();
+}
+
+#[test]
+fn provider_service_migrates_legacy_claude_settings_files() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let existing_settings = json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "sk-existing" } });
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.providers.insert(
+            "existing".to_string(),
+            Provider::with_id(
+                "existing".to_string(),
+                "Existing".to_string(),
+                existing_settings.clone(),
+                None,
+            ),
+        );
+        manager.current = "existing".to_string();
+    }
+    let state = state_from_config(config);
+
+    let claude_dir = home.join(".claude");
+    std::fs::create_dir_all(&claude_dir).expect("create claude dir");
+    let write = |name: &str, value: &serde_json::Value| {
+        std::fs::write(claude_dir.join(name), value.to_string()).expect("write legacy file");
+    };
+    write(
+        "settings-work.json",
+        &json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "sk-work" } }),
+    );
+    write(
+        "settings-team.json",
+        &json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "sk-team" } }),
+    );
+    write("settings-old.json", &existing_settings);
+    write("settings.json", &json!({ "env": {} }));
+
+    let results =
+        ProviderService::migrate_legacy_claude_files(&state, true).expect("migrate legacy files");
+    let outcomes: Vec<LegacyImportOutcome> = results.iter().map(|r| r.outcome.clone()).collect();
+    assert_eq!(
+        outcomes,
+        vec![
+            LegacyImportOutcome::Duplicate("existing".to_string()),
+            LegacyImportOutcome::Imported("team".to_string()),
+            LegacyImportOutcome::Imported("work".to_string()),
+        ]
+    );
+    assert!(results.iter().all(|r| r.removed && !r.path.exists()));
+    assert!(claude_dir.join("settings.json").exists());
+
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list providers");
+    assert_eq!(providers.len(), 3);
+    assert_eq!(
+        providers["work"].settings_config["env"]["ANTHROPIC_AUTH_TOKEN"],
+        json!("sk-work")
+    );
+    assert_eq!(
+        ProviderService::current(&state, AppType::Claude).expect("current"),
+        "existing"
+    );
+}
+
+#[test]
+fn convert_claude_provider_into_codex_config() {
+    let mut source = Provider::with_id(
+        "relay".to_string(),
+        "My Relay".to_string(),
+        json!({
+            "env": {
+                "ANTHROPIC_BASE_URL": "https://relay.example.com/",
+                "ANTHROPIC_AUTH_TOKEN": "sk-relay",
+                "ANTHROPIC_MODEL": "claude-sonnet"
+            }
+        }),
+        Some("https://relay.example.com".to_string()),
+    );
+    source.notes = Some("shared account".to_string());
+
+    let converted = ProviderService::convert_provider(
+        &source,
+        &AppType::Claude,
+        &AppType::Codex,
+        "my-relay".to_string(),
+    )
+    .expect("convert claude provider to codex");
+
+    assert_eq!(converted.id, "my-relay");
+    assert_eq!(converted.name, "My Relay");
+    assert_eq!(
+        converted.website_url.as_deref(),
+        Some("https://relay.example.com")
+    );
+    assert_eq!(converted.notes.as_deref(), Some("shared account"));
+    assert_eq!(
+        converted.settings_config["auth"]["OPENAI_API_KEY"],
+        json!("sk-relay")
+    );
+
+    let config_text = converted.settings_config["config"]
+        .as_str()
+        .expect("codex config text");
+    let config: toml::Value = toml::from_str(config_text).expect("valid config.toml");
+    assert_eq!(config["model_provider"].as_str(), Some("my_relay"));
+    let section = &config["model_providers"]["my_relay"];
+    assert_eq!(
+        section["base_url"].as_str(),
+        Some("https://relay.example.com")
+    );
+    assert_eq!(section["wire_api"].as_str(), Some("responses"));
+    // 模型无法跨应用映射，使用 Codex 默认值
+    assert_ne!(config["model"].as_str(), Some("claude-sonnet"));
+
+    let missing_key = Provider::with_id(
+        "no-key".to_string(),
+        "No Key".to_string(),
+        json!({ "env": { "ANTHROPIC_BASE_URL": "https://relay.example.com" } }),
+        None,
+    );
+    let err = ProviderService::convert_provider(
+        &missing_key,
+        &AppType::Claude,
+        &AppType::Codex,
+        "no-key".to_string(),
+    )
+    .expect_err("missing api key");
+    assert_eq!(err.code(), "provider.convert.api_key_missing");
+}
+
+#[test]
+fn move_provider_converts_settings_and_keeps_metadata() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "active".to_string();
+        manager.providers.insert(
+            "active".to_string(),
+            Provider::with_id(
+                "active".to_string(),
+                "Active".to_string(),
+                json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "sk-active" } }),
+                None,
+            ),
+        );
+        let mut relay = Provider::with_id(
+            "relay".to_string(),
+            "My Relay".to_string(),
+            json!({
+                "env": {
+                    "ANTHROPIC_BASE_URL": "https://relay.example.com",
+                    "ANTHROPIC_AUTH_TOKEN": "sk-relay"
+                }
+            }),
+            None,
+        );
+        relay.notes = Some("shared account".to_string());
+        relay.meta = Some(ProviderMeta {
+            tags: vec!["work".to_string()],
+            ..ProviderMeta::default()
+        });
+        manager.providers.insert("relay".to_string(), relay);
+        manager.providers.insert(
+            "no-key".to_string(),
+            Provider::with_id(
+                "no-key".to_string(),
+                "No Key".to_string(),
+                json!({ "env": {} }),
+                None,
+            ),
+        );
+    }
+    let state = state_from_config(config);
+
+    let outcome = ProviderService::move_provider(&state, AppType::Claude, AppType::Codex, "relay")
+        .expect("move relay");
+    assert!(outcome.settings_converted);
+    let claude = ProviderService::list(&state, AppType::Claude).expect("claude providers");
+    assert!(!claude.contains_key("relay"));
+    let codex = ProviderService::list(&state, AppType::Codex).expect("codex providers");
+    let moved = codex.get("relay").expect("moved provider");
+    assert_eq!(moved.name, "My Relay");
+    assert_eq!(moved.notes.as_deref(), Some("shared account"));
+    assert_eq!(
+        moved.meta.as_ref().map(|meta| meta.tags.clone()),
+        Some(vec!["work".to_string()])
+    );
+    assert_eq!(
+        moved.settings_config["auth"]["OPENAI_API_KEY"],
+        json!("sk-relay")
+    );
+
+    // 无法转换时保留名称并写入空模板
+    let outcome = ProviderService::move_provider(&state, AppType::Claude, AppType::Codex, "no-key")
+        .expect("move provider without credentials");
+    assert!(!outcome.settings_converted);
+    let codex = ProviderService::list(&state, AppType::Codex).expect("codex providers");
+    assert_eq!(codex["no-key"].name, "No Key");
+    assert_eq!(
+        codex["no-key"].settings_config["auth"]["OPENAI_API_KEY"],
+        json!("YOUR_API_KEY")
+    );
+
+    let err = ProviderService::move_provider(&state, AppType::Claude, AppType::Codex, "active")
+        .expect_err("current provider cannot move");
+    assert_eq!(err.code(), "provider.move.current");
+}
+
+#[test]
+fn default_models_are_per_app_and_blank_clears() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    assert_eq!(get_default_model(&AppType::Claude), None);
+    assert_eq!(get_default_model(&AppType::Codex), None);
+
+    set_default_model(&AppType::Codex, Some(" gpt-5.2-codex ".to_string())).expect("save codex");
+    assert_eq!(
+        get_default_model(&AppType::Codex).as_deref(),
+        Some("gpt-5.2-codex")
+    );
+    assert_eq!(
+        get_default_model(&AppType::Claude),
+        None,
+        "defaults are kept per app"
+    );
+
+    set_default_model(&AppType::Codex, Some("   ".to_string())).expect("clear codex");
+    assert_eq!(get_default_model(&AppType::Codex), None);
+}
+
+fn codex_switch_state() -> cc_switch_lib::AppState {
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        manager.current = "old".to_string();
+        for id in ["old", "new"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_string(),
+                    json!({
+                        "auth": { "OPENAI_API_KEY": format!("{id}-key") },
+                        "config": format!("model_provider = \"{id}\"\nmodel = \"gpt-5.2-codex\"\n\n[model_providers.{id}]\nbase_url = \"https://{id}.example/v1\"\n")
+                    }),
+                    None,
+                ),
+            );
+        }
+    }
+    state_from_config(config)
+}
+
+#[test]
+fn switch_codex_skips_common_snippet_extraction_when_disabled() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let live_config = "model_provider = \"old\"\nmodel = \"gpt-5.2-codex\"\nmodel_reasoning_effort = \"high\"\ndisable_response_storage = true\n\n[model_providers.old]\nbase_url = \"https://old.example/v1\"\n";
+    let seed_live = || {
+        write_codex_live_atomic(&json!({ "OPENAI_API_KEY": "old-key" }), Some(live_config))
+            .expect("seed codex live config");
+    };
+
+    seed_live();
+    let state = codex_switch_state();
+    ProviderService::switch(&state, AppType::Codex, "new").expect("switch with extraction");
+    let extracted = state
+        .config
+        .read()
+        .expect("read config")
+        .common_config_snippets
+        .codex
+        .clone()
+        .unwrap_or_default();
+    assert!(
+        extracted.contains("model_reasoning_effort"),
+        "default behavior extracts extra root keys, got: {extracted}"
+    );
+
+    set_no_backfill_extract(true).expect("disable extraction");
+    seed_live();
+    let state = codex_switch_state();
+    let result = ProviderService::switch(&state, AppType::Codex, "new");
+    set_no_backfill_extract(false).expect("restore extraction");
+    result.expect("switch without extraction");
+    assert!(
+        state
+            .config
+            .read()
+            .expect("read config")
+            .common_config_snippets
+            .codex
+            .as_deref()
+            .unwrap_or_default()
+            .is_empty(),
+        "common snippet stays empty when extraction is disabled"
+    );
+}
+
+#[test]
+fn switch_without_snapshot_refresh_keeps_stored_config_byte_identical() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    std::fs::create_dir_all(home.join(".codex")).expect("create codex dir (initialized)");
+
+    // wire_api 缺省，写入 live 时会被补全为 "responses"
+    let authored = json!({
+        "config": "model_provider = \"p1\"\nmodel = \"gpt-5.2-codex\"\n\n[model_providers.p1]\nbase_url = \"https://api.openai.com/v1\"\nrequires_openai_auth = true\n"
+    });
+    let state_with_p1 = || {
+        let mut config = MultiAppConfig::default();
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        manager.current = "p1".to_string();
+        manager.providers.insert(
+            "p1".to_string(),
+            Provider::with_id(
+                "p1".to_string(),
+                "OpenAI Official".to_string(),
+                authored.clone(),
+                None,
+            ),
+        );
+        state_from_config(config)
+    };
+    let stored = |state: &cc_switch_lib::AppState| {
+        serde_json::to_string(
+            &state
+                .config
+                .read()
+                .expect("read config")
+                .get_manager(&AppType::Codex)
+                .expect("codex")
+                .providers["p1"]
+                .settings_config,
+        )
+        .expect("serialize snapshot")
+    };
+
+    let state = state_with_p1();
+    let before = stored(&state);
+    ProviderService::switch_reporting_hooks_with(
+        &state,
+        AppType::Codex,
+        "p1",
+        SwitchOptions {
+            refresh_snapshot: false,
+            ..SwitchOptions::default()
+        },
+    )
+    .expect("switch without refresh");
+    assert_eq!(stored(&state), before, "snapshot must stay as authored");
+    let live_text =
+        std::fs::read_to_string(cc_switch_lib::get_codex_config_path()).expect("read config.toml");
+    assert!(
+        live_text.contains("wire_api"),
+        "live file is still normalized"
+    );
+
+    let state = state_with_p1();
+    ProviderService::switch(&state, AppType::Codex, "p1").expect("switch with refresh");
+    assert_ne!(
+        stored(&state),
+        before,
+        "default refresh picks up the normalized live config"
+    );
+}
+
+#[test]
+fn switch_with_overlay_writes_live_only_and_keeps_stored_provider() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let settings_path = get_claude_settings_path();
+    std::fs::create_dir_all(settings_path.parent().expect("claude dir")).expect("create dir");
+    let base = |token: &str| {
+        json!({
+            "env": {
+                "ANTHROPIC_AUTH_TOKEN": token,
+                "ANTHROPIC_MODEL": "claude-base"
+            }
+        })
+    };
+    std::fs::write(&settings_path, base("sk-token").to_string()).expect("seed claude live");
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "a".to_string();
+        for id in ["a", "b"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(id.to_string(), id.to_uppercase(), base("sk-token"), None),
+            );
+        }
+    }
+    let state = state_from_config(config);
+
+    ProviderService::switch_reporting_hooks_with(
+        &state,
+        AppType::Claude,
+        "b",
+        SwitchOptions {
+            overlay: vec![
+                SettingsPatch::parse("env.ANTHROPIC_MODEL=claude-x").expect("patch"),
+                // 指针形式：键名中的 `.` 不是路径分隔符
+                SettingsPatch::parse("/env/X.TRACE=1").expect("pointer patch"),
+            ],
+            ..SwitchOptions::default()
+        },
+    )
+    .expect("switch with overlay");
+
+    let live: serde_json::Value = read_json_file(&settings_path).expect("read live");
+    assert_eq!(live["env"]["ANTHROPIC_MODEL"], json!("claude-x"));
+    assert_eq!(live["env"]["X.TRACE"], json!("1"));
+    let stored = |id: &str| {
+        ProviderService::list(&state, AppType::Claude).expect("list")[id]
+            .settings_config
+            .clone()
+    };
+    assert_eq!(stored("b")["env"]["ANTHROPIC_MODEL"], json!("claude-base"));
+
+    // 切走时回填 live 也不会把覆盖值写回供应商
+    ProviderService::switch(&state, AppType::Claude, "a").expect("switch back");
+    assert_eq!(stored("b")["env"]["ANTHROPIC_MODEL"], json!("claude-base"));
+    assert!(stored("b")["env"].get("X.TRACE").is_none());
+    let live: serde_json::Value = read_json_file(&settings_path).expect("read live");
+    assert_eq!(live["env"]["ANTHROPIC_MODEL"], json!("claude-base"));
+}
+
+#[test]
+fn refresh_snapshot_after_overlay_switch_keeps_stored_values() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let settings_path = get_claude_settings_path();
+    std::fs::create_dir_all(settings_path.parent().expect("claude dir")).expect("create dir");
+    let base = json!({
+        "env": {
+            "ANTHROPIC_AUTH_TOKEN": "sk-token",
+            "ANTHROPIC_MODEL": "claude-base"
+        }
+    });
+    std::fs::write(&settings_path, base.to_string()).expect("seed claude live");
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "a".to_string();
+        for id in ["a", "b"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(id.to_string(), id.to_uppercase(), base.clone(), None),
+            );
+        }
+    }
+    let state = state_from_config(config);
+
+    ProviderService::switch_reporting_hooks_with(
+        &state,
+        AppType::Claude,
+        "b",
+        SwitchOptions {
+            overlay: vec![SettingsPatch::parse("env.ANTHROPIC_MODEL=claude-x").expect("patch")],
+            ..SwitchOptions::default()
+        },
+    )
+    .expect("switch with overlay");
+
+    // 手动编辑 live 后刷新快照：编辑被保留，覆盖值不会固化
+    let mut live: serde_json::Value = read_json_file(&settings_path).expect("read live");
+    live["env"]["EXTRA"] = json!("hand-edited");
+    std::fs::write(&settings_path, live.to_string()).expect("edit live");
+    ProviderService::refresh_snapshot(&state, AppType::Claude, None).expect("refresh");
+    let stored = |id: &str| {
+        ProviderService::list(&state, AppType::Claude).expect("list")[id]
+            .settings_config
+            .clone()
+    };
+    assert_eq!(stored("b")["env"]["ANTHROPIC_MODEL"], json!("claude-base"));
+    assert_eq!(stored("b")["env"]["EXTRA"], json!("hand-edited"));
+
+    ProviderService::switch(&state, AppType::Claude, "a").expect("switch away");
+    ProviderService::switch(&state, AppType::Claude, "b").expect("switch back");
+    let live: serde_json::Value = read_json_file(&settings_path).expect("read live");
+    assert_eq!(live["env"]["ANTHROPIC_MODEL"], json!("claude-base"));
+}
+
+#[test]
+fn overlay_rejects_codex_config_paths() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        manager.providers.insert(
+            "p1".to_string(),
+            Provider::with_id(
+                "p1".to_string(),
+                "P1".to_string(),
+                json!({ "auth": {}, "config": "model = \"gpt\"\n" }),
+                None,
+            ),
+        );
+    }
+    let state = state_from_config(config);
+
+    let err = ProviderService::switch_reporting_hooks_with(
+        &state,
+        AppType::Codex,
+        "p1",
+        SwitchOptions {
+            overlay: vec![SettingsPatch::parse("config.model=o3").expect("patch")],
+            ..SwitchOptions::default()
+        },
+    )
+    .expect_err("codex config path");
+    assert_eq!(err.code(), "provider.overlay.codex_config");
+    assert_eq!(
+        ProviderService::current(&state, AppType::Codex).expect("current"),
+        ""
+    );
+}
+
+#[test]
+fn favorite_flag_is_stored_in_meta_and_survives_reload() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.providers.insert(
+            "p1".to_string(),
+            Provider::with_id(
+                "p1".to_string(),
+                "P1".to_string(),
+                json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "sk-p1" } }),
+                None,
+            ),
+        );
+    }
+    let state = state_from_config(config);
+
+    ProviderService::set_favorite(&state, AppType::Claude, "p1", true).expect("favorite");
+    let reloaded = cc_switch_lib::AppState::try_new().expect("reload state");
+    let provider = &ProviderService::list(&reloaded, AppType::Claude).expect("list")["p1"];
+    assert!(provider.is_favorite());
+    assert_eq!(
+        provider.meta.as_ref().and_then(|meta| meta.favorite),
+        Some(true)
+    );
+
+    ProviderService::set_favorite(&state, AppType::Claude, "p1", false).expect("unfavorite");
+    assert!(!ProviderService::list(&state, AppType::Claude).expect("list")["p1"].is_favorite());
+    assert!(ProviderService::set_favorite(&state, AppType::Claude, "missing", true).is_err());
+}
+
+#[test]
+fn switch_succeeds_when_previous_live_config_is_corrupt() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    // Claude：settings.json 被截断
+    let settings_path = get_claude_settings_path();
+    std::fs::create_dir_all(settings_path.parent().expect("claude dir")).expect("create dir");
+    std::fs::write(&settings_path, r#"{"env": {"ANTHROPIC_AUTH_TO"#).expect("seed corrupt live");
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "a".to_string();
+        for id in ["a", "b"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_uppercase(),
+                    json!({ "env": { "ANTHROPIC_AUTH_TOKEN": format!("sk-{id}") } }),
+                    None,
+                ),
+            );
+        }
+    }
+    let state = state_from_config(config);
+
+    ProviderService::switch(&state, AppType::Claude, "b").expect("switch despite corrupt live");
+    let live: serde_json::Value = read_json_file(&settings_path).expect("live rewritten");
+    assert_eq!(live["env"]["ANTHROPIC_AUTH_TOKEN"], json!("sk-b"));
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list");
+    assert_eq!(
+        providers["a"].settings_config["env"]["ANTHROPIC_AUTH_TOKEN"],
+        json!("sk-a"),
+        "backfill is skipped, the stored snapshot stays intact"
+    );
+    let corrupt_copy = settings_path.with_file_name("settings.json.corrupt");
+    assert_eq!(
+        std::fs::read_to_string(corrupt_copy).expect("corrupt copy kept"),
+        r#"{"env": {"ANTHROPIC_AUTH_TO"#
+    );
+
+    // Codex：config.toml 被截断
+    let state = codex_switch_state();
+    write_codex_live_atomic(&json!({ "OPENAI_API_KEY": "old-key" }), None)
+        .expect("seed codex auth");
+    std::fs::write(
+        cc_switch_lib::get_codex_config_path(),
+        "[model_providers.old\nbase_url = ",
+    )
+    .expect("seed corrupt config.toml");
+
+    ProviderService::switch(&state, AppType::Codex, "new").expect("switch codex");
+    let live_text =
+        std::fs::read_to_string(cc_switch_lib::get_codex_config_path()).expect("read config.toml");
+    assert!(live_text.contains("https://new.example/v1"));
+    let providers = ProviderService::list(&state, AppType::Codex).expect("list");
+    assert!(providers["old"].settings_config["config"]
+        .as_str()
+        .expect("old config")
+        .contains("https://old.example/v1"));
+}
+
+#[test]
+fn switch_without_backfill_leaves_outgoing_snapshot_untouched() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    // live 可解析但内容是垃圾，默认切换会把它回填进 a 的快照
+    let settings_path = get_claude_settings_path();
+    std::fs::create_dir_all(settings_path.parent().expect("claude dir")).expect("create dir");
+    std::fs::write(
+        &settings_path,
+        r#"{"env": {"ANTHROPIC_AUTH_TOKEN": "garbage"}}"#,
+    )
+    .expect("seed live");
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "a".to_string();
+        for id in ["a", "b"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_uppercase(),
+                    json!({ "env": { "ANTHROPIC_AUTH_TOKEN": format!("sk-{id}") } }),
+                    None,
+                ),
+            );
+        }
+    }
+    let state = state_from_config(config);
+
+    ProviderService::switch_reporting_hooks_with(
+        &state,
+        AppType::Claude,
+        "b",
+        SwitchOptions {
+            backfill: false,
+            ..SwitchOptions::default()
+        },
+    )
+    .expect("switch without backfill");
+    let live: serde_json::Value = read_json_file(&settings_path).expect("live rewritten");
+    assert_eq!(live["env"]["ANTHROPIC_AUTH_TOKEN"], json!("sk-b"));
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list");
+    assert_eq!(
+        providers["a"].settings_config["env"]["ANTHROPIC_AUTH_TOKEN"],
+        json!("sk-a"),
+        "the outgoing provider keeps its stored snapshot"
+    );
+}
+
+#[test]
+fn switch_codex_with_empty_api_key_skips_auth_json_and_warns() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    write_codex_live_atomic(
+        &json!({ "OPENAI_API_KEY": "old-key" }),
+        Some("model_provider = \"old\"\n\n[model_providers.old]\nbase_url = \"https://old.example/v1\"\n"),
+    )
+    .expect("seed codex live");
+    let state = codex_switch_state();
+    state
+        .config
+        .write()
+        .expect("write config")
+        .get_manager_mut(&AppType::Codex)
+        .expect("codex manager")
+        .providers
+        .get_mut("new")
+        .expect("new provider")
+        .settings_config["auth"] = json!({ "OPENAI_API_KEY": "" });
+
+    let outcome =
+        ProviderService::switch_reporting_hooks(&state, AppType::Codex, "new").expect("switch");
+    assert!(outcome.empty_api_key, "an empty key should be reported");
+    assert!(
+        !cc_switch_lib::get_codex_auth_path().exists(),
+        "auth.json with an empty key must not be written"
+    );
+    let live_text =
+        std::fs::read_to_string(cc_switch_lib::get_codex_config_path()).expect("read config.toml");
+    assert!(live_text.contains("https://new.example/v1"));
+}
+
+#[test]
+fn clone_copies_settings_under_fresh_id_without_becoming_current() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        let mut source = Provider::with_id(
+            "work".to_string(),
+            "Work".to_string(),
+            json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "sk-work" } }),
+            None,
+        );
+        source.sort_index = Some(3);
+        source.meta = Some(ProviderMeta {
+            tags: vec!["team".to_string()],
+            last_used_at: Some(1_700_000_000_000),
+            ..ProviderMeta::default()
+        });
+        manager.providers.insert("work".to_string(), source);
+        manager.current = "work".to_string();
+    }
+    let state = state_from_config(config);
+
+    let new_id =
+        ProviderService::clone(&state, AppType::Claude, "work", "Work Backup").expect("clone");
+    assert_eq!(new_id, "work-backup");
+    let again = ProviderService::clone(&state, AppType::Claude, "work", "Work Backup")
+        .expect("clone again");
+    assert_eq!(again, "work-backup-1");
+
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list");
+    let copy = &providers["work-backup"];
+    assert_eq!(copy.name, "Work Backup");
+    assert_eq!(copy.settings_config, providers["work"].settings_config);
+    assert_eq!(copy.sort_index, None);
+    let meta = copy.meta.as_ref().expect("meta copied");
+    assert_eq!(meta.tags, vec!["team".to_string()]);
+    assert_eq!(meta.last_used_at, None);
+    assert_eq!(
+        ProviderService::current(&state, AppType::Claude).expect("current"),
+        "work"
+    );
+
+    let err = ProviderService::clone(&state, AppType::Claude, "missing", "Copy")
+        .expect_err("missing source");
+    assert_eq!(err.code(), "provider.not_found");
+}
+
+#[test]
+fn relative_provider_id_follows_sort_order_and_wraps() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        for (id, sort_index, created_at) in [
+            ("b", Some(1), None),
+            ("a", Some(0), None),
+            ("late", None, Some(20)),
+            ("early", None, Some(10)),
+        ] {
+            let mut provider = Provider::with_id(
+                id.to_string(),
+                id.to_string(),
+                json!({ "env": { "ANTHROPIC_AUTH_TOKEN": format!("sk-{id}") } }),
+                None,
+            );
+            provider.sort_index = sort_index;
+            provider.created_at = created_at;
+            manager.providers.insert(id.to_string(), provider);
+        }
+    }
+    let state = state_from_config(config);
+
+    // 没有当前供应商：--next 取排序中的第一个，--prev 取最后一个
+    assert_eq!(
+        ProviderService::relative_provider_id(&state, AppType::Claude, 1).expect("next"),
+        "a"
+    );
+    assert_eq!(
+        ProviderService::relative_provider_id(&state, AppType::Claude, -1).expect("prev"),
+        "late"
+    );
+
+    ProviderService::switch(&state, AppType::Claude, "early").expect("switch to early");
+    assert_eq!(
+        ProviderService::relative_provider_id(&state, AppType::Claude, 1).expect("next"),
+        "late"
+    );
+    ProviderService::switch(&state, AppType::Claude, "late").expect("switch to late");
+    assert_eq!(
+        ProviderService::relative_provider_id(&state, AppType::Claude, 1).expect("wrap"),
+        "a"
+    );
+    assert_eq!(
+        ProviderService::relative_provider_id(&state, AppType::Claude, -2).expect("back two"),
+        "b"
+    );
+
+    // 当前供应商已归档时不参与排序，与 TUI 列表一致
+    {
+        let mut config = state.config.write().expect("config");
+        let manager = config.get_manager_mut(&AppType::Claude).expect("claude");
+        manager.providers["late"]
+            .meta
+            .get_or_insert_with(Default::default)
+            .archived = Some(true);
+    }
+    assert_eq!(
+        ProviderService::relative_provider_id(&state, AppType::Claude, 1).expect("next"),
+        "a"
+    );
+    assert_eq!(
+        ProviderService::relative_provider_id(&state, AppType::Claude, -1).expect("prev"),
+        "early"
+    );
+}
+
+#[test]
+fn resolve_provider_ref_matches_id_name_or_unique_prefix() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    for app in [AppType::Claude, AppType::Codex] {
+        let manager = config.get_manager_mut(&app).expect("manager");
+        for (id, name) in [
+            ("work", "Work"),
+            ("work-backup", "Work Backup"),
+            ("p-3f9a", "Personal Gmail"),
+        ] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(id.to_string(), name.to_string(), json!({}), None),
+            );
+        }
+    }
+    let state = state_from_config(config);
+
+    for app in [AppType::Claude, AppType::Codex] {
+        let resolve =
+            |query: &str| ProviderService::resolve_provider_ref(&state, app.clone(), query);
+        assert_eq!(resolve("work").expect("exact id"), "work");
+        assert_eq!(resolve("work backup").expect("exact name"), "work-backup");
+        assert_eq!(resolve("pers").expect("name prefix"), "p-3f9a");
+        assert_eq!(resolve("p-3").expect("id prefix"), "p-3f9a");
+
+        let err = resolve("wo").expect_err("ambiguous prefix");
+        assert_eq!(err.code(), "provider.ambiguous");
+        let message = err.to_string();
+        assert!(message.contains("work (Work)"), "{message}");
+        assert!(message.contains("work-backup (Work Backup)"), "{message}");
+
+        assert_eq!(
+            resolve("nothing").expect_err("no match").code(),
+            "provider.not_found"
+        );
+    }
+}
+
+#[test]
+fn resolve_each_app_finds_target_per_app_without_switching() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    for app in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+        let manager = config.get_manager_mut(&app).expect("manager");
+        let mut ids = vec!["custom"];
+        if app != AppType::Gemini {
+            ids.push("default");
+        }
+        // 只有前缀相同的供应商不算命中
+        ids.push("defaults-old");
+        for id in ids {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(id.to_string(), id.to_string(), json!({}), None),
+            );
+        }
+        manager.current = "custom".to_string();
+    }
+    let state = state_from_config(config);
+
+    let results = ProviderService::resolve_each_app(
+        &state,
+        &[AppType::Claude, AppType::Codex, AppType::Gemini],
+        "Default",
+    )
+    .expect("resolve every app");
+    assert_eq!(
+        results,
+        vec![
+            (AppType::Claude, Some("default".to_string())),
+            (AppType::Codex, Some("default".to_string())),
+            (AppType::Gemini, None),
+        ]
+    );
+    for app in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+        assert_eq!(
+            ProviderService::current(&state, app.clone()).expect("current"),
+            "custom",
+            "{app:?}"
+        );
+    }
+
+    // 不做前缀匹配
+    let results = ProviderService::resolve_each_app(&state, &[AppType::Gemini], "defa")
+        .expect("resolve prefix");
+    assert_eq!(results, vec![(AppType::Gemini, None)]);
+
+    // 任一应用同名供应商有多个时整体失败
+    {
+        let mut config = state.config.write().expect("config");
+        let manager = config.get_manager_mut(&AppType::Claude).expect("claude");
+        manager.providers.get_mut("custom").expect("custom").name = "Default".to_string();
+    }
+    let err =
+        ProviderService::resolve_each_app(&state, &[AppType::Codex, AppType::Claude], "DEFAULT")
+            .expect_err("ambiguous in claude");
+    assert_eq!(err.code(), "provider.ambiguous");
+}
+
+#[test]
+fn missing_provider_errors_carry_provider_id_in_json() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let state = state_from_config(MultiAppConfig::default());
+    let errors = [
+        ProviderService::update_tags(&state, AppType::Claude, "ghost", &[], &[])
+            .expect_err("tags")
+            .to_json(),
+        ProviderService::rename_id(&state, AppType::Claude, "ghost", "other")
+            .expect_err("rename")
+            .to_json(),
+        ProviderService::clone(&state, AppType::Claude, "ghost", "Copy")
+            .expect_err("clone")
+            .to_json(),
+    ];
+    for value in errors {
+        assert_eq!(value["code"], json!("provider.not_found"), "{value}");
+        assert_eq!(value["providerId"], json!("ghost"), "{value}");
+    }
+}
@@ -0,0 +1,414 @@
+use clap::Parser;
+use serde_json::{json, Value};
+
+use cc_switch_lib::cli::{Cli, Commands};
+use cc_switch_lib::{
+    get_claude_mcp_path, get_claude_settings_path, get_codex_auth_path, get_codex_config_path,
+    read_json_file, AppError, AppType, McpApps, McpServer, MultiAppConfig, Provider, ProviderMeta,
+    ProviderService, SwitchOptions,
+};
+
+#[path = "support.rs"]
+mod support;
+use support::{ensure_test_home, lock_test_mutex, reset_test_fs, state_from_config};
+
+#[test]
+fn switch_records_last_used_at() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    {
+        let manager = config.get_manager_mut(&AppType::Claude).expect("claude");
+        let mut stale = Provider::with_id(
+            "stale".to_string(),
+            "stale".to_string(),
+            json!({ "env": {} }),
+            None,
+        );
+        stale.meta = Some(ProviderMeta {
+            last_used_at: Some(100),
+            ..ProviderMeta::default()
+        });
+        manager.providers.insert("stale".to_string(), stale);
+        manager.providers.insert(
+            "fresh".to_string(),
+            Provider::with_id(
+                "fresh".to_string(),
+                "Fresh".to_string(),
+                json!({ "env": {} }),
+                None,
+            ),
+        );
+        manager.current = "stale".to_string();
+    }
+    let state = state_from_config(config);
+
+    let before = chrono::Utc::now().timestamp();
+    ProviderService::switch(&state, AppType::Claude, "fresh").expect("switch to fresh");
+
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list");
+    let fresh_used = providers["fresh"].last_used_at().expect("fresh last used");
+    assert!(fresh_used >= before);
+    assert!(fresh_used > providers["stale"].last_used_at().expect("stale last used"));
+
+    // 旧数据缺少 lastUsedAt 字段时仍可解析
+    let legacy: ProviderMeta =
+        serde_json::from_value(json!({ "tags": ["a"] })).expect("parse legacy meta");
+    assert_eq!(legacy.last_used_at, None);
+}
+
+#[test]
+fn switch_warns_about_failed_mcp_server_unless_strict() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    std::fs::create_dir_all(home.join(".claude")).expect("create ~/.claude");
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    {
+        let manager = config.get_manager_mut(&AppType::Claude).expect("claude");
+        for id in ["main", "backup"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(id.to_string(), id.to_string(), json!({ "env": {} }), None),
+            );
+        }
+        manager.current = "main".to_string();
+    }
+    let state = state_from_config(config);
+    state.save().expect("persist providers");
+
+    let server = |id: &str, spec: Value| McpServer {
+        id: id.to_string(),
+        name: id.to_string(),
+        server: spec,
+        apps: McpApps {
+            claude: true,
+            ..McpApps::default()
+        },
+        description: None,
+        homepage: None,
+        docs: None,
+        tags: Vec::new(),
+    };
+    state.config.write().expect("config").mcp.servers = Some(
+        [
+            ("broken", server("broken", json!("not an object"))),
+            ("fetch", server("fetch", json!({ "command": "uvx" }))),
+        ]
+        .into_iter()
+        .map(|(id, server)| (id.to_string(), server))
+        .collect(),
+    );
+
+    let strict_err = ProviderService::switch_reporting_hooks_with(
+        &state,
+        AppType::Claude,
+        "backup",
+        SwitchOptions {
+            strict_mcp: true,
+            ..SwitchOptions::default()
+        },
+    )
+    .expect_err("strict mode rolls back on MCP failure");
+    assert!(matches!(strict_err, AppError::McpValidation(_)));
+    assert_eq!(
+        ProviderService::current(&state, AppType::Claude).expect("current"),
+        "main"
+    );
+
+    let outcome = ProviderService::switch_reporting_hooks(&state, AppType::Claude, "backup")
+        .expect("broken MCP server must not block the switch");
+    assert_eq!(
+        ProviderService::current(&state, AppType::Claude).expect("current"),
+        "backup"
+    );
+    let failed: Vec<&str> = outcome
+        .mcp_failures
+        .iter()
+        .map(|failure| failure.server_id.as_str())
+        .collect();
+    assert_eq!(failed, vec!["broken"]);
+    let live_mcp: Value = read_json_file(&get_claude_mcp_path()).expect("read live MCP servers");
+    assert!(
+        live_mcp["mcpServers"].get("fetch").is_some(),
+        "healthy servers still sync"
+    );
+}
+
+#[test]
+fn refresh_snapshot_picks_up_hand_edited_live_values() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    std::fs::create_dir_all(home.join(".claude")).expect("create ~/.claude");
+
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    {
+        let manager = config.get_manager_mut(&AppType::Claude).expect("claude");
+        for id in ["p1", "p2"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_string(),
+                    json!({ "env": { "ANTHROPIC_AUTH_TOKEN": id } }),
+                    None,
+                ),
+            );
+        }
+        manager.current = "p1".to_string();
+    }
+    let state = state_from_config(config);
+    ProviderService::switch(&state, AppType::Claude, "p1").expect("switch p1");
+
+    let settings_path = get_claude_settings_path();
+    let mut live: Value = read_json_file(&settings_path).expect("read live");
+    live["env"]["ANTHROPIC_MODEL"] = json!("hand-edited-model");
+    std::fs::write(&settings_path, live.to_string()).expect("edit live");
+
+    let err = ProviderService::refresh_snapshot(&state, AppType::Claude, Some("p2"))
+        .expect_err("non-current provider is rejected");
+    assert_eq!(err.code(), "provider.snapshot.not_current");
+
+    let refreshed =
+        ProviderService::refresh_snapshot(&state, AppType::Claude, None).expect("refresh");
+    assert_eq!(refreshed, "p1");
+    let providers = ProviderService::list(&state, AppType::Claude).expect("list");
+    assert_eq!(
+        providers["p1"].settings_config["env"]["ANTHROPIC_MODEL"],
+        json!("hand-edited-model")
+    );
+    assert!(providers["p2"].settings_config["env"]
+        .get("ANTHROPIC_MODEL")
+        .is_none());
+}
+
+#[test]
+fn identify_live_reports_match_and_drift() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    std::fs::create_dir_all(home.join(".claude")).expect("create ~/.claude");
+
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    {
+        let manager = config.get_manager_mut(&AppType::Claude).expect("claude");
+        for (id, url) in [("p1", "https://one.example"), ("p2", "https://two.example")] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_string(),
+                    json!({ "env": { "ANTHROPIC_BASE_URL": url, "ANTHROPIC_MODEL": "m1" } }),
+                    None,
+                ),
+            );
+        }
+        manager.current = "p1".to_string();
+    }
+    let state = state_from_config(config);
+    ProviderService::switch(&state, AppType::Claude, "p1").expect("switch p1");
+
+    let identity = ProviderService::identify_live(&state, AppType::Claude).expect("identify");
+    assert_eq!(identity.matches, vec!["p1".to_string()]);
+    assert!(!ProviderService::current_live_drift(&state, AppType::Claude).expect("drift"));
+    assert_eq!(identity.current, "p1");
+    assert_eq!(
+        identity.live.base_url.as_deref(),
+        Some("https://one.example")
+    );
+
+    let settings_path = get_claude_settings_path();
+    let mut live: Value = read_json_file(&settings_path).expect("read live");
+    live["env"]["ANTHROPIC_MODEL"] = json!("m2");
+    std::fs::write(&settings_path, live.to_string()).expect("edit live");
+
+    let identity = ProviderService::identify_live(&state, AppType::Claude).expect("identify");
+    assert!(identity.matches.is_empty(), "edited model should drift");
+    assert!(ProviderService::current_live_drift(&state, AppType::Claude).expect("drift"));
+    assert_eq!(identity.same_endpoint, vec!["p1".to_string()]);
+}
+
+#[test]
+fn claude_custom_headers_survive_switch_round_trip() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    std::fs::create_dir_all(home.join(".claude")).expect("create ~/.claude");
+
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    let state = state_from_config(config);
+
+    let p1 = Provider::with_id(
+        "p1".to_string(),
+        "First".to_string(),
+        json!({
+            "env": { "ANTHROPIC_AUTH_TOKEN": "token1" },
+            "customHeaders": { "X-Gateway-Tenant": "team-a" }
+        }),
+        None,
+    );
+    let p2 = Provider::with_id(
+        "p2".to_string(),
+        "Second".to_string(),
+        json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "token2" } }),
+        None,
+    );
+
+    ProviderService::add(&state, AppType::Claude, p1).expect("add p1");
+    ProviderService::add(&state, AppType::Claude, p2).expect("add p2");
+
+    let live: Value = read_json_file(&get_claude_settings_path()).expect("read live");
+    assert!(live.get("customHeaders").is_none());
+    assert_eq!(
+        live["env"]["ANTHROPIC_CUSTOM_HEADERS"],
+        json!("X-Gateway-Tenant: team-a")
+    );
+
+    ProviderService::switch(&state, AppType::Claude, "p2").expect("switch to p2");
+
+    let live: Value = read_json_file(&get_claude_settings_path()).expect("read live");
+    assert!(live["env"].get("ANTHROPIC_CUSTOM_HEADERS").is_none());
+
+    let cfg = state.config.read().expect("read config");
+    let p1_after = &cfg.get_manager(&AppType::Claude).expect("claude").providers["p1"];
+    assert_eq!(
+        p1_after.settings_config["customHeaders"],
+        json!({ "X-Gateway-Tenant": "team-a" })
+    );
+    assert!(p1_after.settings_config["env"]
+        .get("ANTHROPIC_CUSTOM_HEADERS")
+        .is_none());
+}
+
+#[test]
+fn claude_provider_with_invalid_header_name_is_rejected() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let mut config = MultiAppConfig::default();
+    config.ensure_app(&AppType::Claude);
+    let state = state_from_config(config);
+
+    let provider = Provider::with_id(
+        "p1".to_string(),
+        "First".to_string(),
+        json!({
+            "env": { "ANTHROPIC_AUTH_TOKEN": "token" },
+            "customHeaders": { "Bad Header": "x" }
+        }),
+        None,
+    );
+
+    let err = ProviderService::add(&state, AppType::Claude, provider)
+        .expect_err("invalid header name should be rejected");
+    assert!(err.to_string().contains("Bad Header"));
+}
+
+/// Claude、Codex 都有 `default`，Gemini 只有 `defaults-old`；当前均为 `custom`
+fn all_apps_state() -> cc_switch_lib::AppState {
+    let mut config = MultiAppConfig::default();
+    for app in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+        config.ensure_app(&app);
+        let manager = config.get_manager_mut(&app).expect("manager");
+        let ids: &[&str] = match app {
+            AppType::Gemini => &["custom", "defaults-old"],
+            _ => &["custom", "default"],
+        };
+        for id in ids {
+            let settings = match app {
+                AppType::Codex => json!({
+                    "auth": { "OPENAI_API_KEY": format!("sk-{id}") },
+                    "config": "model = \"gpt\"\n"
+                }),
+                _ => json!({ "env": { "ANTHROPIC_AUTH_TOKEN": format!("sk-{id}") } }),
+            };
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(id.to_string(), id.to_string(), settings, None),
+            );
+        }
+        manager.current = "custom".to_string();
+    }
+    let state = state_from_config(config);
+    state.save().expect("persist providers");
+    state
+}
+
+fn run_cli(args: &[&str]) -> Result<(), AppError> {
+    let cli = Cli::try_parse_from(std::iter::once("cc-switch").chain(args.iter().copied()))
+        .expect("parse cli");
+    let Some(Commands::Provider(cmd)) = cli.command else {
+        panic!("expected provider command");
+    };
+    cc_switch_lib::cli::commands::provider::execute(cmd, cli.app, cli.verbose)
+}
+
+fn current(app: AppType) -> String {
+    let state = cc_switch_lib::AppState::try_new().expect("reload state");
+    ProviderService::current(&state, app).expect("current")
+}
+
+#[test]
+fn switch_all_apps_switches_exact_matches_and_skips_the_rest() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    std::fs::create_dir_all(home.join(".claude")).expect("claude dir");
+    std::fs::create_dir_all(home.join(".codex")).expect("codex dir");
+    all_apps_state();
+
+    run_cli(&["--app", "all", "provider", "switch", "--id", "default"]).expect("switch all");
+
+    assert_eq!(current(AppType::Claude), "default");
+    assert_eq!(current(AppType::Codex), "default");
+    // 前缀相同的 `defaults-old` 不算命中
+    assert_eq!(current(AppType::Gemini), "custom");
+
+    let live: Value = read_json_file(&get_claude_settings_path()).expect("claude live");
+    assert_eq!(live["env"]["ANTHROPIC_AUTH_TOKEN"], json!("sk-default"));
+    let auth: Value = read_json_file(&get_codex_auth_path()).expect("codex auth");
+    assert_eq!(auth["OPENAI_API_KEY"], json!("sk-default"));
+    assert!(!home.join(".gemini").exists());
+}
+
+#[test]
+fn switch_all_apps_checks_unmanaged_codex_mcp_before_switching_any_app() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    std::fs::create_dir_all(home.join(".claude")).expect("claude dir");
+    std::fs::create_dir_all(home.join(".codex")).expect("codex dir");
+    std::fs::write(
+        get_codex_config_path(),
+        "[mcp_servers.legacy]\ncommand = \"echo\"\n",
+    )
+    .expect("seed codex config");
+    all_apps_state();
+
+    let err = run_cli(&["--app", "all", "provider", "switch", "--id", "default"])
+        .expect_err("unmanaged codex mcp");
+    assert_eq!(err.code(), "provider.switch.unmanaged_mcp");
+    // Claude 排在 Codex 之前，但检查先于任何切换
+    assert_eq!(current(AppType::Claude), "custom");
+    assert_eq!(current(AppType::Codex), "custom");
+
+    run_cli(&[
+        "--app",
+        "all",
+        "provider",
+        "switch",
+        "--id",
+        "default",
+        "--confirm",
+    ])
+    .expect("switch all with --confirm");
+    assert_eq!(current(AppType::Claude), "default");
+    assert_eq!(current(AppType::Codex), "default");
+}
@@ -1,4 +1,6 @@
-use cc_switch_lib::{Database, SkillService};
+use cc_switch_lib::{
+    AppType, Database, InstalledSkill, SkillApps, SkillService, SkillUpdateAction,
+};
 
 #[path = "support.rs"]
 mod support;
@@ -221,3 +223,128 @@ fn pending_migration_with_existing_managed_list_does_not_claim_unmanaged_skills(
         "unmanaged skill should remain unmanaged (not added to db)"
     );
 }
+
+#[test]
+fn list_installed_filtered_returns_only_skills_enabled_for_app() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let claude_dir = home.join(".claude").join("skills");
+    write_skill_md(&claude_dir.join("alpha-skill"), "Alpha Skill", "Alpha");
+    write_skill_md(&claude_dir.join("beta-skill"), "Beta Skill", "Beta");
+    write_skill_md(&claude_dir.join("gamma-tool"), "Gamma Tool", "Gamma");
+    SkillService::import_from_apps(vec![
+        "alpha-skill".to_string(),
+        "beta-skill".to_string(),
+        "gamma-tool".to_string(),
+    ])
+    .expect("import skills from apps");
+
+    SkillService::toggle_app("alpha-skill", &AppType::Codex, true).expect("enable alpha for codex");
+    SkillService::toggle_app("gamma-tool", &AppType::Codex, true).expect("enable gamma for codex");
+
+    let codex_enabled = SkillService::list_installed_filtered(Some(&AppType::Codex), true, None)
+        .expect("list codex-enabled skills");
+    let directories: Vec<&str> = codex_enabled.iter().map(|s| s.directory.as_str()).collect();
+    assert_eq!(directories, vec!["alpha-skill", "gamma-tool"]);
+
+    let codex_named =
+        SkillService::list_installed_filtered(Some(&AppType::Codex), true, Some("SKILL"))
+            .expect("list codex-enabled skills by name");
+    let directories: Vec<&str> = codex_named.iter().map(|s| s.directory.as_str()).collect();
+    assert_eq!(directories, vec!["alpha-skill"]);
+
+    let all_for_codex = SkillService::list_installed_filtered(Some(&AppType::Codex), false, None)
+        .expect("list all skills");
+    assert_eq!(all_for_codex.len(), 3);
+}
+
+#[test]
+fn set_apps_enabled_toggles_several_apps_and_reports_changes() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let claude_dir = home.join(".claude").join("skills");
+    write_skill_md(&claude_dir.join("multi-skill"), "Multi Skill", "Multi");
+    SkillService::import_from_apps(vec!["multi-skill".to_string()]).expect("import skill");
+
+    let changed = SkillService::set_apps_enabled(
+        "multi-skill",
+        &[AppType::Claude, AppType::Codex, AppType::Gemini],
+        true,
+    )
+    .expect("enable for several apps");
+    assert_eq!(changed, vec![AppType::Codex, AppType::Gemini]);
+
+    for app in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+        let enabled = SkillService::list_installed_filtered(Some(&app), true, None)
+            .expect("list enabled skills");
+        assert_eq!(enabled.len(), 1, "skill should be enabled for {app:?}");
+    }
+
+    let changed = SkillService::set_apps_enabled("multi-skill", &[AppType::Codex], true)
+        .expect("enable again");
+    assert!(changed.is_empty(), "already-enabled apps are not reported");
+
+    let err = SkillService::set_apps_enabled("missing-skill", &[AppType::Codex], true)
+        .expect_err("unknown skill is rejected");
+    assert!(err.to_string().contains("missing-skill"));
+}
+
+#[test]
+fn update_plan_skips_pinned_skills_unless_forced() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let claude_dir = home.join(".claude").join("skills");
+    write_skill_md(&claude_dir.join("local-skill"), "Local Skill", "Local");
+    SkillService::import_from_apps(vec!["local-skill".to_string()]).expect("import skill");
+
+    let db = Database::init().expect("init db");
+    for (directory, pinned_ref) in [("pinned-skill", Some("v1.2.0")), ("tracking-skill", None)] {
+        db.save_skill(&InstalledSkill {
+            id: format!("acme/skills:{directory}"),
+            name: directory.to_string(),
+            description: None,
+            directory: directory.to_string(),
+            repo_owner: Some("acme".to_string()),
+            repo_name: Some("skills".to_string()),
+            repo_branch: Some("main".to_string()),
+            pinned_ref: pinned_ref.map(str::to_string),
+            readme_url: None,
+            apps: SkillApps::only(&AppType::Claude),
+            installed_at: 1,
+        })
+        .expect("save skill");
+    }
+
+    let stored = db
+        .get_installed_skill("acme/skills:pinned-skill")
+        .expect("read skill")
+        .expect("skill exists");
+    assert_eq!(stored.pinned_ref.as_deref(), Some("v1.2.0"));
+
+    let plan = SkillService::plan_update(None, false).expect("plan update");
+    assert_eq!(
+        plan,
+        vec![
+            ("local-skill".to_string(), SkillUpdateAction::NoSource),
+            (
+                "pinned-skill".to_string(),
+                SkillUpdateAction::Pinned("v1.2.0".to_string())
+            ),
+            ("tracking-skill".to_string(), SkillUpdateAction::Update),
+        ]
+    );
+
+    let forced = SkillService::plan_update(Some("pinned-skill"), true).expect("plan forced");
+    assert_eq!(
+        forced,
+        vec![("pinned-skill".to_string(), SkillUpdateAction::Update)]
+    );
+
+    assert!(SkillService::plan_update(Some("missing-skill"), false).is_err());
+}
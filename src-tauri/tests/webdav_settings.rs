@@ -16,6 +16,7 @@ fn sample_settings() -> WebDavSyncSettings {
         username: "user@example.com".to_string(),
         password: "app-password".to_string(),
         auto_sync: false,
+        exclude_history: false,
         status: WebDavSyncStatus::default(),
     }
 }